@@ -264,8 +264,9 @@ impl Wallet {
             .await?;
 
         // check status of contract deployment
-        if let TransactionStatus::Failure { .. } | TransactionStatus::SqueezedOut { .. } =
-            &status
+        if let TransactionStatus::Failure { .. }
+        | TransactionStatus::SqueezedOut { .. }
+        | TransactionStatus::Replaced { .. } = &status
         {
             return Err(anyhow!(format!("unexpected transaction status {status:?}")));
         }
@@ -0,0 +1,97 @@
+//! Subcommands of the CLI.
+
+use crate::output::Printer;
+use fuel_core_client::client::{
+    FuelClient,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+};
+
+mod balance;
+mod block;
+mod coin;
+mod contract;
+mod dap;
+mod message;
+mod node;
+mod transaction;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Submit and query transactions.
+    #[clap(subcommand)]
+    Transaction(transaction::Command),
+    /// Query blocks.
+    #[clap(subcommand)]
+    Block(block::Command),
+    /// Query balances of an owner.
+    #[clap(subcommand)]
+    Balance(balance::Command),
+    /// Query coins of an owner.
+    #[clap(subcommand)]
+    Coin(coin::Command),
+    /// Query messages and their proofs.
+    #[clap(subcommand)]
+    Message(message::Command),
+    /// Query balances and storage of a contract.
+    #[clap(subcommand)]
+    Contract(contract::Command),
+    /// Information about the chain.
+    #[clap(subcommand)]
+    Chain(node::ChainCommand),
+    /// Information about the node and its peers.
+    #[clap(subcommand)]
+    Node(node::NodeCommand),
+    /// Gas price estimations.
+    #[clap(subcommand)]
+    GasPrice(node::GasPriceCommand),
+    /// Serve the Debug Adapter Protocol for debugging transactions on the node.
+    Dap(dap::Command),
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Transaction(command) => command.exec(client, printer).await,
+            Command::Block(command) => command.exec(client, printer).await,
+            Command::Balance(command) => command.exec(client, printer).await,
+            Command::Coin(command) => command.exec(client, printer).await,
+            Command::Message(command) => command.exec(client, printer).await,
+            Command::Contract(command) => command.exec(client, printer).await,
+            Command::Chain(command) => command.exec(client, printer).await,
+            Command::Node(command) => command.exec(client, printer).await,
+            Command::GasPrice(command) => command.exec(client, printer).await,
+            Command::Dap(command) => command.exec(client).await,
+        }
+    }
+}
+
+/// Pagination arguments shared by the commands that return a list of results.
+#[derive(Debug, Clone, clap::Args)]
+pub struct PaginationArgs {
+    /// The cursor returned with the previous page.
+    #[clap(long)]
+    pub cursor: Option<String>,
+    /// The number of results in the page.
+    #[clap(long, default_value = "10")]
+    pub results: i32,
+    /// Iterate over results in the backward direction.
+    #[clap(long)]
+    pub backward: bool,
+}
+
+impl From<PaginationArgs> for PaginationRequest<String> {
+    fn from(args: PaginationArgs) -> Self {
+        PaginationRequest {
+            cursor: args.cursor,
+            results: args.results,
+            direction: if args.backward {
+                PageDirection::Backward
+            } else {
+                PageDirection::Forward
+            },
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    commands::PaginationArgs,
+    output::{
+        Printer,
+        hex,
+    },
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::Balance,
+};
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the balance of the owner for the asset.
+    Get {
+        /// The owner of the balance.
+        #[clap(long)]
+        owner: Address,
+        /// The asset id. The base asset is used if not specified.
+        #[clap(long)]
+        asset_id: Option<AssetId>,
+    },
+    /// Get a page of balances of the owner.
+    List {
+        /// The owner of the balances.
+        #[clap(long)]
+        owner: Address,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Get { owner, asset_id } => {
+                let amount = client.balance(&owner, asset_id.as_ref()).await?;
+                printer.print_one(json!({
+                    "owner": hex(owner.as_ref()),
+                    "asset_id": asset_id.map(|asset_id| hex(asset_id.as_ref())),
+                    // The balance doesn't fit into the JSON number.
+                    "amount": amount.to_string(),
+                }))
+            }
+            Command::List { owner, pagination } => {
+                let balances = client.balances(&owner, pagination.into()).await?;
+                printer.print_page(balances, balance_record)
+            }
+        }
+    }
+}
+
+fn balance_record(balance: &Balance) -> Value {
+    json!({
+        "owner": hex(balance.owner.as_ref()),
+        "asset_id": hex(balance.asset_id.as_ref()),
+        "amount": balance.amount.to_string(),
+    })
+}
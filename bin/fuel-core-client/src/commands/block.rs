@@ -0,0 +1,129 @@
+use crate::{
+    commands::PaginationArgs,
+    output::{
+        Printer,
+        hex,
+    },
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    FuelClient,
+    types::{
+        Block,
+        block::{
+            Consensus,
+            Header,
+        },
+        primitives::BlockId,
+    },
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get a block by its height or id.
+    Get {
+        /// The height of the block.
+        #[clap(long, conflicts_with = "id", required_unless_present = "id")]
+        height: Option<u32>,
+        /// The id of the block.
+        #[clap(long)]
+        id: Option<BlockId>,
+    },
+    /// Get all blocks in the inclusive range of heights.
+    Range {
+        /// The height of the first block.
+        #[clap(long)]
+        from: u32,
+        /// The height of the last block.
+        #[clap(long)]
+        to: u32,
+    },
+    /// Get a page of blocks.
+    List {
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Get { height, id } => {
+                let block = match (height, id) {
+                    (Some(height), _) => block_by_height(client, height).await?,
+                    (None, Some(id)) => client
+                        .block(&id)
+                        .await?
+                        .with_context(|| format!("block {id} not found"))?,
+                    (None, None) => {
+                        anyhow::bail!("either `--height` or `--id` is required")
+                    }
+                };
+                printer.print_one(block_record(&block))
+            }
+            Command::Range { from, to } => {
+                anyhow::ensure!(from <= to, "`--from` must not be greater than `--to`");
+                let mut blocks = Vec::new();
+                for height in from..=to {
+                    let block = block_by_height(client, height).await?;
+                    blocks.push(block_record(&block));
+                }
+                printer.print_list(blocks)
+            }
+            Command::List { pagination } => {
+                let blocks = client.blocks(pagination.into()).await?;
+                printer.print_page(blocks, block_record)
+            }
+        }
+    }
+}
+
+async fn block_by_height(client: &FuelClient, height: u32) -> anyhow::Result<Block> {
+    client
+        .block_by_height(BlockHeight::new(height))
+        .await?
+        .with_context(|| format!("block at height {height} not found"))
+}
+
+pub(crate) fn block_record(block: &Block) -> Value {
+    let consensus = match &block.consensus {
+        Consensus::Genesis(_) => "genesis",
+        Consensus::PoAConsensus(_) => "poa",
+        Consensus::BftConsensus(_) => "bft",
+        Consensus::DaDerivedConsensus => "da-derived",
+        Consensus::Unknown => "unknown",
+    };
+    json!({
+        "id": hex(block.id.as_ref()),
+        "height": block.header.height,
+        "time": block.header.time.to_unix(),
+        "da_height": block.header.da_height,
+        "transactions_count": block.header.transactions_count,
+        "consensus": consensus,
+        "producer": block.block_producer().map(|producer| hex(producer.as_ref())),
+        "transactions": block
+            .transactions
+            .iter()
+            .map(|id| hex(id.as_ref()))
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub(crate) fn header_record(header: &Header) -> Value {
+    json!({
+        "id": hex(header.id.as_ref()),
+        "height": header.height,
+        "time": header.time.to_unix(),
+        "da_height": header.da_height,
+        "prev_root": hex(header.prev_root.as_ref()),
+        "application_hash": hex(header.application_hash.as_ref()),
+        "transactions_root": hex(header.transactions_root.as_ref()),
+        "message_outbox_root": hex(header.message_outbox_root.as_ref()),
+        "event_inbox_root": hex(header.event_inbox_root.as_ref()),
+    })
+}
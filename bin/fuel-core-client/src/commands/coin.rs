@@ -0,0 +1,216 @@
+use crate::{
+    commands::PaginationArgs,
+    output::{
+        Printer,
+        hex,
+    },
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    FuelClient,
+    types::{
+        Coin,
+        CoinType,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::UtxoId,
+    fuel_types::{
+        Address,
+        AssetId,
+        Nonce,
+    },
+};
+use serde_json::{
+    Value,
+    json,
+};
+use std::str::FromStr;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get a coin by its UTXO id.
+    Get {
+        /// The UTXO id of the coin.
+        utxo_id: UtxoId,
+    },
+    /// Get a page of coins of the owner.
+    List {
+        /// The owner of the coins.
+        #[clap(long)]
+        owner: Address,
+        /// Return only coins of this asset.
+        #[clap(long)]
+        asset_id: Option<AssetId>,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Get coins that cover the requested amounts of assets.
+    ToSpend {
+        /// The owner of the coins.
+        #[clap(long)]
+        owner: Address,
+        /// The requested amount in the `<asset_id>:<amount>[:<max_coins>]` format.
+        #[clap(long = "query", required = true)]
+        queries: Vec<SpendQuery>,
+        /// Coins that should not be returned.
+        #[clap(long, value_delimiter = ',')]
+        excluded_utxos: Vec<UtxoId>,
+        /// Messages that should not be returned.
+        #[clap(long, value_delimiter = ',')]
+        excluded_messages: Vec<Nonce>,
+    },
+}
+
+/// The amount of the asset requested from the `coins-to-spend` query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendQuery {
+    asset_id: AssetId,
+    amount: u128,
+    max: Option<u16>,
+}
+
+impl FromStr for SpendQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let (Some(asset_id), Some(amount)) = (parts.next(), parts.next()) else {
+            return Err("expected `<asset_id>:<amount>[:<max_coins>]`".to_string());
+        };
+        let asset_id =
+            AssetId::from_str(asset_id).map_err(|e| format!("invalid asset id: {e}"))?;
+        let amount = amount.parse().map_err(|e| format!("invalid amount: {e}"))?;
+        let max = parts
+            .next()
+            .map(|max| max.parse().map_err(|e| format!("invalid max coins: {e}")))
+            .transpose()?;
+        if parts.next().is_some() {
+            return Err("expected `<asset_id>:<amount>[:<max_coins>]`".to_string());
+        }
+
+        Ok(Self {
+            asset_id,
+            amount,
+            max,
+        })
+    }
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Get { utxo_id } => {
+                let coin = client
+                    .coin(&utxo_id)
+                    .await?
+                    .with_context(|| format!("coin {utxo_id:#x} not found"))?;
+                printer.print_one(coin_record(&coin))
+            }
+            Command::List {
+                owner,
+                asset_id,
+                pagination,
+            } => {
+                let coins = client
+                    .coins(&owner, asset_id.as_ref(), pagination.into())
+                    .await?;
+                printer.print_page(coins, coin_record)
+            }
+            Command::ToSpend {
+                owner,
+                queries,
+                excluded_utxos,
+                excluded_messages,
+            } => {
+                let spend_query = queries
+                    .into_iter()
+                    .map(|query| (query.asset_id, query.amount, query.max))
+                    .collect();
+                let excluded_ids =
+                    if excluded_utxos.is_empty() && excluded_messages.is_empty() {
+                        None
+                    } else {
+                        Some((excluded_utxos, excluded_messages))
+                    };
+                let coins = client
+                    .coins_to_spend(&owner, spend_query, excluded_ids)
+                    .await?;
+                let coins = coins
+                    .iter()
+                    .flatten()
+                    .map(coin_type_record)
+                    .collect::<Vec<_>>();
+                printer.print_list(coins)
+            }
+        }
+    }
+}
+
+fn coin_record(coin: &Coin) -> Value {
+    json!({
+        "utxo_id": format!("{:#x}", coin.utxo_id),
+        "owner": hex(coin.owner.as_ref()),
+        "asset_id": hex(coin.asset_id.as_ref()),
+        "amount": coin.amount,
+        "block_created": coin.block_created,
+        "tx_created_idx": coin.tx_created_idx,
+    })
+}
+
+fn coin_type_record(coin: &CoinType) -> Value {
+    match coin {
+        CoinType::Coin(coin) => json!({
+            "type": "coin",
+            "id": format!("{:#x}", coin.utxo_id),
+            "asset_id": hex(coin.asset_id.as_ref()),
+            "amount": coin.amount,
+        }),
+        CoinType::MessageCoin(message) => json!({
+            "type": "message",
+            "id": hex(message.nonce.as_ref()),
+            "asset_id": Value::Null,
+            "amount": message.amount,
+        }),
+        CoinType::Unknown => json!({
+            "type": "unknown",
+        }),
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spend_query__parses_asset_amount_and_max() {
+        let asset_id = AssetId::from([1; 32]);
+        let query: SpendQuery = format!("{asset_id:#x}:100:5").parse().unwrap();
+
+        assert_eq!(
+            query,
+            SpendQuery {
+                asset_id,
+                amount: 100,
+                max: Some(5),
+            }
+        );
+    }
+
+    #[test]
+    fn spend_query__max_is_optional() {
+        let asset_id = AssetId::from([1; 32]);
+        let query: SpendQuery = format!("{asset_id:#x}:100").parse().unwrap();
+
+        assert_eq!(query.max, None);
+    }
+
+    #[test]
+    fn spend_query__rejects_missing_amount() {
+        let asset_id = AssetId::from([1; 32]);
+        let result = format!("{asset_id:#x}").parse::<SpendQuery>();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    commands::PaginationArgs,
+    output::{
+        Printer,
+        hex,
+    },
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::ContractBalance,
+};
+use fuel_core_types::fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+};
+use futures::StreamExt;
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the balance of the contract for the asset.
+    Balance {
+        /// The id of the contract.
+        id: ContractId,
+        /// The asset id. The base asset is used if not specified.
+        #[clap(long)]
+        asset_id: Option<AssetId>,
+    },
+    /// Get a page of balances of the contract.
+    Balances {
+        /// The id of the contract.
+        id: ContractId,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Get storage slots of the contract.
+    /// All slots are returned if no keys are specified.
+    Slots {
+        /// The id of the contract.
+        id: ContractId,
+        /// The keys of the requested storage slots.
+        #[clap(long = "key", value_delimiter = ',')]
+        keys: Vec<Bytes32>,
+        /// The height of the block at which the slots are read.
+        /// The latest block is used if not specified.
+        #[clap(long, requires = "keys")]
+        height: Option<u32>,
+    },
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Balance { id, asset_id } => {
+                let amount = client.contract_balance(&id, asset_id.as_ref()).await?;
+                printer.print_one(json!({
+                    "contract": hex(id.as_ref()),
+                    "asset_id": asset_id.map(|asset_id| hex(asset_id.as_ref())),
+                    "amount": amount,
+                }))
+            }
+            Command::Balances { id, pagination } => {
+                let balances = client.contract_balances(&id, pagination.into()).await?;
+                printer.print_page(balances, contract_balance_record)
+            }
+            Command::Slots { id, keys, height } if !keys.is_empty() => {
+                let slots = client
+                    .contract_slots_values(&id, height.map(BlockHeight::new), keys)
+                    .await?;
+                let slots = slots
+                    .iter()
+                    .map(|(key, value)| slot_record(key, value))
+                    .collect();
+                printer.print_list(slots)
+            }
+            Command::Slots { id, .. } => {
+                let slots = client.contract_storage_slots(&id).await?;
+                let slots = slots
+                    .map(|slot| slot.map(|(key, value)| slot_record(&key, &value)))
+                    .collect::<Vec<_>>()
+                    .await
+                    .into_iter()
+                    .collect::<Result<Vec<_>, _>>()?;
+                printer.print_list(slots)
+            }
+        }
+    }
+}
+
+fn contract_balance_record(balance: &ContractBalance) -> Value {
+    json!({
+        "contract": hex(balance.contract.as_ref()),
+        "asset_id": hex(balance.asset_id.as_ref()),
+        "amount": balance.amount,
+    })
+}
+
+fn slot_record(key: &Bytes32, value: &[u8]) -> Value {
+    json!({
+        "key": hex(key.as_ref()),
+        "value": hex(value),
+    })
+}
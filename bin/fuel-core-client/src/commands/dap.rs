@@ -0,0 +1,36 @@
+//! The Debug Adapter Protocol server on top of the GraphQL debugger of the node.
+
+use fuel_core_client::client::FuelClient;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+
+mod adapter;
+mod protocol;
+
+#[derive(Debug, clap::Args)]
+pub struct Command {
+    /// Listen for the debugger client on the TCP address instead of the stdio.
+    #[clap(long)]
+    pub listen: Option<SocketAddr>,
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient) -> anyhow::Result<()> {
+        let Some(address) = self.listen else {
+            return adapter::serve(client, tokio::io::stdin(), tokio::io::stdout()).await
+        };
+
+        let listener = TcpListener::bind(address).await?;
+        eprintln!(
+            "Listening for the debugger client on {}",
+            listener.local_addr()?
+        );
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let (reader, writer) = stream.into_split();
+            if let Err(err) = adapter::serve(client, reader, writer).await {
+                eprintln!("The debugging session with {peer} failed: {err:#}");
+            }
+        }
+    }
+}
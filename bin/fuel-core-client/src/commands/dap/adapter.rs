@@ -0,0 +1,863 @@
+//! The debug adapter translating the Debug Adapter Protocol requests
+//! into the calls of the node's GraphQL debugger.
+//!
+//! The transaction is the only thread of the debuggee. Breakpoints are the
+//! `(contract, pc)` pairs, where the zero contract id stands for the script
+//! and the predicates of the transaction. A source breakpoint uses the contract
+//! id(or `script`) as the name of the source and the number of the instruction
+//! as the line, while an instruction breakpoint references the `contract:pc` pair.
+
+use super::protocol;
+use anyhow::{
+    Context,
+    anyhow,
+};
+use base64::{
+    Engine,
+    engine::general_purpose::STANDARD as BASE64,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    schema::RunResult,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        ScriptExecutionResult,
+        Transaction,
+    },
+    fuel_types::Word,
+};
+use serde_json::{
+    Value,
+    json,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::Path,
+    str::FromStr,
+};
+use tokio::io::{
+    AsyncRead,
+    AsyncWrite,
+    BufReader,
+};
+
+/// The id of the only thread, the transaction.
+const THREAD_ID: u64 = 1;
+/// The id of the only stack frame.
+const FRAME_ID: u64 = 1;
+/// The reference of the registers scope.
+const REGISTERS_REFERENCE: u64 = 1;
+/// The name of the source of the script and predicates.
+const SCRIPT_SOURCE: &str = "script";
+/// The size of the instruction in bytes.
+const INSTRUCTION_SIZE: u64 = 4;
+/// The number of the VM registers.
+const REGISTER_COUNT: u32 = 64;
+/// The names of the reserved registers.
+const RESERVED_REGISTERS: [&str; 16] = [
+    "zero", "one", "of", "pc", "ssp", "sp", "fp", "hp", "err", "ggas", "cgas", "bal",
+    "is", "ret", "retl", "flag",
+];
+
+/// The breakpoint of the VM.
+type Breakpoint = (ContractId, Word);
+
+/// Serves the debugging session of one client until it disconnects.
+pub async fn serve<R, W>(client: &FuelClient, reader: R, writer: W) -> anyhow::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut reader = BufReader::new(reader);
+    let mut adapter = Adapter::new(client, writer);
+    while let Some(request) = protocol::read_message(&mut reader).await? {
+        if !adapter.handle(request).await? {
+            break
+        }
+    }
+    adapter.end_session().await
+}
+
+/// The launch configuration of the session.
+struct Launch {
+    tx: Transaction,
+    stop_on_entry: bool,
+}
+
+/// What to do after the response is sent.
+enum Action {
+    None,
+    /// Notify the client that the adapter is ready to accept the configuration.
+    Initialized,
+    /// Start the execution of the transaction.
+    Start,
+    /// Resume the execution of the transaction.
+    Resume {
+        reason: &'static str,
+    },
+}
+
+struct Adapter<'a, W> {
+    client: &'a FuelClient,
+    writer: W,
+    seq: u64,
+    session: Option<String>,
+    launch: Option<Launch>,
+    /// The breakpoints requested by `setBreakpoints` for each source.
+    source_breakpoints: HashMap<ContractId, HashSet<Word>>,
+    /// The breakpoints requested by `setInstructionBreakpoints`.
+    instruction_breakpoints: HashSet<Breakpoint>,
+    /// The breakpoints set in the node. The node can't remove breakpoints,
+    /// so the breakpoints which are not requested anymore are skipped by the adapter.
+    installed: HashSet<Breakpoint>,
+    single_stepping: bool,
+    stopped_at: Option<Breakpoint>,
+    /// Whether the transaction panicked or reverted.
+    failed: bool,
+}
+
+impl<'a, W> Adapter<'a, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn new(client: &'a FuelClient, writer: W) -> Self {
+        Self {
+            client,
+            writer,
+            seq: 0,
+            session: None,
+            launch: None,
+            source_breakpoints: HashMap::new(),
+            instruction_breakpoints: HashSet::new(),
+            installed: HashSet::new(),
+            single_stepping: false,
+            stopped_at: None,
+            failed: false,
+        }
+    }
+
+    /// Handles the request from the client.
+    /// Returns `false` when the client asked to end the debugging.
+    async fn handle(&mut self, request: Value) -> anyhow::Result<bool> {
+        let command = request["command"].as_str().unwrap_or_default().to_owned();
+        let arguments = &request["arguments"];
+
+        if matches!(command.as_str(), "disconnect" | "terminate") {
+            self.end_session().await?;
+            self.respond(&request, Ok(Value::Null)).await?;
+            self.event("terminated", json!({})).await?;
+            return Ok(false)
+        }
+
+        let (body, action) = match self.dispatch(&command, arguments).await {
+            Ok((body, action)) => (Ok(body), action),
+            Err(err) => (Err(err), Action::None),
+        };
+        self.respond(&request, body).await?;
+
+        let result = match action {
+            Action::None => Ok(()),
+            Action::Initialized => self.event("initialized", json!({})).await,
+            Action::Start => self.start().await,
+            Action::Resume { reason } => self.resume(reason).await,
+        };
+        if let Err(err) = result {
+            self.event(
+                "output",
+                json!({ "category": "stderr", "output": format!("{err:#}\n") }),
+            )
+            .await?;
+            self.event("terminated", json!({})).await?;
+        }
+        Ok(true)
+    }
+
+    async fn dispatch(
+        &mut self,
+        command: &str,
+        arguments: &Value,
+    ) -> anyhow::Result<(Value, Action)> {
+        match command {
+            "initialize" => Ok((capabilities(), Action::None)),
+            "launch" => {
+                self.launch(arguments).await?;
+                Ok((Value::Null, Action::Initialized))
+            }
+            "setBreakpoints" => {
+                let body = self.set_source_breakpoints(arguments).await?;
+                Ok((body, Action::None))
+            }
+            "setInstructionBreakpoints" => {
+                let body = self.set_instruction_breakpoints(arguments).await?;
+                Ok((body, Action::None))
+            }
+            "setExceptionBreakpoints" => Ok((json!({ "breakpoints": [] }), Action::None)),
+            "configurationDone" => Ok((Value::Null, Action::Start)),
+            "threads" => Ok((
+                json!({ "threads": [{ "id": THREAD_ID, "name": "transaction" }] }),
+                Action::None,
+            )),
+            "stackTrace" => Ok((self.stack_trace(), Action::None)),
+            "scopes" => Ok((
+                json!({ "scopes": [{
+                    "name": "Registers",
+                    "variablesReference": REGISTERS_REFERENCE,
+                    "expensive": false,
+                }] }),
+                Action::None,
+            )),
+            "variables" => {
+                let body = self.variables(arguments).await?;
+                Ok((body, Action::None))
+            }
+            "readMemory" => {
+                let body = self.read_memory(arguments).await?;
+                Ok((body, Action::None))
+            }
+            "continue" => {
+                self.set_single_stepping(false).await?;
+                Ok((
+                    json!({ "allThreadsContinued": true }),
+                    Action::Resume {
+                        reason: "breakpoint",
+                    },
+                ))
+            }
+            "next" | "stepIn" => {
+                self.set_single_stepping(true).await?;
+                Ok((Value::Null, Action::Resume { reason: "step" }))
+            }
+            command => Err(anyhow!("unsupported request `{command}`")),
+        }
+    }
+
+    async fn launch(&mut self, arguments: &Value) -> anyhow::Result<()> {
+        let tx: Transaction = match &arguments["transaction"] {
+            Value::String(path) => {
+                let tx = std::fs::read_to_string(path).with_context(|| {
+                    format!("failed to read the transaction `{path}`")
+                })?;
+                serde_json::from_str(&tx).context("invalid transaction json")?
+            }
+            tx @ Value::Object(_) => {
+                serde_json::from_value(tx.clone()).context("invalid transaction json")?
+            }
+            _ => return Err(anyhow!("the `transaction` launch argument is required")),
+        };
+
+        let session = match arguments["blockHeight"].as_u64() {
+            Some(height) => {
+                let height = u32::try_from(height).context("invalid `blockHeight`")?;
+                self.client.start_session_at(height.into()).await?
+            }
+            None => self.client.start_session().await?,
+        };
+
+        self.session = Some(session);
+        self.launch = Some(Launch {
+            tx,
+            stop_on_entry: arguments["stopOnEntry"].as_bool().unwrap_or_default(),
+        });
+        Ok(())
+    }
+
+    async fn set_source_breakpoints(
+        &mut self,
+        arguments: &Value,
+    ) -> anyhow::Result<Value> {
+        let contract = source_contract(&arguments["source"])?;
+        let lines = arguments["breakpoints"]
+            .as_array()
+            .map(|breakpoints| {
+                breakpoints
+                    .iter()
+                    .filter_map(|breakpoint| breakpoint["line"].as_u64())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let pcs = lines
+            .iter()
+            .map(|line| line_to_pc(*line))
+            .collect::<anyhow::Result<HashSet<_>>>()?;
+
+        self.install(pcs.iter().map(|pc| (contract, *pc))).await?;
+        self.source_breakpoints.insert(contract, pcs);
+
+        let breakpoints = lines
+            .iter()
+            .map(|line| json!({ "verified": true, "line": line }))
+            .collect::<Vec<_>>();
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    async fn set_instruction_breakpoints(
+        &mut self,
+        arguments: &Value,
+    ) -> anyhow::Result<Value> {
+        let mut breakpoints = vec![];
+        let mut requested = HashSet::new();
+        for breakpoint in arguments["breakpoints"].as_array().into_iter().flatten() {
+            let reference = breakpoint["instructionReference"]
+                .as_str()
+                .ok_or_else(|| anyhow!("the `instructionReference` is required"))?;
+            let (contract, pc) = parse_instruction_reference(reference)?;
+            let offset = breakpoint["offset"].as_i64().unwrap_or_default();
+            let pc = pc
+                .checked_add_signed(offset)
+                .ok_or_else(|| anyhow!("invalid instruction offset {offset}"))?;
+
+            requested.insert((contract, pc));
+            breakpoints.push(json!({
+                "verified": true,
+                "instructionReference": instruction_reference(&contract, pc),
+            }));
+        }
+
+        self.install(requested.iter().copied()).await?;
+        self.instruction_breakpoints = requested;
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    fn stack_trace(&self) -> Value {
+        let Some((contract, pc)) = self.stopped_at else {
+            return json!({ "stackFrames": [], "totalFrames": 0 })
+        };
+
+        let name = source_name(&contract);
+        json!({
+            "stackFrames": [{
+                "id": FRAME_ID,
+                "name": name,
+                "source": { "name": name },
+                "line": pc_to_line(pc),
+                "column": 1,
+                "instructionPointerReference": instruction_reference(&contract, pc),
+            }],
+            "totalFrames": 1,
+        })
+    }
+
+    async fn variables(&self, arguments: &Value) -> anyhow::Result<Value> {
+        if arguments["variablesReference"].as_u64() != Some(REGISTERS_REFERENCE) {
+            return Ok(json!({ "variables": [] }))
+        }
+
+        let session = self.session()?;
+        let mut variables = vec![];
+        for register in 0..REGISTER_COUNT {
+            let value = self.client.register(session, register).await?;
+            variables.push(json!({
+                "name": register_name(register),
+                "value": format!("{value:#x}"),
+                "variablesReference": 0,
+                "memoryReference": format!("{value:#x}"),
+            }));
+        }
+        Ok(json!({ "variables": variables }))
+    }
+
+    async fn read_memory(&self, arguments: &Value) -> anyhow::Result<Value> {
+        let reference = arguments["memoryReference"]
+            .as_str()
+            .ok_or_else(|| anyhow!("the `memoryReference` is required"))?;
+        let offset = arguments["offset"].as_i64().unwrap_or_default();
+        let start = parse_address(reference)?
+            .checked_add_signed(offset)
+            .ok_or_else(|| anyhow!("invalid memory offset {offset}"))?;
+        let count = arguments["count"].as_u64().unwrap_or_default();
+
+        let address = format!("{start:#x}");
+        let memory = self
+            .client
+            .memory(
+                self.session()?,
+                u32::try_from(start).context("invalid memory address")?,
+                u32::try_from(count).context("invalid memory size")?,
+            )
+            .await;
+        match memory {
+            Ok(memory) => {
+                Ok(json!({ "address": address, "data": BASE64.encode(memory) }))
+            }
+            Err(_) => Ok(json!({ "address": address, "unreadableBytes": count })),
+        }
+    }
+
+    async fn start(&mut self) -> anyhow::Result<()> {
+        let launch = self
+            .launch
+            .as_ref()
+            .ok_or_else(|| anyhow!("the debugging session is not launched"))?;
+        let tx = launch.tx.clone();
+        let reason = if launch.stop_on_entry {
+            self.set_single_stepping(true).await?;
+            "entry"
+        } else {
+            "breakpoint"
+        };
+
+        self.failed = false;
+        let result = self.client.start_tx(self.session()?, &tx).await?;
+        self.report(result, reason).await
+    }
+
+    async fn resume(&mut self, reason: &'static str) -> anyhow::Result<()> {
+        let result = self.client.continue_tx(self.session()?).await?;
+        self.report(result, reason).await
+    }
+
+    /// Reports the result of the execution to the client. The execution is resumed
+    /// if it stopped on a breakpoint that is not requested by the client anymore.
+    async fn report(
+        &mut self,
+        mut result: RunResult,
+        mut reason: &'static str,
+    ) -> anyhow::Result<()> {
+        loop {
+            for receipt in &result.json_receipts {
+                self.failed |= is_failure(receipt);
+                self.event(
+                    "output",
+                    json!({ "category": "stdout", "output": format!("{receipt}\n") }),
+                )
+                .await?;
+            }
+
+            let Some(breakpoint) = result.breakpoint.take() else {
+                self.stopped_at = None;
+                let exit_code = if self.failed { 1 } else { 0 };
+                self.event("exited", json!({ "exitCode": exit_code }))
+                    .await?;
+                return self.event("terminated", json!({})).await
+            };
+
+            let breakpoint = (ContractId::from(breakpoint.contract), breakpoint.pc.0);
+            if self.single_stepping || self.is_requested(&breakpoint) {
+                self.stopped_at = Some(breakpoint);
+                return self
+                    .event(
+                        "stopped",
+                        json!({
+                            "reason": reason,
+                            "threadId": THREAD_ID,
+                            "allThreadsStopped": true,
+                        }),
+                    )
+                    .await
+            }
+
+            result = self.client.continue_tx(self.session()?).await?;
+            reason = "breakpoint";
+        }
+    }
+
+    fn is_requested(&self, breakpoint: &Breakpoint) -> bool {
+        let (contract, pc) = breakpoint;
+        self.instruction_breakpoints.contains(breakpoint)
+            || self
+                .source_breakpoints
+                .get(contract)
+                .is_some_and(|pcs| pcs.contains(pc))
+    }
+
+    async fn install(
+        &mut self,
+        breakpoints: impl Iterator<Item = Breakpoint>,
+    ) -> anyhow::Result<()> {
+        for (contract, pc) in breakpoints {
+            if self.installed.contains(&(contract, pc)) {
+                continue
+            }
+            // The node expects the number of the instruction instead of its offset.
+            let instruction = pc.checked_div(INSTRUCTION_SIZE).unwrap_or_default();
+            self.client
+                .set_breakpoint(self.session()?, contract, instruction)
+                .await?;
+            self.installed.insert((contract, pc));
+        }
+        Ok(())
+    }
+
+    async fn set_single_stepping(&mut self, enable: bool) -> anyhow::Result<()> {
+        if self.single_stepping != enable {
+            self.client
+                .set_single_stepping(self.session()?, enable)
+                .await?;
+            self.single_stepping = enable;
+        }
+        Ok(())
+    }
+
+    async fn end_session(&mut self) -> anyhow::Result<()> {
+        if let Some(session) = self.session.take() {
+            self.client.end_session(&session).await?;
+        }
+        Ok(())
+    }
+
+    fn session(&self) -> anyhow::Result<&str> {
+        self.session
+            .as_deref()
+            .ok_or_else(|| anyhow!("the debugging session is not launched"))
+    }
+
+    async fn respond(
+        &mut self,
+        request: &Value,
+        body: anyhow::Result<Value>,
+    ) -> anyhow::Result<()> {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+        });
+        match body {
+            Ok(body) => {
+                response["success"] = true.into();
+                response["body"] = body;
+            }
+            Err(err) => {
+                response["success"] = false.into();
+                response["message"] = format!("{err:#}").into();
+            }
+        }
+        self.send(response).await
+    }
+
+    async fn event(&mut self, event: &str, body: Value) -> anyhow::Result<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+            .await
+    }
+
+    async fn send(&mut self, mut message: Value) -> anyhow::Result<()> {
+        self.seq = self.seq.saturating_add(1);
+        message["seq"] = self.seq.into();
+        protocol::write_message(&mut self.writer, &message).await
+    }
+}
+
+fn capabilities() -> Value {
+    json!({
+        "supportsConfigurationDoneRequest": true,
+        "supportsInstructionBreakpoints": true,
+        "supportsReadMemoryRequest": true,
+        "supportsTerminateRequest": true,
+    })
+}
+
+/// Returns `true` if the JSON receipt reports the panic or revert of the transaction.
+fn is_failure(receipt: &str) -> bool {
+    match serde_json::from_str(receipt) {
+        Ok(Receipt::Panic { .. } | Receipt::Revert { .. }) => true,
+        Ok(Receipt::ScriptResult { result, .. }) => {
+            result != ScriptExecutionResult::Success
+        }
+        _ => false,
+    }
+}
+
+fn register_name(register: u32) -> String {
+    usize::try_from(register)
+        .ok()
+        .and_then(|index| RESERVED_REGISTERS.get(index))
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("r{register}"))
+}
+
+fn source_name(contract: &ContractId) -> String {
+    if *contract == ContractId::zeroed() {
+        SCRIPT_SOURCE.to_string()
+    } else {
+        format!("{contract:#x}")
+    }
+}
+
+/// Returns the contract of the source. The name of the source or
+/// the stem of its path is either a contract id or `script`.
+fn source_contract(source: &Value) -> anyhow::Result<ContractId> {
+    let name = source["name"].as_str().or_else(|| {
+        source["path"]
+            .as_str()
+            .and_then(|path| Path::new(path).file_stem())
+            .and_then(|stem| stem.to_str())
+    });
+    match name {
+        Some(SCRIPT_SOURCE) => Ok(ContractId::zeroed()),
+        Some(name) => ContractId::from_str(name)
+            .map_err(|_| anyhow!("the source `{name}` is not a contract id or `script`")),
+        None => Err(anyhow!("the source of the breakpoints is not specified")),
+    }
+}
+
+fn instruction_reference(contract: &ContractId, pc: Word) -> String {
+    format!("{contract:#x}:{pc}")
+}
+
+fn parse_instruction_reference(reference: &str) -> anyhow::Result<Breakpoint> {
+    let (contract, pc) = reference.split_once(':').unwrap_or((reference, "0"));
+    let contract = match contract {
+        SCRIPT_SOURCE => ContractId::zeroed(),
+        contract => ContractId::from_str(contract)
+            .map_err(|_| anyhow!("invalid contract id in `{reference}`"))?,
+    };
+    let pc = pc
+        .parse()
+        .with_context(|| format!("invalid pc in `{reference}`"))?;
+    Ok((contract, pc))
+}
+
+fn parse_address(reference: &str) -> anyhow::Result<Word> {
+    let address = match reference.strip_prefix("0x") {
+        Some(hex) => Word::from_str_radix(hex, 16),
+        None => reference.parse(),
+    };
+    address.with_context(|| format!("invalid memory reference `{reference}`"))
+}
+
+/// The lines are the 1-based numbers of the instructions.
+fn line_to_pc(line: u64) -> anyhow::Result<Word> {
+    line.checked_sub(1)
+        .and_then(|index| index.checked_mul(INSTRUCTION_SIZE))
+        .ok_or_else(|| anyhow!("invalid breakpoint line {line}"))
+}
+
+fn pc_to_line(pc: Word) -> u64 {
+    pc.checked_div(INSTRUCTION_SIZE)
+        .unwrap_or_default()
+        .saturating_add(1)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core::service::{
+        Config,
+        FuelService,
+    };
+    use fuel_core_types::{
+        fuel_asm::{
+            RegId,
+            op,
+        },
+        fuel_tx::TransactionBuilder,
+    };
+    use tokio::io::{
+        DuplexStream,
+        ReadHalf,
+        WriteHalf,
+    };
+
+    /// The debugger client talking to the adapter.
+    struct TestClient {
+        reader: BufReader<ReadHalf<DuplexStream>>,
+        writer: WriteHalf<DuplexStream>,
+        seq: u64,
+    }
+
+    impl TestClient {
+        async fn request(&mut self, command: &str, arguments: Value) -> Value {
+            self.seq = self.seq.saturating_add(1);
+            let request = json!({
+                "seq": self.seq,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            });
+            protocol::write_message(&mut self.writer, &request)
+                .await
+                .unwrap();
+
+            loop {
+                let message = self.next().await;
+                if message["type"] == "response" && message["request_seq"] == self.seq {
+                    assert_eq!(message["success"], true, "{message}");
+                    return message["body"].clone()
+                }
+            }
+        }
+
+        async fn wait_event(&mut self, event: &str) -> Value {
+            loop {
+                let message = self.next().await;
+                if message["type"] == "event" && message["event"] == event {
+                    return message["body"].clone()
+                }
+                assert_ne!(
+                    message["event"], "terminated",
+                    "the session terminated before `{event}`"
+                );
+            }
+        }
+
+        async fn next(&mut self) -> Value {
+            protocol::read_message(&mut self.reader)
+                .await
+                .unwrap()
+                .expect("the adapter closed the stream")
+        }
+    }
+
+    /// Runs the debugging session of the `debug` client against the local node.
+    async fn debug<F>(debug: impl FnOnce(TestClient) -> F)
+    where
+        F: Future<Output = ()>,
+    {
+        let config = Config {
+            // The session is launched on top of the latest block.
+            historical_execution: false,
+            ..Config::local_node()
+        };
+        let node = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(node.bound_address);
+        let (client_stream, adapter_stream) = tokio::io::duplex(1024 * 1024);
+        let (adapter_reader, adapter_writer) = tokio::io::split(adapter_stream);
+        let (client_reader, client_writer) = tokio::io::split(client_stream);
+        let test_client = TestClient {
+            reader: BufReader::new(client_reader),
+            writer: client_writer,
+            seq: 0,
+        };
+
+        let (served, ()) = tokio::join!(
+            serve(&client, adapter_reader, adapter_writer),
+            debug(test_client)
+        );
+        served.unwrap();
+    }
+
+    fn script(
+        ops: impl IntoIterator<Item = fuel_core_types::fuel_asm::Instruction>,
+    ) -> Value {
+        let tx: Transaction =
+            TransactionBuilder::script(ops.into_iter().collect(), vec![])
+                .script_gas_limit(1_000_000)
+                .add_fee_input()
+                .finalize_as_transaction();
+        serde_json::to_value(tx).unwrap()
+    }
+
+    #[test]
+    fn line_to_pc__maps_instruction_numbers_to_offsets() {
+        assert_eq!(line_to_pc(1).unwrap(), 0);
+        assert_eq!(line_to_pc(3).unwrap(), 8);
+        assert!(line_to_pc(0).is_err());
+    }
+
+    #[test]
+    fn pc_to_line__is_inverse_of_line_to_pc() {
+        for line in 1..10 {
+            assert_eq!(pc_to_line(line_to_pc(line).unwrap()), line);
+        }
+    }
+
+    #[test]
+    fn source_contract__accepts_script_and_contract_ids() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+
+        // When
+        let script = source_contract(&json!({ "name": "script" })).unwrap();
+        let by_name =
+            source_contract(&json!({ "name": format!("{contract:#x}") })).unwrap();
+        let by_path =
+            source_contract(&json!({ "path": format!("/tmp/{contract:#x}.bin") }))
+                .unwrap();
+
+        // Then
+        assert_eq!(script, ContractId::zeroed());
+        assert_eq!(by_name, contract);
+        assert_eq!(by_path, contract);
+        assert!(source_contract(&json!({ "name": "main.sw" })).is_err());
+    }
+
+    #[test]
+    fn parse_instruction_reference__is_inverse_of_instruction_reference() {
+        // Given
+        let contract = ContractId::from([2; 32]);
+        let reference = instruction_reference(&contract, 12);
+
+        // When
+        let breakpoint = parse_instruction_reference(&reference).unwrap();
+
+        // Then
+        assert_eq!(breakpoint, (contract, 12));
+    }
+
+    #[test]
+    fn register_name__names_reserved_registers() {
+        assert_eq!(register_name(0), "zero");
+        assert_eq!(register_name(15), "flag");
+        assert_eq!(register_name(16), "r16");
+    }
+
+    #[tokio::test]
+    async fn serve__stops_on_breakpoint_and_exits_after_continue() {
+        // Given
+        let tx = script([
+            op::addi(0x10, 0x10, 1),
+            op::addi(0x10, 0x10, 1),
+            op::ret(RegId::ONE),
+        ]);
+
+        debug(|mut client| async move {
+            client.request("initialize", json!({})).await;
+            client.request("launch", json!({ "transaction": tx })).await;
+            client.wait_event("initialized").await;
+            client
+                .request(
+                    "setBreakpoints",
+                    json!({
+                        "source": { "name": "script" },
+                        "breakpoints": [{ "line": 2 }],
+                    }),
+                )
+                .await;
+
+            // When
+            client.request("configurationDone", json!({})).await;
+            let stopped = client.wait_event("stopped").await;
+            let trace = client
+                .request("stackTrace", json!({ "threadId": THREAD_ID }))
+                .await;
+            client
+                .request("continue", json!({ "threadId": THREAD_ID }))
+                .await;
+            let exited = client.wait_event("exited").await;
+            client.request("disconnect", json!({})).await;
+
+            // Then
+            assert_eq!(stopped["reason"], "breakpoint");
+            assert_eq!(trace["stackFrames"][0]["line"], 2);
+            assert_eq!(exited["exitCode"], 0);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn serve__reports_failure_exit_code_for_reverted_script() {
+        // Given
+        let tx = script([op::rvrt(RegId::ONE)]);
+
+        debug(|mut client| async move {
+            client.request("initialize", json!({})).await;
+            client.request("launch", json!({ "transaction": tx })).await;
+            client.wait_event("initialized").await;
+
+            // When
+            client.request("configurationDone", json!({})).await;
+            let exited = client.wait_event("exited").await;
+            client.request("disconnect", json!({})).await;
+
+            // Then
+            assert_eq!(exited["exitCode"], 1);
+        })
+        .await;
+    }
+}
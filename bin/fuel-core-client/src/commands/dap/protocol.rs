@@ -0,0 +1,122 @@
+//! The wire format of the Debug Adapter Protocol.
+//!
+//! Each message is a JSON document prefixed with the `Content-Length` header,
+//! as described in <https://microsoft.github.io/debug-adapter-protocol/overview>.
+
+use anyhow::Context;
+use serde_json::Value;
+use tokio::io::{
+    AsyncBufRead,
+    AsyncBufReadExt,
+    AsyncReadExt,
+    AsyncWrite,
+    AsyncWriteExt,
+};
+
+const CONTENT_LENGTH: &str = "Content-Length:";
+/// The max size of the message body. The body is allocated before it is read,
+/// so the client can't make the adapter allocate more than this.
+const MAX_CONTENT_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Reads the next message from the client.
+/// Returns `None` when the client closed the stream.
+pub async fn read_message<R>(reader: &mut R) -> anyhow::Result<Option<Value>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(None)
+        }
+
+        let line = line.trim_end();
+        if let Some(length) = line.strip_prefix(CONTENT_LENGTH) {
+            let length = length
+                .trim()
+                .parse::<usize>()
+                .context("invalid `Content-Length` header")?;
+            if length > MAX_CONTENT_LENGTH {
+                anyhow::bail!(
+                    "`Content-Length` {length} exceeds the limit of {MAX_CONTENT_LENGTH} bytes"
+                );
+            }
+            content_length = Some(length);
+        } else if line.is_empty() {
+            if let Some(length) = content_length {
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).await?;
+                let message =
+                    serde_json::from_slice(&body).context("invalid message json")?;
+                return Ok(Some(message))
+            }
+        }
+    }
+}
+
+/// Writes the message to the client.
+pub async fn write_message<W>(writer: &mut W, message: &Value) -> anyhow::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let body = serde_json::to_vec(message)?;
+    let header = format!("{CONTENT_LENGTH} {}\r\n\r\n", body.len());
+    writer.write_all(header.as_bytes()).await?;
+    writer.write_all(&body).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn read_message__returns_written_messages_in_order() {
+        // Given
+        let first = json!({ "seq": 1, "type": "request", "command": "initialize" });
+        let second = json!({ "seq": 2, "type": "request", "command": "threads" });
+        let mut buffer = vec![];
+        write_message(&mut buffer, &first).await.unwrap();
+        write_message(&mut buffer, &second).await.unwrap();
+
+        // When
+        let mut reader = buffer.as_slice();
+        let read_first = read_message(&mut reader).await.unwrap();
+        let read_second = read_message(&mut reader).await.unwrap();
+        let end = read_message(&mut reader).await.unwrap();
+
+        // Then
+        assert_eq!(read_first, Some(first));
+        assert_eq!(read_second, Some(second));
+        assert_eq!(end, None);
+    }
+
+    #[tokio::test]
+    async fn read_message__fails_on_invalid_content_length() {
+        // Given
+        let mut reader = "Content-Length: abc\r\n\r\n{}".as_bytes();
+
+        // When
+        let result = read_message(&mut reader).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn read_message__fails_on_too_large_content_length() {
+        // Given
+        let header = format!("Content-Length: {}\r\n\r\n{{}}", MAX_CONTENT_LENGTH + 1);
+        let mut reader = header.as_bytes();
+
+        // When
+        let result = read_message(&mut reader).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    commands::{
+        PaginationArgs,
+        block::header_record,
+    },
+    output::{
+        Printer,
+        hex,
+    },
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    FuelClient,
+    types::{
+        MerkleProof,
+        Message,
+        message::MessageStatus,
+        primitives::BlockId,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Nonce,
+    },
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get a message by its nonce.
+    Get {
+        /// The nonce of the message.
+        nonce: Nonce,
+    },
+    /// Get a page of messages, optionally filtered by the recipient.
+    List {
+        /// The recipient of the messages.
+        #[clap(long)]
+        owner: Option<Address>,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Get the status of a message.
+    Status {
+        /// The nonce of the message.
+        nonce: Nonce,
+    },
+    /// Get the merkle proof of the message sent by the transaction.
+    Proof {
+        /// The id of the transaction that sent the message.
+        #[clap(long)]
+        transaction_id: TxId,
+        /// The nonce of the message.
+        #[clap(long)]
+        nonce: Nonce,
+        /// The id of the block used as the root of the proof.
+        #[clap(long, conflicts_with = "commit_block_height")]
+        commit_block_id: Option<BlockId>,
+        /// The height of the block used as the root of the proof.
+        #[clap(long)]
+        commit_block_height: Option<u32>,
+    },
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Get { nonce } => {
+                let message = client
+                    .message(&nonce)
+                    .await?
+                    .with_context(|| format!("message {nonce} not found"))?;
+                printer.print_one(message_record(&message))
+            }
+            Command::List { owner, pagination } => {
+                let messages = client.messages(owner.as_ref(), pagination.into()).await?;
+                printer.print_page(messages, message_record)
+            }
+            Command::Status { nonce } => {
+                let status = match client.message_status(&nonce).await? {
+                    MessageStatus::Unspent => "unspent",
+                    MessageStatus::Spent => "spent",
+                    MessageStatus::NotFound => "not_found",
+                };
+                printer.print_one(json!({
+                    "nonce": hex(nonce.as_ref()),
+                    "status": status,
+                }))
+            }
+            Command::Proof {
+                transaction_id,
+                nonce,
+                commit_block_id,
+                commit_block_height,
+            } => {
+                let proof = client
+                    .message_proof(
+                        &transaction_id,
+                        &nonce,
+                        commit_block_id.as_ref(),
+                        commit_block_height.map(BlockHeight::new),
+                    )
+                    .await?;
+                printer.print_one(json!({
+                    "sender": hex(proof.sender.as_ref()),
+                    "recipient": hex(proof.recipient.as_ref()),
+                    "nonce": hex(proof.nonce.as_ref()),
+                    "amount": proof.amount,
+                    "data": hex(&proof.data),
+                    "message_proof": merkle_proof_record(&proof.message_proof),
+                    "block_proof": merkle_proof_record(&proof.block_proof),
+                    "message_block_header": header_record(&proof.message_block_header),
+                    "commit_block_header": header_record(&proof.commit_block_header),
+                }))
+            }
+        }
+    }
+}
+
+fn message_record(message: &Message) -> Value {
+    json!({
+        "nonce": hex(message.nonce.as_ref()),
+        "sender": hex(message.sender.as_ref()),
+        "recipient": hex(message.recipient.as_ref()),
+        "amount": message.amount,
+        "data": hex(&message.data),
+        "da_height": message.da_height,
+    })
+}
+
+fn merkle_proof_record(proof: &MerkleProof) -> Value {
+    json!({
+        "proof_set": proof
+            .proof_set
+            .iter()
+            .map(|root| hex(root.as_ref()))
+            .collect::<Vec<_>>(),
+        "proof_index": proof.proof_index,
+    })
+}
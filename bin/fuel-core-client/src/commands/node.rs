@@ -0,0 +1,130 @@
+use crate::{
+    commands::block::block_record,
+    output::Printer,
+};
+use fuel_core_client::client::FuelClient;
+use serde_json::json;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ChainCommand {
+    /// Get the chain name, the latest block and the main consensus parameters.
+    Info,
+}
+
+impl ChainCommand {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            ChainCommand::Info => {
+                let chain = client.chain_info().await?;
+                let parameters = &chain.consensus_parameters;
+                printer.print_one(json!({
+                    "name": chain.name,
+                    "chain_id": *parameters.chain_id(),
+                    "da_height": chain.da_height,
+                    "block_gas_limit": parameters.block_gas_limit(),
+                    "latest_block": block_record(&chain.latest_block),
+                }))
+            }
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum NodeCommand {
+    /// Get the version and the configuration of the node.
+    Info,
+    /// Get the peers connected to the node.
+    Peers,
+    /// Check that the node is healthy.
+    Health,
+}
+
+impl NodeCommand {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            NodeCommand::Info => {
+                let info = client.node_info().await?;
+                printer.print_one(json!({
+                    "node_version": info.node_version,
+                    "utxo_validation": info.utxo_validation,
+                    "vm_backtrace": info.vm_backtrace,
+                    "max_tx": info.max_tx,
+                    "max_gas": info.max_gas,
+                    "max_size": info.max_size,
+                    "max_depth": info.max_depth,
+                    "indexation": {
+                        "balances": info.indexation.balances,
+                        "coins_to_spend": info.indexation.coins_to_spend,
+                        "asset_metadata": info.indexation.asset_metadata,
+                    },
+                    "tx_pool_stats": {
+                        "tx_count": info.tx_pool_stats.tx_count.0,
+                        "total_gas": info.tx_pool_stats.total_gas.0,
+                        "total_size": info.tx_pool_stats.total_size.0,
+                    },
+                }))
+            }
+            NodeCommand::Peers => {
+                let peers = client.connected_peers_info().await?;
+                let peers = peers
+                    .into_iter()
+                    .map(|peer| {
+                        let mut addresses =
+                            peer.peer_addresses.into_iter().collect::<Vec<_>>();
+                        addresses.sort();
+                        json!({
+                            "id": peer.id.to_string(),
+                            "client_version": peer.client_version,
+                            "block_height": peer
+                                .heartbeat_data
+                                .block_height
+                                .map(u32::from),
+                            "app_score": peer.app_score,
+                            "addresses": addresses,
+                        })
+                    })
+                    .collect();
+                printer.print_list(peers)
+            }
+            NodeCommand::Health => {
+                let healthy = client.health().await?;
+                printer.print_one(json!({ "healthy": healthy }))?;
+                anyhow::ensure!(healthy, "the node is not healthy");
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum GasPriceCommand {
+    /// Get the gas price of the latest block.
+    Latest,
+    /// Estimate the gas price for the transaction included within the block horizon.
+    Estimate {
+        /// The number of blocks in which the transaction should be included.
+        #[clap(long, default_value = "10")]
+        block_horizon: u32,
+    },
+}
+
+impl GasPriceCommand {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            GasPriceCommand::Latest => {
+                let price = client.latest_gas_price().await?;
+                printer.print_one(json!({
+                    "gas_price": price.gas_price,
+                    "block_height": u32::from(price.block_height),
+                }))
+            }
+            GasPriceCommand::Estimate { block_horizon } => {
+                let price = client.estimate_gas_price(block_horizon).await?;
+                printer.print_one(json!({
+                    "gas_price": price.gas_price.0,
+                    "block_horizon": block_horizon,
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::output::{
+    Printer,
+    hex,
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
+use futures::StreamExt;
+use serde_json::{
+    Value,
+    json,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Submit a JSON encoded transaction for inclusion in a block
+    Submit { tx: String },
+    /// Submit a JSON encoded transaction for predicate estimation.
+    EstimatePredicates { tx: String },
+    /// Submit a JSON encoded transaction for a dry-run execution
+    DryRun { txs: Vec<String> },
+    /// Get the transactions associated with a particular transaction id
+    Get { id: TxId },
+    /// Get the receipts for a particular transaction id
+    Receipts { id: TxId },
+    /// Get the status of a particular transaction id
+    Status {
+        id: TxId,
+        /// Follow the status updates until the transaction reaches the final status.
+        #[clap(long)]
+        follow: bool,
+    },
+}
+
+impl Command {
+    pub async fn exec(self, client: &FuelClient, printer: Printer) -> anyhow::Result<()> {
+        match self {
+            Command::Submit { tx } => {
+                let tx = parse_transaction(&tx)?;
+                let tx_id = client.submit(&tx).await?;
+                // Scripts rely on the plain transaction id, so the JSON is printed
+                // only when it is requested explicitly.
+                if printer.json_requested() {
+                    printer.print_one(json!({ "id": hex(tx_id.as_ref()) }))
+                } else {
+                    println!("{tx_id}");
+                    Ok(())
+                }
+            }
+            Command::EstimatePredicates { tx } => {
+                let mut tx = parse_transaction(&tx)?;
+                client.estimate_predicates(&mut tx).await?;
+                printer.print_one(serde_json::to_value(tx)?)
+            }
+            Command::DryRun { txs } => {
+                let txs = txs
+                    .iter()
+                    .map(|tx| parse_transaction(tx))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let statuses = client.dry_run(&txs).await?;
+                let statuses = statuses
+                    .into_iter()
+                    .map(serde_json::to_value)
+                    .collect::<Result<Vec<_>, _>>()?;
+                printer.print_list(statuses)
+            }
+            Command::Get { id } => {
+                let tx = client
+                    .transaction(&id)
+                    .await?
+                    .with_context(|| format!("transaction {id} not found"))?;
+                printer.print_one(serde_json::to_value(tx)?)
+            }
+            Command::Receipts { id } => {
+                let receipts = client
+                    .receipts(&id)
+                    .await?
+                    .with_context(|| format!("receipts of transaction {id} not found"))?;
+                let receipts = receipts
+                    .into_iter()
+                    .map(serde_json::to_value)
+                    .collect::<Result<Vec<_>, _>>()?;
+                printer.print_list(receipts)
+            }
+            Command::Status { id, follow: false } => {
+                let status = client.transaction_status(&id).await?;
+                printer.print_one(status_record(&status))
+            }
+            Command::Status { id, follow: true } => {
+                let mut statuses = client.subscribe_transaction_status(&id).await?;
+                while let Some(status) = statuses.next().await {
+                    let status = status?;
+                    printer.print_one(status_record(&status))?;
+                    if status.is_final() {
+                        break;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn parse_transaction(tx: &str) -> anyhow::Result<Transaction> {
+    serde_json::from_str(tx).context("invalid transaction json")
+}
+
+fn status_record(status: &TransactionStatus) -> Value {
+    match status {
+        TransactionStatus::Submitted { submitted_at } => json!({
+            "status": "submitted",
+            "time": submitted_at.to_unix(),
+        }),
+        TransactionStatus::Success {
+            block_height,
+            time,
+            total_gas,
+            total_fee,
+            ..
+        } => json!({
+            "status": "success",
+            "block_height": u32::from(*block_height),
+            "time": time.to_unix(),
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+        }),
+        TransactionStatus::PreconfirmationSuccess {
+            tx_pointer,
+            total_gas,
+            total_fee,
+            ..
+        } => json!({
+            "status": "preconfirmation_success",
+            "block_height": u32::from(tx_pointer.block_height()),
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+        }),
+        TransactionStatus::SqueezedOut { reason } => json!({
+            "status": "squeezed_out",
+            "reason": reason,
+        }),
+        TransactionStatus::Replaced {
+            replaced_by,
+            reason,
+        } => json!({
+            "status": "replaced",
+            "replaced_by": replaced_by.to_string(),
+            "reason": reason,
+        }),
+        TransactionStatus::Failure {
+            block_height,
+            time,
+            total_gas,
+            total_fee,
+            reason,
+            ..
+        } => json!({
+            "status": "failure",
+            "block_height": u32::from(*block_height),
+            "time": time.to_unix(),
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+            "reason": reason,
+        }),
+        TransactionStatus::PreconfirmationFailure {
+            tx_pointer,
+            total_gas,
+            total_fee,
+            reason,
+            ..
+        } => json!({
+            "status": "preconfirmation_failure",
+            "block_height": u32::from(tx_pointer.block_height()),
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+            "reason": reason,
+        }),
+    }
+}
@@ -3,94 +3,114 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
 
+use anyhow::Context;
 use clap::Parser;
 use fuel_core_client::client::FuelClient;
-use fuel_core_types::fuel_tx::{
-    Transaction,
-    TxId,
+use output::{
+    OutputFormat,
+    Printer,
 };
-use serde_json::json;
 
-#[derive(Parser)]
-enum Command {
-    #[clap(subcommand)]
-    Transaction(TransactionCommands),
-}
-
-#[derive(Parser)]
-enum TransactionCommands {
-    /// Submit a JSON encoded transaction for inclusion in a block
-    Submit { tx: String },
-    /// Submit a JSON encoded transaction for predicate estimation.
-    EstimatePredicates { tx: String },
-    /// Submit a JSON encoded transaction for a dry-run execution
-    DryRun { txs: Vec<String> },
-    /// Get the transactions associated with a particular transaction id
-    Get { id: String },
-    /// Get the receipts for a particular transaction id
-    Receipts { id: String },
-}
+mod commands;
+mod output;
 
 #[derive(Parser)]
 #[clap(name = "fuel-gql-cli", about = "Fuel GraphQL Endpoint CLI", version)]
 struct CliArgs {
     #[clap(name = "endpoint", default_value = "127.0.0.1:4000", long = "endpoint")]
     endpoint: String,
+    /// The format of the output. Defaults to JSON.
+    #[clap(long = "output", value_enum, global = true)]
+    output: Option<OutputFormat>,
     #[clap(subcommand)]
-    command: Command,
+    command: commands::Command,
 }
 
 impl CliArgs {
-    async fn exec(&self) {
-        let client =
-            FuelClient::new(self.endpoint.as_str()).expect("expected valid endpoint");
-
-        match &self.command {
-            Command::Transaction(sub_cmd) => match sub_cmd {
-                TransactionCommands::Submit { tx } => {
-                    let tx: Transaction =
-                        serde_json::from_str(tx).expect("invalid transaction json");
-
-                    let result = client.submit(&tx).await;
-                    println!("{}", result.unwrap());
-                }
-                TransactionCommands::EstimatePredicates { tx } => {
-                    let mut tx: Transaction =
-                        serde_json::from_str(tx).expect("invalid transaction json");
-
-                    client
-                        .estimate_predicates(&mut tx)
-                        .await
-                        .expect("Should be able to estimate predicates");
-                    println!("{:?}", tx);
-                }
-                TransactionCommands::DryRun { txs } => {
-                    let txs: Vec<Transaction> = txs
-                        .iter()
-                        .map(|tx| {
-                            serde_json::from_str(tx).expect("invalid transaction json")
-                        })
-                        .collect();
-
-                    let result = client.dry_run(&txs).await;
-                    println!("{:?}", result.unwrap());
-                }
-                TransactionCommands::Get { id } => {
-                    let tx_id = id.parse::<TxId>().expect("invalid transaction id");
-                    let tx = client.transaction(&tx_id).await.unwrap();
-                    println!("{:?}", json!(tx).to_string())
-                }
-                TransactionCommands::Receipts { id } => {
-                    let tx_id = id.parse::<TxId>().expect("invalid transaction id");
-                    let receipts = client.receipts(&tx_id).await.unwrap().unwrap();
-                    println!("{:?}", json!(receipts).to_string())
-                }
-            },
-        }
+    async fn exec(self) -> anyhow::Result<()> {
+        let client = FuelClient::new(self.endpoint.as_str())
+            .with_context(|| format!("invalid endpoint `{}`", self.endpoint))?;
+
+        self.command.exec(&client, Printer::new(self.output)).await
     }
 }
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() {
-    CliArgs::parse().exec().await;
+async fn main() -> anyhow::Result<()> {
+    CliArgs::parse().exec().await
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse__output_format_is_global() {
+        let args = CliArgs::try_parse_from([
+            "fuel-core-client",
+            "block",
+            "get",
+            "--height",
+            "1",
+            "--output",
+            "table",
+        ])
+        .unwrap();
+
+        assert_eq!(args.output, Some(OutputFormat::Table));
+    }
+
+    #[test]
+    fn parse__json_is_requested_only_explicitly() {
+        let default =
+            CliArgs::try_parse_from(["fuel-core-client", "transaction", "submit", "{}"])
+                .unwrap();
+        let json = CliArgs::try_parse_from([
+            "fuel-core-client",
+            "transaction",
+            "submit",
+            "{}",
+            "--output",
+            "json",
+        ])
+        .unwrap();
+
+        assert!(!Printer::new(default.output).json_requested());
+        assert!(Printer::new(json.output).json_requested());
+    }
+
+    #[test]
+    fn parse__block_get_requires_height_or_id() {
+        let result = CliArgs::try_parse_from(["fuel-core-client", "block", "get"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse__block_get_rejects_height_and_id() {
+        let result = CliArgs::try_parse_from([
+            "fuel-core-client",
+            "block",
+            "get",
+            "--height",
+            "1",
+            "--id",
+            "0x0000000000000000000000000000000000000000000000000000000000000000",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse__dap_accepts_listen_address() {
+        let args = CliArgs::try_parse_from([
+            "fuel-core-client",
+            "dap",
+            "--listen",
+            "127.0.0.1:4711",
+        ]);
+
+        assert!(args.is_ok());
+    }
 }
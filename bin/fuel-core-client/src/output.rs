@@ -0,0 +1,166 @@
+//! Formatting of the command results.
+
+use fuel_core_client::client::pagination::PaginatedResult;
+use serde_json::{
+    Map,
+    Value,
+    json,
+};
+
+/// The format used to print the command results.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Machine-readable JSON, one document per result.
+    #[default]
+    Json,
+    /// Human-readable table.
+    Table,
+}
+
+/// Prints the command results in the requested format to the stdout.
+#[derive(Copy, Clone, Debug)]
+pub struct Printer {
+    /// The format requested by the user, if any.
+    requested: Option<OutputFormat>,
+}
+
+impl Printer {
+    pub fn new(requested: Option<OutputFormat>) -> Self {
+        Self { requested }
+    }
+
+    /// Returns `true` if the user explicitly asked for the JSON format.
+    pub fn json_requested(&self) -> bool {
+        self.requested == Some(OutputFormat::Json)
+    }
+
+    fn format(&self) -> OutputFormat {
+        self.requested.unwrap_or_default()
+    }
+
+    /// Prints a single record.
+    ///
+    /// In the JSON format, the record is printed on its own line, so the
+    /// streaming commands produce valid JSON lines.
+    pub fn print_one(&self, record: Value) -> anyhow::Result<()> {
+        match self.format() {
+            OutputFormat::Json => println!("{}", serde_json::to_string(&record)?),
+            OutputFormat::Table => match record {
+                Value::Object(fields) => {
+                    let width = fields.keys().map(String::len).max().unwrap_or_default();
+                    for (key, value) in &fields {
+                        println!("{key:<width$}  {}", cell(value));
+                    }
+                }
+                value => println!("{}", cell(&value)),
+            },
+        }
+        Ok(())
+    }
+
+    /// Prints a list of records.
+    pub fn print_list(&self, records: Vec<Value>) -> anyhow::Result<()> {
+        match self.format() {
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string(&Value::Array(records))?)
+            }
+            OutputFormat::Table => print_table(&records),
+        }
+        Ok(())
+    }
+
+    /// Prints a page of records along with the pagination information.
+    pub fn print_page<T>(
+        &self,
+        page: PaginatedResult<T, String>,
+        record: impl Fn(&T) -> Value,
+    ) -> anyhow::Result<()> {
+        let records = page.results.iter().map(record).collect::<Vec<_>>();
+        match self.format() {
+            OutputFormat::Json => {
+                let page = json!({
+                    "results": records,
+                    "cursor": page.cursor,
+                    "has_next_page": page.has_next_page,
+                    "has_previous_page": page.has_previous_page,
+                });
+                println!("{}", serde_json::to_string(&page)?)
+            }
+            OutputFormat::Table => {
+                print_table(&records);
+                // The pagination information goes to the stderr to keep the table parsable.
+                if let Some(cursor) = page.cursor {
+                    eprintln!("cursor: {cursor}");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the bytes as a `0x` prefixed hex string.
+pub fn hex(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn print_table(records: &[Value]) {
+    let mut columns = Vec::<String>::new();
+    for record in records {
+        if let Value::Object(fields) = record {
+            for key in fields.keys() {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+    }
+
+    let empty = Map::new();
+    let rows = records
+        .iter()
+        .map(|record| {
+            let fields = record.as_object().unwrap_or(&empty);
+            columns
+                .iter()
+                .map(|column| fields.get(column).map(cell).unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let widths = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain(core::iter::once(column.len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let print_row = |row: &[String]| {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    if !columns.is_empty() {
+        print_row(&columns);
+    }
+    for row in &rows {
+        print_row(row);
+    }
+}
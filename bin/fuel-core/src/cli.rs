@@ -27,8 +27,14 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(feature = "backup")]
+pub mod backup;
 pub mod fee_contract;
 #[cfg(feature = "rocksdb")]
+pub mod replay;
+#[cfg(feature = "backup")]
+pub mod restore;
+#[cfg(feature = "rocksdb")]
 pub mod rollback;
 pub mod run;
 #[cfg(feature = "rocksdb")]
@@ -57,6 +63,12 @@ pub enum Fuel {
     Snapshot(snapshot::Command),
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
+    #[cfg(feature = "rocksdb")]
+    Replay(replay::Command),
+    #[cfg(feature = "backup")]
+    Backup(backup::Command),
+    #[cfg(feature = "backup")]
+    Restore(restore::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -141,6 +153,12 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Replay(command) => replay::exec(command).await,
+            #[cfg(feature = "backup")]
+            Fuel::Backup(command) => backup::exec(command).await,
+            #[cfg(feature = "backup")]
+            Fuel::Restore(command) => restore::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -674,4 +692,77 @@ mod tests {
             };
         }
     }
+
+    #[cfg(feature = "backup")]
+    mod backup_restore_tests {
+        use super::*;
+
+        #[test]
+        fn backup_path_required() {
+            // given
+            let line = "./core backup --db-path ./db";
+
+            // when
+            let result = parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn can_backup() {
+            // given
+            let line = "./core backup --db-path ./db --backup-path ./backup";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the backup command")
+                .command;
+
+            // then
+            let Fuel::Backup(backup::Command {
+                database_path,
+                backup_path,
+            }) = command
+            else {
+                panic!("Expected a backup command")
+            };
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert_eq!(backup_path, PathBuf::from("./backup"));
+        }
+
+        #[test]
+        fn restore_is_not_forced_by_default() {
+            // given
+            let line = "./core restore --backup-path ./backup";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the restore command")
+                .command;
+
+            // then
+            let Fuel::Restore(restore::Command { force, .. }) = command else {
+                panic!("Expected a restore command")
+            };
+            assert!(!force);
+        }
+
+        #[test]
+        fn restore_can_be_forced() {
+            // given
+            let line = "./core restore --backup-path ./backup --force";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the restore command")
+                .command;
+
+            // then
+            let Fuel::Restore(restore::Command { force, .. }) = command else {
+                panic!("Expected a restore command")
+            };
+            assert!(force);
+        }
+    }
 }
@@ -0,0 +1,93 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::rocks_db::{
+        ColumnsPolicy,
+        DatabaseConfig,
+    },
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Backs up all databases of the node into the backup directory.
+/// The node must be stopped during the backup.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// The directory where to store the backup. It must not exist or be empty.
+    #[clap(name = "BACKUP_PATH", long = "backup-path", value_parser)]
+    pub backup_path: PathBuf,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let db_path = command.database_path.as_path();
+    let backup_path = command.backup_path.as_path();
+
+    if !db_path.is_dir() {
+        anyhow::bail!("The database directory {db_path:?} doesn't exist");
+    }
+
+    if !is_empty_or_missing(backup_path)? {
+        anyhow::bail!(
+            "The backup directory {backup_path:?} is not empty. \
+            Please use a new directory for the backup."
+        );
+    }
+
+    check_version(db_path)?;
+
+    tracing::info!("Backing up the database from {db_path:?} to {backup_path:?}");
+    CombinedDatabase::backup(db_path, backup_path)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to back up the database at path {db_path:?}"
+        ))?;
+    tracing::info!("The backup is stored in {backup_path:?}");
+
+    Ok(())
+}
+
+/// Opens the database at `path` in read-only mode and ensures that versions of all
+/// databases are supported by this binary.
+pub(crate) fn check_version(path: &Path) -> anyhow::Result<()> {
+    let db = CombinedDatabase::open_read_only(
+        path,
+        DatabaseConfig {
+            cache_capacity: None,
+            max_fds: -1,
+            columns_policy: ColumnsPolicy::Lazy,
+        },
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!("failed to open combined database at path {path:?}"))?;
+
+    db.check_version()
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "the database at path {path:?} has unsupported version"
+        ))?;
+
+    Ok(())
+}
+
+pub(crate) fn is_empty_or_missing(path: &Path) -> anyhow::Result<bool> {
+    if !path.exists() {
+        return Ok(true)
+    }
+
+    let mut entries = std::fs::read_dir(path)
+        .context(format!("failed to read the directory {path:?}"))?;
+    Ok(entries.next().is_none())
+}
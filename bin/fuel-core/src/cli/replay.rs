@@ -0,0 +1,632 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+    types::{
+        blockchain::{
+            block::Block,
+            header::{
+                ApplicationHeader,
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::DaBlockHeight,
+        },
+        fuel_tx::{
+            ContractId,
+            Transaction,
+        },
+        fuel_types::BlockHeight,
+        services::{
+            block_producer::Components,
+            executor::{
+                StorageReadReplayEvent,
+                TransactionExecutionStatus,
+            },
+            relayer::Event,
+        },
+    },
+    upgradable_executor::{
+        config::Config as ExecutorConfig,
+        executor::Executor,
+        native_executor::ports::RelayerPort,
+        replay::{
+            RelayerEventsReplay,
+            StorageReadReplay,
+            diff_tx_statuses,
+        },
+    },
+};
+use fuel_core_storage::{
+    MerkleRootStorage,
+    StorageAsRef,
+    tables::FuelBlocks,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
+};
+use rlimit::{
+    Resource,
+    getrlimit,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Re-executes a block or transactions using the recorded storage reads,
+/// without access to the database.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the replay operation.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Records the storage reads of the block execution from the database into a file.
+    Record {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+
+        /// The height of the block to record.
+        #[clap(long = "block-height")]
+        block_height: u32,
+
+        /// Enable full utxo stateful validation, the same as the node which produced
+        /// the block.
+        #[arg(long = "utxo-validation", env)]
+        utxo_validation: bool,
+
+        /// The file where the recording is saved.
+        #[clap(long = "output")]
+        output: PathBuf,
+    },
+    /// Records the storage reads of the dry run of the transactions on top of
+    /// the latest block from the database into a file.
+    RecordDryRun {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+
+        /// The JSON file with the transactions to execute.
+        #[clap(long = "transactions")]
+        transactions: PathBuf,
+
+        /// The gas price used by the dry run.
+        #[clap(long = "gas-price", default_value = "0")]
+        gas_price: u64,
+
+        /// Enable full utxo stateful validation.
+        #[arg(long = "utxo-validation", env)]
+        utxo_validation: bool,
+
+        /// The file where the recording is saved.
+        #[clap(long = "output")]
+        output: PathBuf,
+    },
+    /// Re-executes the recording and prints the differences between the results.
+    /// Exits with an error if the execution fails or the results differ.
+    Execute {
+        /// The file with the recording.
+        #[clap(long = "input")]
+        input: PathBuf,
+
+        /// The executor used for the re-execution.
+        #[clap(long = "executor", value_enum, default_value_t = ExecutorKind::Both)]
+        executor: ExecutorKind,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExecutorKind {
+    /// Only the native executor.
+    Native,
+    /// Only the WASM executor.
+    Wasm,
+    /// Both executors, comparing their results.
+    Both,
+}
+
+/// The recording of the execution that can be replayed without the database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording {
+    /// What to re-execute.
+    pub target: ReplayTarget,
+    /// The storage reads recorded during the original execution.
+    pub storage_reads: Vec<StorageReadReplayEvent>,
+    /// The relayer events used by the original execution.
+    #[serde(default)]
+    pub relayer_events: Vec<(DaBlockHeight, Vec<Event>)>,
+    /// Whether the original execution used the utxo validation.
+    pub utxo_validation: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReplayTarget {
+    /// The block is validated by the executor.
+    Block(Block),
+    /// The transactions are executed in the dry run mode, like the
+    /// `dryRunRecordStorageReads` GraphQL query does.
+    Transactions {
+        header: PartialBlockHeader,
+        transactions: Vec<Transaction>,
+        gas_price: u64,
+        #[serde(default)]
+        coinbase_recipient: ContractId,
+        /// The statuses returned by the original execution, if known.
+        #[serde(default)]
+        expected: Option<Vec<TransactionExecutionStatus>>,
+    },
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Record {
+            database_path,
+            rocksdb_max_fds,
+            block_height,
+            utxo_validation,
+            output,
+        } => record(
+            &database_path,
+            rocksdb_max_fds,
+            block_height.into(),
+            utxo_validation,
+            &output,
+        ),
+        SubCommands::RecordDryRun {
+            database_path,
+            rocksdb_max_fds,
+            transactions,
+            gas_price,
+            utxo_validation,
+            output,
+        } => record_dry_run(
+            &database_path,
+            rocksdb_max_fds,
+            &transactions,
+            gas_price,
+            utxo_validation,
+            &output,
+        ),
+        SubCommands::Execute { input, executor } => execute(&input, executor),
+    }
+}
+
+fn open_database(path: &Path, rocksdb_max_fds: i32) -> anyhow::Result<CombinedDatabase> {
+    CombinedDatabase::open(
+        path,
+        StateRewindPolicy::RewindFullRange,
+        DatabaseConfig {
+            cache_capacity: Some(64 * 1024 * 1024),
+            max_fds: rocksdb_max_fds,
+            columns_policy: ColumnsPolicy::Lazy,
+        },
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!("failed to open combined database at path {path:?}"))
+}
+
+fn save_recording(recording: &Recording, output: &Path) -> anyhow::Result<()> {
+    let file = std::fs::File::create(output)
+        .with_context(|| format!("failed to create the file {output:?}"))?;
+    serde_json::to_writer(file, recording)?;
+    Ok(())
+}
+
+fn record(
+    path: &Path,
+    rocksdb_max_fds: i32,
+    block_height: BlockHeight,
+    utxo_validation: bool,
+    output: &Path,
+) -> anyhow::Result<()> {
+    let db = open_database(path, rocksdb_max_fds)?;
+
+    let on_chain = db.on_chain().latest_view()?;
+    let block = on_chain
+        .get_full_block(&block_height)?
+        .ok_or_else(|| anyhow::anyhow!("The block at height {block_height} not found"))?;
+
+    let executor = Executor::native(
+        db.on_chain().clone(),
+        db.relayer().clone(),
+        executor_config(utxo_validation),
+    );
+    let storage_reads = executor.storage_read_replay(&block)?;
+
+    // The relayer events are not a part of the storage reads,
+    // so we record all events processed by the block.
+    let mut relayer_events = vec![];
+    if let Some(previous_block_height) = block_height.pred() {
+        let previous_block = on_chain
+            .get_full_block(&previous_block_height)?
+            .ok_or_else(|| {
+                anyhow::anyhow!("The block at height {previous_block_height} not found")
+            })?;
+        let relayer = db.relayer().latest_view()?;
+        let from = previous_block.header().da_height().saturating_add(1);
+        let to = *block.header().da_height();
+        for da_height in from..=to {
+            let da_height = DaBlockHeight::from(da_height);
+            let events = relayer.get_events(&da_height)?;
+            relayer_events.push((da_height, events));
+        }
+    }
+
+    let recording = Recording {
+        target: ReplayTarget::Block(block),
+        storage_reads,
+        relayer_events,
+        utxo_validation,
+    };
+    save_recording(&recording, output)?;
+
+    tracing::info!(
+        "The execution of the block at height {block_height} is recorded into {output:?}"
+    );
+    Ok(())
+}
+
+fn record_dry_run(
+    path: &Path,
+    rocksdb_max_fds: i32,
+    transactions: &Path,
+    gas_price: u64,
+    utxo_validation: bool,
+    output: &Path,
+) -> anyhow::Result<()> {
+    let file = std::fs::File::open(transactions)
+        .with_context(|| format!("failed to open the file {transactions:?}"))?;
+    let transactions: Vec<Transaction> =
+        serde_json::from_reader(std::io::BufReader::new(file)).with_context(|| {
+            format!("failed to decode the transactions from {transactions:?}")
+        })?;
+
+    let db = open_database(path, rocksdb_max_fds)?;
+    let latest_height = db
+        .on_chain()
+        .latest_height()
+        .ok_or_else(|| anyhow::anyhow!("The database doesn't contain blocks"))?;
+    let on_chain = db.on_chain().latest_view()?;
+    let latest_block = on_chain.get_full_block(&latest_height)?.ok_or_else(|| {
+        anyhow::anyhow!("The block at height {latest_height} not found")
+    })?;
+    let latest_header = latest_block.header();
+
+    // The dry run is executed on top of the latest block, like the
+    // `dryRunRecordStorageReads` GraphQL query does.
+    let header = PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: *latest_header.da_height(),
+            consensus_parameters_version: latest_header.consensus_parameters_version(),
+            state_transition_bytecode_version: latest_header
+                .state_transition_bytecode_version(),
+            generated: Default::default(),
+        },
+        consensus: ConsensusHeader {
+            prev_root: on_chain
+                .storage::<FuelBlocks>()
+                .root(&latest_height)?
+                .into(),
+            height: latest_height
+                .succ()
+                .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))?,
+            time: latest_header.time(),
+            generated: Default::default(),
+        },
+    };
+
+    let executor = Executor::native(
+        db.on_chain().clone(),
+        db.relayer().clone(),
+        executor_config(utxo_validation),
+    );
+    let components = Components {
+        header_to_produce: header,
+        transactions_source: transactions.clone(),
+        coinbase_recipient: Default::default(),
+        gas_price,
+    };
+    let result = executor.dry_run(components, None, None, true, Default::default())?;
+
+    let recording = Recording {
+        target: ReplayTarget::Transactions {
+            header,
+            transactions,
+            gas_price,
+            coinbase_recipient: Default::default(),
+            expected: Some(
+                result
+                    .transactions
+                    .into_iter()
+                    .map(|(_, status)| status)
+                    .collect(),
+            ),
+        },
+        storage_reads: result.storage_reads,
+        relayer_events: vec![],
+        utxo_validation,
+    };
+    save_recording(&recording, output)?;
+
+    tracing::info!(
+        "The dry run on top of the block at height {latest_height} is recorded into {output:?}"
+    );
+    Ok(())
+}
+
+fn execute(input: &Path, executor_kind: ExecutorKind) -> anyhow::Result<()> {
+    let file = std::fs::File::open(input)
+        .with_context(|| format!("failed to open the file {input:?}"))?;
+    let recording: Recording = serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("failed to decode the recording from {input:?}"))?;
+
+    let storage = StorageReadReplay::new(recording.storage_reads);
+    let relayer = RelayerEventsReplay::new(recording.relayer_events);
+    let config = executor_config(recording.utxo_validation);
+
+    let mut results = vec![];
+    if executor_kind != ExecutorKind::Wasm {
+        let executor = Executor::native(storage.clone(), relayer.clone(), config.clone());
+        results.push(("native", replay(&executor, &recording.target)));
+    }
+    if executor_kind != ExecutorKind::Native {
+        let executor = Executor::wasm(storage.clone(), relayer.clone(), config.clone());
+        results.push(("wasm", replay(&executor, &recording.target)));
+    }
+
+    let mut diff = vec![];
+    for (name, result) in &results {
+        match result {
+            Ok(statuses) => {
+                tracing::info!(
+                    "The {name} executor executed {} transactions",
+                    statuses.len()
+                );
+                if let ReplayTarget::Transactions {
+                    expected: Some(expected),
+                    ..
+                } = &recording.target
+                {
+                    diff.extend(
+                        diff_tx_statuses(expected, statuses)
+                            .into_iter()
+                            .map(|line| format!("[{name}] {line}")),
+                    );
+                }
+            }
+            Err(err) => diff.push(format!("[{name}] The execution failed: {err}")),
+        }
+    }
+
+    if let [(_, Ok(native)), (_, Ok(wasm))] = results.as_slice() {
+        diff.extend(
+            diff_tx_statuses(native, wasm)
+                .into_iter()
+                .map(|line| format!("[native vs wasm] {line}")),
+        );
+    }
+
+    if diff.is_empty() {
+        tracing::info!("The replay matches the original execution");
+        Ok(())
+    } else {
+        for line in &diff {
+            tracing::error!("{line}");
+        }
+        Err(anyhow::anyhow!(
+            "The replay found {} differences with the original execution",
+            diff.len()
+        ))
+    }
+}
+
+fn executor_config(utxo_validation: bool) -> ExecutorConfig {
+    ExecutorConfig {
+        forbid_fake_coins_default: utxo_validation,
+        ..Default::default()
+    }
+}
+
+fn replay(
+    executor: &Executor<StorageReadReplay, RelayerEventsReplay>,
+    target: &ReplayTarget,
+) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+    match target {
+        ReplayTarget::Block(block) => {
+            // Validation re-executes the block and checks that the result matches the block.
+            let result = executor.validate(block)?.into_result();
+            Ok(result.tx_status)
+        }
+        ReplayTarget::Transactions {
+            header,
+            transactions,
+            gas_price,
+            coinbase_recipient,
+            ..
+        } => {
+            let components = Components {
+                header_to_produce: *header,
+                transactions_source: transactions.clone(),
+                coinbase_recipient: *coinbase_recipient,
+                gas_price: *gas_price,
+            };
+            let result =
+                executor.dry_run(components, None, None, false, Default::default())?;
+            Ok(result
+                .transactions
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core::{
+        fuel_core_graphql_api::ports::ConsensusModulePort,
+        service::{
+            Config,
+            FuelService,
+        },
+        types::{
+            fuel_asm::op,
+            fuel_tx::TransactionBuilder,
+        },
+    };
+    use test_case::test_case;
+
+    /// Produces the blocks in the database at the `path` and stops the node.
+    fn given_database_with_blocks(path: &Path, number_of_blocks: u32) {
+        let mut config = Config::local_node();
+        config.combined_db_config.database_path = path.to_path_buf();
+        let fut = async move {
+            let node = FuelService::new_node(config).await.unwrap();
+            node.shared
+                .poa_adapter
+                .manually_produce_blocks(None, number_of_blocks)
+                .await
+                .unwrap();
+            node.send_stop_signal_and_await_shutdown().await.unwrap();
+        };
+
+        // Because the test_case macro doesn't work with async tests
+        tokio::runtime::Runtime::new().unwrap().block_on(fut);
+    }
+
+    #[test_case(ExecutorKind::Native; "native")]
+    #[test_case(ExecutorKind::Wasm; "wasm")]
+    #[test_case(ExecutorKind::Both; "both")]
+    fn execute__succeeds_on_recorded_block(executor: ExecutorKind) {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db");
+        let recording = dir.path().join("recording.json");
+        given_database_with_blocks(&db_path, 2);
+        record(&db_path, -1, 2u32.into(), false, &recording).unwrap();
+
+        // When
+        let result = execute(&recording, executor);
+
+        // Then
+        result.expect("The replay should match the original execution");
+    }
+
+    #[test_case(ExecutorKind::Native; "native")]
+    #[test_case(ExecutorKind::Wasm; "wasm")]
+    #[test_case(ExecutorKind::Both; "both")]
+    fn execute__succeeds_on_recorded_dry_run(executor: ExecutorKind) {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db");
+        let transactions = dir.path().join("transactions.json");
+        let recording = dir.path().join("recording.json");
+        given_database_with_blocks(&db_path, 1);
+        let script =
+            TransactionBuilder::script(vec![op::ret(1)].into_iter().collect(), vec![])
+                .script_gas_limit(10_000)
+                .add_fee_input()
+                .finalize_as_transaction();
+        std::fs::write(&transactions, serde_json::to_vec(&vec![script]).unwrap())
+            .unwrap();
+        record_dry_run(&db_path, -1, &transactions, 0, false, &recording).unwrap();
+
+        // When
+        let result = execute(&recording, executor);
+
+        // Then
+        result.expect("The replay should match the original execution");
+        let file = std::fs::File::open(&recording).unwrap();
+        let recording: Recording = serde_json::from_reader(file).unwrap();
+        let ReplayTarget::Transactions {
+            expected: Some(expected),
+            ..
+        } = recording.target
+        else {
+            panic!("The dry run should be recorded with the expected statuses");
+        };
+        assert_eq!(expected.len(), 1);
+    }
+
+    #[test]
+    fn execute__fails_on_empty_recording() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("recording.json");
+        let recording = Recording {
+            target: ReplayTarget::Block(Block::default()),
+            storage_reads: vec![],
+            relayer_events: vec![],
+            utxo_validation: false,
+        };
+        std::fs::write(&input, serde_json::to_vec(&recording).unwrap()).unwrap();
+
+        // When
+        let result = execute(&input, ExecutorKind::Native);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use crate::cli::{
+    backup::{
+        check_version,
+        is_empty_or_missing,
+    },
+    default_db_path,
+};
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::combined_database::CombinedDatabase;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Restores all databases of the node from the backup directory.
+/// The node must be stopped during the restore.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path where to restore the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// The directory with the backup created by the `backup` command.
+    #[clap(name = "BACKUP_PATH", long = "backup-path", value_parser)]
+    pub backup_path: PathBuf,
+
+    /// Removes the existing database at `DB_PATH` before the restore.
+    #[clap(long = "force", default_value = "false")]
+    pub force: bool,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let db_path = command.database_path.as_path();
+    let backup_path = command.backup_path.as_path();
+
+    if !backup_path.is_dir() {
+        anyhow::bail!("The backup directory {backup_path:?} doesn't exist");
+    }
+
+    let replace_existing = !is_empty_or_missing(db_path)?;
+    if replace_existing && !command.force {
+        anyhow::bail!(
+            "The database directory {db_path:?} is not empty. \
+            Use `--force` to replace the existing database."
+        );
+    }
+
+    // The backup is restored next to the database and replaces it only
+    // after the check, so the existing database is kept if the restore fails.
+    let staging_path = sibling_path(db_path, "restore")?;
+    // The leftover of the interrupted restore.
+    remove_if_exists(&staging_path)?;
+    if let Some(parent) = staging_path.parent() {
+        std::fs::create_dir_all(parent)
+            .context(format!("failed to create the directory {parent:?}"))?;
+    }
+
+    tracing::info!("Restoring the database from {backup_path:?} to {staging_path:?}");
+    let restored = CombinedDatabase::restore(&staging_path, backup_path)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to restore the database from the backup at path {backup_path:?}"
+        ))
+        .and_then(|_| check_version(&staging_path));
+    if let Err(err) = restored {
+        // The restored database can't be used by this binary, so we don't leave it.
+        remove_if_exists(&staging_path)?;
+        return Err(err)
+    }
+
+    if replace_existing {
+        tracing::warn!("Replacing the existing database at {db_path:?}");
+        let previous_path = sibling_path(db_path, "previous")?;
+        remove_if_exists(&previous_path)?;
+        std::fs::rename(db_path, &previous_path).context(format!(
+            "failed to move the existing database at path {db_path:?}"
+        ))?;
+        if let Err(err) = std::fs::rename(&staging_path, db_path) {
+            std::fs::rename(&previous_path, db_path).context(format!(
+                "failed to move the existing database back to path {db_path:?}"
+            ))?;
+            return Err(err).context(format!(
+                "failed to move the restored database to path {db_path:?}"
+            ))
+        }
+        remove_if_exists(&previous_path)?;
+    } else {
+        // The database directory may exist, but it is empty.
+        remove_if_exists(db_path)?;
+        std::fs::rename(&staging_path, db_path).context(format!(
+            "failed to move the restored database to path {db_path:?}"
+        ))?;
+    }
+    tracing::info!("The database is restored to {db_path:?}");
+
+    Ok(())
+}
+
+/// Returns the path next to the database directory with the `suffix`
+/// appended to its name, so the rename between them doesn't cross file systems.
+fn sibling_path(db_path: &Path, suffix: &str) -> anyhow::Result<PathBuf> {
+    let mut name = db_path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("The database path {db_path:?} has no name"))?
+        .to_os_string();
+    name.push(format!(".{suffix}"));
+    Ok(db_path.with_file_name(name))
+}
+
+fn remove_if_exists(path: &Path) -> anyhow::Result<()> {
+    if path.exists() {
+        std::fs::remove_dir_all(path)
+            .context(format!("failed to remove the directory {path:?}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::cli::backup;
+    use fuel_core::state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    };
+    use fuel_core_storage::{
+        StorageAsMut,
+        StorageAsRef,
+        tables::Coins,
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+
+    fn database_config() -> DatabaseConfig {
+        DatabaseConfig {
+            cache_capacity: None,
+            max_fds: 512,
+            columns_policy: ColumnsPolicy::Lazy,
+        }
+    }
+
+    fn create_database_with_coin(path: &Path, utxo_id: UtxoId) {
+        let mut db =
+            CombinedDatabase::open(path, StateRewindPolicy::NoRewind, database_config())
+                .unwrap();
+        let mut tx = db.on_chain_mut().write_transaction();
+        tx.storage_as_mut::<Coins>()
+            .insert(&utxo_id, &CompressedCoin::default())
+            .unwrap();
+        tx.commit().unwrap();
+    }
+
+    fn has_coin(path: &Path, utxo_id: UtxoId) -> bool {
+        let db = CombinedDatabase::open_read_only(path, database_config()).unwrap();
+        db.on_chain()
+            .storage::<Coins>()
+            .contains_key(&utxo_id)
+            .unwrap()
+    }
+
+    fn restore_command(database_path: &Path, backup_path: &Path) -> Command {
+        Command {
+            database_path: database_path.to_path_buf(),
+            backup_path: backup_path.to_path_buf(),
+            force: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn restore__replaces_existing_database_with_backup() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("source");
+        let backup_path = dir.path().join("backup");
+        let db_path = dir.path().join("db");
+        let backed_up_coin = UtxoId::new([1; 32].into(), 0);
+        let existing_coin = UtxoId::new([2; 32].into(), 0);
+        create_database_with_coin(&source_path, backed_up_coin);
+        backup::exec(backup::Command {
+            database_path: source_path.clone(),
+            backup_path: backup_path.clone(),
+        })
+        .await
+        .unwrap();
+        create_database_with_coin(&db_path, existing_coin);
+
+        // When
+        exec(restore_command(&db_path, &backup_path)).await.unwrap();
+
+        // Then
+        assert!(has_coin(&db_path, backed_up_coin));
+        assert!(!has_coin(&db_path, existing_coin));
+        assert!(!sibling_path(&db_path, "restore").unwrap().exists());
+        assert!(!sibling_path(&db_path, "previous").unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn restore__keeps_existing_database_if_backup_is_invalid() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let backup_path = dir.path().join("backup");
+        let db_path = dir.path().join("db");
+        let existing_coin = UtxoId::new([2; 32].into(), 0);
+        std::fs::create_dir_all(&backup_path).unwrap();
+        std::fs::write(backup_path.join("garbage"), [0xff; 16]).unwrap();
+        create_database_with_coin(&db_path, existing_coin);
+
+        // When
+        let result = exec(restore_command(&db_path, &backup_path)).await;
+
+        // Then
+        assert!(result.is_err());
+        assert!(has_coin(&db_path, existing_coin));
+        assert!(!sibling_path(&db_path, "restore").unwrap().exists());
+    }
+}
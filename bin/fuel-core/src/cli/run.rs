@@ -24,6 +24,7 @@ use fuel_core::{
     fuel_core_graphql_api::{
         Costs,
         ServiceConfig as GraphQLConfig,
+        rate_limit::RateLimitConfig,
     },
     producer::Config as ProducerConfig,
     service::{
@@ -32,6 +33,7 @@ use fuel_core::{
         RelayerConsensusConfig,
         config::{
             DaCompressionMode,
+            DaDerivationConfig,
             Trigger,
         },
         genesis::NotifyCancel,
@@ -49,7 +51,10 @@ use fuel_core::{
         ServiceChannelLimits,
     },
     types::{
-        fuel_tx::ContractId,
+        fuel_tx::{
+            Address,
+            ContractId,
+        },
         fuel_vm::SecretKey,
         secrecy::Secret,
     },
@@ -58,6 +63,7 @@ use fuel_core::{
 use fuel_core_chain_config::{
     SnapshotMetadata,
     SnapshotReader,
+    verify_snapshot,
 };
 use fuel_core_metrics::config::{
     DisableConfig,
@@ -180,6 +186,11 @@ pub struct Command {
     #[arg(name = "SNAPSHOT", long = "snapshot", env)]
     pub snapshot: Option<PathBuf>,
 
+    /// The address of the signer of the snapshot manifest. If set, the snapshot
+    /// without a manifest or with a manifest not signed by this address is refused.
+    #[arg(long = "snapshot-signer", requires = "SNAPSHOT", env)]
+    pub snapshot_signer: Option<Address>,
+
     /// Prunes the db. Genesis is done from the provided snapshot or the local testnet
     /// configuration.
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
@@ -245,6 +256,25 @@ pub struct Command {
     #[arg(long = "da-compression-starting-height", env)]
     pub da_compression_starting_height: Option<NonZeroU32>,
 
+    /// If given, the node derives the blocks from the postcard encoded DA compressed
+    /// blocks in the directory, stored as `{height}.bin`, instead of producing them.
+    #[arg(
+        long = "da-derivation-path",
+        env,
+        requires = "da_derivation_retention",
+        conflicts_with = "da_compression"
+    )]
+    pub da_derivation_path: Option<PathBuf>,
+
+    /// The retention time of the temporal registry used by the node
+    /// that compressed the blocks.
+    #[arg(long = "da-derivation-retention", env, requires = "da_derivation_path")]
+    pub da_derivation_retention: Option<humantime::Duration>,
+
+    /// How often the DA derivation directory is checked for the next block.
+    #[arg(long = "da-derivation-poll-interval", default_value = "1s", env)]
+    pub da_derivation_poll_interval: humantime::Duration,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -283,6 +313,10 @@ pub struct Command {
     #[cfg(feature = "p2p")]
     pub sync_args: p2p::SyncArgs,
 
+    #[cfg_attr(feature = "p2p", clap(flatten))]
+    #[cfg(feature = "p2p")]
+    pub bft_args: p2p::BftArgs,
+
     #[cfg_attr(feature = "p2p", clap(flatten))]
     #[cfg(feature = "p2p")]
     pub pre_confirmation_signature_service_args:
@@ -335,6 +369,7 @@ impl Command {
             state_rewind_duration,
             db_prune,
             snapshot,
+            snapshot_signer,
             continue_on_error,
             vm_backtrace: _,
             debug,
@@ -349,6 +384,9 @@ impl Command {
             consensus_aws_kms,
             da_compression,
             da_compression_starting_height,
+            da_derivation_path,
+            da_derivation_retention,
+            da_derivation_poll_interval,
             poa_trigger,
             predefined_blocks_path,
             coinbase_recipient,
@@ -359,6 +397,8 @@ impl Command {
             #[cfg(feature = "p2p")]
             sync_args,
             #[cfg(feature = "p2p")]
+            bft_args,
+            #[cfg(feature = "p2p")]
             pre_confirmation_signature_service_args,
             #[cfg(feature = "shared-sequencer")]
             shared_sequencer_args,
@@ -404,11 +444,17 @@ impl Command {
         }
 
         let addr = net::SocketAddr::new(graphql.ip, graphql.port);
+        let graphql_rate_limit = graphql
+            .rate_limit_config
+            .as_ref()
+            .map(RateLimitConfig::load)
+            .transpose()?;
 
         let snapshot_reader = match snapshot.as_ref() {
             None => crate::cli::local_testnet_reader(),
             Some(path) => {
                 let metadata = SnapshotMetadata::read(path)?;
+                verify_snapshot(path, &metadata, snapshot_signer.as_ref())?;
                 SnapshotReader::open(metadata)?
             }
         };
@@ -421,6 +467,8 @@ impl Command {
         let p2p_cfg = p2p_args.into_config(
             chain_config.chain_name.clone(),
             metrics.is_enabled(Module::P2P),
+            (database_type == DbType::RocksDb)
+                .then(|| database_path.join(p2p::PEER_REPUTATION_FILE)),
         )?;
 
         #[cfg(feature = "p2p")]
@@ -571,6 +619,14 @@ impl Command {
             None => DaCompressionMode::Disabled,
         };
 
+        let da_derivation = da_derivation_path.zip(da_derivation_retention).map(
+            |(source_path, retention_duration)| DaDerivationConfig {
+                source_path,
+                retention_duration: retention_duration.into(),
+                poll_interval: da_derivation_poll_interval.into(),
+            },
+        );
+
         let TxPoolArgs {
             tx_pool_ttl,
             tx_ttl_check_interval,
@@ -590,6 +646,9 @@ impl Command {
             tx_max_pending_write_requests,
             tx_pending_pool_ttl,
             tx_pending_pool_size_percentage,
+            tx_pool_selection_algorithm,
+            tx_pool_max_txs_per_owner,
+            tx_min_replacement_tip_bump_percentage,
         } = tx_pool;
 
         let TxStatusManagerArgs {
@@ -670,6 +729,7 @@ impl Command {
                 assemble_tx_estimate_predicates_limit: graphql
                     .assemble_tx_estimate_predicates_limit,
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                metrics: metrics.is_enabled(Module::GraphQL),
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
                     coins_to_spend: graphql.costs.coins_to_spend,
@@ -700,6 +760,8 @@ impl Command {
                 required_fuel_block_height_timeout: graphql
                     .required_fuel_block_height_timeout
                     .into(),
+                admin_token: graphql.admin_token,
+                rate_limit: graphql_rate_limit,
             },
             combined_db_config,
             snapshot_reader,
@@ -723,6 +785,10 @@ impl Command {
                 service_channel_limits,
                 pending_pool_tx_ttl: tx_pending_pool_ttl.into(),
                 max_pending_pool_size_percentage: tx_pending_pool_size_percentage,
+                selection_algorithm: tx_pool_selection_algorithm
+                    .into_config(tx_pool_max_txs_per_owner),
+                min_replacement_tip_bump_percentage:
+                    tx_min_replacement_tip_bump_percentage,
                 metrics: metrics.is_enabled(Module::TxPool),
             },
             block_producer: ProducerConfig {
@@ -732,14 +798,19 @@ impl Command {
             gas_price_config,
             block_importer,
             da_compression,
+            da_derivation,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
+            snap_sync: sync_args.snap_sync_config(),
+            #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             #[cfg(feature = "p2p")]
             pre_confirmation_signature_service: preconfirmation_signature_service_config,
+            #[cfg(feature = "p2p")]
+            bft_timeouts: bft_args.into(),
             #[cfg(feature = "shared-sequencer")]
             shared_sequencer: shared_sequencer_args.try_into()?,
             consensus_signer,
@@ -1,6 +1,9 @@
 //! Clap configuration related to GraphQL service.
 
-use std::net;
+use std::{
+    net,
+    path::PathBuf,
+};
 
 use fuel_core::fuel_core_graphql_api::DEFAULT_QUERY_COSTS;
 
@@ -99,6 +102,20 @@ pub struct GraphQLArgs {
     )]
     pub required_fuel_block_height_timeout: humantime::Duration,
 
+    /// The token that enables the admin GraphQL API, e.g. for the runtime management
+    /// of the TxPool blacklist. Requests must pass it in the `Authorization: Bearer`
+    /// header. The admin API is disabled if the token is not set.
+    #[clap(long = "admin-token", env)]
+    pub admin_token: Option<String>,
+
+    /// The path to the JSON file with per-client rate limits of the GraphQL API.
+    /// The file defines the token bucket quota for each IP address and named
+    /// API keys with their own quotas. Clients pass the key in the `x-api-key` header.
+    /// Each query takes as many tokens as its complexity.
+    /// Requests are not rate limited if the file is not set.
+    #[clap(long = "graphql-rate-limit-config", env)]
+    pub rate_limit_config: Option<PathBuf>,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
@@ -18,6 +18,7 @@ use fuel_core::{
     types::{
         fuel_crypto,
         fuel_crypto::SecretKey,
+        fuel_types::Bytes32,
     },
 };
 use std::{
@@ -33,6 +34,9 @@ use std::{
 const MAX_RESPONSE_SIZE_STR: &str =
     const_format::formatcp!("{}", MAX_RESPONSE_SIZE.get());
 
+/// The file in the database directory with the scores and bans of peers.
+pub const PEER_REPUTATION_FILE: &str = "peer_reputation.json";
+
 #[derive(Debug, Clone, Args)]
 pub struct P2PArgs {
     /// Enable P2P. By default, P2P is disabled, even when the binary is compiled with the "p2p"
@@ -58,6 +62,11 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// p2p network's UDP Port for the QUIC transport.
+    /// The QUIC transport is disabled if it is not set.
+    #[clap(long = "quic-peering-port", env)]
+    pub quic_peering_port: Option<u16>,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: u32,
@@ -123,6 +132,11 @@ pub struct P2PArgs {
     #[clap(long = "connection-idle-timeout", default_value = "120s", env)]
     pub connection_idle_timeout: humantime::Duration,
 
+    /// How long the peer stays banned after its reputation drops below the threshold.
+    /// The bans are kept next to the database and survive restarts of the node.
+    #[clap(long = "peer-ban-duration", default_value = "1h", env)]
+    pub peer_ban_duration: humantime::Duration,
+
     /// Choose how often to receive PeerInfo from other nodes
     #[clap(long = "info-interval", default_value = "3s", env)]
     pub info_interval: humantime::Duration,
@@ -213,6 +227,19 @@ pub struct P2PArgs {
     /// Subscribe to pre-confirmation gossip topic
     #[clap(long = "subscribe-to-pre-confirmations", env)]
     subscribe_to_pre_confirmations: bool,
+
+    /// Subscribe to new block gossip topic to import blocks as soon as they are produced
+    #[clap(long = "subscribe-to-new-blocks", env)]
+    subscribe_to_new_blocks: bool,
+
+    /// Subscribe to BFT proposal and vote gossip topics, required for the BFT validators
+    #[clap(long = "subscribe-to-bft-consensus", env)]
+    subscribe_to_bft_consensus: bool,
+
+    /// The interval in blocks between the state checkpoints served to the peers
+    /// that bootstrap with the snap sync. If not set, the state is not served.
+    #[clap(long = "state-checkpoint-interval", env)]
+    pub state_checkpoint_interval: Option<NonZeroU32>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -223,6 +250,35 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// Bootstrap the fresh node from the state checkpoint with this root served
+    /// by the peers instead of executing all blocks since the genesis.
+    /// The root should be obtained from a trusted node, which logs the root
+    /// of each state checkpoint it computes.
+    #[clap(long = "snap-sync-root", env)]
+    pub snap_sync_root: Option<Bytes32>,
+    /// The time to find the peers serving the state checkpoint.
+    /// After it, the node falls back to the sync of the blocks.
+    #[clap(long = "snap-sync-timeout", default_value = "60s", env)]
+    pub snap_sync_timeout: humantime::Duration,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BftArgs {
+    /// The time to wait for the proposal of the BFT round.
+    #[clap(long = "bft-propose-timeout", default_value = "3s", env)]
+    pub propose_timeout: humantime::Duration,
+    /// The time to wait for the prevotes of the BFT round.
+    #[clap(long = "bft-prevote-timeout", default_value = "1s", env)]
+    pub prevote_timeout: humantime::Duration,
+    /// The time to wait for the precommits of the BFT round.
+    #[clap(long = "bft-precommit-timeout", default_value = "1s", env)]
+    pub precommit_timeout: humantime::Duration,
+    /// The increase of the BFT timeouts with each subsequent round of the same height.
+    #[clap(long = "bft-round-timeout-delta", default_value = "500ms", env)]
+    pub round_timeout_delta: humantime::Duration,
+    /// The delay between the commit of the block and the start of the next height.
+    #[clap(long = "bft-commit-timeout", default_value = "1s", env)]
+    pub commit_timeout: humantime::Duration,
 }
 
 #[derive(Clone, Debug)]
@@ -261,11 +317,34 @@ impl From<SyncArgs> for fuel_core::sync::Config {
     }
 }
 
+impl SyncArgs {
+    /// Returns the parameters of the snap sync if it is enabled.
+    pub fn snap_sync_config(&self) -> Option<fuel_core::sync::state_sync::Config> {
+        self.snap_sync_root.map(|root| fuel_core::sync::state_sync::Config {
+            timeout: self.snap_sync_timeout.into(),
+            ..fuel_core::sync::state_sync::Config::new(root)
+        })
+    }
+}
+
+impl From<BftArgs> for fuel_core::bft::Timeouts {
+    fn from(value: BftArgs) -> Self {
+        Self {
+            propose: value.propose_timeout.into(),
+            prevote: value.prevote_timeout.into(),
+            precommit: value.precommit_timeout.into(),
+            delta: value.round_timeout_delta.into(),
+            commit: value.commit_timeout.into(),
+        }
+    }
+}
+
 impl P2PArgs {
     pub fn into_config(
         self,
         network_name: String,
         metrics: bool,
+        reputation_path: Option<PathBuf>,
     ) -> anyhow::Result<Option<Config<NotInitialized>>> {
         if !self.enable_p2p {
             tracing::info!("P2P service disabled");
@@ -325,6 +404,7 @@ impl P2PArgs {
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
             public_address: self.public_address,
             tcp_port: self.peering_port,
+            quic_port: self.quic_peering_port,
             max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             max_txs_per_request: self.max_txs_per_request,
@@ -334,6 +414,8 @@ impl P2PArgs {
             enable_mdns: self.enable_mdns,
             max_discovery_peers_connected: self.max_discovery_peers_connected,
             max_outgoing_connections: self.max_outgoing_connections,
+            reputation_path,
+            ban_duration: self.peer_ban_duration.into(),
             max_connections_per_peer: self.max_connections_per_peer,
             max_functional_peers_connected: self.max_peers_connected,
             allow_private_addresses: self.allow_private_addresses,
@@ -355,6 +437,9 @@ impl P2PArgs {
             state: NotInitialized,
             subscribe_to_new_tx: self.subscribe_to_new_tx,
             subscribe_to_pre_confirmations: self.subscribe_to_pre_confirmations,
+            subscribe_to_new_blocks: self.subscribe_to_new_blocks,
+            subscribe_to_bft_consensus: self.subscribe_to_bft_consensus,
+            state_checkpoint_interval: self.state_checkpoint_interval,
         };
         Ok(Some(config))
     }
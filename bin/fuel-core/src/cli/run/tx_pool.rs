@@ -1,5 +1,6 @@
 //! Clap configuration related to TxPool service.
 
+use fuel_core::txpool::config::SelectionAlgorithmConfig;
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -8,6 +9,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
 };
+use std::num::NonZeroUsize;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
@@ -89,6 +91,59 @@ pub struct TxPoolArgs {
     /// The max percentage of the `TxPool` that can be used by the `PendingPool`.
     #[clap(long = "tx-pending-pool-size-percentage", default_value = "50", env)]
     pub tx_pending_pool_size_percentage: u16,
+
+    /// The algorithm used to select transactions from the `TxPool` for the block.
+    #[clap(
+        long = "tx-pool-selection-algorithm",
+        default_value = "ratio-tip-gas",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub tx_pool_selection_algorithm: SelectionAlgorithm,
+
+    /// The max number of transactions of one owner that can be selected for the block.
+    /// Used only by the `fair` selection algorithm.
+    #[clap(long = "tx-pool-max-txs-per-owner", default_value = "16", env)]
+    pub tx_pool_max_txs_per_owner: NonZeroUsize,
+
+    /// The min percentage by which the tip of a transaction replacing another one
+    /// (by spending the same input) must exceed the tip of the replaced transaction.
+    #[clap(
+        long = "tx-min-replacement-tip-bump-percentage",
+        default_value = "10",
+        env
+    )]
+    pub tx_min_replacement_tip_bump_percentage: u16,
+}
+
+/// The policy used to select transactions from the `TxPool`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelectionAlgorithm {
+    /// Transactions with the highest tip/gas ratio go first.
+    RatioTipGas,
+    /// Transactions go in the order they arrived in the `TxPool`.
+    Fifo,
+    /// Transactions with the highest absolute tip go first.
+    TipFirst,
+    /// Same as `ratio-tip-gas`, but caps the number of transactions per owner.
+    Fair,
+}
+
+impl SelectionAlgorithm {
+    pub fn into_config(
+        self,
+        max_txs_per_owner: NonZeroUsize,
+    ) -> SelectionAlgorithmConfig {
+        match self {
+            SelectionAlgorithm::RatioTipGas => SelectionAlgorithmConfig::RatioTipGas,
+            SelectionAlgorithm::Fifo => SelectionAlgorithmConfig::Fifo,
+            SelectionAlgorithm::TipFirst => SelectionAlgorithmConfig::TipFirst,
+            SelectionAlgorithm::Fair => {
+                SelectionAlgorithmConfig::Fair { max_txs_per_owner }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -148,4 +203,23 @@ mod tests {
 
         Ok(blacklist)
     }
+
+    #[test_case(&[""] => Ok(SelectionAlgorithmConfig::RatioTipGas); "defaults works")]
+    #[test_case(&["", "--tx-pool-selection-algorithm=fifo"] => Ok(SelectionAlgorithmConfig::Fifo); "fifo works")]
+    #[test_case(&["", "--tx-pool-selection-algorithm=tip-first"] => Ok(SelectionAlgorithmConfig::TipFirst); "tip first works")]
+    #[test_case(&["", "--tx-pool-selection-algorithm=fair", "--tx-pool-max-txs-per-owner=5"]
+        => Ok(SelectionAlgorithmConfig::Fair { max_txs_per_owner: NonZeroUsize::new(5).unwrap() }); "fair works")]
+    #[test_case(&["", "--tx-pool-selection-algorithm=fair", "--tx-pool-max-txs-per-owner=0"]
+        => matches Err(_); "zero txs per owner fails")]
+    fn parse_selection_algorithm(
+        args: &[&str],
+    ) -> Result<SelectionAlgorithmConfig, String> {
+        let command: Command =
+            Command::try_parse_from(args).map_err(|e| e.to_string())?;
+        let args = command.tx_pool;
+
+        Ok(args
+            .tx_pool_selection_algorithm
+            .into_config(args.tx_pool_max_txs_per_owner))
+    }
 }
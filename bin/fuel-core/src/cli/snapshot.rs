@@ -13,7 +13,10 @@ use fuel_core::{
             DatabaseConfig,
         },
     },
-    types::fuel_types::ContractId,
+    types::fuel_types::{
+        Address,
+        ContractId,
+    },
 };
 use fuel_core_chain_config::ChainConfig;
 use rlimit::{
@@ -62,6 +65,12 @@ pub struct Command {
     )]
     pub max_database_cache_size: usize,
 
+    /// The secret key used to sign the manifest of the created snapshot,
+    /// e.g. the one generated by `fuel-core-keygen`.
+    /// Setting via the `SNAPSHOT_SIGNING_KEY_SECRET` ENV var is preferred.
+    #[arg(long = "signing-key", env = "SNAPSHOT_SIGNING_KEY_SECRET")]
+    pub signing_key: Option<String>,
+
     /// The sub-command of the snapshot operation.
     #[command(subcommand)]
     pub subcommand: SubCommands,
@@ -93,6 +102,30 @@ impl Encoding {
             Encoding::Parquet { group_size, .. } => Some(group_size),
         }
     }
+
+    #[cfg(feature = "rocksdb")]
+    fn create_writer(
+        self,
+        output_dir: &Path,
+    ) -> anyhow::Result<fuel_core_chain_config::SnapshotWriter> {
+        use fuel_core_chain_config::SnapshotWriter;
+
+        match self {
+            Encoding::Json => Ok(SnapshotWriter::json(output_dir)),
+            #[cfg(feature = "parquet")]
+            Encoding::Parquet { compression, .. } => {
+                SnapshotWriter::parquet(output_dir, compression.try_into()?)
+            }
+        }
+    }
+
+    #[cfg(feature = "rocksdb")]
+    fn writer(
+        self,
+        output_dir: PathBuf,
+    ) -> impl Fn() -> anyhow::Result<fuel_core_chain_config::SnapshotWriter> {
+        move || self.create_writer(&output_dir)
+    }
 }
 
 #[derive(Debug, Clone, Subcommand)]
@@ -131,6 +164,47 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Creates a snapshot of the entries modified by the blocks in the
+    /// `(from_height, to_height]` range. The database should keep the history
+    /// of the modifications for the whole range, see `--state-rewind-duration`
+    /// of the `run` command.
+    #[command(arg_required_else_help = true)]
+    Diff {
+        /// The height of the snapshot that the diff is applied to.
+        #[clap(long = "from-height")]
+        from_height: u32,
+        /// The height of the state written into the diff.
+        #[clap(long = "to-height")]
+        to_height: u32,
+        /// Specify a path to the chain config. Defaults used if no path
+        /// is provided.
+        #[clap(name = "CHAIN_CONFIG", long = "chain")]
+        chain_config: Option<PathBuf>,
+        /// Encoding format for the chain state files.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
+    /// Applies the diff created by the `diff` command to the snapshot
+    /// and writes the resulting snapshot into the output directory.
+    #[command(arg_required_else_help = true)]
+    ApplyDiff {
+        /// The directory of the snapshot at the `from_height` of the diff.
+        #[clap(long = "snapshot")]
+        snapshot: PathBuf,
+        /// The directory of the diff.
+        #[clap(long = "diff")]
+        diff: PathBuf,
+        /// Encoding format for the chain state files.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
+    /// Verifies the snapshot in the output directory against its manifest:
+    /// the hashes of the files, the number of entries per table and the signature.
+    Verify {
+        /// The address of the key that must have signed the manifest.
+        #[clap(long = "signer")]
+        signer: Option<Address>,
+    },
 }
 
 fn get_default_max_fds() -> i32 {
@@ -141,21 +215,46 @@ fn get_default_max_fds() -> i32 {
 
 #[cfg(feature = "rocksdb")]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
-    use fuel_core::service::genesis::Exporter;
+    use fuel_core::{
+        service::genesis::{
+            Exporter,
+            apply_diff,
+        },
+        types::fuel_vm::SecretKey,
+    };
     use fuel_core_chain_config::{
         MAX_GROUP_SIZE,
+        SnapshotDiff,
+        SnapshotManifest,
+        SnapshotMetadata,
+        SnapshotReader,
         SnapshotWriter,
     };
+    use std::str::FromStr;
 
     use crate::cli::ShutdownListener;
 
-    let db = open_db(
-        &command.database_path,
-        Some(command.max_database_cache_size),
-        command.rocksdb_max_fds,
-    )?;
+    let open_database = || {
+        open_db(
+            &command.database_path,
+            Some(command.max_database_cache_size),
+            command.rocksdb_max_fds,
+        )
+    };
     let output_dir = command.output_dir;
-    let shutdown_listener = ShutdownListener::spawn();
+    let signing_key = command
+        .signing_key
+        .map(|key| SecretKey::from_str(&key))
+        .transpose()
+        .context("failed to parse snapshot signing key")?;
+    let sign_manifest = |output_dir: &Path| -> anyhow::Result<()> {
+        if let Some(signing_key) = signing_key.as_ref() {
+            let mut manifest = SnapshotManifest::read(output_dir)?;
+            manifest.sign(signing_key);
+            manifest.write(output_dir)?;
+        }
+        Ok(())
+    };
 
     match command.subcommand {
         SubCommands::Everything {
@@ -168,34 +267,92 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
                 .unwrap_or_else(|| Encoding::Json);
 
             let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
-            let writer = move || match encoding {
-                Encoding::Json => Ok(SnapshotWriter::json(output_dir.clone())),
-                #[cfg(feature = "parquet")]
-                Encoding::Parquet { compression, .. } => {
-                    SnapshotWriter::parquet(output_dir.clone(), compression.try_into()?)
-                }
-            };
             Exporter::new(
-                db,
+                open_database()?,
                 load_chain_config_or_use_testnet(chain_config.as_deref())?,
-                writer,
+                encoding.writer(output_dir.clone()),
                 group_size,
-                shutdown_listener,
+                ShutdownListener::spawn(),
             )
             .write_full_snapshot()
-            .await
+            .await?;
+            sign_manifest(&output_dir)
         }
         SubCommands::Contract { contract_id } => {
-            let writer = move || Ok(SnapshotWriter::json(output_dir.clone()));
+            let writer = {
+                let output_dir = output_dir.clone();
+                move || Ok(SnapshotWriter::json(output_dir.clone()))
+            };
             Exporter::new(
-                db,
+                open_database()?,
                 local_testnet_chain_config(),
                 writer,
                 MAX_GROUP_SIZE,
-                shutdown_listener,
+                ShutdownListener::spawn(),
             )
             .write_contract_snapshot(contract_id)
-            .await
+            .await?;
+            sign_manifest(&output_dir)
+        }
+        SubCommands::Diff {
+            from_height,
+            to_height,
+            chain_config,
+            encoding_command,
+        } => {
+            let encoding = encoding_command
+                .map(|f| f.encoding())
+                .unwrap_or_else(|| Encoding::Json);
+
+            let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
+            let diff = Exporter::new(
+                open_database()?,
+                load_chain_config_or_use_testnet(chain_config.as_deref())?,
+                encoding.writer(output_dir.clone()),
+                group_size,
+                ShutdownListener::spawn(),
+            )
+            .write_diff_snapshot(from_height.into(), to_height.into())
+            .await?;
+            // The manifest generated by the exporter doesn't cover the diff,
+            // so it is generated again after the diff is written.
+            diff.write(&output_dir)?;
+            let metadata = SnapshotMetadata::read(&output_dir)?;
+            SnapshotManifest::generate(&output_dir, &metadata)?.write(&output_dir)?;
+            sign_manifest(&output_dir)
+        }
+        SubCommands::ApplyDiff {
+            snapshot,
+            diff,
+            encoding_command,
+        } => {
+            let encoding = encoding_command
+                .map(|f| f.encoding())
+                .unwrap_or_else(|| Encoding::Json);
+
+            let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
+            let base = SnapshotReader::open(SnapshotMetadata::read(&snapshot)?)?;
+            let diff_reader = SnapshotReader::open(SnapshotMetadata::read(&diff)?)?;
+            let snapshot_diff = SnapshotDiff::read(&diff)?;
+            apply_diff(
+                &base,
+                &diff_reader,
+                &snapshot_diff,
+                encoding.create_writer(&output_dir)?,
+                group_size,
+            )?;
+            sign_manifest(&output_dir)
+        }
+        SubCommands::Verify { signer } => {
+            let metadata = SnapshotMetadata::read(&output_dir)?;
+            let manifest = SnapshotManifest::read(&output_dir)?;
+            manifest.verify(&output_dir, &metadata, signer.as_ref())?;
+            println!(
+                "The snapshot {} matches its manifest {}",
+                output_dir.display(),
+                manifest.digest
+            );
+            Ok(())
         }
     }
 }
@@ -232,7 +389,10 @@ mod tests {
     use std::iter::repeat_with;
 
     use fuel_core::{
+        database::Database,
         fuel_core_graphql_api::storage::transactions::{
+            ContractTransactionIndexKey,
+            ContractTransactions,
             OwnedTransactionIndexKey,
             OwnedTransactions,
             TransactionStatuses,
@@ -243,16 +403,20 @@ mod tests {
         AddTable,
         AsTable,
         LastBlockConfig,
+        SnapshotDiff,
+        SnapshotManifest,
         SnapshotMetadata,
         SnapshotReader,
         StateConfig,
         StateConfigBuilder,
         TableEntry,
+        verify_snapshot,
     };
     use fuel_core_storage::{
         ContractsAssetKey,
         ContractsStateKey,
         StorageAsMut,
+        kv_store::StorageColumn,
         structured_storage::TableWithBlueprint,
         tables::{
             Coins,
@@ -264,7 +428,12 @@ mod tests {
             Messages,
             Transactions,
         },
-        transactional::AtomicView,
+        transactional::{
+            AtomicView,
+            HistoricalView,
+            StorageTransaction,
+            WriteTransaction,
+        },
     };
     use fuel_core_types::{
         blockchain::{
@@ -283,13 +452,18 @@ mod tests {
             },
         },
         fuel_tx::{
+            Input,
             Receipt,
             TransactionBuilder,
             TxPointer,
             UniqueIdentifier,
             UtxoId,
         },
-        fuel_types::ChainId,
+        fuel_types::{
+            BlockHeight,
+            ChainId,
+        },
+        fuel_vm::SecretKey,
         services::transaction_status::TransactionExecutionStatus,
         tai64::Tai64,
     };
@@ -317,6 +491,7 @@ mod tests {
         transactions: Vec<TableEntry<Transactions>>,
         transaction_statuses: Vec<TableEntry<TransactionStatuses>>,
         owned_transactions: Vec<TableEntry<OwnedTransactions>>,
+        contract_transactions: Vec<TableEntry<ContractTransactions>>,
     }
 
     #[derive(Debug, PartialEq)]
@@ -348,6 +523,7 @@ mod tests {
             self.transactions.sort_by_key(|e| e.key);
             self.transaction_statuses.sort_by_key(|e| e.key);
             self.owned_transactions.sort_by_key(|e| e.key.clone());
+            self.contract_transactions.sort_by_key(|e| e.key.clone());
             self
         }
 
@@ -419,6 +595,7 @@ mod tests {
                 transactions: read(reader),
                 transaction_statuses: read(reader),
                 owned_transactions: read(reader),
+                contract_transactions: read(reader),
             }
         }
     }
@@ -477,6 +654,8 @@ mod tests {
 
             let owned_transactions = vec![self.given_owned_transaction()];
 
+            let contract_transactions = vec![self.given_contract_transaction()];
+
             let block = self.given_block();
 
             SnapshotData {
@@ -492,6 +671,7 @@ mod tests {
                 transactions,
                 transaction_statuses,
                 owned_transactions,
+                contract_transactions,
             }
         }
 
@@ -555,6 +735,48 @@ mod tests {
             TableEntry { key, value }
         }
 
+        fn given_contract_transaction(&mut self) -> TableEntry<ContractTransactions> {
+            let key = ContractTransactionIndexKey {
+                contract_id: self.rng.r#gen(),
+                block_height: self.rng.r#gen(),
+                tx_idx: self.rng.r#gen(),
+            };
+            let value = self.rng.r#gen();
+
+            self.db
+                .off_chain_mut()
+                .storage_as_mut::<ContractTransactions>()
+                .insert(&key, &value)
+                .unwrap();
+
+            TableEntry { key, value }
+        }
+
+        // Commits the changes together with the next block,
+        // so the database keeps the history of the modifications.
+        fn given_next_block(
+            &mut self,
+            changes: impl FnOnce(&mut StorageTransaction<&mut Database>),
+        ) -> BlockHeight {
+            let height = self
+                .db
+                .on_chain()
+                .latest_height()
+                .and_then(|height| height.succ())
+                .expect("The database should have the block");
+            let mut block = CompressedBlock::default();
+            block.header_mut().set_block_height(height);
+
+            let mut tx = self.db.on_chain_mut().write_transaction();
+            changes(&mut tx);
+            tx.storage_as_mut::<FuelBlocks>()
+                .insert(&height, &block)
+                .unwrap();
+            tx.commit().unwrap();
+
+            height
+        }
+
         fn given_block(&mut self) -> TableEntry<FuelBlocks> {
             let mut block = CompressedBlock::default();
             let height = self.rng.r#gen();
@@ -720,6 +942,7 @@ mod tests {
                 encoding_command: Some(EncodingCommand::Encoding { encoding }),
             },
             rocksdb_max_fds: 512,
+            signing_key: None,
         });
 
         // Because the test_case macro doesn't work with async tests
@@ -748,6 +971,175 @@ mod tests {
         Ok(())
     }
 
+    #[cfg_attr(feature = "parquet", test_case(Encoding::Parquet { group_size: 2, compression: 1 }; "parquet"))]
+    #[test_case(Encoding::Json; "json")]
+    fn signed_snapshot_is_verified_until_tampered(
+        encoding: Encoding,
+    ) -> anyhow::Result<()> {
+        // given
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        std::fs::create_dir(&db_path)?;
+
+        let mut rng = StdRng::seed_from_u64(2);
+        let signing_key = SecretKey::random(&mut rng);
+        let signer = Input::owner(&signing_key.public_key());
+        let other_signer = Input::owner(&SecretKey::random(&mut rng).public_key());
+
+        let mut db = DbPopulator::new(open_db(&db_path, None, 512)?, rng);
+        db.given_persisted_data();
+        db.flush();
+
+        let run = |subcommand: SubCommands, signing_key: Option<String>| {
+            let fut = exec(Command {
+                database_path: db_path.clone(),
+                max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+                output_dir: snapshot_dir.clone(),
+                subcommand,
+                rocksdb_max_fds: 512,
+                signing_key,
+            });
+
+            // Because the test_case macro doesn't work with async tests
+            tokio::runtime::Runtime::new().unwrap().block_on(fut)
+        };
+        run(
+            SubCommands::Everything {
+                chain_config: None,
+                encoding_command: Some(EncodingCommand::Encoding { encoding }),
+            },
+            Some(signing_key.to_string()),
+        )?;
+
+        // when
+        let signed_by_signer = run(
+            SubCommands::Verify {
+                signer: Some(signer),
+            },
+            None,
+        );
+        let signed_by_other = run(
+            SubCommands::Verify {
+                signer: Some(other_signer),
+            },
+            None,
+        );
+        let chain_config = snapshot_dir.join("chain_config.json");
+        let mut content = std::fs::read(&chain_config)?;
+        content.truncate(content.len().saturating_div(2));
+        std::fs::write(&chain_config, content)?;
+        let tampered = run(SubCommands::Verify { signer: None }, None);
+
+        // then
+        signed_by_signer.expect("The snapshot is signed by the signer");
+        signed_by_other.expect_err("The snapshot is not signed by the other signer");
+        tampered.expect_err("The tampered snapshot should fail the verification");
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "parquet", test_case(Encoding::Parquet { group_size: 2, compression: 1 }; "parquet"))]
+    #[test_case(Encoding::Json; "json")]
+    fn diff_snapshot_applied_to_snapshot_matches_everything_snapshot(
+        encoding: Encoding,
+    ) -> anyhow::Result<()> {
+        use pretty_assertions::assert_eq;
+
+        // given
+        let temp_dir = tempfile::tempdir()?;
+        let db_path = temp_dir.path().join("db");
+        let snapshot = |output_dir: &str, subcommand: SubCommands| {
+            let fut = exec(Command {
+                database_path: db_path.clone(),
+                max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+                output_dir: temp_dir.path().join(output_dir),
+                subcommand,
+                rocksdb_max_fds: 512,
+                signing_key: None,
+            });
+
+            // Because the test_case macro doesn't work with async tests
+            tokio::runtime::Runtime::new().unwrap().block_on(fut)
+        };
+        let encoding_command = || Some(EncodingCommand::Encoding { encoding });
+        let everything = || SubCommands::Everything {
+            chain_config: None,
+            encoding_command: encoding_command(),
+        };
+
+        let mut db =
+            DbPopulator::new(open_db_with_history(&db_path)?, StdRng::seed_from_u64(2));
+        let state = db.given_persisted_data();
+        let from_height = state.common.block.key;
+        db.flush();
+        snapshot("base", everything())?;
+
+        let mut db =
+            DbPopulator::new(open_db_with_history(&db_path)?, StdRng::seed_from_u64(2));
+        let contract_id = *state.common.contract_state[0].key.contract_id();
+        db.given_next_block(|tx| {
+            tx.storage_as_mut::<Coins>()
+                .remove(&state.common.coins[0].key)
+                .unwrap();
+            tx.storage_as_mut::<ContractsState>()
+                .insert(&state.common.contract_state[0].key, &[42; 32])
+                .unwrap();
+            tx.storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(&contract_id, &[7; 32].into()), &100)
+                .unwrap();
+        });
+        let to_height = db.given_next_block(|tx| {
+            tx.storage_as_mut::<ContractsState>()
+                .remove(&state.common.contract_state[1].key)
+                .unwrap();
+            tx.storage_as_mut::<ContractsAssets>()
+                .insert(&state.common.contract_balance[0].key, &1)
+                .unwrap();
+        });
+        db.flush();
+
+        // when
+        snapshot(
+            "diff",
+            SubCommands::Diff {
+                from_height: from_height.into(),
+                to_height: to_height.into(),
+                chain_config: None,
+                encoding_command: encoding_command(),
+            },
+        )?;
+        snapshot(
+            "applied",
+            SubCommands::ApplyDiff {
+                snapshot: temp_dir.path().join("base"),
+                diff: temp_dir.path().join("diff"),
+                encoding_command: encoding_command(),
+            },
+        )?;
+        snapshot("expected", everything())?;
+
+        // then
+        let diff_dir = temp_dir.path().join("diff");
+        let manifest = SnapshotManifest::read(&diff_dir)?;
+        assert!(manifest.files.contains_key(Path::new("diff.json")));
+        verify_snapshot(&diff_dir, &SnapshotMetadata::read(&diff_dir)?, None)?;
+
+        let diff = SnapshotDiff::read(&diff_dir)?;
+        assert_eq!(diff.removed_keys(&Coins::column().name()).len(), 1);
+        assert_eq!(diff.removed_keys(&ContractsState::column().name()).len(), 1);
+
+        let read = |dir: &str| -> anyhow::Result<CommonData> {
+            let metadata = SnapshotMetadata::read(temp_dir.path().join(dir))?;
+            Ok(SnapshotData::read_from_snapshot(metadata).common)
+        };
+        let expected = read("expected")?;
+        assert_eq!(expected.block.key, to_height);
+        assert_eq!(read("applied")?, expected);
+
+        Ok(())
+    }
+
     #[cfg(feature = "parquet")]
     #[test_case(2; "parquet group_size=2")]
     #[test_case(5; "parquet group_size=5")]
@@ -780,6 +1172,7 @@ mod tests {
                 }),
             },
             rocksdb_max_fds: 512,
+            signing_key: None,
         });
 
         tokio::runtime::Runtime::new()
@@ -832,6 +1225,7 @@ mod tests {
             max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
             subcommand: SubCommands::Contract { contract_id },
             rocksdb_max_fds: 512,
+            signing_key: None,
         })
         .await?;
 
@@ -853,6 +1247,19 @@ mod tests {
         Ok(())
     }
 
+    fn open_db_with_history(path: &Path) -> anyhow::Result<CombinedDatabase> {
+        CombinedDatabase::open(
+            path,
+            StateRewindPolicy::RewindFullRange,
+            DatabaseConfig {
+                cache_capacity: None,
+                max_fds: 512,
+                columns_policy: ColumnsPolicy::OnCreation,
+            },
+        )
+        .map_err(Into::into)
+    }
+
     #[cfg(feature = "parquet")]
     fn assert_groups_as_expected<T>(
         expected_group_size: usize,
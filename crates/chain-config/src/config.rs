@@ -7,6 +7,8 @@ mod message;
 #[cfg(feature = "test-helpers")]
 mod randomize;
 #[cfg(feature = "std")]
+mod snapshot_diff;
+#[cfg(feature = "std")]
 mod snapshot_metadata;
 mod state;
 mod table_entry;
@@ -20,6 +22,8 @@ pub use message::*;
 #[cfg(feature = "test-helpers")]
 pub use randomize::*;
 #[cfg(feature = "std")]
+pub use snapshot_diff::*;
+#[cfg(feature = "std")]
 pub use snapshot_metadata::*;
 pub use state::*;
 pub use table_entry::*;
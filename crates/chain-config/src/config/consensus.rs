@@ -18,6 +18,7 @@ use fuel_core_chain_config::default_consensus_dev_key;
 pub enum ConsensusConfig {
     PoA { signing_key: Address },
     PoAV2(PoAV2),
+    Bft(BftConfig),
 }
 
 impl ConsensusConfig {
@@ -80,6 +81,76 @@ impl PoAV2 {
     }
 }
 
+/// The set of validators of the BFT consensus.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BftConfig {
+    validators: Vec<BftValidator>,
+}
+
+/// The validator of the BFT consensus.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BftValidator {
+    /// The address of the key used by the validator to sign the messages.
+    pub address: Address,
+    /// The weight of the validator's votes.
+    pub voting_power: u64,
+}
+
+impl BftConfig {
+    pub fn new(validators: Vec<BftValidator>) -> Self {
+        Self { validators }
+    }
+
+    /// Returns the validators in the order they propose blocks.
+    pub fn validators(&self) -> &[BftValidator] {
+        &self.validators
+    }
+
+    /// Returns the voting power of the validator, or `None` if
+    /// the address doesn't belong to the validator set.
+    pub fn voting_power(&self, address: &Address) -> Option<u64> {
+        self.validators
+            .iter()
+            .find(|validator| &validator.address == address)
+            .map(|validator| validator.voting_power)
+    }
+
+    /// Returns the sum of the voting power of all validators.
+    pub fn total_voting_power(&self) -> u64 {
+        self.validators.iter().fold(0u64, |total, validator| {
+            total.saturating_add(validator.voting_power)
+        })
+    }
+
+    /// Returns `true` if the voting power is more than 2/3 of the total voting power.
+    pub fn is_quorum(&self, voting_power: u64) -> bool {
+        let total = u128::from(self.total_voting_power());
+        u128::from(voting_power).saturating_mul(3) > total.saturating_mul(2)
+    }
+
+    /// Returns `true` if the voting power is more than 1/3 of the total voting power,
+    /// meaning that at least one honest validator is among the voters.
+    pub fn is_honest_majority_possible(&self, voting_power: u64) -> bool {
+        let total = u128::from(self.total_voting_power());
+        u128::from(voting_power).saturating_mul(3) > total
+    }
+
+    /// Returns the address of the validator that proposes the block at the height in the round.
+    ///
+    /// The validators take turns in the round-robin order, starting from the
+    /// next validator at each height.
+    pub fn proposer(&self, height: BlockHeight, round: u32) -> Option<Address> {
+        let len = u64::try_from(self.validators.len()).ok()?;
+        let index = u64::from(u32::from(height))
+            .saturating_add(u64::from(round))
+            .checked_rem(len)?;
+        let index = usize::try_from(index).ok()?;
+        self.validators
+            .get(index)
+            .map(|validator| validator.address)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -119,4 +190,44 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    #[test]
+    fn bft__quorum_requires_more_than_two_thirds_of_voting_power() {
+        // Given
+        let bft = BftConfig::new(
+            (1u8..=4)
+                .map(|i| BftValidator {
+                    address: Address::from([i; 32]),
+                    voting_power: 10,
+                })
+                .collect(),
+        );
+
+        // When/Then
+        assert_eq!(bft.total_voting_power(), 40);
+        assert!(!bft.is_quorum(20));
+        assert!(!bft.is_quorum(26));
+        assert!(bft.is_quorum(27));
+        assert!(!bft.is_honest_majority_possible(13));
+        assert!(bft.is_honest_majority_possible(14));
+    }
+
+    #[test]
+    fn bft__proposer_rotates_with_height_and_round() {
+        // Given
+        let validators: Vec<_> = (1u8..=3)
+            .map(|i| BftValidator {
+                address: Address::from([i; 32]),
+                voting_power: 1,
+            })
+            .collect();
+        let bft = BftConfig::new(validators.clone());
+
+        // When/Then
+        assert_eq!(bft.proposer(0u32.into(), 0), Some(validators[0].address));
+        assert_eq!(bft.proposer(1u32.into(), 0), Some(validators[1].address));
+        assert_eq!(bft.proposer(1u32.into(), 1), Some(validators[2].address));
+        assert_eq!(bft.proposer(1u32.into(), 2), Some(validators[0].address));
+        assert_eq!(BftConfig::new(vec![]).proposer(1u32.into(), 0), None);
+    }
 }
@@ -0,0 +1,88 @@
+use crate::serialization::HexIfHumanReadable;
+use anyhow::Context;
+use fuel_core_types::fuel_types::BlockHeight;
+use serde_with::serde_as;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+
+/// Describes the changes of the state between two heights.
+///
+/// The diff is stored next to the regular snapshot files, which contain the
+/// entries modified in the range with their values at the `to_height`.
+/// The keys removed in the range are listed in the diff itself.
+#[serde_as]
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// The height of the state that the diff is applied to.
+    pub from_height: BlockHeight,
+    /// The height of the state after the diff is applied.
+    pub to_height: BlockHeight,
+    /// The encoded keys removed in the range, grouped by the table name.
+    #[serde_as(as = "BTreeMap<_, Vec<HexIfHumanReadable>>")]
+    pub removed_keys: BTreeMap<String, Vec<Vec<u8>>>,
+}
+
+impl SnapshotDiff {
+    pub const DIFF_FILENAME: &'static str = "diff.json";
+
+    pub fn new(from_height: BlockHeight, to_height: BlockHeight) -> Self {
+        Self {
+            from_height,
+            to_height,
+            removed_keys: BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if the snapshot directory contains the diff.
+    pub fn exists(dir: impl AsRef<Path>) -> bool {
+        dir.as_ref().join(Self::DIFF_FILENAME).exists()
+    }
+
+    pub fn read(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = dir.as_ref().join(Self::DIFF_FILENAME);
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("Could not open snapshot diff file: {path:?}"))?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    pub fn write(&self, dir: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = dir.as_ref().join(Self::DIFF_FILENAME);
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the removed keys of the table.
+    pub fn removed_keys(&self, table: &str) -> &[Vec<u8>] {
+        self.removed_keys
+            .get(table)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_survives_write_and_read() {
+        // given
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut diff = SnapshotDiff::new(10.into(), 20.into());
+        diff.removed_keys
+            .insert("Coins".to_string(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        // when
+        diff.write(temp_dir.path()).unwrap();
+
+        // then
+        assert!(SnapshotDiff::exists(temp_dir.path()));
+        let read = SnapshotDiff::read(temp_dir.path()).unwrap();
+        assert_eq!(read, diff);
+        assert_eq!(read.removed_keys("Coins"), [vec![1, 2, 3], vec![4, 5, 6]]);
+        assert!(read.removed_keys("Messages").is_empty());
+    }
+}
@@ -68,7 +68,7 @@ pub struct SnapshotMetadata {
 }
 
 impl SnapshotMetadata {
-    const METADATA_FILENAME: &'static str = "metadata.json";
+    pub const METADATA_FILENAME: &'static str = "metadata.json";
     pub fn read(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
         let path = dir.as_ref().join(Self::METADATA_FILENAME);
         let mut json = String::new();
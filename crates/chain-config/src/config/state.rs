@@ -69,6 +69,8 @@ use fuel_core_types::{
     fuel_vm::SecretKey,
 };
 
+#[cfg(feature = "std")]
+mod manifest;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reader;
@@ -657,6 +659,12 @@ impl StateConfig {
     }
 }
 
+#[cfg(feature = "std")]
+pub use manifest::{
+    ManifestSignature,
+    SnapshotManifest,
+    verify_snapshot,
+};
 pub use reader::{
     GroupIter,
     Groups,
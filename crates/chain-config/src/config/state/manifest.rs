@@ -0,0 +1,424 @@
+use crate::{
+    AsTable,
+    BYTECODE_NAME,
+    SnapshotDiff,
+    SnapshotMetadata,
+    SnapshotReader,
+    StateConfig,
+    TableEncoding,
+    config::table_entry::TableEntry,
+};
+use anyhow::Context;
+use fuel_core_storage::{
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        Messages,
+    },
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        Bytes32,
+    },
+    fuel_vm::BlobData,
+};
+use std::{
+    collections::BTreeMap,
+    io::Read,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// The integrity manifest of the snapshot. It is written next to the
+/// `metadata.json` and allows to detect truncated or tampered snapshot files
+/// before they are imported.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    /// The sha256 hashes of the snapshot files relative to the snapshot directory.
+    pub files: BTreeMap<PathBuf, Bytes32>,
+    /// The number of entries per table.
+    pub table_entries: BTreeMap<String, u64>,
+    /// The sha256 hash of the `files` and `table_entries`.
+    pub digest: Bytes32,
+    /// The signature of the `digest`, if the snapshot was signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ManifestSignature>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct ManifestSignature {
+    /// The address of the key that signed the manifest.
+    pub signer: Address,
+    pub signature: Signature,
+}
+
+impl SnapshotManifest {
+    pub const MANIFEST_FILENAME: &'static str = "manifest.json";
+
+    /// Builds the manifest from the files of the snapshot in the `dir`.
+    pub fn generate(dir: &Path, metadata: &SnapshotMetadata) -> anyhow::Result<Self> {
+        let mut files = BTreeMap::new();
+        for path in snapshot_files(dir, metadata) {
+            let hash = hash_file(&path)?;
+            let relative_path = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+            files.insert(relative_path, hash);
+        }
+        let table_entries = count_table_entries(metadata)?;
+        let digest = digest(&files, &table_entries)?;
+
+        Ok(Self {
+            files,
+            table_entries,
+            digest,
+            signature: None,
+        })
+    }
+
+    pub fn read(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = dir.as_ref().join(Self::MANIFEST_FILENAME);
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("Could not open snapshot manifest: {path:?}"))?;
+        serde_json::from_reader(file).map_err(Into::into)
+    }
+
+    /// Reads the manifest of the snapshot if the snapshot has it.
+    pub fn read_if_exists(dir: impl AsRef<Path>) -> anyhow::Result<Option<Self>> {
+        if dir.as_ref().join(Self::MANIFEST_FILENAME).exists() {
+            Self::read(dir).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn write(&self, dir: &Path) -> anyhow::Result<()> {
+        let path = dir.join(Self::MANIFEST_FILENAME);
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    /// Signs the `digest` of the manifest with the `secret_key`.
+    pub fn sign(&mut self, secret_key: &SecretKey) {
+        let signature = Signature::sign(secret_key, &Message::new(self.digest));
+        self.signature = Some(ManifestSignature {
+            signer: Input::owner(&secret_key.public_key()),
+            signature,
+        });
+    }
+
+    /// Verifies that the snapshot in the `dir` matches the manifest.
+    /// If the `expected_signer` is set, the manifest must be signed by it.
+    pub fn verify(
+        &self,
+        dir: &Path,
+        metadata: &SnapshotMetadata,
+        expected_signer: Option<&Address>,
+    ) -> anyhow::Result<()> {
+        let expected_digest = digest(&self.files, &self.table_entries)?;
+        anyhow::ensure!(
+            expected_digest == self.digest,
+            "The manifest digest {} doesn't match its content {expected_digest}",
+            self.digest
+        );
+
+        match (&self.signature, expected_signer) {
+            (Some(ManifestSignature { signer, signature }), _) => {
+                let public_key = signature
+                    .recover(&Message::new(self.digest))
+                    .map_err(|e| anyhow::anyhow!("Invalid manifest signature: {e:?}"))?;
+                anyhow::ensure!(
+                    Input::owner(&public_key) == *signer,
+                    "The manifest is not signed by {signer}"
+                );
+                if let Some(expected_signer) = expected_signer {
+                    anyhow::ensure!(
+                        signer == expected_signer,
+                        "The manifest is signed by {signer} instead of {expected_signer}"
+                    );
+                }
+            }
+            (None, Some(expected_signer)) => {
+                anyhow::bail!(
+                    "The manifest is not signed, expected a signature of {expected_signer}"
+                )
+            }
+            (None, None) => {}
+        }
+
+        let mut files = BTreeMap::new();
+        for path in snapshot_files(dir, metadata) {
+            let relative_path = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+            files.insert(relative_path, path);
+        }
+        for relative_path in self.files.keys() {
+            anyhow::ensure!(
+                files.contains_key(relative_path),
+                "The file {relative_path:?} from the manifest is not part of the snapshot"
+            );
+        }
+        for (relative_path, path) in files {
+            let expected_hash = self.files.get(&relative_path).ok_or_else(|| {
+                anyhow::anyhow!("The file {relative_path:?} is missing in the manifest")
+            })?;
+            let hash = hash_file(&path)?;
+            anyhow::ensure!(
+                hash == *expected_hash,
+                "The hash {hash} of the file {relative_path:?} doesn't match the manifest hash {expected_hash}"
+            );
+        }
+
+        let table_entries = count_table_entries(metadata)?;
+        anyhow::ensure!(
+            table_entries == self.table_entries,
+            "The table entries {table_entries:?} don't match the manifest {:?}",
+            self.table_entries
+        );
+
+        Ok(())
+    }
+}
+
+/// Verifies the snapshot in the `dir` against its manifest.
+/// If the `expected_signer` is set, the snapshot must have a manifest signed by it.
+/// Otherwise, snapshots created before the manifest was introduced are accepted as they are.
+pub fn verify_snapshot(
+    dir: &Path,
+    metadata: &SnapshotMetadata,
+    expected_signer: Option<&Address>,
+) -> anyhow::Result<()> {
+    match SnapshotManifest::read_if_exists(dir)? {
+        Some(manifest) => manifest
+            .verify(dir, metadata, expected_signer)
+            .with_context(|| format!("The snapshot {dir:?} failed the verification")),
+        None => match expected_signer {
+            Some(expected_signer) => Err(anyhow::anyhow!(
+                "The snapshot {dir:?} doesn't have a manifest, expected a manifest signed by {expected_signer}"
+            )),
+            None => Ok(()),
+        },
+    }
+}
+
+fn snapshot_files(dir: &Path, metadata: &SnapshotMetadata) -> Vec<PathBuf> {
+    let mut files = vec![
+        dir.join(SnapshotMetadata::METADATA_FILENAME),
+        metadata.chain_config.clone(),
+    ];
+    let bytecode_path = metadata.chain_config.with_file_name(BYTECODE_NAME);
+    if bytecode_path.exists() {
+        files.push(bytecode_path);
+    }
+    let diff_path = dir.join(SnapshotDiff::DIFF_FILENAME);
+    if diff_path.exists() {
+        files.push(diff_path);
+    }
+
+    match &metadata.table_encoding {
+        TableEncoding::Json { filepath } => files.push(filepath.clone()),
+        #[cfg(feature = "parquet")]
+        TableEncoding::Parquet {
+            tables,
+            latest_block_config_path,
+        } => {
+            files.extend(tables.values().cloned());
+            files.push(latest_block_config_path.clone());
+        }
+    }
+
+    files
+}
+
+fn hash_file(path: &Path) -> anyhow::Result<Bytes32> {
+    let mut file = std::fs::File::open(path)
+        .with_context(|| format!("Could not open snapshot file: {path:?}"))?;
+    let mut hasher = Hasher::default();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        let Some(chunk) = buffer.get(..read).filter(|chunk| !chunk.is_empty()) else {
+            break
+        };
+        hasher.input(chunk);
+    }
+    Ok(hasher.finalize())
+}
+
+fn digest(
+    files: &BTreeMap<PathBuf, Bytes32>,
+    table_entries: &BTreeMap<String, u64>,
+) -> anyhow::Result<Bytes32> {
+    let encoded = serde_json::to_vec(&(files, table_entries))?;
+    Ok(Hasher::hash(encoded))
+}
+
+fn count_table_entries(
+    metadata: &SnapshotMetadata,
+) -> anyhow::Result<BTreeMap<String, u64>> {
+    match &metadata.table_encoding {
+        TableEncoding::Json { .. } => {
+            let reader = SnapshotReader::open(metadata.clone())?;
+            let mut entries = BTreeMap::new();
+            count_json_entries::<Coins>(&reader, &mut entries)?;
+            count_json_entries::<Messages>(&reader, &mut entries)?;
+            count_json_entries::<BlobData>(&reader, &mut entries)?;
+            count_json_entries::<ContractsRawCode>(&reader, &mut entries)?;
+            count_json_entries::<ContractsLatestUtxo>(&reader, &mut entries)?;
+            count_json_entries::<ContractsState>(&reader, &mut entries)?;
+            count_json_entries::<ContractsAssets>(&reader, &mut entries)?;
+            Ok(entries)
+        }
+        #[cfg(feature = "parquet")]
+        TableEncoding::Parquet { tables, .. } => {
+            let mut entries = BTreeMap::new();
+            for (name, path) in tables {
+                let file = std::fs::File::open(path).with_context(|| {
+                    format!("Could not open {path:?} in order to count table '{name}'")
+                })?;
+                let rows = super::parquet::decode::Decoder::new(file)?.num_rows()?;
+                entries.insert(name.clone(), rows);
+            }
+            Ok(entries)
+        }
+    }
+}
+
+fn count_json_entries<T>(
+    reader: &SnapshotReader,
+    entries: &mut BTreeMap<String, u64>,
+) -> anyhow::Result<()>
+where
+    T: TableWithBlueprint,
+    StateConfig: AsTable<T>,
+    TableEntry<T>: serde::de::DeserializeOwned,
+{
+    let mut count = 0u64;
+    for group in reader.read::<T>()? {
+        count = count.saturating_add(u64::try_from(group?.len())?);
+    }
+    if count > 0 {
+        entries.insert(T::column().name().to_string(), count);
+    }
+    Ok(())
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ChainConfig,
+        Randomize,
+        SnapshotWriter,
+    };
+    use rand::{
+        SeedableRng,
+        rngs::StdRng,
+    };
+
+    fn given_json_snapshot(dir: &Path) -> SnapshotMetadata {
+        let mut rng = StdRng::from_seed([0; 32]);
+        let state = StateConfig::randomize(&mut rng);
+        SnapshotWriter::json(dir)
+            .write_state_config(state, &ChainConfig::local_testnet())
+            .unwrap()
+    }
+
+    #[test]
+    fn verify__accepts_unmodified_snapshot() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = given_json_snapshot(dir.path());
+
+        // when
+        let result = verify_snapshot(dir.path(), &metadata, None);
+
+        // then
+        result.expect("The snapshot should pass the verification");
+    }
+
+    #[test]
+    fn verify__rejects_truncated_file() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = given_json_snapshot(dir.path());
+        let TableEncoding::Json { filepath } = &metadata.table_encoding else {
+            panic!("Expected json encoding")
+        };
+        let content = std::fs::read(filepath).unwrap();
+        std::fs::write(filepath, &content[..content.len().saturating_div(2)]).unwrap();
+
+        // when
+        let result = verify_snapshot(dir.path(), &metadata, None);
+
+        // then
+        result.expect_err("The truncated snapshot should fail the verification");
+    }
+
+    #[test]
+    fn verify__checks_signer_of_signed_manifest() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = given_json_snapshot(dir.path());
+        let mut rng = StdRng::from_seed([1; 32]);
+        let secret_key = SecretKey::random(&mut rng);
+        let signer = Input::owner(&secret_key.public_key());
+        let other_signer = Input::owner(&SecretKey::random(&mut rng).public_key());
+        let mut manifest = SnapshotManifest::read(dir.path()).unwrap();
+        manifest.sign(&secret_key);
+
+        // when
+        let signed_by_signer = manifest.verify(dir.path(), &metadata, Some(&signer));
+        let signed_by_other = manifest.verify(dir.path(), &metadata, Some(&other_signer));
+
+        // then
+        signed_by_signer.expect("The manifest is signed by the signer");
+        signed_by_other.expect_err("The manifest is not signed by the other signer");
+    }
+
+    #[test]
+    fn verify_snapshot__rejects_missing_manifest_if_signer_is_expected() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = given_json_snapshot(dir.path());
+        std::fs::remove_file(dir.path().join(SnapshotManifest::MANIFEST_FILENAME))
+            .unwrap();
+        let signer = Address::new([1; 32]);
+
+        // when
+        let without_signer = verify_snapshot(dir.path(), &metadata, None);
+        let with_signer = verify_snapshot(dir.path(), &metadata, Some(&signer));
+
+        // then
+        without_signer.expect("The snapshot without manifest is accepted");
+        with_signer.expect_err("The snapshot without manifest should be rejected");
+    }
+
+    #[test]
+    fn verify_snapshot__rejects_unsigned_manifest_if_signer_is_expected() {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = given_json_snapshot(dir.path());
+        let signer = Address::new([1; 32]);
+
+        // when
+        let result = verify_snapshot(dir.path(), &metadata, Some(&signer));
+
+        // then
+        result.expect_err("The unsigned snapshot should be rejected");
+    }
+}
@@ -27,6 +27,11 @@ where
         self.data_source.num_row_groups()
     }
 
+    pub fn num_rows(&self) -> anyhow::Result<u64> {
+        let rows = self.data_source.metadata().file_metadata().num_rows();
+        u64::try_from(rows).map_err(|e| anyhow!(e))
+    }
+
     fn current_group(&self) -> anyhow::Result<Vec<Vec<u8>>> {
         let data = self
             .data_source
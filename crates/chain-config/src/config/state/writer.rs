@@ -2,6 +2,7 @@ use crate::{
     AddTable,
     ChainConfig,
     LastBlockConfig,
+    SnapshotManifest,
     SnapshotMetadata,
     StateConfigBuilder,
     TableEncoding,
@@ -365,6 +366,7 @@ impl SnapshotWriter {
             table_encoding,
         };
         metadata.clone().write(dir)?;
+        SnapshotManifest::generate(dir, &metadata)?.write(dir)?;
         Ok(metadata)
     }
 
@@ -16,6 +16,7 @@ use crate::{
             relayed_tx::RelayedTransactionStatusArgs,
             tx::{
                 DryRunArg,
+                DryRunWithOverridesArg,
                 TxWithEstimatedPredicatesArg,
             },
         },
@@ -78,6 +79,7 @@ use fuel_core_types::{
         canonical::Serialize,
     },
     services::executor::{
+        DryRunOverrides,
         StorageReadReplayEvent,
         TransactionExecutionStatus,
     },
@@ -107,6 +109,7 @@ use schema::{
     SetBreakpointArgs,
     SetSingleStepping,
     SetSingleSteppingArgs,
+    StartSessionArgs,
     StartTx,
     StartTxArgs,
     U32,
@@ -131,6 +134,7 @@ use schema::{
     },
     tx::{
         AssembleTxArg,
+        TransactionsByContractConnectionArgs,
         TransactionsByOwnerConnectionArgs,
         TxArg,
         TxIdArgs,
@@ -139,6 +143,7 @@ use schema::{
 #[cfg(feature = "subscriptions")]
 use std::future;
 use std::{
+    borrow::Cow,
     convert::TryInto,
     io::{
         self,
@@ -154,7 +159,6 @@ use std::{
         Mutex,
     },
 };
-use std::borrow::Cow;
 use tai64::Tai64;
 use tracing as _;
 use types::{
@@ -245,6 +249,9 @@ impl Clone for ChainStateInfo {
     }
 }
 
+/// The header used to pass the API key to the node.
+const API_KEY_HEADER: &str = "x-api-key";
+
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
@@ -253,6 +260,8 @@ pub struct FuelClient {
     url: reqwest::Url,
     require_height: ConsistencyPolicy,
     chain_state_info: ChainStateInfo,
+    admin_token: Option<String>,
+    api_key: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -284,6 +293,8 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                admin_token: None,
+                api_key: None,
             })
         }
 
@@ -297,6 +308,8 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                admin_token: None,
+                api_key: None,
             })
         }
     }
@@ -345,6 +358,18 @@ impl FuelClient {
         self
     }
 
+    /// Sets the token used to authorize requests to the admin API of the node.
+    pub fn with_admin_token(&mut self, token: impl Into<String>) -> &mut Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
+    /// Sets the API key used to identify the client by the rate limiter of the node.
+    pub fn with_api_key(&mut self, api_key: impl Into<String>) -> &mut Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
     pub fn use_manual_consistency_policy(
         &mut self,
         height: Option<BlockHeight>,
@@ -416,9 +441,14 @@ impl FuelClient {
     {
         let required_fuel_block_height = self.required_block_height();
         let fuel_operation = FuelOperation::new(q, required_fuel_block_height);
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(admin_token) = &self.admin_token {
+            request = request.bearer_auth(admin_token);
+        }
+        if let Some(api_key) = &self.api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+        let response = request
             .run_fuel_graphql(fuel_operation)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -507,6 +537,18 @@ impl FuelClient {
                 })?;
         }
 
+        if let Some(api_key) = &self.api_key {
+            client_builder =
+                client_builder
+                    .header(API_KEY_HEADER, api_key)
+                    .map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::Other,
+                            format!("Failed to add header to client {e:?}"),
+                        )
+                    })?;
+        }
+
         if let Some(value) = self.cookie.deref().cookies(&self.url) {
             let value = value.to_str().map_err(|e| {
                 io::Error::new(
@@ -628,6 +670,25 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Returns the gas prices, fullness, DA costs and tips at the `percentiles`
+    /// of the blocks from `from` to `to` inclusive.
+    pub async fn gas_price_history(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+        percentiles: &[u8],
+    ) -> io::Result<Vec<types::gas_price::BlockGasPrice>> {
+        let args = schema::gas_price::GasPriceHistoryArgs {
+            from: from.into(),
+            to: to.into(),
+            percentiles: percentiles.iter().copied().map(Into::into).collect(),
+        };
+        let query = schema::gas_price::QueryGasPriceHistory::build(args);
+        self.query(query)
+            .await
+            .map(|r| r.gas_price_history.into_iter().map(Into::into).collect())
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -685,7 +746,7 @@ impl FuelClient {
         root: Bytes32,
     ) -> io::Result<Option<StateTransitionBytecode>> {
         let args = schema::upgrades::StateTransitionBytecodeByRootArgs {
-            root: HexString(Bytes (Cow::Owned(root.to_vec()))),
+            root: HexString(Bytes(Cow::Owned(root.to_vec()))),
         };
         let query = schema::upgrades::StateTransitionBytecodeByRootQuery::build(args);
 
@@ -734,6 +795,36 @@ impl FuelClient {
             .collect()
     }
 
+    /// Like `dry_run_opt`, but executes the transactions on top of the `overrides`
+    /// of the state and the block header. The overrides only exist during the dry run.
+    pub async fn dry_run_with_overrides(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+        at_height: Option<BlockHeight>,
+        overrides: DryRunOverrides,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(Cow::Owned(tx.to_bytes()))))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunWithOverrides, DryRunWithOverridesArg> =
+            schema::tx::DryRunWithOverrides::build(DryRunWithOverridesArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+                block_height: at_height.map(|bh| bh.into()),
+                overrides: Some(overrides.into()),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
     /// Like `dry_run_opt`, but also returns the storage reads
     pub async fn dry_run_opt_record_storage_reads(
         &self,
@@ -1103,8 +1194,67 @@ impl FuelClient {
             .map(|r| r.contract_balance_values.into_iter().collect())
     }
 
+    /// Returns the sparse merkle proof of the storage slot of the contract
+    /// at the `block_height`, or at the latest height if not provided.
+    /// The proof can be checked with [`types::ContractStorageProof::verify_slot`].
+    pub async fn contract_slot_proof(
+        &self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+        block_height: Option<BlockHeight>,
+    ) -> io::Result<types::ContractStorageProof> {
+        let query = schema::storage::ContractSlotProof::build(
+            schema::storage::ContractSlotProofArgs {
+                contract: (*contract_id).into(),
+                key: (*key).into(),
+                height: block_height.map(|b| (*b).into()),
+            },
+        );
+
+        self.query(query)
+            .await
+            .map(|r| r.contract_slot_proof.into())
+    }
+
+    /// Returns the sparse merkle proof of the balance of the contract
+    /// at the `block_height`, or at the latest height if not provided.
+    /// The proof can be checked with [`types::ContractStorageProof::verify_balance`].
+    pub async fn contract_balance_proof(
+        &self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        block_height: Option<BlockHeight>,
+    ) -> io::Result<types::ContractStorageProof> {
+        let query = schema::storage::ContractBalanceProof::build(
+            schema::storage::ContractBalanceProofArgs {
+                contract: (*contract_id).into(),
+                asset: (*asset_id).into(),
+                height: block_height.map(|b| (*b).into()),
+            },
+        );
+
+        self.query(query)
+            .await
+            .map(|r| r.contract_balance_proof.into())
+    }
+
     pub async fn start_session(&self) -> io::Result<String> {
-        let query = schema::StartSession::build(());
+        let query = schema::StartSession::build(StartSessionArgs { block_height: None });
+
+        self.query(query)
+            .await
+            .map(|r| r.start_session.into_inner())
+    }
+
+    /// Starts the debugger session on top of the state before the block at `block_height`,
+    /// allowing to re-execute transactions of this block.
+    pub async fn start_session_at(
+        &self,
+        block_height: BlockHeight,
+    ) -> io::Result<String> {
+        let query = schema::StartSession::build(StartSessionArgs {
+            block_height: Some(block_height.into()),
+        });
 
         self.query(query)
             .await
@@ -1334,6 +1484,24 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns the transactions that interacted with the contract.
+    pub async fn transactions_by_contract(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract: schema::ContractId = (*contract).into();
+        let args = TransactionsByContractConnectionArgs::from((contract, request));
+        let query = schema::tx::TransactionsByContractQuery::build(args);
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1395,6 +1563,90 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn blacklist(&self) -> io::Result<types::Blacklist> {
+        let query = schema::admin::BlacklistQuery::build(());
+
+        Ok(self.query(query).await?.blacklist.into())
+    }
+
+    /// Adds the `entries` to the blacklist of the transaction pool
+    /// and returns the updated blacklist.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn blacklist_add(
+        &self,
+        entries: types::Blacklist,
+    ) -> io::Result<types::Blacklist> {
+        let query = schema::admin::BlacklistAddMutation::build(
+            schema::admin::BlacklistUpdateArgs::from(entries),
+        );
+
+        Ok(self.query(query).await?.blacklist_add.into())
+    }
+
+    /// Removes the `entries` from the blacklist of the transaction pool
+    /// and returns the updated blacklist.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    pub async fn blacklist_remove(
+        &self,
+        entries: types::Blacklist,
+    ) -> io::Result<types::Blacklist> {
+        let query = schema::admin::BlacklistRemoveMutation::build(
+            schema::admin::BlacklistUpdateArgs::from(entries),
+        );
+
+        Ok(self.query(query).await?.blacklist_remove.into())
+    }
+
+    /// Returns the peers banned by the node.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    #[cfg(feature = "std")]
+    pub async fn banned_peers(
+        &self,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::BannedPeer>> {
+        let query = schema::admin::BannedPeersQuery::build(());
+
+        let peers = self.query(query).await?.banned_peers;
+        Ok(peers.into_iter().map(Into::into).collect())
+    }
+
+    /// Bans the peer for the `duration`, or permanently if the `duration` is not set,
+    /// and returns the updated list of banned peers.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    #[cfg(feature = "std")]
+    pub async fn ban_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+        reason: &str,
+        duration: Option<std::time::Duration>,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::BannedPeer>> {
+        let query = schema::admin::BanPeerMutation::build(schema::admin::BanPeerArgs {
+            id: peer_id.to_string(),
+            reason: reason.to_string(),
+            duration_seconds: duration.map(|duration| duration.as_secs().into()),
+        });
+
+        let peers = self.query(query).await?.ban_peer;
+        Ok(peers.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes the ban of the peer and returns the updated list of banned peers.
+    /// Requires the admin token, see [`FuelClient::with_admin_token`].
+    #[cfg(feature = "std")]
+    pub async fn unban_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::BannedPeer>> {
+        let query =
+            schema::admin::UnbanPeerMutation::build(schema::admin::UnbanPeerArgs {
+                id: peer_id.to_string(),
+            });
+
+        let peers = self.query(query).await?.unban_peer;
+        Ok(peers.into_iter().map(Into::into).collect())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -1471,6 +1723,102 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Subscribes to the blocks imported by the node. If `from_height` is provided,
+    /// the stream starts from the stored block at this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Block>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::BlocksSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::BlocksSubscription>| {
+                let block: types::Block = result?.blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Similar to [`Self::subscribe_blocks`], but each block also contains
+    /// its transactions with their statuses and receipts.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_blocks_with_transactions(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::BlockWithTransactions>> + '_>
+    {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::BlocksWithTransactionsSubscription::build(
+            BlocksSubscriptionArgs {
+                from_height: from_height.map(|height| U32(height.into())),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::BlocksWithTransactionsSubscription>| {
+                let block: types::BlockWithTransactions = result?.blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the logs matching the `filter` from the blocks imported by the node.
+    /// If `filter.from_height` is provided, the stream starts from the stored logs
+    /// at this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_logs(
+        &self,
+        filter: types::LogFilter,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Log>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::log::LogsSubscriptionArgs;
+        let s = schema::log::LogsSubscription::build(LogsSubscriptionArgs {
+            filter: filter.into(),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::log::LogsSubscription>| {
+                let log: types::Log = result?.logs.try_into()?;
+                Result::<_, io::Error>::Ok(log)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the headers of the blocks imported by the node. If `from_height`
+    /// is provided, the stream starts from the stored header at this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_headers(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::block::Header>> + '_> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::HeadersSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::HeadersSubscription>| {
+                let header: types::block::Header = result?.headers.try_into()?;
+                Result::<_, io::Error>::Ok(header)
+            },
+        );
+
+        Ok(stream)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -1601,6 +1949,21 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the logs matching the `filter`, ordered by the position in the chain.
+    /// Requires the logs indexation to be enabled on the node.
+    pub async fn logs(
+        &self,
+        filter: types::LogFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Log, String>> {
+        let args = schema::log::LogsConnectionArgs::from((filter.into(), request));
+        let query = schema::log::LogsQuery::build(args);
+
+        let logs = self.query(query).await?.logs.try_into()?;
+
+        Ok(logs)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -26,6 +26,7 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod admin;
 pub mod assets;
 pub mod balance;
 pub mod blob;
@@ -34,6 +35,7 @@ pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod da_compressed;
+pub mod log;
 pub mod message;
 pub mod node_info;
 pub mod storage_read_replay;
@@ -52,9 +54,19 @@ pub struct Health {
     pub health: bool,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct StartSessionArgs {
+    pub block_height: Option<U32>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
-#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "StartSessionArgs"
+)]
 pub struct StartSession {
+    #[arguments(blockHeight: $block_height)]
     pub start_session: cynic::Id,
 }
 
@@ -0,0 +1,124 @@
+use crate::client::schema::{
+    Address,
+    ContractId,
+    Nonce,
+    U64,
+    UtxoId,
+    schema,
+};
+#[cfg(feature = "std")]
+use fuel_core_types::services::p2p::PeerId;
+#[cfg(feature = "std")]
+use std::{
+    str::FromStr,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Blacklist {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct BlacklistQuery {
+    pub blacklist: Blacklist,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlacklistUpdateArgs {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BlacklistUpdateArgs"
+)]
+pub struct BlacklistAddMutation {
+    #[arguments(owners: $owners, coins: $coins, messages: $messages, contracts: $contracts)]
+    pub blacklist_add: Blacklist,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BlacklistUpdateArgs"
+)]
+pub struct BlacklistRemoveMutation {
+    #[arguments(owners: $owners, coins: $coins, messages: $messages, contracts: $contracts)]
+    pub blacklist_remove: Blacklist,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BannedPeer {
+    pub id: String,
+    pub reason: String,
+    pub expires_at_ms: Option<U64>,
+}
+
+#[cfg(feature = "std")]
+impl From<BannedPeer> for fuel_core_types::services::p2p::BannedPeer {
+    fn from(peer: BannedPeer) -> Self {
+        Self {
+            id: PeerId::from_str(peer.id.as_str()).unwrap_or_default(),
+            reason: peer.reason,
+            expires_at: peer
+                .expires_at_ms
+                .and_then(|ms| UNIX_EPOCH.checked_add(Duration::from_millis(ms.0))),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct BannedPeersQuery {
+    pub banned_peers: Vec<BannedPeer>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BanPeerArgs {
+    pub id: String,
+    pub reason: String,
+    pub duration_seconds: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BanPeerArgs"
+)]
+pub struct BanPeerMutation {
+    #[arguments(id: $id, reason: $reason, durationSeconds: $duration_seconds)]
+    pub ban_peer: Vec<BannedPeer>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UnbanPeerArgs {
+    pub id: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "UnbanPeerArgs"
+)]
+pub struct UnbanPeerMutation {
+    #[arguments(id: $id)]
+    pub unban_peer: Vec<BannedPeer>,
+}
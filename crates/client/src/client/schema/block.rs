@@ -10,6 +10,7 @@ use crate::client::schema::{
     U32,
     U64,
     schema,
+    tx::OpaqueTransactionWithStatus,
 };
 use fuel_core_types::{
     fuel_crypto,
@@ -92,6 +93,57 @@ pub struct Block {
     pub transaction_ids: Vec<TransactionId>,
 }
 
+/// Block with transactions and their statuses
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockWithTransactions {
+    pub version: BlockVersion,
+    pub id: BlockId,
+    pub header: Header,
+    pub consensus: Consensus,
+    pub transaction_ids: Vec<TransactionId>,
+    pub transactions: Vec<OpaqueTransactionWithStatus>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksSubscriptionArgs {
+    /// The height of the first block in the stream.
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct BlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub blocks: Block,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct BlocksWithTransactionsSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub blocks: BlockWithTransactions,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct HeadersSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub headers: Header,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
 pub struct BlockIdFragment {
@@ -156,6 +208,8 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
+    DaDerivedConsensus(DaDerivedConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -176,19 +230,52 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BftConsensus {
+    pub round: U32,
+    pub proposer_signature: Signature,
+    pub precommits: Vec<Signature>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaDerivedConsensus {
+    pub sealed: bool,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
-        let message = self.header.id.clone().into_message();
-        match &self.consensus {
-            Consensus::Genesis(_) => Some(Default::default()),
-            Consensus::PoAConsensus(poa) => {
-                let signature = poa.signature.clone().into_signature();
-                let producer_pub_key = signature.recover(&message);
-                producer_pub_key.ok()
-            }
-            Consensus::Unknown => None,
+        block_producer(&self.header, &self.consensus)
+    }
+}
+
+impl BlockWithTransactions {
+    /// Returns the block producer public key, if any.
+    pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
+        block_producer(&self.header, &self.consensus)
+    }
+}
+
+fn block_producer(
+    header: &Header,
+    consensus: &Consensus,
+) -> Option<fuel_crypto::PublicKey> {
+    let message = header.id.clone().into_message();
+    match consensus {
+        Consensus::Genesis(_) => Some(Default::default()),
+        Consensus::PoAConsensus(poa) => {
+            let signature = poa.signature.clone().into_signature();
+            let producer_pub_key = signature.recover(&message);
+            producer_pub_key.ok()
+        }
+        Consensus::BftConsensus(bft) => {
+            let signature = bft.proposer_signature.clone().into_signature();
+            let producer_pub_key = signature.recover(&message);
+            producer_pub_key.ok()
         }
+        Consensus::DaDerivedConsensus(_) | Consensus::Unknown => None,
     }
 }
 
@@ -1,6 +1,8 @@
 use crate::client::schema::{
+    U8,
     U32,
     U64,
+    U128,
     schema,
 };
 
@@ -47,6 +49,47 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct GasPriceHistoryArgs {
+    /// The height of the first block.
+    pub from: U32,
+    /// The height of the last block, inclusive.
+    pub to: U32,
+    /// The percentiles of tips to return, from 0 to 100.
+    pub percentiles: Vec<U8>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockFullness {
+    pub used: U64,
+    pub capacity: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", variables = "GasPriceHistoryArgs")]
+pub struct BlockGasPrice {
+    pub block_height: U32,
+    pub exec_gas_price: U64,
+    pub da_gas_price: Option<U64>,
+    pub da_cost_per_byte: Option<U128>,
+    pub recorded_da_cost: Option<U128>,
+    pub fullness: BlockFullness,
+    #[arguments(percentiles: $percentiles)]
+    pub tip_percentiles: Vec<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "GasPriceHistoryArgs"
+)]
+pub struct QueryGasPriceHistory {
+    #[arguments(from: $from, to: $to)]
+    pub gas_price_history: Vec<BlockGasPrice>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +111,16 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(args);
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn gas_price_history_query_gql_output() {
+        use cynic::QueryBuilder;
+        let args = GasPriceHistoryArgs {
+            from: 10.into(),
+            to: 20.into(),
+            percentiles: vec![25.into(), 50.into(), 75.into()],
+        };
+        let operation = QueryGasPriceHistory::build(args);
+        insta::assert_snapshot!(operation.query)
+    }
 }
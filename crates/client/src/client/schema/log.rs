@@ -0,0 +1,135 @@
+use crate::client::{
+    PageDirection,
+    PaginationRequest,
+    schema::{
+        ContractId,
+        PageInfo,
+        TransactionId,
+        U16,
+        U32,
+        U64,
+        schema,
+        tx::transparent_receipt::Receipt,
+    },
+};
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogFilterInput {
+    /// The contracts that emitted the logs.
+    pub contracts: Vec<ContractId>,
+    /// The ids of the logs(the value of the `rb` register).
+    /// If not specified, the logs with any id are returned.
+    pub log_ids: Option<Vec<U64>>,
+    /// The first height of the range, inclusive.
+    pub from_height: Option<U32>,
+    /// The last height of the range, inclusive.
+    pub to_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Log {
+    pub contract_id: ContractId,
+    pub log_id: U64,
+    pub block_height: U32,
+    pub tx_index: U16,
+    pub transaction_id: TransactionId,
+    pub receipt_index: U16,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct LogsConnectionArgs {
+    /// Filter logs based on a filter
+    pub filter: LogFilterInput,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n logs in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n logs in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(LogFilterInput, PaginationRequest<String>)> for LogsConnectionArgs {
+    fn from(r: (LogFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => LogsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => LogsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogEdge {
+    pub cursor: String,
+    pub node: Log,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogConnection {
+    pub edges: Vec<LogEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "LogsConnectionArgs"
+)]
+pub struct LogsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub logs: LogConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct LogsSubscriptionArgs {
+    pub filter: LogFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "LogsSubscriptionArgs"
+)]
+pub struct LogsSubscription {
+    #[arguments(filter: $filter)]
+    pub logs: Log,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logs_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = LogsQuery::build(LogsConnectionArgs {
+            filter: LogFilterInput::default(),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -95,6 +95,8 @@ pub struct IndexationFlags {
     pub balances: bool,
     pub coins_to_spend: bool,
     pub asset_metadata: bool,
+    pub transactions_by_contract: bool,
+    pub logs: bool,
 }
 
 #[cfg(test)]
@@ -173,6 +173,12 @@ impl From<TxPointer> for ::fuel_core_types::fuel_tx::TxPointer {
     }
 }
 
+impl From<fuel_core_types::fuel_tx::TxPointer> for TxPointer {
+    fn from(value: fuel_core_types::fuel_tx::TxPointer) -> Self {
+        Self(HexFormatted(value))
+    }
+}
+
 impl LowerHex for TxPointer {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         LowerHex::fmt(&self.0.0, f)
@@ -285,6 +291,7 @@ number_scalar!(U128, u128);
 number_scalar!(U64, u64);
 number_scalar!(U32, u32);
 number_scalar!(U16, u16);
+number_scalar!(U8, u8);
 
 impl TryFrom<U64> for PanicInstruction {
     type Error = ConversionError;
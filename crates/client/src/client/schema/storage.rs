@@ -91,3 +91,55 @@ pub struct ContractBalanceValuesArgs {
     pub block_height: Option<U32>,
     pub assets: Vec<AssetId>,
 }
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractSlotProofArgs"
+)]
+pub struct ContractSlotProof {
+    #[arguments(contract: $contract, key: $key, height: $height)]
+    pub contract_slot_proof: ContractStorageProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractSlotProofArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceProofArgs"
+)]
+pub struct ContractBalanceProof {
+    #[arguments(contract: $contract, asset: $asset, height: $height)]
+    pub contract_balance_proof: ContractStorageProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceProofArgs {
+    pub contract: ContractId,
+    pub asset: AssetId,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageProof {
+    pub root: Bytes32,
+    pub value: Option<HexString>,
+    pub proof_set: Vec<Bytes32>,
+    pub exclusion_leaf: Option<SparseMerkleLeaf>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleLeaf {
+    pub leaf_key: Bytes32,
+    pub leaf_value: Bytes32,
+}
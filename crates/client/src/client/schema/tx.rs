@@ -6,6 +6,7 @@ use crate::client::{
         Address,
         AssetId,
         ConnectionArgsFields,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
@@ -33,6 +34,7 @@ use fuel_core_types::{
     },
     fuel_vm,
     services::executor::{
+        DryRunOverrides,
         TransactionExecutionResult,
         TransactionExecutionStatus,
     },
@@ -198,6 +200,7 @@ pub enum TransactionStatus {
     SuccessStatus(SuccessStatus),
     PreconfirmationSuccessStatus(PreconfirmationSuccessStatus),
     SqueezedOutStatus(SqueezedOutStatus),
+    ReplacedStatus(ReplacedStatus),
     FailureStatus(FailureStatus),
     PreconfirmationFailureStatus(PreconfirmationFailureStatus),
     #[cynic(fallback)]
@@ -215,6 +218,7 @@ pub enum StatusWithTransaction {
     SuccessStatus(SuccessStatusWithTransaction),
     PreconfirmationSuccessStatus(PreconfirmationSuccessStatusWithTransaction),
     SqueezedOutStatus(SqueezedOutStatus),
+    ReplacedStatus(ReplacedStatus),
     FailureStatus(FailureStatusWithTransaction),
     PreconfirmationFailureStatus(PreconfirmationFailureStatusWithTransaction),
     #[cynic(fallback)]
@@ -342,6 +346,13 @@ pub struct SqueezedOutStatus {
     pub reason: String,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReplacedStatus {
+    pub replaced_by: TransactionId,
+    pub reason: String,
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(cynic::InlineFragments, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
@@ -473,6 +484,55 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that interacted with the `contract`
+    pub contract: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct StatusChangeSubscriptionArgs {
     pub id: TransactionId,
@@ -623,6 +683,118 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunOverridesInput {
+    pub block_height: Option<U32>,
+    pub timestamp: Option<Tai64Timestamp>,
+    pub contracts: Vec<ContractOverrideInput>,
+    pub coins: Vec<CoinOverrideInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractOverrideInput {
+    pub contract_id: ContractId,
+    pub bytecode: Option<HexString>,
+    pub storage_slots: Vec<StorageSlotOverrideInput>,
+    pub balances: Vec<BalanceOverrideInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageSlotOverrideInput {
+    pub key: super::Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceOverrideInput {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub tx_pointer: Option<TxPointer>,
+}
+
+impl From<DryRunOverrides> for DryRunOverridesInput {
+    fn from(overrides: DryRunOverrides) -> Self {
+        let contracts = overrides
+            .state
+            .contracts
+            .into_iter()
+            .map(|contract| ContractOverrideInput {
+                contract_id: contract.contract_id.into(),
+                bytecode: contract.bytecode.map(Into::into),
+                storage_slots: contract
+                    .storage_slots
+                    .into_iter()
+                    .map(|(key, value)| StorageSlotOverrideInput {
+                        key: key.into(),
+                        value: value.into(),
+                    })
+                    .collect(),
+                balances: contract
+                    .balances
+                    .into_iter()
+                    .map(|(asset_id, amount)| BalanceOverrideInput {
+                        asset_id: asset_id.into(),
+                        amount: amount.into(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let coins = overrides
+            .state
+            .coins
+            .into_iter()
+            .map(|coin| CoinOverrideInput {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                tx_pointer: Some(coin.tx_pointer.into()),
+            })
+            .collect();
+
+        Self {
+            block_height: overrides.block_height.map(Into::into),
+            timestamp: overrides.timestamp.map(Tai64Timestamp),
+            contracts,
+            coins,
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunWithOverridesArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub gas_price: Option<U64>,
+    pub block_height: Option<U32>,
+    pub overrides: Option<DryRunOverridesInput>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DryRunWithOverridesArg"
+)]
+pub struct DryRunWithOverrides {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height, overrides: $overrides)]
+    pub dry_run: Vec<DryRunTransactionExecutionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct DryRunStorageReads {
@@ -748,6 +920,21 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[cfg(not(feature = "test-helpers"))]
+    #[test]
+    fn transactions_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByContractQuery::build(TransactionsByContractConnectionArgs {
+                contract: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -1,4 +1,5 @@
 pub mod balance;
+pub mod blacklist;
 pub mod blob;
 pub mod block;
 pub mod chain_info;
@@ -10,14 +11,17 @@ pub mod upgrades;
 pub mod assemble_tx;
 pub mod asset;
 pub mod gas_price;
+pub mod log;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
 pub use balance::Balance;
+pub use blacklist::Blacklist;
 pub use blob::Blob;
 pub use block::{
     Block,
+    BlockWithTransactions,
     Consensus,
 };
 pub use chain_info::ChainInfo;
@@ -34,7 +38,14 @@ pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
-pub use merkle_proof::MerkleProof;
+pub use log::{
+    Log,
+    LogFilter,
+};
+pub use merkle_proof::{
+    ContractStorageProof,
+    MerkleProof,
+};
 pub use message::{
     Message,
     MessageProof,
@@ -133,6 +144,10 @@ pub enum TransactionStatus {
     SqueezedOut {
         reason: String,
     },
+    Replaced {
+        replaced_by: TxId,
+        reason: String,
+    },
     Failure {
         block_height: BlockHeight,
         time: Tai64,
@@ -158,7 +173,8 @@ impl TransactionStatus {
         match self {
             TransactionStatus::Success { .. }
             | TransactionStatus::Failure { .. }
-            | TransactionStatus::SqueezedOut { .. } => true,
+            | TransactionStatus::SqueezedOut { .. }
+            | TransactionStatus::Replaced { .. } => true,
             TransactionStatus::Submitted { .. }
             | TransactionStatus::PreconfirmationSuccess { .. }
             | TransactionStatus::PreconfirmationFailure { .. } => false,
@@ -259,6 +275,10 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
             SchemaTxStatus::SqueezedOutStatus(s) => {
                 TransactionStatus::SqueezedOut { reason: s.reason }
             }
+            SchemaTxStatus::ReplacedStatus(s) => TransactionStatus::Replaced {
+                replaced_by: s.replaced_by.into(),
+                reason: s.reason,
+            },
             SchemaTxStatus::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
@@ -290,6 +310,10 @@ pub enum StatusWithTransaction {
     SqueezedOut {
         reason: String,
     },
+    Replaced {
+        replaced_by: TxId,
+        reason: String,
+    },
     Failure {
         transaction: Transaction,
         block_height: BlockHeight,
@@ -407,6 +431,10 @@ impl TryFrom<SchemaStatusWithTx> for StatusWithTransaction {
             SchemaStatusWithTx::SqueezedOutStatus(s) => {
                 StatusWithTransaction::SqueezedOut { reason: s.reason }
             }
+            SchemaStatusWithTx::ReplacedStatus(s) => StatusWithTransaction::Replaced {
+                replaced_by: s.replaced_by.into(),
+                reason: s.reason,
+            },
             SchemaStatusWithTx::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
@@ -0,0 +1,41 @@
+use crate::client::schema;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ContractId,
+        UtxoId,
+    },
+    fuel_types::Nonce,
+};
+
+/// The blacklist of the transaction pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Blacklist {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+// GraphQL Translation
+impl From<schema::admin::Blacklist> for Blacklist {
+    fn from(value: schema::admin::Blacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<Blacklist> for schema::admin::BlacklistUpdateArgs {
+    fn from(value: Blacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
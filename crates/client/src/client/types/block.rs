@@ -2,13 +2,16 @@ use crate::client::{
     PaginatedResult,
     schema,
     schema::ConversionError,
-    types::primitives::{
-        BlockId,
-        Hash,
-        MerkleRoot,
-        PublicKey,
-        Signature,
-        TransactionId,
+    types::{
+        TransactionResponse,
+        primitives::{
+            BlockId,
+            Hash,
+            MerkleRoot,
+            PublicKey,
+            Signature,
+            TransactionId,
+        },
     },
 };
 
@@ -27,6 +30,13 @@ pub struct Block {
     pub block_producer: Option<PublicKey>,
 }
 
+/// Block with transactions and their statuses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockWithTransactions {
+    pub block: Block,
+    pub transactions: Vec<TransactionResponse>,
+}
+
 impl Block {
     pub fn block_producer(&self) -> Option<&PublicKey> {
         self.block_producer.as_ref()
@@ -56,6 +66,9 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
+    /// The block was derived from the DA compressed block and isn't sealed.
+    DaDerivedConsensus,
     Unknown,
 }
 
@@ -73,6 +86,13 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BftConsensus {
+    pub round: u32,
+    pub proposer_signature: Signature,
+    pub precommits: Vec<Signature>,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -132,6 +152,12 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::BftConsensus(bft) => {
+                Consensus::BftConsensus(bft.into())
+            }
+            schema::block::Consensus::DaDerivedConsensus(_) => {
+                Consensus::DaDerivedConsensus
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -158,6 +184,20 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BftConsensus> for BftConsensus {
+    fn from(value: schema::block::BftConsensus) -> Self {
+        let into_signature = |signature: schema::Signature| {
+            let bytes: [u8; 64] = signature.0.0.into();
+            Signature::from_bytes(bytes)
+        };
+        Self {
+            round: value.round.into(),
+            proposer_signature: into_signature(value.proposer_signature),
+            precommits: value.precommits.into_iter().map(into_signature).collect(),
+        }
+    }
+}
+
 impl TryFrom<schema::block::Block> for Block {
     type Error = ConversionError;
 
@@ -186,6 +226,44 @@ impl TryFrom<schema::block::Block> for Block {
     }
 }
 
+impl TryFrom<schema::block::BlockWithTransactions> for BlockWithTransactions {
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::block::BlockWithTransactions,
+    ) -> Result<Self, Self::Error> {
+        match value.version {
+            BlockVersion::V1 => {
+                let block_producer = value.block_producer();
+                let id = value.id.into();
+                let header = value.header.try_into()?;
+                let consensus = value.consensus.into();
+                let transaction_ids = value
+                    .transaction_ids
+                    .into_iter()
+                    .map(Into::into)
+                    .collect::<Vec<TransactionId>>();
+                let transactions = value
+                    .transactions
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Self {
+                    block: Block {
+                        id,
+                        header,
+                        consensus,
+                        transactions: transaction_ids,
+                        block_producer,
+                    },
+                    transactions,
+                })
+            }
+            _ => Err(ConversionError::UnknownVariant("BlockVersion")),
+        }
+    }
+}
+
 impl TryFrom<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     type Error = ConversionError;
 
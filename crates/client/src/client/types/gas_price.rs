@@ -28,3 +28,30 @@ impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockGasPrice {
+    pub block_height: BlockHeight,
+    pub exec_gas_price: u64,
+    pub da_gas_price: Option<u64>,
+    pub da_cost_per_byte: Option<u128>,
+    pub recorded_da_cost: Option<u128>,
+    pub gas_used: u64,
+    pub gas_capacity: u64,
+    pub tip_percentiles: Vec<u64>,
+}
+
+impl From<schema::gas_price::BlockGasPrice> for BlockGasPrice {
+    fn from(value: schema::gas_price::BlockGasPrice) -> Self {
+        Self {
+            block_height: value.block_height.into(),
+            exec_gas_price: value.exec_gas_price.into(),
+            da_gas_price: value.da_gas_price.map(Into::into),
+            da_cost_per_byte: value.da_cost_per_byte.map(Into::into),
+            recorded_da_cost: value.recorded_da_cost.map(Into::into),
+            gas_used: value.fullness.used.into(),
+            gas_capacity: value.fullness.capacity.into(),
+            tip_percentiles: value.tip_percentiles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
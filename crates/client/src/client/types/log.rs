@@ -0,0 +1,88 @@
+use crate::client::{
+    PaginatedResult,
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::primitives::{
+        ContractId,
+        TransactionId,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
+
+/// The filter of the logs indexed by the node.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// The contracts that emitted the logs.
+    pub contracts: Vec<ContractId>,
+    /// The ids of the logs(the value of the `rb` register).
+    /// If `None`, the logs with any id are returned.
+    pub log_ids: Option<Vec<u64>>,
+    /// The first height of the range, inclusive.
+    pub from_height: Option<BlockHeight>,
+    /// The last height of the range, inclusive.
+    pub to_height: Option<BlockHeight>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+    pub contract_id: ContractId,
+    pub log_id: u64,
+    pub block_height: BlockHeight,
+    pub tx_index: u16,
+    pub transaction_id: TransactionId,
+    pub receipt_index: u16,
+    pub receipt: Receipt,
+}
+
+// GraphQL Translation
+
+impl From<LogFilter> for schema::log::LogFilterInput {
+    fn from(value: LogFilter) -> Self {
+        Self {
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+            log_ids: value
+                .log_ids
+                .map(|log_ids| log_ids.into_iter().map(Into::into).collect()),
+            from_height: value.from_height.map(|height| u32::from(height).into()),
+            to_height: value.to_height.map(|height| u32::from(height).into()),
+        }
+    }
+}
+
+impl TryFrom<schema::log::Log> for Log {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::log::Log) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract_id: value.contract_id.into(),
+            log_id: value.log_id.into(),
+            block_height: value.block_height.into(),
+            tx_index: value.tx_index.into(),
+            transaction_id: value.transaction_id.into(),
+            receipt_index: value.receipt_index.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<schema::log::LogConnection> for PaginatedResult<Log, String> {
+    type Error = ConversionError;
+
+    fn try_from(conn: schema::log::LogConnection) -> Result<Self, Self::Error> {
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn
+                .edges
+                .into_iter()
+                .map(|e| e.node.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -1,6 +1,21 @@
 use crate::client::{
     schema,
-    types::primitives::MerkleRoot,
+    types::primitives::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        MerkleRoot,
+    },
+};
+use fuel_core_types::fuel_merkle::sparse::{
+    MerkleTreeKey,
+    proof::{
+        ExclusionLeaf,
+        ExclusionLeafData,
+        ExclusionProof,
+        InclusionProof,
+        Proof,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -11,6 +26,49 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// The proof of the contract storage slot or balance in the sparse merkle tree
+/// of the contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStorageProof {
+    /// The root of the sparse merkle tree.
+    pub root: MerkleRoot,
+    /// The encoded value of the leaf. `None` for exclusion proofs.
+    pub value: Option<Vec<u8>>,
+    /// The inclusion proof if the leaf exists, otherwise the exclusion proof.
+    pub proof: Proof,
+}
+
+impl ContractStorageProof {
+    /// Verifies the proof of the `key` storage slot of the `contract_id` against the root.
+    pub fn verify_slot(&self, contract_id: &ContractId, key: &Bytes32) -> bool {
+        self.verify(contract_id.as_ref(), key.as_ref())
+    }
+
+    /// Verifies the proof of the `asset_id` balance of the `contract_id` against the root.
+    pub fn verify_balance(&self, contract_id: &ContractId, asset_id: &AssetId) -> bool {
+        self.verify(contract_id.as_ref(), asset_id.as_ref())
+    }
+
+    /// Returns the balance proven by the inclusion proof.
+    /// `None` for exclusion proofs, meaning the balance is zero.
+    pub fn balance(&self) -> Option<u64> {
+        let bytes = self.value.as_deref()?;
+        Some(u64::from_be_bytes(bytes.try_into().ok()?))
+    }
+
+    fn verify(&self, contract_id: &[u8], sub_key: &[u8]) -> bool {
+        // The leaves are keyed by the contract id concatenated with the slot key or asset id.
+        let key = MerkleTreeKey::new([contract_id, sub_key].concat());
+        match (&self.proof, &self.value) {
+            (Proof::Inclusion(proof), Some(value)) => {
+                proof.verify(&self.root, &key, value)
+            }
+            (Proof::Exclusion(proof), None) => proof.verify(&self.root, &key),
+            _ => false,
+        }
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -28,3 +86,33 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl From<schema::storage::ContractStorageProof> for ContractStorageProof {
+    fn from(value: schema::storage::ContractStorageProof) -> Self {
+        let proof_set = value
+            .proof_set
+            .into_iter()
+            .map(|hash| *fuel_core_types::fuel_types::Bytes32::from(hash))
+            .collect::<Vec<_>>();
+        let value_bytes = value.value.map(Into::into);
+        let proof = if value_bytes.is_some() {
+            Proof::Inclusion(InclusionProof { proof_set })
+        } else {
+            let leaf = match value.exclusion_leaf {
+                Some(leaf) => ExclusionLeaf::Leaf(ExclusionLeafData {
+                    leaf_key: *fuel_core_types::fuel_types::Bytes32::from(leaf.leaf_key),
+                    leaf_value: *fuel_core_types::fuel_types::Bytes32::from(
+                        leaf.leaf_value,
+                    ),
+                }),
+                None => ExclusionLeaf::Placeholder,
+            };
+            Proof::Exclusion(ExclusionProof { proof_set, leaf })
+        };
+        Self {
+            root: value.root.into(),
+            value: value_bytes,
+            proof,
+        }
+    }
+}
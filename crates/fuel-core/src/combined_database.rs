@@ -111,18 +111,23 @@ impl CombinedDatabase {
         db_dir: &std::path::Path,
         temp_dir: &std::path::Path,
     ) -> crate::database::Result<()> {
+        tracing::info!("Backing up the on-chain database");
         crate::state::rocks_db::RocksDb::<OnChain>::backup(db_dir, temp_dir)
             .trace_err("Failed to backup on-chain database")?;
 
+        tracing::info!("Backing up the off-chain database");
         crate::state::rocks_db::RocksDb::<OffChain>::backup(db_dir, temp_dir)
             .trace_err("Failed to backup off-chain database")?;
 
+        tracing::info!("Backing up the relayer database");
         crate::state::rocks_db::RocksDb::<Relayer>::backup(db_dir, temp_dir)
             .trace_err("Failed to backup relayer database")?;
 
+        tracing::info!("Backing up the gas-price database");
         crate::state::rocks_db::RocksDb::<GasPriceDatabase>::backup(db_dir, temp_dir)
             .trace_err("Failed to backup gas-price database")?;
 
+        tracing::info!("Backing up the compression database");
         crate::state::rocks_db::RocksDb::<CompressionDatabase>::backup(db_dir, temp_dir)
             .trace_err("Failed to backup compression database")?;
 
@@ -156,24 +161,29 @@ impl CombinedDatabase {
         backup_dir: &std::path::Path,
         temp_restore_dir: &std::path::Path,
     ) -> crate::database::Result<()> {
+        tracing::info!("Restoring the on-chain database");
         crate::state::rocks_db::RocksDb::<OnChain>::restore(temp_restore_dir, backup_dir)
             .trace_err("Failed to restore on-chain database")?;
 
+        tracing::info!("Restoring the off-chain database");
         crate::state::rocks_db::RocksDb::<OffChain>::restore(
             temp_restore_dir,
             backup_dir,
         )
         .trace_err("Failed to restore off-chain database")?;
 
+        tracing::info!("Restoring the relayer database");
         crate::state::rocks_db::RocksDb::<Relayer>::restore(temp_restore_dir, backup_dir)
             .trace_err("Failed to restore relayer database")?;
 
+        tracing::info!("Restoring the gas-price database");
         crate::state::rocks_db::RocksDb::<GasPriceDatabase>::restore(
             temp_restore_dir,
             backup_dir,
         )
         .trace_err("Failed to restore gas-price database")?;
 
+        tracing::info!("Restoring the compression database");
         crate::state::rocks_db::RocksDb::<CompressionDatabase>::restore(
             temp_restore_dir,
             backup_dir,
@@ -246,6 +256,30 @@ impl CombinedDatabase {
         })
     }
 
+    /// Opens all databases without the write access.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_read_only(
+        path: &std::path::Path,
+        database_config: DatabaseConfig,
+    ) -> crate::database::Result<Self> {
+        let max_fds = match database_config.max_fds {
+            -1 => -1,
+            _ => database_config.max_fds.saturating_div(4),
+        };
+        let database_config = DatabaseConfig {
+            max_fds,
+            ..database_config
+        };
+
+        Ok(Self {
+            on_chain: Database::open_rocksdb_read_only(path, database_config)?,
+            off_chain: Database::open_rocksdb_read_only(path, database_config)?,
+            relayer: Database::open_rocksdb_read_only(path, database_config)?,
+            gas_price: Database::open_rocksdb_read_only(path, database_config)?,
+            compression: Database::open_rocksdb_read_only(path, database_config)?,
+        })
+    }
+
     /// A test-only temporary rocksdb database with given rewind policy.
     #[cfg(feature = "rocksdb")]
     pub fn temp_database_with_state_rewind_policy(
@@ -16,6 +16,7 @@ use crate::{
         ColumnType,
         IterableKeyValueView,
         KeyValueView,
+        ModifiedKeys,
         data_source::{
             DataSource,
             DataSourceType,
@@ -250,6 +251,26 @@ where
         Ok(Self::new(Arc::new(db)))
     }
 
+    /// Opens the database without the write access,
+    /// so the files of the database are not modified.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_rocksdb_read_only(
+        path: &Path,
+        database_config: DatabaseConfig,
+    ) -> Result<Self> {
+        use anyhow::Context;
+
+        let db = HistoricalRocksDB::<Description>::default_open_read_only(
+            path,
+            StateRewindPolicy::NoRewind,
+            database_config,
+        )
+        .map_err(Into::<anyhow::Error>::into)
+        .with_context(|| format!("Failed to open rocksdb at {path:?} in read-only mode"))?;
+
+        Ok(Self::new(Arc::new(db)))
+    }
+
     /// Converts the regular database to an unchecked database.
     ///
     /// Returns an error in the case regular database is initialized with the `GenesisDatabase`,
@@ -354,6 +375,15 @@ where
         Ok(())
     }
 
+    /// Returns the keys modified by the blocks in the `(from, to]` range of heights.
+    pub fn modified_keys(
+        &self,
+        from: &Description::Height,
+        to: &Description::Height,
+    ) -> StorageResult<ModifiedKeys> {
+        self.inner_storage().data.modified_keys(from, to)
+    }
+
     fn latest_view_with_height(
         &self,
         height: Option<Description::Height>,
@@ -502,7 +532,7 @@ where
     Changes: Into<StorageChanges>,
 {
     // Gets the all new heights from the `changes`
-    let mut changes = changes.into();
+    let changes = changes.into();
     let iterator = ChangesIterator::<Description::Column>::new(&changes);
     let new_heights = heights_lookup(&iterator)?;
 
@@ -554,6 +584,24 @@ where
         }
     };
 
+    commit_changes_at_height(database, new_height, changes)
+}
+
+/// Commits the `changes` and updates the metadata to the `new_height`
+/// without checking that the height is linked to the previous one.
+///
+/// The regular commits should go through [`commit_changes_with_height_update`].
+/// It is used by the state sync that jumps over the blocks not executed by the node.
+pub fn commit_changes_at_height<Description>(
+    database: &mut Database<Description>,
+    new_height: Option<Description::Height>,
+    mut changes: StorageChanges,
+) -> StorageResult<()>
+where
+    Description: DatabaseDescription,
+    for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
+        StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
     if let Some(new_height) = new_height {
         // We want to update the metadata table to include a new height.
         // For that, we are building a new storage transaction.
@@ -85,6 +85,8 @@ pub enum IndexationKind {
     Balances,
     CoinsToSpend,
     AssetMetadata,
+    TransactionsByContract,
+    Logs,
 }
 
 impl IndexationKind {
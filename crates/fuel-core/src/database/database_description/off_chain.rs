@@ -27,8 +27,10 @@ impl DatabaseDescription for OffChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
+            | Self::Column::TransactionsByContractBlockIdx
+            | Self::Column::Logs
             | Self::Column::OwnedMessageIds => {
-                // prefix is address length
+                // prefix is address or contract id length
                 Some(32)
             }
             _ => None,
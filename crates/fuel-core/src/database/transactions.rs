@@ -1,6 +1,8 @@
 use crate::{
     database::OffChainIterableKeyValueView,
     fuel_core_graphql_api::storage::transactions::{
+        ContractTransactionIndexKey,
+        ContractTransactions,
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
         OwnedTransactions,
@@ -18,6 +20,7 @@ use fuel_core_types::{
     self,
     fuel_tx::{
         Bytes32,
+        ContractId,
         TxPointer,
     },
     fuel_types::Address,
@@ -63,6 +66,31 @@ impl OffChainIterableKeyValueView {
         })
     }
 
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx] => transaction id`.
+    /// The layout and the cursor are the same as in the [`Self::owned_transactions`].
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            ContractTransactionIndexKey::new(
+                &contract_id,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<ContractTransactions, _>(
+            Some(contract_id),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
     pub fn get_tx_status(
         &self,
         id: &Bytes32,
@@ -15,6 +15,7 @@ pub mod database;
 pub(crate) mod extensions;
 pub(crate) mod indexation;
 pub mod ports;
+pub mod rate_limit;
 pub mod storage;
 pub mod worker_service;
 
@@ -51,11 +52,19 @@ pub struct ServiceConfig {
     pub required_fuel_block_height_timeout: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// Enables the metrics of the queries.
+    pub metrics: bool,
     pub api_request_timeout: Duration,
     pub assemble_tx_dry_run_limit: usize,
     pub assemble_tx_estimate_predicates_limit: usize,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
+    /// The token required to access the admin API.
+    /// The admin API is disabled if the token is not set.
+    pub admin_token: Option<String>,
+    /// The per-client rate limits of the API.
+    /// Requests are not rate limited if it is not set.
+    pub rate_limit: Option<rate_limit::RateLimitConfig>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -7,6 +7,7 @@ use crate::{
             ChainStateProvider as ChainStateProviderTrait,
             ConsensusModulePort,
             GasPriceEstimate,
+            GasPriceHistory,
             OffChainDatabase,
             OffChainDatabaseAt,
             OnChainDatabase,
@@ -20,13 +21,24 @@ use crate::{
         extensions::{
             chain_state_info::ChainStateInfoExtension,
             metrics::MetricsExtension,
+            rate_limit::{
+                RATE_LIMIT_RETRY_AFTER,
+                RateLimitExtension,
+                error_response as rate_limit_error,
+            },
             required_fuel_block_height::RequiredFuelBlockHeightExtension,
             validation::ValidationExtension,
         },
+        rate_limit::{
+            API_KEY_HEADER,
+            Client,
+            RateLimiter,
+        },
     },
     schema::{
         CoreSchema,
         CoreSchemaBuilder,
+        admin::AuthorizationToken,
     },
     service::{
         adapters::SharedMemoryPool,
@@ -36,26 +48,33 @@ use crate::{
 use async_graphql::{
     Request,
     Response,
+    Value,
     http::GraphiQLSource,
 };
 use axum::{
     Json,
     Router,
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
     http::{
+        HeaderMap,
         HeaderValue,
+        StatusCode,
         header::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            AUTHORIZATION,
+            RETRY_AFTER,
         },
     },
     response::{
         Html,
         IntoResponse,
+        Response as HttpResponse,
         Sse,
         sse::Event,
     },
@@ -118,6 +137,8 @@ pub type P2pService = Box<dyn P2pPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
+pub type GasPriceHistoryProvider = Box<dyn GasPriceHistory>;
+
 pub type ChainInfoProvider = Box<dyn ChainStateProviderTrait>;
 
 pub type DaCompressionProvider = Box<dyn DatabaseDaCompressedBlocks>;
@@ -200,7 +221,7 @@ impl RunnableService for GraphqlService {
         let server = axum::Server::from_tcp(listener)
             .unwrap()
             .executor(executor)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -248,6 +269,7 @@ pub fn new_service<OnChain, OffChain>(
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
     gas_price_provider: GasPriceProvider,
+    gas_price_history_provider: GasPriceHistoryProvider,
     chain_state_info_provider: ChainInfoProvider,
     memory_pool: SharedMemoryPool,
     block_height_subscriber: block_height_subscription::Subscriber,
@@ -284,6 +306,7 @@ where
     let max_queries_resolver_recursive_depth =
         config.config.max_queries_resolver_recursive_depth;
     let number_of_threads = config.config.number_of_threads;
+    let rate_limiter = RateLimiter::new(config.config.rate_limit.clone());
     let required_fuel_block_height_tolerance =
         config.config.required_fuel_block_height_tolerance;
     let required_fuel_block_height_timeout =
@@ -299,6 +322,7 @@ where
         .extension(ChainStateInfoExtension::new(block_height_subscriber.clone()))
         .extension(MetricsExtension::new(
             config.config.query_log_threshold_time,
+            config.config.metrics,
         ))
         .data(config)
         .data(combined_read_database)
@@ -308,6 +332,7 @@ where
         .data(consensus_module)
         .data(p2p_service)
         .data(gas_price_provider)
+        .data(gas_price_history_provider)
         .data(chain_state_info_provider)
         .data(memory_pool)
         .data(da_compression_provider)
@@ -315,6 +340,7 @@ where
         .extension(ValidationExtension::new(
             max_queries_resolver_recursive_depth,
         ))
+        .extension(RateLimitExtension::new(rate_limiter.clone()))
         .extension(async_graphql::extensions::Tracing)
         .extension(RequiredFuelBlockHeightExtension::new(
             required_fuel_block_height_tolerance,
@@ -345,6 +371,7 @@ where
         .route("/v1/health", get(health))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(rate_limiter))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -419,26 +446,103 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Attaches the bearer token from the `Authorization` header to the request,
+/// so the admin API can verify it.
+fn with_authorization(request: Request, headers: &HeaderMap) -> Request {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token) => request.data(AuthorizationToken(token.trim().to_string())),
+        None => request,
+    }
+}
+
+/// Identifies the client by the API key from the [`API_KEY_HEADER`] header
+/// or by the IP address of the connection.
+fn identify_client(
+    rate_limiter: &RateLimiter,
+    headers: &HeaderMap,
+    remote_addr: SocketAddr,
+) -> Client {
+    let api_key = headers
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    rate_limiter.identify(api_key, remote_addr.ip())
+}
+
+/// Rejects the request of the client without tokens left before executing it.
+fn check_rate_limit(
+    rate_limiter: &RateLimiter,
+    client: &Client,
+) -> Result<(), HttpResponse> {
+    rate_limiter.check(client).map_err(|retry_after| {
+        let error = rate_limit_error(retry_after);
+        let response = unify_response(Response::from_errors(vec![error]));
+        into_http_response(response)
+    })
+}
+
+/// Converts the GraphQL response into the HTTP response.
+/// Throttled requests are answered with `429 Too Many Requests`
+/// and the `Retry-After` header.
+fn into_http_response(response: Response) -> HttpResponse {
+    let retry_after = match response.extensions.get(RATE_LIMIT_RETRY_AFTER) {
+        Some(Value::Number(seconds)) => seconds.as_u64(),
+        _ => None,
+    };
+
+    match retry_after {
+        Some(retry_after) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_after.to_string())],
+            Json(response),
+        )
+            .into_response(),
+        None => Json(response).into_response(),
+    }
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    rate_limiter: Extension<RateLimiter>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Json<Response> {
-    let response = schema.execute(req.0).await;
+) -> HttpResponse {
+    let client = identify_client(&rate_limiter, &headers, remote_addr);
+    if let Err(response) = check_rate_limit(&rate_limiter, &client) {
+        return response;
+    }
+
+    let request = with_authorization(req.0, &headers).data(client);
+    let response = schema.execute(request).await;
     let response = unify_response(response);
 
-    response.into()
+    into_http_response(response)
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    rate_limiter: Extension<RateLimiter>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
-    let stream = schema.execute_stream(req.0).map(|response| {
+) -> Result<Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>>, HttpResponse>
+{
+    let client = identify_client(&rate_limiter, &headers, remote_addr);
+    check_rate_limit(&rate_limiter, &client)?;
+
+    let request = with_authorization(req.0, &headers).data(client);
+    let stream = schema.execute_stream(request).map(|response| {
         let response = unify_response(response);
         Event::default().json_data(response)
     });
-    Sse::new(stream)
-        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
+    Ok(Sse::new(stream)
+        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text")))
 }
 
 async fn ok() -> anyhow::Result<(), ()> {
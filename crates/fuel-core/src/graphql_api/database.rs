@@ -3,11 +3,16 @@ use crate::{
     fuel_core_graphql_api::{
         database::arc_wrapper::ArcWrapper,
         ports::{
+            ContractStorageProof,
             OffChainDatabase,
             OffChainDatabaseAt,
             OnChainDatabase,
             OnChainDatabaseAt,
         },
+        storage::logs::{
+            IndexedLog,
+            LogKey,
+        },
     },
 };
 use fuel_core_services::yield_stream::StreamYieldExt;
@@ -173,6 +178,16 @@ impl ReadDatabase {
                         indexation_flags.insert(kind);
                     }
                 }
+                IndexationKind::TransactionsByContract => {
+                    if off_chain.transactions_by_contract_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::Logs => {
+                    if off_chain.logs_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
             }
         }
         Ok(Self {
@@ -469,6 +484,29 @@ impl ReadView {
         )
     }
 
+    pub fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, TxId)>> + '_ {
+        futures::stream::iter(self.off_chain.contract_transactions_ids(
+            contract_id,
+            start,
+            direction,
+        ))
+    }
+
+    pub fn contract_logs(
+        &self,
+        contract_id: ContractId,
+        log_id: Option<u64>,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(LogKey, IndexedLog)>> {
+        self.off_chain.logs(contract_id, log_id, start, direction)
+    }
+
     pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
@@ -517,6 +555,22 @@ impl ReadViewAt {
         futures::stream::iter(self.on_chain.contract_balance_values(contract_id, assets))
             .yield_each(self.batch_size)
     }
+
+    pub fn contract_slot_proof(
+        &self,
+        contract_id: ContractId,
+        storage_slot: Bytes32,
+    ) -> StorageResult<ContractStorageProof> {
+        self.on_chain.contract_slot_proof(contract_id, storage_slot)
+    }
+
+    pub fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<ContractStorageProof> {
+        self.on_chain.contract_balance_proof(contract_id, asset)
+    }
 }
 
 #[test]
@@ -4,12 +4,14 @@ use crate::fuel_core_graphql_api::extensions::{
         CURRENT_FUEL_BLOCK_HEIGHT,
         CURRENT_STF_VERSION,
     },
+    rate_limit::RATE_LIMIT_RETRY_AFTER,
     required_fuel_block_height::FUEL_BLOCK_HEIGHT_PRECONDITION_FAILED,
 };
 use async_graphql::Response;
 
 pub(crate) mod chain_state_info;
 pub(crate) mod metrics;
+pub(crate) mod rate_limit;
 pub(crate) mod required_fuel_block_height;
 pub(crate) mod validation;
 
@@ -29,6 +31,11 @@ pub fn unify_response(mut response: Response) -> Response {
                     value.clone(),
                 );
             }
+            if let Some(value) = extensions.get(RATE_LIMIT_RETRY_AFTER) {
+                response
+                    .extensions
+                    .insert(RATE_LIMIT_RETRY_AFTER.to_string(), value.clone());
+            }
             if let Some(value) = extensions.get(CURRENT_STF_VERSION) {
                 response
                     .extensions
@@ -1,4 +1,5 @@
 use async_graphql::{
+    Request,
     Response,
     ServerError,
     ServerResult,
@@ -10,14 +11,23 @@ use async_graphql::{
         ExtensionContext,
         ExtensionFactory,
         NextParseQuery,
+        NextPrepareRequest,
         NextRequest,
         NextResolve,
+        NextSubscribe,
         NextValidation,
         ResolveInfo,
     },
-    parser::types::ExecutableDocument,
+    parser::types::{
+        ExecutableDocument,
+        Selection,
+    },
 };
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use futures::{
+    StreamExt,
+    stream::BoxStream,
+};
 use std::{
     sync::{
         Arc,
@@ -27,13 +37,19 @@ use std::{
 };
 use tokio::time::Instant;
 
+/// Logs the slow queries and, if the `metrics` are enabled,
+/// records the metrics of the queries.
 pub(crate) struct MetricsExtension {
     log_threshold_ms: Duration,
+    metrics: bool,
 }
 
 impl MetricsExtension {
-    pub fn new(log_threshold_ms: Duration) -> Self {
-        MetricsExtension { log_threshold_ms }
+    pub fn new(log_threshold_ms: Duration, metrics: bool) -> Self {
+        MetricsExtension {
+            log_threshold_ms,
+            metrics,
+        }
     }
 }
 
@@ -41,14 +57,23 @@ impl ExtensionFactory for MetricsExtension {
     fn create(&self) -> Arc<dyn Extension> {
         Arc::new(MetricsExtInner {
             log_threshold_ms: self.log_threshold_ms,
+            metrics: self.metrics,
+            operation_name: OnceLock::new(),
             current_query: OnceLock::new(),
+            top_level_fields: OnceLock::new(),
+            validated_fields: Default::default(),
         })
     }
 }
 
 pub(crate) struct MetricsExtInner {
     log_threshold_ms: Duration,
+    metrics: bool,
+    operation_name: OnceLock<Option<String>>,
     current_query: OnceLock<String>,
+    top_level_fields: OnceLock<Vec<String>>,
+    /// The top-level fields of the operation, set once the operation passed the validation.
+    validated_fields: Arc<OnceLock<Vec<String>>>,
 }
 
 #[async_trait::async_trait]
@@ -58,6 +83,10 @@ impl Extension for MetricsExtInner {
         ctx: &ExtensionContext<'_>,
         next: NextRequest<'_>,
     ) -> Response {
+        if !self.metrics {
+            return next.run(ctx).await
+        }
+
         let start_time = Instant::now();
         let result = next.run(ctx).await;
         let seconds = start_time.elapsed().as_secs_f64();
@@ -66,6 +95,16 @@ impl Extension for MetricsExtInner {
         result
     }
 
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let _ = self.operation_name.set(request.operation_name.clone());
+        next.run(ctx, request).await
+    }
+
     async fn parse_query(
         &self,
         ctx: &ExtensionContext<'_>,
@@ -73,11 +112,19 @@ impl Extension for MetricsExtInner {
         variables: &Variables,
         next: NextParseQuery<'_>,
     ) -> ServerResult<ExecutableDocument> {
-        let doc = next.run(ctx, query, variables).await?;
+        let doc = next.run(ctx, query, variables).await.inspect_err(|err| {
+            if self.metrics {
+                record_rejection(std::slice::from_ref(err))
+            }
+        })?;
         let set_query_res = self.current_query.set(query.to_string());
         if set_query_res.is_err() {
             tracing::warn!("Failed to save current query {query:?}");
         }
+        let operation_name = self.operation_name.get().and_then(Option::as_deref);
+        let _ = self
+            .top_level_fields
+            .set(top_level_fields(&doc, operation_name));
         Ok(doc)
     }
 
@@ -101,7 +148,7 @@ impl Extension for MetricsExtInner {
         let res = next.run(ctx, info).await;
         let elapsed = start_time.elapsed();
 
-        if let Some(field_name) = field_name {
+        if let Some(field_name) = field_name.filter(|_| self.metrics) {
             graphql_metrics().graphql_observe(field_name, elapsed.as_secs_f64());
         }
 
@@ -127,8 +174,292 @@ impl Extension for MetricsExtInner {
         ctx: &ExtensionContext<'_>,
         next: NextValidation<'_>,
     ) -> Result<ValidationResult, Vec<ServerError>> {
-        let result = next.run(ctx).await?;
-        graphql_metrics().graphql_complexity_observe(result.complexity as f64);
+        if !self.metrics {
+            return next.run(ctx).await
+        }
+
+        let result = next
+            .run(ctx)
+            .await
+            .inspect_err(|errors| record_rejection(errors))?;
+        let complexity = result.complexity as f64;
+        graphql_metrics().graphql_complexity_observe(complexity);
+
+        // The complexity is calculated for the whole document,
+        // so it is divided between the top-level fields of the executed operation.
+        let fields = self
+            .top_level_fields
+            .get()
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        let field_complexity = complexity / fields.len().max(1) as f64;
+        for field in fields {
+            graphql_metrics().graphql_field_complexity_observe(field, field_complexity);
+        }
+        let _ = self.validated_fields.set(fields.to_vec());
         Ok(result)
     }
+
+    fn subscribe<'s>(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        stream: BoxStream<'s, Response>,
+        next: NextSubscribe<'_>,
+    ) -> BoxStream<'s, Response> {
+        if !self.metrics {
+            return next.run(ctx, stream)
+        }
+
+        let mut stream = next.run(ctx, stream);
+        let validated_fields = self.validated_fields.clone();
+        let mut active_subscription = None;
+
+        futures::stream::poll_fn(move |cx| {
+            let poll = stream.poll_next_unpin(cx);
+            // The query is validated during the first poll of the stream.
+            // The rejected subscriptions are not counted.
+            if active_subscription.is_none() {
+                if let Some(fields) = validated_fields.get() {
+                    active_subscription = Some(ActiveSubscription::new(fields.clone()));
+                }
+            }
+            poll
+        })
+        .boxed()
+    }
+}
+
+/// Counts the subscription as active until its stream is dropped.
+struct ActiveSubscription {
+    fields: Vec<String>,
+}
+
+impl ActiveSubscription {
+    fn new(fields: Vec<String>) -> Self {
+        for field in &fields {
+            graphql_metrics().graphql_subscription_started(field);
+        }
+        Self { fields }
+    }
+}
+
+impl Drop for ActiveSubscription {
+    fn drop(&mut self) {
+        for field in &self.fields {
+            graphql_metrics().graphql_subscription_finished(field);
+        }
+    }
+}
+
+/// Returns the top-level fields of the operation executed by the request.
+fn top_level_fields(
+    doc: &ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Vec<String> {
+    doc.operations
+        .iter()
+        .find(|(name, _)| match operation_name {
+            Some(operation_name) => {
+                name.is_some_and(|name| name.as_str() == operation_name)
+            }
+            None => true,
+        })
+        .into_iter()
+        .flat_map(|(_, operation)| operation.node.selection_set.node.items.iter())
+        .filter_map(|selection| match &selection.node {
+            Selection::Field(field) => Some(field.node.name.node.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn record_rejection(errors: &[ServerError]) {
+    let mut reasons = errors
+        .iter()
+        .map(|error| rejection_reason(&error.message))
+        .collect::<Vec<_>>();
+    reasons.sort_unstable();
+    reasons.dedup();
+
+    for reason in reasons {
+        graphql_metrics().graphql_rejected_query(reason);
+    }
+}
+
+/// Classifies the error of the query by the limit it violated.
+fn rejection_reason(message: &str) -> &'static str {
+    if message.starts_with("Query is too complex") {
+        "complexity"
+    } else if message.starts_with("Query is nested too deep") {
+        "depth"
+    } else if message.starts_with("The recursion depth of the query")
+        || message.starts_with("Recursion detected")
+    {
+        "recursion"
+    } else if message.starts_with("The number of directives") {
+        "directives"
+    } else {
+        "invalid"
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use async_graphql::{
+        EmptyMutation,
+        Object,
+        Schema,
+        Subscription,
+    };
+    use futures::{
+        FutureExt,
+        Stream,
+    };
+    use test_case::test_case;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn metrics_disabled_field(&self) -> u32 {
+            1
+        }
+
+        async fn metrics_first_field(&self) -> u32 {
+            1
+        }
+
+        async fn metrics_second_field(&self) -> u32 {
+            1
+        }
+
+        async fn metrics_skipped_field(&self) -> u32 {
+            1
+        }
+    }
+
+    struct TestSubscription;
+
+    #[Subscription]
+    impl TestSubscription {
+        async fn metrics_subscription(&self) -> impl Stream<Item = u32> {
+            futures::stream::pending()
+        }
+
+        async fn metrics_rejected_subscription(&self) -> impl Stream<Item = u32> {
+            futures::stream::pending()
+        }
+    }
+
+    fn schema(metrics: bool) -> Schema<Query, EmptyMutation, TestSubscription> {
+        Schema::build(Query, EmptyMutation, TestSubscription)
+            .extension(MetricsExtension::new(Duration::from_secs(60), metrics))
+            .finish()
+    }
+
+    /// Returns the value of the metric from the global registry.
+    fn metric(name: &str) -> Option<f64> {
+        fuel_core_metrics::encode_metrics()
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.trim().parse().ok())
+    }
+
+    #[tokio::test]
+    async fn validation__does_not_record_metrics_if_disabled() {
+        // Given
+        let schema = schema(false);
+
+        // When
+        let response = schema.execute("{ metricsDisabledField }").await;
+
+        // Then
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            metric(r#"graphql_field_complexity_count{field="metricsDisabledField"}"#),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn validation__divides_complexity_of_executed_operation_between_fields() {
+        // Given
+        let schema = schema(true);
+        let query = r#"
+            query Executed { metricsFirstField metricsSecondField }
+            query Skipped { metricsSkippedField }
+        "#;
+
+        // When
+        let response = schema
+            .execute(Request::new(query).operation_name("Executed"))
+            .await;
+
+        // Then
+        // The complexity of the document includes the field of the skipped operation.
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            metric(r#"graphql_field_complexity_sum{field="metricsFirstField"}"#),
+            Some(1.5)
+        );
+        assert_eq!(
+            metric(r#"graphql_field_complexity_sum{field="metricsSecondField"}"#),
+            Some(1.5)
+        );
+        assert_eq!(
+            metric(r#"graphql_field_complexity_count{field="metricsSkippedField"}"#),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe__tracks_active_subscription_until_dropped() {
+        // Given
+        let schema = schema(true);
+        let gauge = r#"graphql_active_subscriptions{field="metricsSubscription"}"#;
+        let mut stream = schema.execute_stream("subscription { metricsSubscription }");
+
+        // When
+        assert!(stream.next().now_or_never().is_none());
+        let active = metric(gauge);
+        drop(stream);
+        let finished = metric(gauge);
+
+        // Then
+        assert_eq!(active, Some(1.0));
+        assert_eq!(finished, Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn subscribe__does_not_track_subscription_rejected_by_validation() {
+        // Given
+        let schema = schema(true);
+        let gauge =
+            r#"graphql_active_subscriptions{field="metricsRejectedSubscription"}"#;
+        let mut stream = schema
+            .execute_stream("subscription { metricsRejectedSubscription unknownField }");
+
+        // When
+        let response = stream.next().now_or_never().flatten();
+        let active = metric(gauge);
+
+        // Then
+        assert!(response.is_some_and(|response| response.is_err()));
+        assert_eq!(active, None);
+    }
+
+    #[test_case("Query is too complex.", "complexity"; "complexity")]
+    #[test_case("Query is nested too deep.", "depth"; "depth")]
+    #[test_case("The recursion depth of the query cannot be greater than `16`", "recursion"; "recursion depth")]
+    #[test_case("Recursion detected for field `coins`", "recursion"; "resolver recursion")]
+    #[test_case("Unknown field \"foo\" on type \"Query\".", "invalid"; "unknown field")]
+    fn rejection_reason__classifies_limit_errors(message: &str, expected: &str) {
+        // When
+        let reason = rejection_reason(message);
+
+        // Then
+        assert_eq!(reason, expected);
+    }
 }
@@ -0,0 +1,82 @@
+use crate::fuel_core_graphql_api::rate_limit::{
+    Client,
+    RateLimiter,
+    retry_after_seconds,
+};
+use async_graphql::{
+    ServerError,
+    ValidationResult,
+    Value,
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+/// The number of seconds after which the throttled client can retry the request.
+pub(crate) const RATE_LIMIT_RETRY_AFTER: &str = "rate_limit_retry_after";
+
+/// The extension takes the complexity of the query from the token bucket of the client.
+/// The client is set in the request data by the graphql handler as a value of type
+/// `Client`. Requests without the client are not limited.
+pub(crate) struct RateLimitExtension {
+    rate_limiter: RateLimiter,
+}
+
+impl RateLimitExtension {
+    pub fn new(rate_limiter: RateLimiter) -> Self {
+        Self { rate_limiter }
+    }
+}
+
+impl ExtensionFactory for RateLimitExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RateLimitInner {
+            rate_limiter: self.rate_limiter.clone(),
+        })
+    }
+}
+
+struct RateLimitInner {
+    rate_limiter: RateLimiter,
+}
+
+#[async_trait::async_trait]
+impl Extension for RateLimitInner {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        if let Some(client) = ctx.data_opt::<Client>() {
+            if let Err(retry_after) = self.rate_limiter.take(client, result.complexity) {
+                return Err(vec![error_response(retry_after)]);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub(crate) fn error_response(retry_after: Duration) -> ServerError {
+    let retry_after = retry_after_seconds(retry_after);
+    let mut error = ServerError::new(
+        format!("Rate limit exceeded. Retry after {retry_after} seconds"),
+        None,
+    );
+
+    error
+        .extensions
+        .get_or_insert_with(Default::default)
+        .set(RATE_LIMIT_RETRY_AFTER, Value::from(retry_after));
+
+    error
+}
@@ -1,9 +1,14 @@
 use super::storage::{
     assets::AssetDetails,
     balances::TotalBalanceAmount,
+    logs::{
+        IndexedLog,
+        LogKey,
+    },
 };
 use crate::fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey;
 use async_trait::async_trait;
+use fuel_core_gas_price_service::common::updater_metadata::UpdaterMetadata;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     Error as StorageError,
@@ -25,7 +30,13 @@ use fuel_core_storage::{
     },
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    config::{
+        BlackList,
+        BlackListUpdate,
+    },
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -46,6 +57,7 @@ use fuel_core_types::{
         },
         transaction::RelayedTransactionStatus,
     },
+    fuel_merkle::sparse::proof::Proof,
     fuel_tx::{
         Bytes32,
         ConsensusParameters,
@@ -65,11 +77,16 @@ use fuel_core_types::{
     fuel_vm::interpreter::Memory,
     services::{
         executor::{
+            DryRunOverrides,
             DryRunResult,
             StorageReadReplayEvent,
         },
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            BannedPeer,
+            PeerId,
+            PeerInfo,
+        },
         transaction_status::{
             self,
             TransactionStatus,
@@ -77,7 +94,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub struct CoinsToSpendIndexIter<'a> {
     pub big_coins_iter: BoxedIter<'a, Result<CoinsToSpendIndexKey, StorageError>>,
@@ -128,6 +148,23 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Iterates over the logs emitted by the contract.
+    /// If `log_id` is specified, only the logs with this id are returned.
+    fn logs(
+        &self,
+        contract_id: ContractId,
+        log_id: Option<u64>,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(LogKey, IndexedLog)>>;
+
     fn coins_to_spend_index(
         &self,
         owner: &Address,
@@ -253,6 +290,10 @@ pub trait TxPoolPort: Send + Sync {
     async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
 
     fn latest_pool_stats(&self) -> TxPoolStats;
+
+    fn blacklist(&self) -> BlackList;
+
+    async fn update_blacklist(&self, update: BlackListUpdate) -> anyhow::Result<()>;
 }
 
 #[async_trait]
@@ -271,10 +312,10 @@ pub trait BlockProducerPort: Send + Sync {
         &self,
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
-        time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        overrides: DryRunOverrides,
     ) -> anyhow::Result<DryRunResult>;
 
     async fn storage_read_replay(
@@ -306,6 +347,19 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeer>>;
+
+    /// Bans the peer for the `duration`, or permanently if the `duration` is not set.
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    /// Removes the ban of the peer. Returns `false` if the peer was not banned.
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool>;
 }
 
 /// Trait for defining how to estimate gas price for future blocks
@@ -314,6 +368,16 @@ pub trait GasPriceEstimate: Send + Sync {
     fn worst_case_gas_price(&self, height: BlockHeight) -> Option<u64>;
 }
 
+/// Trait for getting the historical state of the gas price algorithm.
+pub trait GasPriceHistory: Send + Sync {
+    /// Returns the state of the gas price algorithm after processing
+    /// the L2 block at the given height, if it is known.
+    fn gas_price_metadata(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<UpdaterMetadata>>;
+}
+
 /// Trait for getting VM memory.
 #[async_trait::async_trait]
 pub trait MemoryPool {
@@ -341,6 +405,7 @@ pub mod worker {
                 MessageBalances,
             },
             coins::CoinsToSpendIndex,
+            logs::Logs,
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
@@ -360,6 +425,7 @@ pub mod worker {
         fuel_tx::{
             Address,
             Bytes32,
+            ContractId,
         },
         fuel_types::BlockHeight,
         services::{
@@ -395,6 +461,12 @@ pub mod worker {
 
         /// Checks if AssetMetadata indexation functionality is available.
         fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if TransactionsByContract indexation functionality is available.
+        fn transactions_by_contract_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if Logs indexation functionality is available.
+        fn logs_indexation_enabled(&self) -> StorageResult<bool>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -420,6 +492,7 @@ pub mod worker {
         + StorageMutate<MessageBalances, Error = StorageError>
         + StorageMutate<CoinsToSpendIndex, Error = StorageError>
         + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<Logs, Error = StorageError>
     {
         fn record_tx_id_owner(
             &mut self,
@@ -429,6 +502,14 @@ pub mod worker {
             tx_id: &Bytes32,
         ) -> StorageResult<()>;
 
+        fn record_tx_id_contract(
+            &mut self,
+            contract_id: &ContractId,
+            block_height: BlockHeight,
+            tx_idx: u16,
+            tx_id: &Bytes32,
+        ) -> StorageResult<()>;
+
         fn update_tx_status(
             &mut self,
             id: &Bytes32,
@@ -498,6 +579,30 @@ pub trait OnChainDatabaseAt: Send + Sync {
         contract_id: ContractId,
         assets: Vec<AssetId>,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the sparse merkle proof of the storage slot of the contract.
+    fn contract_slot_proof(
+        &self,
+        contract_id: ContractId,
+        storage_slot: Bytes32,
+    ) -> StorageResult<ContractStorageProof>;
+
+    /// Returns the sparse merkle proof of the balance of the contract.
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<ContractStorageProof>;
+}
+
+/// The proof of the leaf in the sparse merkle tree of the contract storage or balances.
+pub struct ContractStorageProof {
+    /// The root of the sparse merkle tree.
+    pub root: Bytes32,
+    /// The encoded value of the leaf. `None` if the leaf doesn't exist.
+    pub value: Option<Vec<u8>>,
+    /// The inclusion proof if the leaf exists, otherwise the exclusion proof.
+    pub proof: Proof,
 }
 
 pub trait OffChainDatabaseAt: Send + Sync {}
@@ -0,0 +1,407 @@
+//! Per-client rate limiting of the GraphQL API.
+//!
+//! Each client has a token bucket. Clients are identified by the API key passed in
+//! the [`API_KEY_HEADER`] header, or by their IP address if the key is absent or unknown.
+//! Every query takes from the bucket as many tokens as its complexity, calculated
+//! from the query [`Costs`](super::Costs).
+
+use anyhow::Context;
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    net::IpAddr,
+    path::Path,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The header used by clients to pass their API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The number of tracked clients after which the clients with full buckets are forgotten.
+const MAX_TRACKED_CLIENTS: usize = 100_000;
+
+/// The quota of the token bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Quota {
+    /// The max number of tokens in the bucket. It limits the burst of requests.
+    pub capacity: u64,
+    /// The number of tokens added to the bucket every second.
+    pub refill_per_second: u64,
+}
+
+/// The API key with its own quota.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApiKey {
+    /// The name of the key owner, used in logs and metrics instead of the key itself.
+    pub name: String,
+    /// The secret value passed by the client in the [`API_KEY_HEADER`] header.
+    pub key: String,
+    #[serde(flatten)]
+    pub quota: Quota,
+}
+
+/// The configuration of the rate limits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RateLimitConfig {
+    /// The quota of each IP address that doesn't use an API key.
+    /// Requests without an API key are not limited if it is not set.
+    #[serde(default)]
+    pub per_ip: Option<Quota>,
+    /// The API keys and their quotas.
+    #[serde(default)]
+    pub api_keys: Vec<ApiKey>,
+}
+
+impl RateLimitConfig {
+    /// Reads the configuration from the JSON file.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).with_context(|| {
+            format!("Failed to open the rate limit config {}", path.display())
+        })?;
+        let config: Self = serde_json::from_reader(std::io::BufReader::new(file))
+            .with_context(|| {
+                format!("Failed to parse the rate limit config {}", path.display())
+            })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        let quotas = self
+            .per_ip
+            .iter()
+            .chain(self.api_keys.iter().map(|api_key| &api_key.quota));
+        for quota in quotas {
+            if quota.capacity == 0 || quota.refill_per_second == 0 {
+                anyhow::bail!(
+                    "The capacity and the refill rate of the quota should be positive"
+                );
+            }
+        }
+
+        let mut names = HashSet::new();
+        let mut keys = HashSet::new();
+        for api_key in &self.api_keys {
+            if !names.insert(api_key.name.as_str()) {
+                anyhow::bail!("The name of the API key {} is not unique", api_key.name);
+            }
+            if !keys.insert(api_key.key.as_str()) {
+                anyhow::bail!("The API key of {} is not unique", api_key.name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The client of the GraphQL API, identified by its API key or IP address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Client {
+    ApiKey(String),
+    Ip(IpAddr),
+}
+
+impl Client {
+    fn label(&self) -> &str {
+        match self {
+            Client::ApiKey(name) => name.as_str(),
+            Client::Ip(_) => "ip",
+        }
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(quota: &Quota, now: Instant) -> Self {
+        Self {
+            tokens: quota.capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, quota: &Quota, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * quota.refill_per_second as f64)
+            .min(quota.capacity as f64);
+        self.updated_at = now;
+    }
+
+    fn is_full(&self, quota: &Quota, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens + elapsed * quota.refill_per_second as f64 >= quota.capacity as f64
+    }
+
+    /// Takes `cost` tokens from the bucket if `consume` is `true`. Returns the time
+    /// after which the tokens will be available if the bucket doesn't have enough of them.
+    ///
+    /// The cost is capped by the capacity, so any query can be executed
+    /// once the bucket is full.
+    fn try_take(
+        &mut self,
+        quota: &Quota,
+        cost: f64,
+        consume: bool,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        self.refill(quota, now);
+        let cost = cost.min(quota.capacity as f64);
+
+        if self.tokens >= cost {
+            if consume {
+                self.tokens -= cost;
+            }
+            Ok(())
+        } else {
+            let missing = cost - self.tokens;
+            let seconds = missing / quota.refill_per_second as f64;
+            Err(Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX))
+        }
+    }
+}
+
+struct Inner {
+    config: RateLimitConfig,
+    buckets: HashMap<Client, TokenBucket>,
+}
+
+impl Inner {
+    fn try_take(
+        &mut self,
+        client: &Client,
+        cost: f64,
+        consume: bool,
+    ) -> Result<(), Duration> {
+        let Some(quota) = client_quota(&self.config, client) else {
+            return Ok(())
+        };
+        let now = Instant::now();
+
+        if self.buckets.len() >= MAX_TRACKED_CLIENTS {
+            let config = &self.config;
+            self.buckets.retain(|client, bucket| {
+                client_quota(config, client)
+                    .is_some_and(|quota| !bucket.is_full(&quota, now))
+            });
+        }
+
+        self.buckets
+            .entry(client.clone())
+            .or_insert_with(|| TokenBucket::new(&quota, now))
+            .try_take(&quota, cost, consume, now)
+    }
+}
+
+fn client_quota(config: &RateLimitConfig, client: &Client) -> Option<Quota> {
+    match client {
+        Client::ApiKey(name) => config
+            .api_keys
+            .iter()
+            .find(|api_key| &api_key.name == name)
+            .map(|api_key| api_key.quota),
+        Client::Ip(_) => config.per_ip,
+    }
+}
+
+/// The token bucket rate limiter shared by the GraphQL handlers and the
+/// [`RateLimitExtension`](super::extensions::rate_limit::RateLimitExtension).
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    inner: Option<Arc<Mutex<Inner>>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: Option<RateLimitConfig>) -> Self {
+        let inner = config.map(|config| {
+            Arc::new(Mutex::new(Inner {
+                config,
+                buckets: HashMap::new(),
+            }))
+        });
+        Self { inner }
+    }
+
+    /// Identifies the client by the API key or, if the key is unknown, by the IP address.
+    pub fn identify(&self, api_key: Option<&str>, ip: IpAddr) -> Client {
+        let name = self.inner.as_ref().and_then(|inner| {
+            let inner = inner.lock();
+            api_key.and_then(|api_key| {
+                inner
+                    .config
+                    .api_keys
+                    .iter()
+                    .find(|known| known.key == api_key)
+                    .map(|known| known.name.clone())
+            })
+        });
+
+        match name {
+            Some(name) => Client::ApiKey(name),
+            None => {
+                if api_key.is_some() {
+                    tracing::debug!("Unknown API key is used by {ip}");
+                }
+                Client::Ip(ip)
+            }
+        }
+    }
+
+    /// Checks that the client has at least one token left without taking it.
+    /// It allows rejecting requests of throttled clients before executing them.
+    pub fn check(&self, client: &Client) -> Result<(), Duration> {
+        self.try_take(client, 1.0, false)
+    }
+
+    /// Takes `cost` tokens from the bucket of the client.
+    /// Returns the time after which the request can be retried on failure.
+    pub fn take(&self, client: &Client, cost: usize) -> Result<(), Duration> {
+        self.try_take(client, cost as f64, true)
+    }
+
+    fn try_take(
+        &self,
+        client: &Client,
+        cost: f64,
+        consume: bool,
+    ) -> Result<(), Duration> {
+        let Some(inner) = &self.inner else {
+            return Ok(())
+        };
+
+        let result = inner.lock().try_take(client, cost, consume);
+        if result.is_err() {
+            graphql_metrics().graphql_throttled_request(client.label());
+        }
+        result
+    }
+}
+
+/// Converts the retry duration into the value of the `Retry-After` header in seconds.
+pub(crate) fn retry_after_seconds(retry_after: Duration) -> u64 {
+    let seconds = retry_after.as_secs();
+    if retry_after.subsec_nanos() > 0 {
+        seconds.saturating_add(1)
+    } else {
+        seconds
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const QUOTA: Quota = Quota {
+        capacity: 100,
+        refill_per_second: 10,
+    };
+
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(Some(RateLimitConfig {
+            per_ip: Some(QUOTA),
+            api_keys: vec![ApiKey {
+                name: "partner".to_string(),
+                key: "secret".to_string(),
+                quota: Quota {
+                    capacity: 1_000,
+                    refill_per_second: 100,
+                },
+            }],
+        }))
+    }
+
+    fn ip(last: u8) -> IpAddr {
+        Ipv4Addr::new(10, 0, 0, last).into()
+    }
+
+    #[test]
+    fn take__succeeds_until_the_bucket_is_empty() {
+        // Given
+        let limiter = limiter();
+        let client = limiter.identify(None, ip(1));
+        limiter.take(&client, 60).unwrap();
+
+        // When
+        let result = limiter.take(&client, 60);
+
+        // Then
+        let retry_after = result.unwrap_err();
+        assert!(retry_after > Duration::from_secs(1));
+        assert!(retry_after <= Duration::from_secs(2));
+        assert!(limiter.check(&limiter.identify(None, ip(2))).is_ok());
+    }
+
+    #[test]
+    fn take__cost_is_capped_by_the_capacity() {
+        // Given
+        let limiter = limiter();
+        let client = limiter.identify(None, ip(1));
+
+        // When
+        let result = limiter.take(&client, 1_000_000);
+
+        // Then
+        assert!(result.is_ok());
+        assert!(limiter.check(&client).is_err());
+    }
+
+    #[test]
+    fn identify__known_api_key_uses_its_own_quota() {
+        // Given
+        let limiter = limiter();
+
+        // When
+        let client = limiter.identify(Some("secret"), ip(1));
+
+        // Then
+        assert_eq!(client, Client::ApiKey("partner".to_string()));
+        assert!(limiter.take(&client, 500).is_ok());
+        assert!(limiter.take(&client, 500).is_ok());
+        assert!(limiter.check(&limiter.identify(None, ip(1))).is_ok());
+    }
+
+    #[test]
+    fn identify__unknown_api_key_falls_back_to_ip() {
+        // Given
+        let limiter = limiter();
+
+        // When
+        let client = limiter.identify(Some("unknown"), ip(1));
+
+        // Then
+        assert_eq!(client, Client::Ip(ip(1)));
+    }
+
+    #[test]
+    fn take__disabled_limiter_never_throttles() {
+        // Given
+        let limiter = RateLimiter::new(None);
+        let client = limiter.identify(None, ip(1));
+
+        // When
+        let result = limiter.take(&client, usize::MAX);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn retry_after_seconds__rounds_up() {
+        assert_eq!(retry_after_seconds(Duration::from_millis(1)), 1);
+        assert_eq!(retry_after_seconds(Duration::from_secs(2)), 2);
+        assert_eq!(retry_after_seconds(Duration::from_millis(2_500)), 3);
+    }
+}
@@ -4,6 +4,8 @@ use crate::{
         coins::OwnedCoins,
         messages::OwnedMessageIds,
         transactions::{
+            ContractTransactionIndexKey,
+            ContractTransactions,
             OwnedTransactionIndexKey,
             OwnedTransactions,
             TransactionStatuses,
@@ -30,6 +32,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
     },
     fuel_types::BlockHeight,
     services::transaction_status::TransactionExecutionStatus,
@@ -41,6 +44,7 @@ pub mod balances;
 pub mod blocks;
 pub mod coins;
 pub mod contracts;
+pub mod logs;
 pub mod messages;
 pub mod old;
 pub mod statistic;
@@ -103,6 +107,11 @@ pub enum Column {
     AssetsInfo = 25,
     /// Index of the coins that are available to spend.
     CoinsToSpend = 26,
+    /// The column of the table of all transactions that interacted with the contract.
+    /// See [`ContractTransactions`](transactions::ContractTransactions)
+    TransactionsByContractBlockIdx = 27,
+    /// See [`Logs`](logs::Logs)
+    Logs = 28,
 }
 
 impl Column {
@@ -146,6 +155,19 @@ where
         )
     }
 
+    fn record_tx_id_contract(
+        &mut self,
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &Bytes32,
+    ) -> StorageResult<()> {
+        self.storage::<ContractTransactions>().insert(
+            &ContractTransactionIndexKey::new(contract_id, block_height, tx_idx),
+            tx_id,
+        )
+    }
+
     fn update_tx_status(
         &mut self,
         id: &Bytes32,
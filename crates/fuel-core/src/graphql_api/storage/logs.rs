@@ -0,0 +1,216 @@
+use fuel_core_storage::{
+    Mappable,
+    blueprint::plain::Plain,
+    codec::{
+        Decode,
+        Encode,
+        manual::Manual,
+        postcard::Postcard,
+    },
+    structured_storage::TableWithBlueprint,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+
+/// The index of the `Log` and `LogData` receipts by the contract that emitted them,
+/// the log id(the value of the `rb` register), and the position in the chain.
+pub struct Logs;
+
+impl Mappable for Logs {
+    type Key = LogKey;
+    type OwnedKey = Self::Key;
+    type Value = IndexedLog;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for Logs {
+    type Blueprint = Plain<Manual<LogKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Logs
+    }
+}
+
+const CONTRACT_ID_SIZE: usize = ContractId::LEN;
+const LOG_ID_SIZE: usize = size_of::<u64>();
+const POINTER_SIZE: usize =
+    size_of::<BlockHeight>() + size_of::<u16>() + size_of::<u16>();
+const KEY_SIZE: usize = CONTRACT_ID_SIZE + LOG_ID_SIZE + POINTER_SIZE;
+
+/// The position of the receipt in the chain.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct LogPointer {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u16,
+}
+
+impl LogPointer {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u16) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    /// The first pointer at the `block_height`.
+    pub fn first_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, 0, 0)
+    }
+
+    /// The last pointer at the `block_height`.
+    pub fn last_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, u16::MAX, u16::MAX)
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct LogKey {
+    pub contract_id: ContractId,
+    pub log_id: u64,
+    pub pointer: LogPointer,
+}
+
+impl LogKey {
+    pub fn new(contract_id: ContractId, log_id: u64, pointer: LogPointer) -> Self {
+        Self {
+            contract_id,
+            log_id,
+            pointer,
+        }
+    }
+
+    /// Returns the prefix of the keys of the contract and the log id, if specified.
+    pub fn prefix(contract_id: &ContractId, log_id: Option<u64>) -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(CONTRACT_ID_SIZE + LOG_ID_SIZE);
+        prefix.extend_from_slice(contract_id.as_ref());
+        if let Some(log_id) = log_id {
+            prefix.extend_from_slice(&log_id.to_be_bytes());
+        }
+        prefix
+    }
+}
+
+impl Encode<LogKey> for Manual<LogKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(key: &LogKey) -> Self::Encoder<'_> {
+        // contract_id + log_id + block_height + tx_idx + receipt_idx
+        let mut bytes = [0u8; KEY_SIZE];
+        let (contract_id, rest) = bytes.split_at_mut(CONTRACT_ID_SIZE);
+        let (log_id, rest) = rest.split_at_mut(LOG_ID_SIZE);
+        let (block_height, rest) = rest.split_at_mut(size_of::<BlockHeight>());
+        let (tx_idx, receipt_idx) = rest.split_at_mut(size_of::<u16>());
+        contract_id.copy_from_slice(key.contract_id.as_ref());
+        log_id.copy_from_slice(&key.log_id.to_be_bytes());
+        block_height.copy_from_slice(&key.pointer.block_height.to_bytes());
+        tx_idx.copy_from_slice(&key.pointer.tx_idx.to_be_bytes());
+        receipt_idx.copy_from_slice(&key.pointer.receipt_idx.to_be_bytes());
+        bytes
+    }
+}
+
+impl Decode<LogKey> for Manual<LogKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<LogKey> {
+        let bytes: &[u8; KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (contract_id, rest) = bytes.split_at(CONTRACT_ID_SIZE);
+        let (log_id, rest) = rest.split_at(LOG_ID_SIZE);
+        let (block_height, rest) = rest.split_at(size_of::<BlockHeight>());
+        let (tx_idx, receipt_idx) = rest.split_at(size_of::<u16>());
+
+        Ok(LogKey {
+            contract_id: ContractId::try_from(contract_id)?,
+            log_id: u64::from_be_bytes(log_id.try_into()?),
+            pointer: LogPointer {
+                block_height: u32::from_be_bytes(block_height.try_into()?).into(),
+                tx_idx: u16::from_be_bytes(tx_idx.try_into()?),
+                receipt_idx: u16::from_be_bytes(receipt_idx.try_into()?),
+            },
+        })
+    }
+}
+
+/// The log receipt with the id of the transaction that emitted it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IndexedLog {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <Logs as Mappable>::Key {
+        LogKey::new(
+            ContractId::new(rng.r#gen()),
+            rng.r#gen(),
+            LogPointer::new(rng.r#gen::<u32>().into(), rng.r#gen(), rng.r#gen()),
+        )
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        Logs,
+        <Logs as Mappable>::Key::new(
+            ContractId::default(),
+            1,
+            LogPointer::new(2.into(), 3, 4)
+        ),
+        IndexedLog {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        IndexedLog {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        },
+        generate_key
+    );
+
+    #[test]
+    fn encoded_keys_are_sorted_by_contract_log_id_and_pointer() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let keys = [
+            LogKey::new(contract_id, 1, LogPointer::new(1.into(), 5, 5)),
+            LogKey::new(contract_id, 1, LogPointer::new(2.into(), 0, 0)),
+            LogKey::new(contract_id, 2, LogPointer::new(0.into(), 0, 0)),
+            LogKey::new(ContractId::from([2; 32]), 0, LogPointer::default()),
+        ];
+
+        // When
+        let encoded = keys
+            .iter()
+            .map(|key| Manual::<LogKey>::encode(key))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert!(encoded.is_sorted());
+        for (key, bytes) in keys.iter().zip(encoded.iter()) {
+            assert_eq!(&Manual::<LogKey>::decode(bytes).unwrap(), key);
+        }
+    }
+}
@@ -25,6 +25,12 @@ use crate::{
                 owner_coin_id_key,
             },
             contracts::ContractsInfo,
+            logs::{
+                IndexedLog,
+                LogKey,
+                LogPointer,
+                Logs,
+            },
             messages::{
                 OwnedMessageIds,
                 OwnedMessageKey,
@@ -143,6 +149,8 @@ pub struct Task<TxStatusManager, D> {
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
     asset_metadata_indexation_enabled: bool,
+    transactions_by_contract_indexation_enabled: bool,
+    logs_indexation_enabled: bool,
     base_asset_id: AssetId,
     block_height_subscription_handler: block_height_subscription::Handler,
 }
@@ -165,6 +173,16 @@ where
         // save the associated owner for each transaction in the block
         index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
 
+        // save the contracts that each transaction in the block interacted with
+        index_tx_contracts_for_block(
+            &result,
+            &mut transaction,
+            self.transactions_by_contract_indexation_enabled,
+        )?;
+
+        // save the logs emitted by the contracts in the block
+        index_logs_for_block(&result, &mut transaction, self.logs_indexation_enabled)?;
+
         // save the transaction related information
         process_transactions(block.transactions().iter(), &mut transaction)?;
 
@@ -343,35 +361,13 @@ where
 {
     for (tx_idx, tx) in block.transactions().iter().enumerate() {
         let block_height = *block.header().height();
-        let inputs;
-        let outputs;
         let tx_idx = u16::try_from(tx_idx).map_err(|e| {
             anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
         })?;
         let tx_id = tx.id(chain_id);
-        match tx {
-            Transaction::Script(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Create(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Mint(_) => continue,
-            Transaction::Upgrade(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Upload(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Blob(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-        }
+        let Some((inputs, outputs)) = inputs_and_outputs(tx) else {
+            continue
+        };
         persist_owners_index(
             block_height,
             inputs,
@@ -384,6 +380,137 @@ where
     Ok(())
 }
 
+/// Associate all transactions within a block to the contracts they interacted with.
+/// The contract is indexed if it is used as an input, created by the transaction,
+/// or called during the execution.
+fn index_tx_contracts_for_block<T>(
+    import_result: &ImportResult,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let block = &import_result.sealed_block.entity;
+    let block_height = *block.header().height();
+    let transactions = block.transactions().iter();
+    for (tx_idx, (tx, status)) in
+        transactions.zip(import_result.tx_status.iter()).enumerate()
+    {
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+        let Some((inputs, outputs)) = inputs_and_outputs(tx) else {
+            continue
+        };
+
+        let mut contracts = vec![];
+        for input in inputs {
+            if let Input::Contract(contract) = input {
+                contracts.push(contract.contract_id);
+            }
+        }
+        for output in outputs {
+            if let Output::ContractCreated { contract_id, .. } = output {
+                contracts.push(*contract_id);
+            }
+        }
+        for receipt in status.result.receipts() {
+            if let Receipt::Call { to, .. } = receipt {
+                contracts.push(*to);
+            }
+        }
+
+        contracts.sort();
+        contracts.dedup();
+
+        for contract_id in contracts.iter() {
+            block_st_transaction.record_tx_id_contract(
+                contract_id,
+                block_height,
+                tx_idx,
+                &status.id,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Index the `Log` and `LogData` receipts of all successful transactions within a block
+/// by the contract that emitted them and the log id(the value of the `rb` register).
+/// The logs of the failed transactions are skipped, since their effects are reverted.
+fn index_logs_for_block<T>(
+    import_result: &ImportResult,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for (tx_idx, status) in import_result.tx_status.iter().enumerate() {
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        for (receipt_idx, receipt) in receipts.iter().enumerate() {
+            let (contract_id, log_id) = match receipt {
+                Receipt::Log { id, rb, .. } | Receipt::LogData { id, rb, .. } => {
+                    (*id, *rb)
+                }
+                _ => continue,
+            };
+            let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+                anyhow::anyhow!(
+                    "The transaction has more than `u16::MAX` receipts, {}",
+                    e
+                )
+            })?;
+            let key = LogKey::new(
+                contract_id,
+                log_id,
+                LogPointer::new(block_height, tx_idx, receipt_idx),
+            );
+            let log = IndexedLog {
+                tx_id: status.id,
+                receipt: receipt.clone(),
+            };
+            block_st_transaction.storage::<Logs>().insert(&key, &log)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the inputs and outputs of the transaction, or `None` for the `Mint`.
+fn inputs_and_outputs(tx: &Transaction) -> Option<(&[Input], &[Output])> {
+    match tx {
+        Transaction::Script(tx) => {
+            Some((tx.inputs().as_slice(), tx.outputs().as_slice()))
+        }
+        Transaction::Create(tx) => {
+            Some((tx.inputs().as_slice(), tx.outputs().as_slice()))
+        }
+        Transaction::Mint(_) => None,
+        Transaction::Upgrade(tx) => {
+            Some((tx.inputs().as_slice(), tx.outputs().as_slice()))
+        }
+        Transaction::Upload(tx) => {
+            Some((tx.inputs().as_slice(), tx.outputs().as_slice()))
+        }
+        Transaction::Blob(tx) => Some((tx.inputs().as_slice(), tx.outputs().as_slice())),
+    }
+}
+
 /// Index the tx id by owner for all of the inputs and outputs
 fn persist_owners_index<T>(
     block_height: BlockHeight,
@@ -578,10 +705,17 @@ where
         let asset_metadata_indexation_enabled = self
             .off_chain_database
             .asset_metadata_indexation_enabled()?;
+        let transactions_by_contract_indexation_enabled = self
+            .off_chain_database
+            .transactions_by_contract_indexation_enabled()?;
+        let logs_indexation_enabled =
+            self.off_chain_database.logs_indexation_enabled()?;
         tracing::info!(
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            transactions_by_contract_indexation_enabled,
+            logs_indexation_enabled,
             "Indexation availability status"
         );
         tracing::debug!(
@@ -610,6 +744,8 @@ where
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            transactions_by_contract_indexation_enabled,
+            logs_indexation_enabled,
             base_asset_id,
             block_height_subscription_handler,
         };
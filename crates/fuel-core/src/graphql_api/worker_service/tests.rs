@@ -3,12 +3,29 @@
 use super::*;
 use crate::{
     database::Database,
-    graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
+    graphql_api::storage::{
+        logs::{
+            IndexedLog,
+            LogKey,
+            LogPointer,
+            Logs,
+        },
+        relayed_transactions::RelayedTransactionStatuses,
+        transactions::{
+            ContractTransactionIndexKey,
+            ContractTransactions,
+        },
+    },
 };
 use fuel_core_services::stream::IntoBoxStream;
 use fuel_core_storage::StorageAsRef;
 use fuel_core_types::{
-    fuel_tx::Bytes32,
+    blockchain::SealedBlock,
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+        TransactionBuilder,
+    },
     fuel_types::BlockHeight,
     services::transaction_status::TransactionStatus,
 };
@@ -59,6 +76,184 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[tokio::test]
+async fn run__transactions_are_indexed_by_contract() {
+    let database = Database::in_memory();
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let input_contract = ContractId::from([1; 32]);
+    let called_contract = ContractId::from([2; 32]);
+    let unrelated_contract = ContractId::from([3; 32]);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            input_contract,
+        ))
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    let receipts = vec![Receipt::call(
+        input_contract,
+        called_contract,
+        0,
+        Default::default(),
+        0,
+        0,
+        0,
+        0,
+        0,
+    )];
+    let mut sealed_block = SealedBlock::default();
+    sealed_block.entity.transactions_mut().push(tx);
+    let block = Arc::new(
+        ImportResult {
+            sealed_block,
+            tx_status: vec![TransactionExecutionStatus {
+                id: tx_id,
+                result: TransactionExecutionResult::Success {
+                    result: None,
+                    receipts,
+                    total_gas: 0,
+                    total_fee: 0,
+                },
+            }],
+            events: vec![],
+            source: Default::default(),
+        }
+        .wrap(),
+    );
+    let block_importer = tokio_stream::iter(vec![block]).into_boxed();
+
+    // when
+    let mut task =
+        worker_task_with_block_importer_and_db(block_importer, database.clone());
+    let _ = task.run(&mut state_watcher).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // then
+    let storage = database.storage_as_ref::<ContractTransactions>();
+    let key = |contract_id| ContractTransactionIndexKey::new(&contract_id, 0.into(), 0);
+    assert_eq!(*storage.get(&key(input_contract)).unwrap().unwrap(), tx_id);
+    assert_eq!(*storage.get(&key(called_contract)).unwrap().unwrap(), tx_id);
+    assert!(storage.get(&key(unrelated_contract)).unwrap().is_none());
+}
+
+#[tokio::test]
+async fn run__logs_are_indexed_by_contract_and_log_id() {
+    let database = Database::in_memory();
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let contract = ContractId::from([1; 32]);
+    let tx_id: Bytes32 = [2; 32].into();
+    let log = Receipt::log(contract, 0, 7, 0, 0, 0, 0);
+    let log_data = Receipt::log_data(contract, 0, 8, 0, 0, 0, vec![1, 2, 3]);
+    let receipts = vec![
+        Receipt::call(
+            Default::default(),
+            contract,
+            0,
+            Default::default(),
+            0,
+            0,
+            0,
+            0,
+            0,
+        ),
+        log.clone(),
+        log_data.clone(),
+    ];
+    let mut sealed_block = SealedBlock::default();
+    sealed_block
+        .entity
+        .transactions_mut()
+        .push(TransactionBuilder::script(vec![], vec![]).finalize_as_transaction());
+    let block = Arc::new(
+        ImportResult {
+            sealed_block,
+            tx_status: vec![TransactionExecutionStatus {
+                id: tx_id,
+                result: TransactionExecutionResult::Success {
+                    result: None,
+                    receipts,
+                    total_gas: 0,
+                    total_fee: 0,
+                },
+            }],
+            events: vec![],
+            source: Default::default(),
+        }
+        .wrap(),
+    );
+    let block_importer = tokio_stream::iter(vec![block]).into_boxed();
+
+    // when
+    let mut task =
+        worker_task_with_block_importer_and_db(block_importer, database.clone());
+    let _ = task.run(&mut state_watcher).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // then
+    let storage = database.storage_as_ref::<Logs>();
+    let key = |log_id, receipt_idx| {
+        LogKey::new(contract, log_id, LogPointer::new(0.into(), 0, receipt_idx))
+    };
+    let expected = |receipt| IndexedLog { tx_id, receipt };
+    assert_eq!(*storage.get(&key(7, 1)).unwrap().unwrap(), expected(log));
+    assert_eq!(
+        *storage.get(&key(8, 2)).unwrap().unwrap(),
+        expected(log_data)
+    );
+    assert!(storage.get(&key(0, 0)).unwrap().is_none());
+}
+
+#[tokio::test]
+async fn run__logs_of_failed_transactions_are_not_indexed() {
+    let database = Database::in_memory();
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let contract = ContractId::from([1; 32]);
+    let receipts = vec![Receipt::log(contract, 0, 7, 0, 0, 0, 0)];
+    let mut sealed_block = SealedBlock::default();
+    sealed_block
+        .entity
+        .transactions_mut()
+        .push(TransactionBuilder::script(vec![], vec![]).finalize_as_transaction());
+    let block = Arc::new(
+        ImportResult {
+            sealed_block,
+            tx_status: vec![TransactionExecutionStatus {
+                id: [2; 32].into(),
+                result: TransactionExecutionResult::Failed {
+                    result: None,
+                    receipts,
+                    total_gas: 0,
+                    total_fee: 0,
+                },
+            }],
+            events: vec![],
+            source: Default::default(),
+        }
+        .wrap(),
+    );
+    let block_importer = tokio_stream::iter(vec![block]).into_boxed();
+
+    // when
+    let mut task =
+        worker_task_with_block_importer_and_db(block_importer, database.clone());
+    let _ = task.run(&mut state_watcher).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // then
+    let storage = database.storage_as_ref::<Logs>();
+    let key = LogKey::new(contract, 7, LogPointer::new(0.into(), 0, 0));
+    assert!(storage.get(&key).unwrap().is_none());
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(
         ImportResult {
@@ -88,6 +283,8 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         balances_indexation_enabled: true,
         coins_to_spend_indexation_enabled: true,
         asset_metadata_indexation_enabled: true,
+        transactions_by_contract_indexation_enabled: true,
+        logs_indexation_enabled: true,
         base_asset_id: Default::default(),
         block_height_subscription_handler: Default::default(),
     }
@@ -11,6 +11,9 @@ use tokio_util::sync::CancellationToken;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+#[cfg(feature = "p2p")]
+#[doc(no_inline)]
+pub use fuel_core_bft as bft;
 #[doc(no_inline)]
 pub use fuel_core_chain_config as chain_config;
 #[cfg(feature = "p2p")]
@@ -18,6 +18,8 @@ use crate::{
     },
 };
 use fuel_core_chain_config::{
+    BftConfig,
+    BftValidator,
     ConsensusConfig,
     StateConfig,
 };
@@ -71,6 +73,7 @@ use rand::{
 };
 use std::{
     collections::HashMap,
+    num::NonZeroU32,
     ops::{
         Index,
         IndexMut,
@@ -91,6 +94,7 @@ pub struct CustomizeConfig {
     min_exec_gas_price: Option<u64>,
     max_functional_peers_connected: Option<u32>,
     max_discovery_peers_connected: Option<u32>,
+    state_checkpoint_interval: Option<NonZeroU32>,
 }
 
 impl CustomizeConfig {
@@ -99,6 +103,7 @@ impl CustomizeConfig {
             min_exec_gas_price: None,
             max_functional_peers_connected: None,
             max_discovery_peers_connected: None,
+            state_checkpoint_interval: None,
         }
     }
 
@@ -116,6 +121,11 @@ impl CustomizeConfig {
         self.max_discovery_peers_connected = Some(max_peers_connected);
         self
     }
+
+    pub fn state_checkpoint_interval(mut self, interval: NonZeroU32) -> Self {
+        self.state_checkpoint_interval = Some(interval);
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -150,6 +160,17 @@ pub struct ValidatorSetup {
     pub config_overrides: CustomizeConfig,
 }
 
+#[derive(Clone)]
+/// Setup for a network of the BFT validators
+pub struct BftSetup {
+    /// Secret keys of the validators, each validator runs its own node.
+    pub validators: Vec<SecretKey>,
+    /// Number of nodes that follow the consensus without voting.
+    pub num_followers: usize,
+    /// The timeouts of the consensus rounds.
+    pub timeouts: fuel_core_bft::Timeouts,
+}
+
 #[derive(Clone)]
 pub struct BootstrapSetup {
     pub name: String,
@@ -174,6 +195,12 @@ pub struct Nodes {
     pub validators: Vec<Node>,
 }
 
+pub struct BftNodes {
+    pub bootstrap: Bootstrap,
+    pub validators: Vec<Node>,
+    pub followers: Vec<Node>,
+}
+
 /// Nodes accessible by their name.
 pub struct NamedNodes(pub HashMap<String, Node>);
 
@@ -469,6 +496,74 @@ pub async fn make_nodes(
     }
 }
 
+/// Creates the network of the BFT validators and followers connected
+/// through the bootstrap node. All validators have the same voting power.
+pub async fn make_bft_nodes(setup: BftSetup, config: Option<Config>) -> BftNodes {
+    let BftSetup {
+        validators,
+        num_followers,
+        timeouts,
+    } = setup;
+
+    let mut config = config.unwrap_or_else(Config::local_node);
+    let mut chain_config = config.snapshot_reader.chain_config().clone();
+    chain_config.consensus = ConsensusConfig::Bft(BftConfig::new(
+        validators
+            .iter()
+            .map(|secret| BftValidator {
+                address: Input::owner(&secret.public_key()),
+                voting_power: 1,
+            })
+            .collect(),
+    ));
+    config.snapshot_reader = config
+        .snapshot_reader
+        .clone()
+        .with_chain_config(chain_config);
+    config.block_production = Trigger::Never;
+    config.bft_timeouts = timeouts;
+
+    let bootstrap_config = make_config(
+        "b:0".to_string(),
+        config.clone(),
+        CustomizeConfig::no_overrides(),
+    );
+    let bootstrap = Bootstrap::new(&bootstrap_config)
+        .await
+        .expect("Failed to create bootstrap node");
+    let make_node_config = |name: String, signer: SignMode| {
+        let mut node_config =
+            make_config(name, config.clone(), CustomizeConfig::no_overrides());
+        node_config
+            .p2p
+            .as_mut()
+            .unwrap()
+            .reserved_nodes
+            .clone_from(&bootstrap.listeners());
+        node_config.consensus_signer = signer;
+        node_config
+    };
+
+    let mut validator_nodes = Vec::with_capacity(validators.len());
+    for (i, secret) in validators.into_iter().enumerate() {
+        let signer = SignMode::Key(Secret::new(secret.into()));
+        let node_config = make_node_config(format!("bft:{i}"), signer);
+        validator_nodes.push(make_node(node_config, vec![]).await);
+    }
+
+    let mut followers = Vec::with_capacity(num_followers);
+    for i in 0..num_followers {
+        let node_config = make_node_config(format!("f:{i}"), SignMode::Unavailable);
+        followers.push(make_node(node_config, vec![]).await);
+    }
+
+    BftNodes {
+        bootstrap,
+        validators: validator_nodes,
+        followers,
+    }
+}
+
 fn update_signing_key(config: &mut Config, key: Address) {
     let snapshot_reader = &config.snapshot_reader;
 
@@ -480,6 +575,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        ConsensusConfig::Bft(_) => {
+            // The BFT validator set is defined by the caller.
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
 }
@@ -507,6 +605,11 @@ pub fn make_config(
         {
             p2p.max_functional_peers_connected = max_functional_peers_connected;
         }
+
+        if let Some(state_checkpoint_interval) = config_overrides.state_checkpoint_interval
+        {
+            p2p.state_checkpoint_interval = Some(state_checkpoint_interval);
+        }
     }
     node_config
 }
@@ -776,6 +879,34 @@ impl ValidatorSetup {
         }
     }
 }
+impl BftSetup {
+    /// Creates the setup with `num_validators` deterministic validator keys.
+    pub fn new(num_validators: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let validators = (0..num_validators)
+            .map(|_| SecretKey::random(&mut rng))
+            .collect();
+        Self {
+            validators,
+            num_followers: 0,
+            timeouts: fuel_core_bft::Timeouts {
+                propose: Duration::from_secs(2),
+                prevote: Duration::from_millis(500),
+                precommit: Duration::from_millis(500),
+                delta: Duration::from_millis(250),
+                commit: Duration::from_millis(200),
+            },
+        }
+    }
+
+    pub fn with_followers(self, num_followers: usize) -> Self {
+        Self {
+            num_followers,
+            ..self
+        }
+    }
+}
+
 impl BootstrapSetup {
     pub fn new(pub_key: Address) -> Self {
         Self {
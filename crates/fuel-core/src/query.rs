@@ -4,6 +4,7 @@ mod blob;
 mod block;
 mod coin;
 mod contract;
+mod log;
 mod message;
 mod subscriptions;
 mod tx;
@@ -11,5 +12,6 @@ mod upgrades;
 
 // TODO: Remove reexporting of everything
 pub use balance::*;
+pub use log::*;
 pub use message::*;
 pub(crate) use subscriptions::*;
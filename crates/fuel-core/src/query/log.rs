@@ -0,0 +1,293 @@
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::database::ReadView,
+    graphql_api::storage::logs::{
+        IndexedLog,
+        LogKey,
+        LogPointer,
+    },
+};
+use fuel_core_services::yield_stream::StreamYieldExt;
+use fuel_core_storage::{
+    Result as StorageResult,
+    iter::IterDirection,
+};
+use fuel_core_types::{
+    fuel_tx::ContractId,
+    fuel_types::BlockHeight,
+};
+use futures::Stream;
+use itertools::{
+    Either,
+    Itertools,
+};
+
+/// The maximum number of the `(contract, log id)` pairs that one query can search over.
+pub const MAX_LOG_FILTER_PAIRS: usize = 100;
+
+/// The number of blocks which logs are read at once when the filter doesn't
+/// specify the log ids.
+const LOG_SCAN_WINDOW: u32 = 1000;
+
+/// The filter of the indexed logs.
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// The contracts that emitted the logs.
+    pub contracts: Vec<ContractId>,
+    /// The ids of the logs(the value of the `rb` register).
+    /// If `None`, the logs with any id are returned.
+    pub log_ids: Option<Vec<u64>>,
+    /// The first height of the range, inclusive.
+    pub from_height: Option<BlockHeight>,
+    /// The last height of the range, inclusive.
+    pub to_height: Option<BlockHeight>,
+}
+
+/// The logs of the window of blocks.
+struct LogsWindow {
+    /// The logs ordered by the position in the chain.
+    logs: Vec<(LogKey, IndexedLog)>,
+    /// The cursor of the next window, `None` if it was the last window.
+    next_cursor: Option<LogPointer>,
+}
+
+impl ReadView {
+    /// Returns the logs matching the `filter` ordered by their position in the chain.
+    pub fn logs(
+        &self,
+        filter: &LogFilter,
+        start: Option<LogPointer>,
+        direction: IterDirection,
+    ) -> StorageResult<impl Stream<Item = StorageResult<(LogKey, IndexedLog)>> + '_> {
+        if !self.indexation_flags.contains(&IndexationKind::Logs) {
+            return Err(anyhow::anyhow!("Logs index is not available").into());
+        }
+
+        let from_height = filter.from_height;
+        let to_height = filter.to_height;
+        let bound = match direction {
+            IterDirection::Forward => from_height.map(LogPointer::first_at),
+            IterDirection::Reverse => to_height.map(LogPointer::last_at),
+        };
+        let start = match (start, bound) {
+            (Some(start), Some(bound)) => match direction {
+                IterDirection::Forward => Some(start.max(bound)),
+                IterDirection::Reverse => Some(start.min(bound)),
+            },
+            (start, bound) => start.or(bound),
+        };
+        let in_range = move |pointer: &LogPointer| {
+            from_height.is_none_or(|height| pointer.block_height >= height)
+                && to_height.is_none_or(|height| pointer.block_height <= height)
+        };
+
+        let logs = match &filter.log_ids {
+            Some(log_ids) => {
+                let pairs = log_filter_pairs(&filter.contracts, log_ids)?;
+                Either::Left(self.merged_logs(pairs, start, direction))
+            }
+            None => {
+                let contracts = filter.contracts.iter().copied().unique().collect();
+                Either::Right(self.scanned_logs(contracts, start, direction))
+            }
+        }
+        .take_while(move |result| match result {
+            Ok((key, _)) => in_range(&key.pointer),
+            Err(_) => true,
+        });
+
+        Ok(futures::stream::iter(logs).yield_each(self.batch_size))
+    }
+
+    /// Returns the logs of the `(contract, log id)` pairs ordered by the position in the chain.
+    fn merged_logs(
+        &self,
+        pairs: Vec<(ContractId, u64)>,
+        start: Option<LogPointer>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<(LogKey, IndexedLog)>> + '_ {
+        // Each `(contract, log id)` pair is a separate sorted range of the index,
+        // so the ranges are merged by the position of the log in the chain.
+        pairs
+            .into_iter()
+            .map(move |(contract_id, log_id)| {
+                let start =
+                    start.map(|pointer| LogKey::new(contract_id, log_id, pointer));
+                self.contract_logs(contract_id, Some(log_id), start, direction)
+            })
+            .kmerge_by(move |a, b| match (a, b) {
+                (Ok((a, _)), Ok((b, _))) => match direction {
+                    IterDirection::Forward => a.pointer < b.pointer,
+                    IterDirection::Reverse => a.pointer > b.pointer,
+                },
+                (Err(_), _) => true,
+                (Ok(_), Err(_)) => false,
+            })
+    }
+
+    /// Returns the logs with any log id emitted by the `contracts`,
+    /// ordered by the position in the chain.
+    ///
+    /// The contracts can emit any number of the log ids, so instead of merging
+    /// the ranges of all log ids at once, the logs are read by the windows
+    /// of [`LOG_SCAN_WINDOW`] blocks.
+    fn scanned_logs(
+        &self,
+        contracts: Vec<ContractId>,
+        start: Option<LogPointer>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<(LogKey, IndexedLog)>> + '_ {
+        let mut cursor = start;
+        let mut finished = false;
+        let mut window = Vec::new().into_iter();
+
+        std::iter::from_fn(move || {
+            loop {
+                if let Some(log) = window.next() {
+                    return Some(Ok(log))
+                }
+                if finished {
+                    return None
+                }
+
+                match self.logs_window(&contracts, cursor, direction) {
+                    Ok(Some(LogsWindow { logs, next_cursor })) => {
+                        window = logs.into_iter();
+                        finished = next_cursor.is_none();
+                        cursor = next_cursor;
+                    }
+                    Ok(None) => finished = true,
+                    Err(err) => {
+                        finished = true;
+                        return Some(Err(err))
+                    }
+                }
+            }
+        })
+    }
+
+    /// Returns the logs of the `contracts` within the window of blocks starting
+    /// at the nearest log after the `cursor`, and the cursor of the next window.
+    /// Returns `None` if there are no logs after the `cursor`.
+    fn logs_window(
+        &self,
+        contracts: &[ContractId],
+        cursor: Option<LogPointer>,
+        direction: IterDirection,
+    ) -> StorageResult<Option<LogsWindow>> {
+        let mut pairs = vec![];
+        for contract_id in contracts {
+            for log_id in self.contract_log_ids(*contract_id) {
+                pairs.push((*contract_id, log_id?));
+            }
+        }
+        let logs_after_cursor = |(contract_id, log_id): &(ContractId, u64)| {
+            let start = cursor.map(|pointer| LogKey::new(*contract_id, *log_id, pointer));
+            self.contract_logs(*contract_id, Some(*log_id), start, direction)
+        };
+
+        let mut nearest: Option<LogPointer> = None;
+        for pair in &pairs {
+            let Some(entry) = logs_after_cursor(pair).next() else {
+                continue
+            };
+            let (key, _) = entry?;
+            nearest = Some(match (nearest, direction) {
+                (None, _) => key.pointer,
+                (Some(nearest), IterDirection::Forward) => nearest.min(key.pointer),
+                (Some(nearest), IterDirection::Reverse) => nearest.max(key.pointer),
+            });
+        }
+        let Some(nearest) = nearest else {
+            return Ok(None)
+        };
+
+        let nearest_height = u32::from(nearest.block_height);
+        let window_end = match direction {
+            IterDirection::Forward => {
+                nearest_height.saturating_add(LOG_SCAN_WINDOW.saturating_sub(1))
+            }
+            IterDirection::Reverse => {
+                nearest_height.saturating_sub(LOG_SCAN_WINDOW.saturating_sub(1))
+            }
+        };
+        let in_window = |pointer: &LogPointer| {
+            let height = u32::from(pointer.block_height);
+            match direction {
+                IterDirection::Forward => height <= window_end,
+                IterDirection::Reverse => height >= window_end,
+            }
+        };
+
+        let mut logs = vec![];
+        for pair in &pairs {
+            for entry in logs_after_cursor(pair) {
+                let (key, log) = entry?;
+                if !in_window(&key.pointer) {
+                    break
+                }
+                logs.push((key, log));
+            }
+        }
+        logs.sort_by(|(a, _), (b, _)| match direction {
+            IterDirection::Forward => a.pointer.cmp(&b.pointer),
+            IterDirection::Reverse => b.pointer.cmp(&a.pointer),
+        });
+
+        let next_cursor = match direction {
+            IterDirection::Forward => window_end
+                .checked_add(1)
+                .map(|height| LogPointer::first_at(height.into())),
+            IterDirection::Reverse => window_end
+                .checked_sub(1)
+                .map(|height| LogPointer::last_at(height.into())),
+        };
+        Ok(Some(LogsWindow { logs, next_cursor }))
+    }
+
+    /// Returns the log ids emitted by the contract in the ascending order.
+    fn contract_log_ids(
+        &self,
+        contract_id: ContractId,
+    ) -> impl Iterator<Item = StorageResult<u64>> + '_ {
+        // Jumps over the index from one log id of the contract to the next one.
+        let mut next_log_id = Some(0);
+        std::iter::from_fn(move || {
+            let log_id = next_log_id.take()?;
+            let start = LogKey::new(contract_id, log_id, LogPointer::default());
+            let entry = self
+                .contract_logs(contract_id, None, Some(start), IterDirection::Forward)
+                .next()?;
+            match entry {
+                Ok((key, _)) => {
+                    next_log_id = key.log_id.checked_add(1);
+                    Some(Ok(key.log_id))
+                }
+                Err(err) => Some(Err(err)),
+            }
+        })
+    }
+}
+
+/// Returns the `(contract, log id)` pairs to search over.
+fn log_filter_pairs(
+    contracts: &[ContractId],
+    log_ids: &[u64],
+) -> StorageResult<Vec<(ContractId, u64)>> {
+    let pairs = contracts
+        .iter()
+        .unique()
+        .cartesian_product(log_ids.iter().unique())
+        .map(|(contract_id, log_id)| (*contract_id, *log_id))
+        .collect::<Vec<_>>();
+
+    if pairs.len() > MAX_LOG_FILTER_PAIRS {
+        return Err(anyhow::anyhow!(
+            "The filter matches more than {MAX_LOG_FILTER_PAIRS} \
+            (contract, log id) pairs"
+        )
+        .into());
+    }
+
+    Ok(pairs)
+}
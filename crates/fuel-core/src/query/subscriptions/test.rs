@@ -231,6 +231,7 @@ fn next_state(state: TransactionStatus) -> Flow {
         TransactionStatus::PreConfirmationSqueezedOut { .. } => {
             Flow::Break(FinalTxStatus::Squeezed)
         }
+        TransactionStatus::Replaced { .. } => Flow::Break(FinalTxStatus::Squeezed),
     }
 }
 
@@ -299,7 +300,8 @@ impl From<crate::schema::tx::types::TransactionStatus> for TxStatus {
             crate::schema::tx::types::TransactionStatus::PreconfirmationSuccess(_) => {
                 TxStatus::NotFinal(NotFinalTxStatus::PreconfirmationSuccess)
             }
-            crate::schema::tx::types::TransactionStatus::SqueezedOut(_) => {
+            crate::schema::tx::types::TransactionStatus::SqueezedOut(_)
+            | crate::schema::tx::types::TransactionStatus::Replaced(_) => {
                 TxStatus::Final(FinalTxStatus::Squeezed)
             }
             crate::schema::tx::types::TransactionStatus::Failure(_) => {
@@ -8,6 +8,7 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        ContractId,
         Receipt,
         Transaction,
         TxId,
@@ -42,8 +43,28 @@ impl ReadView {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
-        self.owned_transactions_ids(owner, start, direction)
-            .chunks(self.batch_size)
+        self.transactions_by_ids(self.owned_transactions_ids(owner, start, direction))
+    }
+
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
+        self.transactions_by_ids(self.contract_transactions_ids(
+            contract_id,
+            start,
+            direction,
+        ))
+    }
+
+    /// Fetches the transactions in batches for the stream of their ids.
+    fn transactions_by_ids<'a>(
+        &'a self,
+        ids: impl Stream<Item = StorageResult<(TxPointer, TxId)>> + 'a,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + 'a {
+        ids.chunks(self.batch_size)
             .map(|chunk| {
                 use itertools::Itertools;
 
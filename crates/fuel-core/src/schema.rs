@@ -30,6 +30,7 @@ use futures::{
 use std::borrow::Cow;
 use tokio_stream::StreamExt;
 
+pub mod admin;
 pub mod assets;
 pub mod balance;
 pub mod blob;
@@ -40,6 +41,7 @@ pub mod contract;
 pub mod da_compressed;
 pub mod dap;
 pub mod health;
+pub mod log;
 pub mod message;
 pub mod node_info;
 pub mod upgrades;
@@ -53,6 +55,7 @@ pub mod storage;
 
 #[derive(MergedObject, Default)]
 pub struct Query(
+    admin::AdminQuery,
     assets::AssetInfoQuery,
     dap::DapQuery,
     balance::BalanceQuery,
@@ -65,9 +68,11 @@ pub struct Query(
     da_compressed::DaCompressedBlockQuery,
     contract::ContractQuery,
     contract::ContractBalanceQuery,
+    log::LogQuery,
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
+    gas_price::GasPriceHistoryQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
@@ -75,10 +80,20 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    admin::AdminMutation,
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription, storage::StorageSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    storage::StorageSubscription,
+    block::BlockSubscription,
+    log::LogSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -0,0 +1,260 @@
+use crate::{
+    fuel_core_graphql_api::{
+        Config as GraphQLConfig,
+        api_service::{
+            P2pService,
+            TxPool,
+        },
+    },
+    schema::scalars::{
+        Address,
+        ContractId,
+        Nonce,
+        U64,
+        UtxoId,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_txpool::config::{
+    BlackList,
+    BlackListEntry,
+    BlackListUpdate,
+};
+use fuel_core_types::services::p2p::PeerId;
+use std::{
+    str::FromStr,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
+};
+
+/// The token passed by the client in the `Authorization: Bearer <token>` header.
+pub struct AuthorizationToken(pub String);
+
+/// Checks that the request is authorized with the admin token.
+/// The admin API is disabled if the node doesn't have the admin token configured.
+pub fn require_admin(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+
+    let Some(admin_token) = config.config.admin_token.as_ref() else {
+        return Err(async_graphql::Error::new("The admin API is disabled"))
+    };
+
+    match ctx.data_opt::<AuthorizationToken>() {
+        Some(token) if constant_time_eq(token.0.as_bytes(), admin_token.as_bytes()) => {
+            Ok(())
+        }
+        _ => Err(async_graphql::Error::new("Invalid admin token")),
+    }
+}
+
+/// Compares tokens without leaking the length of the matching prefix through timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+pub struct Blacklist(BlackList);
+
+#[Object]
+impl Blacklist {
+    /// Blacklisted owners of coins and senders or recipients of messages.
+    async fn owners(&self) -> Vec<Address> {
+        let mut owners: Vec<_> = self.0.owners.iter().copied().collect();
+        owners.sort();
+        owners.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted coins.
+    async fn coins(&self) -> Vec<UtxoId> {
+        let mut coins: Vec<_> = self.0.coins.iter().copied().collect();
+        coins.sort();
+        coins.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted messages.
+    async fn messages(&self) -> Vec<Nonce> {
+        let mut messages: Vec<_> = self.0.messages.iter().copied().collect();
+        messages.sort();
+        messages.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted contracts.
+    async fn contracts(&self) -> Vec<ContractId> {
+        let mut contracts: Vec<_> = self.0.contracts.iter().copied().collect();
+        contracts.sort();
+        contracts.into_iter().map(Into::into).collect()
+    }
+}
+
+pub struct BannedPeer(fuel_core_types::services::p2p::BannedPeer);
+
+#[Object]
+impl BannedPeer {
+    /// The libp2p peer id
+    async fn id(&self) -> String {
+        self.0.id.to_string()
+    }
+
+    /// Why the peer was banned
+    async fn reason(&self) -> &str {
+        &self.0.reason
+    }
+
+    /// When the ban expires, as milliseconds since the Unix epoch.
+    /// The ban is permanent if it is not set.
+    async fn expires_at_ms(&self) -> Option<U64> {
+        self.0.expires_at.map(|time| {
+            let time = time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            U64(time.try_into().unwrap_or_default())
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct AdminQuery;
+
+#[Object]
+impl AdminQuery {
+    /// Returns the current blacklist of the transaction pool.
+    async fn blacklist(&self, ctx: &Context<'_>) -> async_graphql::Result<Blacklist> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        Ok(Blacklist(txpool.blacklist()))
+    }
+
+    /// Returns the peers banned by the node. The bans survive restarts of the node.
+    async fn banned_peers(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<BannedPeer>> {
+        require_admin(ctx)?;
+        banned_peers(ctx).await
+    }
+}
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Adds entries to the blacklist of the transaction pool.
+    /// Transactions in the pool that use blacklisted inputs are removed
+    /// together with their dependents.
+    async fn blacklist_add(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] owners: Vec<Address>,
+        #[graphql(default)] coins: Vec<UtxoId>,
+        #[graphql(default)] messages: Vec<Nonce>,
+        #[graphql(default)] contracts: Vec<ContractId>,
+    ) -> async_graphql::Result<Blacklist> {
+        require_admin(ctx)?;
+        let entries = blacklist_entries(owners, coins, messages, contracts);
+        update_blacklist(ctx, BlackListUpdate::Add(entries)).await
+    }
+
+    /// Removes entries from the blacklist of the transaction pool.
+    async fn blacklist_remove(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] owners: Vec<Address>,
+        #[graphql(default)] coins: Vec<UtxoId>,
+        #[graphql(default)] messages: Vec<Nonce>,
+        #[graphql(default)] contracts: Vec<ContractId>,
+    ) -> async_graphql::Result<Blacklist> {
+        require_admin(ctx)?;
+        let entries = blacklist_entries(owners, coins, messages, contracts);
+        update_blacklist(ctx, BlackListUpdate::Remove(entries)).await
+    }
+
+    /// Bans the peer and disconnects it. The banned peer can't connect to the node
+    /// until the ban expires after `duration_seconds`, or until it is unbanned
+    /// if the duration is not set.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        reason: String,
+        duration_seconds: Option<U64>,
+    ) -> async_graphql::Result<Vec<BannedPeer>> {
+        require_admin(ctx)?;
+        let peer_id = PeerId::from_str(&id).map_err(async_graphql::Error::new)?;
+        let duration = duration_seconds.map(|seconds| Duration::from_secs(seconds.0));
+
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.ban_peer(peer_id, reason, duration).await?;
+
+        banned_peers(ctx).await
+    }
+
+    /// Removes the ban of the peer.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> async_graphql::Result<Vec<BannedPeer>> {
+        require_admin(ctx)?;
+        let peer_id = PeerId::from_str(&id).map_err(async_graphql::Error::new)?;
+
+        let p2p = ctx.data_unchecked::<P2pService>();
+        if !p2p.unban_peer(peer_id).await? {
+            return Err(async_graphql::Error::new(format!(
+                "The peer {id} is not banned"
+            )))
+        }
+
+        banned_peers(ctx).await
+    }
+}
+
+async fn banned_peers(ctx: &Context<'_>) -> async_graphql::Result<Vec<BannedPeer>> {
+    let p2p = ctx.data_unchecked::<P2pService>();
+    let mut peers = p2p.banned_peers().await?;
+    peers.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Ok(peers.into_iter().map(BannedPeer).collect())
+}
+
+async fn update_blacklist(
+    ctx: &Context<'_>,
+    update: BlackListUpdate,
+) -> async_graphql::Result<Blacklist> {
+    let txpool = ctx.data_unchecked::<TxPool>();
+    txpool.update_blacklist(update).await?;
+
+    Ok(Blacklist(txpool.blacklist()))
+}
+
+fn blacklist_entries(
+    owners: Vec<Address>,
+    coins: Vec<UtxoId>,
+    messages: Vec<Nonce>,
+    contracts: Vec<ContractId>,
+) -> Vec<BlackListEntry> {
+    let owners = owners
+        .into_iter()
+        .map(|owner| BlackListEntry::Owner(owner.into()));
+    let coins = coins
+        .into_iter()
+        .map(|utxo_id| BlackListEntry::Coin(utxo_id.into()));
+    let messages = messages
+        .into_iter()
+        .map(|nonce| BlackListEntry::Message(nonce.into()));
+    let contracts = contracts
+        .into_iter()
+        .map(|contract_id| BlackListEntry::Contract(contract_id.into()));
+
+    owners
+        .chain(coins)
+        .chain(messages)
+        .chain(contracts)
+        .collect()
+}
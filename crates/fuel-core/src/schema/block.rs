@@ -7,7 +7,10 @@ use crate::{
     fuel_core_graphql_api::{
         Config as GraphQLConfig,
         IntoApiResult,
-        api_service::ConsensusModule,
+        api_service::{
+            ConsensusModule,
+            ReadDatabase,
+        },
         block_height_subscription,
         database::ReadView,
         query_costs,
@@ -30,6 +33,7 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
     connection::{
         Connection,
@@ -66,6 +70,8 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    Bft(BftConsensus),
+    DaDerived(DaDerivedConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -90,6 +96,14 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct BftConsensus {
+    round: U32,
+    proposer_signature: Signature,
+    precommits: Vec<Signature>,
+}
+
+pub struct DaDerivedConsensus;
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -261,6 +275,33 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BftConsensus {
+    /// Gets the round in which the validators committed the block.
+    async fn round(&self) -> U32 {
+        self.round
+    }
+
+    /// Gets the signature of the block by the proposer of the round.
+    async fn proposer_signature(&self) -> Signature {
+        self.proposer_signature
+    }
+
+    /// Gets the precommit signatures of the validators that committed the block.
+    async fn precommits(&self) -> Vec<Signature> {
+        self.precommits.clone()
+    }
+}
+
+#[Object]
+impl DaDerivedConsensus {
+    /// Always `false`: the block was derived from the DA compressed block,
+    /// which doesn't contain the seal of the block producer.
+    async fn sealed(&self) -> bool {
+        false
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -375,6 +416,93 @@ where
     })
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks imported by the node.
+    ///
+    /// If `from_height` is provided, the stream first replays all stored blocks
+    /// starting from this height and then continues with new blocks without gaps.
+    /// Otherwise, the stream starts with the next imported block.
+    ///
+    /// The block is delivered after the node has processed it, so its transactions
+    /// and their statuses with receipts can be requested as a part of the event.
+    #[graphql(complexity = "query_costs().block_header + child_complexity")]
+    async fn blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Block>> + 'a + use<'a>,
+    > {
+        let stream = imported_blocks(ctx, from_height)?;
+        Ok(stream.map(|result| result.map(Into::into)))
+    }
+
+    /// Returns a stream of headers of blocks imported by the node.
+    ///
+    /// If `from_height` is provided, the stream first replays all stored headers
+    /// starting from this height and then continues with new headers without gaps.
+    /// Otherwise, the stream starts with the header of the next imported block.
+    #[graphql(complexity = "query_costs().block_header + child_complexity")]
+    async fn headers<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first header in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Header>> + 'a + use<'a>,
+    > {
+        let stream = imported_blocks(ctx, from_height)?;
+        Ok(stream.map(|result| result.map(Into::into)))
+    }
+}
+
+/// Returns the stream of blocks starting from `from_height`, or from the next block
+/// if the height is not specified. Each block is read from the database only after
+/// the off-chain worker has processed it, so replayed and live blocks are delivered
+/// in the same way and without gaps. The stream ends after the first error.
+fn imported_blocks<'a>(
+    ctx: &'a Context<'a>,
+    from_height: Option<U32>,
+) -> async_graphql::Result<
+    impl Stream<Item = async_graphql::Result<CompressedBlock>> + 'a + use<'a>,
+> {
+    let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+    let database: &ReadDatabase = ctx.data_unchecked();
+
+    let first_height = match from_height {
+        Some(height) => {
+            let height: u32 = height.into();
+            BlockHeight::from(height)
+        }
+        None => subscriber
+            .current_block_height()
+            .succ()
+            .ok_or_else(|| anyhow!("The maximum block height is reached"))?,
+    };
+
+    let stream = futures::stream::unfold(Some(first_height), move |height| async move {
+        let height = height?;
+        let result = async {
+            subscriber.wait_for_block_height(height).await?;
+            let block = database.view()?.block(&height)?;
+            Ok::<_, async_graphql::Error>(block)
+        }
+        .await;
+
+        match result {
+            Ok(block) => Some((Ok(block), height.succ())),
+            Err(err) => Some((Err(err), None)),
+        }
+    });
+
+    Ok(stream)
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -453,6 +581,12 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             })),
+            CoreConsensus::Bft(bft) => Ok(Consensus::Bft(BftConsensus {
+                round: bft.round.into(),
+                proposer_signature: bft.proposer_signature.into(),
+                precommits: bft.precommits.into_iter().map(Into::into).collect(),
+            })),
+            CoreConsensus::DaDerived => Ok(Consensus::DaDerived(DaDerivedConsensus)),
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
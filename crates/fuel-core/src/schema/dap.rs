@@ -1,10 +1,13 @@
 use crate::{
     database::{
         Database,
-        OnChainIterableKeyValueView,
+        OnChainKeyValueView,
         database_description::on_chain::OnChain,
     },
-    fuel_core_graphql_api::api_service::ChainInfoProvider,
+    fuel_core_graphql_api::{
+        api_service::ChainInfoProvider,
+        require_historical_execution,
+    },
     schema::scalars::{
         U32,
         U64,
@@ -19,50 +22,83 @@ use async_graphql::{
 };
 use fuel_core_storage::{
     InterpreterStorage,
+    StorageAsRef,
     not_found,
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
     transactional::{
         AtomicView,
+        HistoricalView,
         IntoTransaction,
         StorageTransaction,
     },
     vm_storage::VmStorage,
 };
 use fuel_core_types::{
+    blockchain::block::CompressedBlock,
     fuel_asm::{
         Instruction,
+        PanicReason,
         RegisterId,
         Word,
     },
     fuel_tx::{
+        Blob,
         ConsensusParameters,
+        ContractId,
+        Create,
         Executable,
+        Mint,
+        Receipt,
         Script,
         Transaction,
+        TxId,
+        UniqueIdentifier,
+        Upgrade,
+        Upload,
         field::{
+            InputContract,
+            MintGasPrice,
             Policies,
             ScriptGasLimit,
             Witnesses,
         },
         policies::PolicyType,
     },
+    fuel_types::BlockHeight,
     fuel_vm::{
         Interpreter,
         InterpreterError,
+        PredicateStorage,
+        ProgramState,
+        RuntimePredicate,
         checked_transaction::{
+            Checked,
             CheckedTransaction,
             IntoChecked,
         },
+        context::Context as VmContext,
         interpreter::{
+            CheckedMetadata,
+            ExecutableTransaction,
             InterpreterParams,
             MemoryInstance,
         },
-        state::DebugEval,
+        predicate::PredicateStorageError,
+        state::{
+            DebugEval,
+            ExecuteState,
+        },
     },
 };
 use futures::lock::Mutex;
 use std::{
-    collections::HashMap,
-    io,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     sync,
     sync::Arc,
 };
@@ -87,17 +123,228 @@ pub fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
     }
 }
 
-type FrozenDatabase = VmStorage<StorageTransaction<OnChainIterableKeyValueView>>;
+type FrozenDatabase = VmStorage<StorageTransaction<OnChainKeyValueView>>;
+type PredicateDatabase = PredicateStorage<StorageTransaction<OnChainKeyValueView>>;
+
+type DebugVm<Tx> = Interpreter<MemoryInstance, FrozenDatabase, Tx>;
+type PredicateVm<Tx> = Interpreter<MemoryInstance, PredicateDatabase, Tx>;
+
+/// Dispatches the call to the interpreter inside of the `SessionVm` or `PredicateSessionVm`.
+macro_rules! dispatch {
+    ($vm:expr, $kind:ident, $inner:ident => $body:expr) => {
+        match $vm {
+            $kind::Script($inner) => $body,
+            $kind::Create($inner) => $body,
+            $kind::Upgrade($inner) => $body,
+            $kind::Upload($inner) => $body,
+            $kind::Blob($inner) => $body,
+        }
+    };
+}
+
+/// Defines the interpreter of the debugger for each executable transaction type.
+macro_rules! session_vm {
+    ($name:ident, $vm:ident) => {
+        enum $name {
+            Script($vm<Script>),
+            Create($vm<Create>),
+            Upgrade($vm<Upgrade>),
+            Upload($vm<Upload>),
+            Blob($vm<Blob>),
+        }
+
+        impl $name {
+            fn register(&self, register: RegisterId) -> Option<Word> {
+                dispatch!(self, $name, vm => vm.registers().get(register).copied())
+            }
+
+            fn memory(&self, start: usize, size: usize) -> Option<&[u8]> {
+                dispatch!(self, $name, vm => vm.memory().read(start, size).ok())
+            }
+
+            fn exec(&mut self, op: Instruction) -> anyhow::Result<()> {
+                dispatch!(self, $name, vm => vm
+                    .instruction::<_, false>(op)
+                    .map(|_| ())
+                    .map_err(|e| anyhow!("{e:?}")))
+            }
+
+            fn set_single_stepping(&mut self, enable: bool) {
+                dispatch!(self, $name, vm => vm.set_single_stepping(enable))
+            }
+
+            fn set_breakpoint(&mut self, breakpoint: gql_types::Breakpoint) {
+                dispatch!(self, $name, vm => vm.set_breakpoint(breakpoint.into()))
+            }
+        }
+    };
+}
+
+session_vm!(SessionVm, DebugVm);
+session_vm!(PredicateSessionVm, PredicateVm);
+
+impl SessionVm {
+    fn vm_storage(&self) -> &FrozenDatabase {
+        dispatch!(self, SessionVm, vm => vm.as_ref())
+    }
+
+    fn block_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.vm_storage().block_height()?)
+    }
+
+    fn receipts(&self) -> &[Receipt] {
+        dispatch!(self, SessionVm, vm => vm.receipts())
+    }
+
+    fn resume(
+        &mut self,
+    ) -> Result<ProgramState, InterpreterError<fuel_core_storage::Error>> {
+        dispatch!(self, SessionVm, vm => vm.resume())
+    }
+}
+
+impl PredicateSessionVm {
+    fn verify(
+        &mut self,
+    ) -> Result<ProgramState, InterpreterError<PredicateStorageError>> {
+        dispatch!(self, PredicateSessionVm, vm => verify_predicate(vm))
+    }
+}
 
-#[derive(Default, Debug)]
+/// Executes the predicate until it returns or stops on a breakpoint,
+/// the same way as the `Interpreter::verify_predicate` does.
+fn verify_predicate<Tx>(
+    vm: &mut PredicateVm<Tx>,
+) -> Result<ProgramState, InterpreterError<PredicateStorageError>>
+where
+    Tx: ExecutableTransaction,
+{
+    loop {
+        match vm.execute::<true>()? {
+            ExecuteState::Return(1) => return Ok(ProgramState::Return(1)),
+            ExecuteState::Return(_) => {
+                return Err(InterpreterError::Panic(
+                    PanicReason::PredicateReturnedNonOne,
+                ))
+            }
+            // A predicate is not expected to return data
+            ExecuteState::ReturnData(_) => {
+                return Err(InterpreterError::Panic(
+                    PanicReason::ContractInstructionNotAllowed,
+                ))
+            }
+            ExecuteState::Revert(r) => return Ok(ProgramState::Revert(r)),
+            ExecuteState::Proceed => (),
+            ExecuteState::DebugEvent(d) => return Ok(ProgramState::VerifyPredicate(d)),
+        }
+    }
+}
+
+/// The transaction types that can be executed by the debugger.
+trait SessionTransaction: ExecutableTransaction {
+    fn session_vm(vm: &mut SessionVm) -> Option<&mut DebugVm<Self>>;
+
+    fn into_session_vm(vm: DebugVm<Self>) -> SessionVm;
+
+    fn into_predicate_vm(vm: PredicateVm<Self>) -> PredicateSessionVm;
+}
+
+macro_rules! session_transaction {
+    ($($tx:ident),*) => {
+        $(
+            impl SessionTransaction for $tx {
+                fn session_vm(vm: &mut SessionVm) -> Option<&mut DebugVm<Self>> {
+                    match vm {
+                        SessionVm::$tx(vm) => Some(vm),
+                        _ => None,
+                    }
+                }
+
+                fn into_session_vm(vm: DebugVm<Self>) -> SessionVm {
+                    SessionVm::$tx(vm)
+                }
+
+                fn into_predicate_vm(vm: PredicateVm<Self>) -> PredicateSessionVm {
+                    PredicateSessionVm::$tx(vm)
+                }
+            }
+        )*
+    };
+}
+
+session_transaction!(Script, Create, Upgrade, Upload, Blob);
+
+/// The state of the debugger session, shared by all interpreters spawned within it.
+struct Session {
+    /// The height of the block the session re-executes transactions of.
+    /// The state of the session is the state before this block.
+    /// `None` means the session runs on top of the most recent state.
+    height: Option<BlockHeight>,
+    /// The consensus parameters of the block the session is forked at.
+    params: Arc<ConsensusParameters>,
+    /// The gas price of the transactions. For the historical session,
+    /// it is the gas price of the block.
+    gas_price: Word,
+    /// The coinbase recipient. For the historical session,
+    /// it is the coinbase recipient of the block.
+    coinbase: ContractId,
+    /// The block re-executed by the historical session.
+    block: Option<HistoricalBlock>,
+    single_stepping: bool,
+    breakpoints: Vec<gql_types::Breakpoint>,
+    /// The transaction whose predicates are being evaluated.
+    pending: Option<PendingTx>,
+}
+
+/// The transactions of the block re-executed by the historical session.
+struct HistoricalBlock {
+    /// The transactions of the block, except the `Mint`.
+    transactions: Vec<Transaction>,
+    /// The number of the transactions already applied to the state of the session.
+    applied: usize,
+}
+
+impl Session {
+    fn interpreter_params(&self) -> InterpreterParams {
+        InterpreterParams::new(self.gas_price, self.params.as_ref())
+    }
+
+    fn interpreter<S, Tx>(&self, storage: S) -> Interpreter<MemoryInstance, S, Tx>
+    where
+        Tx: ExecutableTransaction,
+    {
+        let mut vm = Interpreter::with_storage(
+            MemoryInstance::new(),
+            storage,
+            self.interpreter_params(),
+        );
+        vm.set_single_stepping(self.single_stepping);
+        for breakpoint in &self.breakpoints {
+            vm.set_breakpoint((*breakpoint).into());
+        }
+        vm
+    }
+}
+
+/// The transaction waiting for the evaluation of its predicates before the execution.
+struct PendingTx {
+    tx: CheckedTransaction,
+    /// The interpreter of the predicate being evaluated.
+    vm: Option<PredicateSessionVm>,
+    /// The indexes of the predicate inputs that are not evaluated yet.
+    predicates: VecDeque<usize>,
+}
+
+#[derive(Default)]
 pub struct ConcreteStorage {
-    vm: HashMap<ID, Interpreter<MemoryInstance, FrozenDatabase, Script>>,
+    vm: HashMap<ID, SessionVm>,
     tx: HashMap<ID, Vec<Script>>,
+    session: HashMap<ID, Session>,
 }
 
-/// The gas price used for transactions in the debugger. It is set to 0 because
-/// the debugger does not actually execute transactions, but only simulates
-/// their execution.
+/// The gas price used for transactions in the debugger on top of the latest state.
+/// It is set to 0 because the debugger does not actually execute transactions,
+/// but only simulates their execution.
 const GAS_PRICE: u64 = 0;
 
 impl ConcreteStorage {
@@ -106,141 +353,602 @@ impl ConcreteStorage {
     }
 
     pub fn register(&self, id: &ID, register: RegisterId) -> Option<Word> {
-        self.vm
-            .get(id)
-            .and_then(|vm| vm.registers().get(register).copied())
+        match self.predicate_vm(id) {
+            Some(vm) => vm.register(register),
+            None => self.vm.get(id).and_then(|vm| vm.register(register)),
+        }
     }
 
     pub fn memory(&self, id: &ID, start: usize, size: usize) -> Option<&[u8]> {
-        self.vm
-            .get(id)
-            .and_then(|vm| vm.memory().read(start, size).ok())
+        match self.predicate_vm(id) {
+            Some(vm) => vm.memory(start, size),
+            None => self.vm.get(id).and_then(|vm| vm.memory(start, size)),
+        }
     }
 
     pub fn init(
         &mut self,
         txs: &[Script],
-        params: Arc<ConsensusParameters>,
+        chain_info: &ChainInfoProvider,
         storage: &Database<OnChain>,
+        height: Option<BlockHeight>,
     ) -> anyhow::Result<ID> {
         let id = Uuid::new_v4();
         let id = ID::from(id);
 
-        let vm_database = Self::vm_database(storage)?;
-        let tx = Self::dummy_tx(params.tx_params().max_gas_per_tx() / 2);
-        let checked_tx = tx
-            .into_checked_basic(vm_database.block_height()?, &params)
-            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        let (view, block) = Self::fork(storage, height)?;
+        let params = chain_info.consensus_params_at_version(
+            &block.header().consensus_parameters_version(),
+        )?;
+        let (gas_price, coinbase, block_transactions) = match height {
+            Some(_) => {
+                // The transactions of the block are re-executed with the same
+                // gas price and coinbase recipient as they were in the block.
+                let (transactions, mint) = Self::block_transactions(storage, &block)?;
+                let historical_block = HistoricalBlock {
+                    transactions,
+                    applied: 0,
+                };
+                (
+                    *mint.gas_price(),
+                    mint.input_contract().contract_id,
+                    Some(historical_block),
+                )
+            }
+            None => (GAS_PRICE, ContractId::default(), None),
+        };
+        let session = Session {
+            height,
+            params,
+            gas_price,
+            coinbase,
+            block: block_transactions,
+            single_stepping: false,
+            breakpoints: vec![],
+            pending: None,
+        };
+
+        let vm =
+            Self::dummy_vm(&session, Self::vm_database(view, &block, &session.coinbase))?;
         self.tx
             .get_mut(&id)
             .map(|tx| tx.extend_from_slice(txs))
             .unwrap_or_else(|| {
                 self.tx.insert(id.clone(), txs.to_owned());
             });
-
-        let gas_costs = params.gas_costs();
-        let fee_params = params.fee_params();
-
-        let ready_tx = checked_tx
-            .into_ready(
-                GAS_PRICE,
-                gas_costs,
-                fee_params,
-                Some(vm_database.block_height()?),
-            )
-            .map_err(|e| {
-                anyhow!("Failed to apply dynamic values to checked tx: {:?}", e)
-            })?;
-
-        let interpreter_params = InterpreterParams::new(GAS_PRICE, params.as_ref());
-        let mut vm = Interpreter::with_storage(
-            MemoryInstance::new(),
-            vm_database,
-            interpreter_params,
-        );
-        vm.transact(ready_tx).map_err(|e| anyhow::anyhow!(e))?;
         self.vm.insert(id.clone(), vm);
+        self.session.insert(id.clone(), session);
 
         Ok(id)
     }
 
     pub fn kill(&mut self, id: &ID) -> bool {
         self.tx.remove(id);
+        self.session.remove(id);
         self.vm.remove(id).is_some()
     }
 
-    pub fn reset(
+    pub fn reset(&mut self, id: &ID, storage: &Database<OnChain>) -> anyhow::Result<()> {
+        let session = self
+            .session
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("The VM instance was not found"))?;
+        session.single_stepping = false;
+        session.breakpoints.clear();
+        session.pending = None;
+        if let Some(block) = session.block.as_mut() {
+            block.applied = 0;
+        }
+
+        let (view, block) = Self::fork(storage, session.height)?;
+        let vm_database = Self::vm_database(view, &block, &session.coinbase);
+        let vm = match self.tx.get(id).and_then(|tx| tx.first()).cloned() {
+            Some(tx) => {
+                let checked_tx = tx
+                    .into_checked_basic(vm_database.block_height()?, &session.params)
+                    .map_err(|e| anyhow!("{:?}", e))?;
+                let mut vm = session.interpreter(vm_database);
+                Self::run(&mut vm, checked_tx, session, session.gas_price)?;
+                SessionVm::Script(vm)
+            }
+            None => Self::dummy_vm(session, vm_database)?,
+        };
+
+        self.vm
+            .insert(id.clone(), vm)
+            .ok_or_else(|| anyhow!("The VM instance was not found"))?;
+        Ok(())
+    }
+
+    pub fn exec(&mut self, id: &ID, op: Instruction) -> anyhow::Result<()> {
+        if let Some(vm) = self.predicate_vm_mut(id) {
+            return vm.exec(op)
+        }
+
+        self.vm
+            .get_mut(id)
+            .map(|vm| vm.exec(op))
+            .ok_or_else(|| anyhow!("The VM instance was not found"))?
+    }
+
+    pub fn set_single_stepping(&mut self, id: &ID, enable: bool) -> anyhow::Result<()> {
+        let (vm, session) = self.session_mut(id)?;
+        session.single_stepping = enable;
+        vm.set_single_stepping(enable);
+        if let Some(vm) = session.pending.as_mut().and_then(|tx| tx.vm.as_mut()) {
+            vm.set_single_stepping(enable);
+        }
+        Ok(())
+    }
+
+    pub fn set_breakpoint(
+        &mut self,
+        id: &ID,
+        breakpoint: gql_types::Breakpoint,
+    ) -> anyhow::Result<()> {
+        let (vm, session) = self.session_mut(id)?;
+        session.breakpoints.push(breakpoint);
+        vm.set_breakpoint(breakpoint);
+        if let Some(vm) = session.pending.as_mut().and_then(|tx| tx.vm.as_mut()) {
+            vm.set_breakpoint(breakpoint);
+        }
+        Ok(())
+    }
+
+    /// Starts the evaluation of the transaction. The predicates of the transaction
+    /// are evaluated first, and after the transaction itself is executed.
+    pub fn start_tx(
+        &mut self,
+        id: &ID,
+        tx: Transaction,
+        storage: &Database<OnChain>,
+    ) -> anyhow::Result<gql_types::RunResult> {
+        let (vm, session) = self.session_mut(id)?;
+        let tx_id = tx.id(&session.params.chain_id());
+        Self::apply_preceding_txs(vm, session, &tx_id)?;
+
+        let tx: CheckedTransaction = tx
+            .into_checked_basic(vm.block_height()?, &session.params)
+            .map_err(|err| anyhow!("{:?}", err))?
+            .into();
+
+        let predicates = match &tx {
+            CheckedTransaction::Script(tx) => predicate_inputs(tx, &session.params),
+            CheckedTransaction::Create(tx) => predicate_inputs(tx, &session.params),
+            CheckedTransaction::Upgrade(tx) => predicate_inputs(tx, &session.params),
+            CheckedTransaction::Upload(tx) => predicate_inputs(tx, &session.params),
+            CheckedTransaction::Blob(tx) => predicate_inputs(tx, &session.params),
+            CheckedTransaction::Mint(_) => {
+                return Err(anyhow!(
+                    "`Mint` is not supported, it is produced by the block producer \
+                    and is not executed by the VM"
+                ))
+            }
+        };
+
+        session.pending = Some(PendingTx {
+            tx,
+            vm: None,
+            predicates,
+        });
+        self.run_pending(id, storage)
+    }
+
+    /// Resumes the evaluation of the transaction after a breakpoint.
+    pub fn continue_tx(
+        &mut self,
+        id: &ID,
+        storage: &Database<OnChain>,
+    ) -> anyhow::Result<gql_types::RunResult> {
+        let (vm, session) = self.session_mut(id)?;
+        if session.pending.is_some() {
+            return self.run_pending(id, storage)
+        }
+
+        let receipt_count_before = vm.receipts().len();
+
+        let state = match vm.resume() {
+            Ok(state) => state,
+            // The transaction was already completed earlier, so it cannot be resumed
+            Err(InterpreterError::DebugStateNotInitialized) => {
+                return Ok(gql_types::RunResult {
+                    state: gql_types::RunState::Completed,
+                    breakpoint: None,
+                    json_receipts: Vec::new(),
+                })
+            }
+            // The transaction was already completed earlier, so it cannot be resumed
+            Err(err) => return Err(anyhow!("VM error: {err:?}")),
+        };
+
+        Ok(gql_types::RunResult::new(
+            &state,
+            vm.receipts().iter().skip(receipt_count_before),
+        ))
+    }
+
+    /// Evaluates the predicates of the pending transaction one by one,
+    /// and executes the transaction when all of them are verified.
+    fn run_pending(
         &mut self,
         id: &ID,
-        params: Arc<ConsensusParameters>,
         storage: &Database<OnChain>,
+    ) -> anyhow::Result<gql_types::RunResult> {
+        let (_, session) = self.session_mut(id)?;
+        let result = Self::evaluate_predicates(session, storage);
+        let pending = match result {
+            Ok(None) => session.pending.take(),
+            Ok(Some(result)) => return Ok(result),
+            Err(err) => {
+                session.pending = None;
+                return Err(err)
+            }
+        };
+        let Some(pending) = pending else {
+            return Err(anyhow!("The VM instance was not found"))
+        };
+
+        match pending.tx {
+            CheckedTransaction::Script(tx) => self.transact(id, tx),
+            CheckedTransaction::Create(tx) => self.transact(id, tx),
+            CheckedTransaction::Upgrade(tx) => self.transact(id, tx),
+            CheckedTransaction::Upload(tx) => self.transact(id, tx),
+            CheckedTransaction::Blob(tx) => self.transact(id, tx),
+            CheckedTransaction::Mint(_) => Err(anyhow!("`Mint` is not supported")),
+        }
+    }
+
+    /// Returns the result of the predicate evaluation if it stopped on a breakpoint,
+    /// or `None` if all predicates of the pending transaction are verified.
+    fn evaluate_predicates(
+        session: &mut Session,
+        storage: &Database<OnChain>,
+    ) -> anyhow::Result<Option<gql_types::RunResult>> {
+        loop {
+            let Some(pending) = session.pending.as_mut() else {
+                return Ok(None)
+            };
+
+            if let Some(vm) = pending.vm.as_mut() {
+                match vm.verify() {
+                    Ok(
+                        state @ ProgramState::VerifyPredicate(DebugEval::Breakpoint(_)),
+                    ) => {
+                        return Ok(Some(gql_types::RunResult::new(
+                            &state,
+                            std::iter::empty(),
+                        )))
+                    }
+                    Ok(ProgramState::VerifyPredicate(DebugEval::Continue)) => continue,
+                    Ok(_) => pending.vm = None,
+                    Err(err) => {
+                        return Err(anyhow!("Predicate verification failed: {err:?}"))
+                    }
+                }
+            }
+
+            let Some(idx) = pending.predicates.pop_front() else {
+                return Ok(None)
+            };
+            // Predicates can only read blobs, which are taken from the state
+            // the session is forked at.
+            let (view, _) = Self::fork(storage, session.height)?;
+            let predicate_storage = PredicateStorage::new(view.into_transaction());
+            let Some(pending) = session.pending.as_ref() else {
+                return Ok(None)
+            };
+            let vm = match &pending.tx {
+                CheckedTransaction::Script(tx) => {
+                    Self::init_predicate(session, tx, idx, predicate_storage)?
+                }
+                CheckedTransaction::Create(tx) => {
+                    Self::init_predicate(session, tx, idx, predicate_storage)?
+                }
+                CheckedTransaction::Upgrade(tx) => {
+                    Self::init_predicate(session, tx, idx, predicate_storage)?
+                }
+                CheckedTransaction::Upload(tx) => {
+                    Self::init_predicate(session, tx, idx, predicate_storage)?
+                }
+                CheckedTransaction::Blob(tx) => {
+                    Self::init_predicate(session, tx, idx, predicate_storage)?
+                }
+                CheckedTransaction::Mint(_) => {
+                    return Err(anyhow!("`Mint` is not supported"))
+                }
+            };
+            if let Some(pending) = session.pending.as_mut() {
+                pending.vm = Some(vm);
+            }
+        }
+    }
+
+    fn init_predicate<Tx>(
+        session: &Session,
+        tx: &Checked<Tx>,
+        idx: usize,
+        storage: PredicateDatabase,
+    ) -> anyhow::Result<PredicateSessionVm>
+    where
+        Tx: SessionTransaction,
+    {
+        let tx = tx.transaction();
+        let params = session.params.as_ref();
+        let predicate =
+            RuntimePredicate::from_tx(tx, params.tx_params().tx_offset(), idx)
+                .ok_or_else(|| anyhow!("The input {idx} is not a predicate"))?;
+
+        let mut vm = session.interpreter(storage);
+        vm.init_predicate(
+            VmContext::PredicateVerification { program: predicate },
+            tx.clone(),
+            params.predicate_params().max_gas_per_predicate(),
+        )
+        .map_err(|e| anyhow!("Failed to initialize the predicate {idx}: {e:?}"))?;
+
+        Ok(Tx::into_predicate_vm(vm))
+    }
+
+    /// Executes the transaction on top of the state of the session. The interpreter
+    /// of the session is replaced if it was created for another transaction type.
+    fn transact<Tx>(
+        &mut self,
+        id: &ID,
+        checked_tx: Checked<Tx>,
+    ) -> anyhow::Result<gql_types::RunResult>
+    where
+        Tx: SessionTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let (vm, session) = self.session_mut(id)?;
+
+        if Tx::session_vm(vm).is_none() {
+            // The state of the session is kept by the new interpreter.
+            let storage = vm.vm_storage().clone();
+            *vm = Tx::into_session_vm(session.interpreter(storage));
+        }
+        let vm = Tx::session_vm(vm).expect("The interpreter was created above; qed");
+
+        Self::run(vm, checked_tx, session, session.gas_price)
+    }
+
+    /// Applies the transactions of the historical block preceding the transaction
+    /// with `tx_id`, so it is executed on top of the same state as in the block.
+    /// The transactions are applied without breakpoints.
+    ///
+    /// Returns an error if the state of the session already contains the changes
+    /// of the transaction, because the state before it can't be restored
+    /// without resetting the session.
+    fn apply_preceding_txs(
+        vm: &mut SessionVm,
+        session: &mut Session,
+        tx_id: &TxId,
     ) -> anyhow::Result<()> {
-        let vm_database = Self::vm_database(storage)?;
-        let tx = self
-            .tx
-            .get(id)
-            .and_then(|tx| tx.first())
-            .cloned()
-            .unwrap_or(Self::dummy_tx(params.tx_params().max_gas_per_tx() / 2));
+        let chain_id = session.params.chain_id();
+        let Some(block) = session.block.as_mut() else {
+            return Ok(())
+        };
+        let Some(position) = block
+            .transactions
+            .iter()
+            .position(|tx| tx.id(&chain_id) == *tx_id)
+        else {
+            return Ok(())
+        };
+        if position < block.applied {
+            return Err(anyhow!(
+                "The transaction {tx_id} was already applied to the state of the session, \
+                reset the session to execute it again"
+            ))
+        }
+        let preceding = block
+            .transactions
+            .get(block.applied..position)
+            .unwrap_or_default()
+            .to_vec();
+        block.applied = position.saturating_add(1);
+        if preceding.is_empty() {
+            return Ok(())
+        }
 
-        let checked_tx = tx
-            .into_checked_basic(vm_database.block_height()?, &params)
-            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        let mut storage = vm.vm_storage().clone();
+        for tx in preceding {
+            let checked_tx: CheckedTransaction = tx
+                .into_checked_basic(storage.block_height()?, &session.params)
+                .map_err(|e| anyhow!("{:?}", e))?
+                .into();
+            storage = match checked_tx {
+                CheckedTransaction::Script(tx) => Self::apply(storage, tx, session)?,
+                CheckedTransaction::Create(tx) => Self::apply(storage, tx, session)?,
+                CheckedTransaction::Upgrade(tx) => Self::apply(storage, tx, session)?,
+                CheckedTransaction::Upload(tx) => Self::apply(storage, tx, session)?,
+                CheckedTransaction::Blob(tx) => Self::apply(storage, tx, session)?,
+                CheckedTransaction::Mint(_) => storage,
+            };
+        }
+        *vm = SessionVm::Script(session.interpreter(storage));
 
-        let gas_costs = params.gas_costs();
-        let fee_params = params.fee_params();
+        Ok(())
+    }
 
+    /// Executes the transaction and returns the storage with its changes.
+    /// The changes are discarded if the transaction reverts.
+    fn apply<Tx>(
+        storage: FrozenDatabase,
+        checked_tx: Checked<Tx>,
+        session: &Session,
+    ) -> anyhow::Result<FrozenDatabase>
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
         let ready_tx = checked_tx
             .into_ready(
-                GAS_PRICE,
-                gas_costs,
-                fee_params,
-                Some(vm_database.block_height()?),
+                session.gas_price,
+                session.params.gas_costs(),
+                session.params.fee_params(),
+                Some(storage.block_height()?),
             )
             .map_err(|e| {
                 anyhow!("Failed to apply dynamic values to checked tx: {:?}", e)
             })?;
-
-        let interpreter_params = InterpreterParams::new(GAS_PRICE, params.as_ref());
-        let mut vm = Interpreter::with_storage(
+        let mut vm: DebugVm<Tx> = Interpreter::with_storage(
             MemoryInstance::new(),
-            vm_database,
-            interpreter_params,
+            storage.clone(),
+            session.interpreter_params(),
         );
-        vm.transact(ready_tx).map_err(|e| anyhow::anyhow!(e))?;
-        self.vm.insert(id.clone(), vm).ok_or_else(|| {
-            io::Error::new(io::ErrorKind::NotFound, "The VM instance was not found")
-        })?;
-        Ok(())
+        let reverted = vm
+            .transact(ready_tx)
+            .map_err(|err| anyhow!("Transaction failed: {err:?}"))?
+            .should_revert();
+
+        if reverted {
+            Ok(storage)
+        } else {
+            Ok(vm.as_ref().clone())
+        }
     }
 
-    pub fn exec(&mut self, id: &ID, op: Instruction) -> anyhow::Result<()> {
-        self.vm
+    fn run<Tx>(
+        vm: &mut DebugVm<Tx>,
+        checked_tx: Checked<Tx>,
+        session: &Session,
+        gas_price: Word,
+    ) -> anyhow::Result<gql_types::RunResult>
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        vm.set_gas_price(gas_price);
+        let ready_tx = checked_tx
+            .into_ready(
+                gas_price,
+                session.params.gas_costs(),
+                session.params.fee_params(),
+                Some(vm.as_ref().block_height()?),
+            )
+            .map_err(|e| {
+                anyhow!("Failed to apply dynamic values to checked tx: {:?}", e)
+            })?;
+        let state_ref = vm
+            .transact(ready_tx)
+            .map_err(|err| anyhow!("Transaction failed: {err:?}"))?;
+
+        Ok(gql_types::RunResult::new(
+            state_ref.state(),
+            state_ref.receipts().iter(),
+        ))
+    }
+
+    fn session_mut(&mut self, id: &ID) -> anyhow::Result<(&mut SessionVm, &mut Session)> {
+        let vm = self
+            .vm
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("The VM instance was not found"))?;
+        let session = self
+            .session
             .get_mut(id)
-            .map(|vm| vm.instruction::<_, false>(op))
-            .transpose()
-            .map_err(|e| anyhow::anyhow!(e))?
-            .map(|_| ())
-            .ok_or_else(|| anyhow::anyhow!("The VM instance was not found"))
+            .ok_or_else(|| anyhow!("The VM instance was not found"))?;
+        Ok((vm, session))
     }
 
-    fn vm_database(storage: &Database<OnChain>) -> anyhow::Result<FrozenDatabase> {
+    fn predicate_vm(&self, id: &ID) -> Option<&PredicateSessionVm> {
+        self.session
+            .get(id)
+            .and_then(|session| session.pending.as_ref())
+            .and_then(|tx| tx.vm.as_ref())
+    }
+
+    fn predicate_vm_mut(&mut self, id: &ID) -> Option<&mut PredicateSessionVm> {
+        self.session
+            .get_mut(id)
+            .and_then(|session| session.pending.as_mut())
+            .and_then(|tx| tx.vm.as_mut())
+    }
+
+    fn dummy_vm(
+        session: &Session,
+        vm_database: FrozenDatabase,
+    ) -> anyhow::Result<SessionVm> {
+        let params = session.params.as_ref();
+        let tx = Self::dummy_tx(params.tx_params().max_gas_per_tx() / 2);
+        let checked_tx = tx
+            .into_checked_basic(vm_database.block_height()?, params)
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        // The dummy transaction doesn't pay the fee, so it is executed with
+        // the zero gas price.
+        let mut vm = session.interpreter(vm_database);
+        Self::run(&mut vm, checked_tx, session, GAS_PRICE)?;
+        Ok(SessionVm::Script(vm))
+    }
+
+    /// Returns the state and the block the session is forked at.
+    /// For the historical session, it is the state before the block at `height`.
+    fn fork(
+        storage: &Database<OnChain>,
+        height: Option<BlockHeight>,
+    ) -> anyhow::Result<(OnChainKeyValueView, CompressedBlock)> {
         let view = storage.latest_view()?;
+
+        let Some(height) = height else {
+            let block = view
+                .get_current_block()?
+                .ok_or(not_found!("Block for VMDatabase"))?;
+            return Ok((view.into_key_value_view(), block))
+        };
+
         let block = view
-            .get_current_block()?
-            .ok_or(not_found!("Block for VMDatabase"))?;
+            .storage::<FuelBlocks>()
+            .get(&height)?
+            .ok_or(not_found!(FuelBlocks))?
+            .into_owned();
+        let previous_height = height
+            .pred()
+            .ok_or_else(|| anyhow!("The genesis block can't be re-executed"))?;
+        let view = storage.view_at(&previous_height)?;
+
+        Ok((view, block))
+    }
 
+    /// Returns the transactions of the block, except the `Mint`, and the `Mint` itself.
+    fn block_transactions(
+        storage: &Database<OnChain>,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<(Vec<Transaction>, Mint)> {
+        let view = storage.latest_view()?;
+        let mut transactions = block
+            .transactions()
+            .iter()
+            .map(|tx_id| {
+                let tx = view
+                    .storage::<Transactions>()
+                    .get(tx_id)?
+                    .ok_or(not_found!(Transactions))?
+                    .into_owned();
+                Ok(tx)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let Some(Transaction::Mint(mint)) = transactions.pop() else {
+            return Err(anyhow!("The last transaction of the block is not a `Mint`"))
+        };
+        Ok((transactions, mint))
+    }
+
+    fn vm_database(
+        view: OnChainKeyValueView,
+        block: &CompressedBlock,
+        coinbase: &ContractId,
+    ) -> FrozenDatabase {
         let application_header = block.header().as_empty_application_header();
 
-        let vm_database = VmStorage::new(
+        VmStorage::new(
             view.into_transaction(),
             block.header().consensus(),
             &application_header,
-            // TODO: Use a real coinbase address
-            Default::default(),
-        );
-
-        Ok(vm_database)
+            *coinbase,
+        )
     }
 
     fn dummy_tx(gas_limit: u64) -> Script {
@@ -261,6 +969,19 @@ impl ConcreteStorage {
     }
 }
 
+/// Returns the indexes of the inputs with predicates.
+fn predicate_inputs<Tx>(tx: &Checked<Tx>, params: &ConsensusParameters) -> VecDeque<usize>
+where
+    Tx: ExecutableTransaction,
+{
+    let tx = tx.transaction();
+    (0..tx.inputs().len())
+        .filter(|idx| {
+            RuntimePredicate::from_tx(tx, params.tx_params().tx_offset(), *idx).is_some()
+        })
+        .collect()
+}
+
 pub type GraphStorage = sync::Arc<Mutex<ConcreteStorage>>;
 
 #[derive(Default)]
@@ -319,20 +1040,33 @@ impl DapMutation {
     /// A new VM instance is spawned for each session.
     /// The session is run in a separate database transaction,
     /// on top of the most recent node state.
-    async fn start_session(&self, ctx: &Context<'_>) -> async_graphql::Result<ID> {
+    ///
+    /// If `block_height` is provided, the session is forked from the state
+    /// before the block at this height, allowing re-execution of its transactions
+    /// as they were executed in the block. The transactions use the gas price and
+    /// the coinbase recipient of the block, and the transactions of the block
+    /// preceding the started one are applied before it.
+    /// Requires historical execution config to be enabled.
+    async fn start_session(
+        &self,
+        ctx: &Context<'_>,
+        block_height: Option<U32>,
+    ) -> async_graphql::Result<ID> {
         require_debug(ctx)?;
+        if block_height.is_some() {
+            require_historical_execution(ctx)?;
+        }
         trace!("Initializing new interpreter");
 
         let db = ctx.data_unchecked::<Database>();
-        let params = ctx
-            .data_unchecked::<ChainInfoProvider>()
-            .current_consensus_params();
+        let chain_info = ctx.data_unchecked::<ChainInfoProvider>();
 
-        let id =
-            ctx.data_unchecked::<GraphStorage>()
-                .lock()
-                .await
-                .init(&[], params, db)?;
+        let id = ctx.data_unchecked::<GraphStorage>().lock().await.init(
+            &[],
+            chain_info,
+            db,
+            block_height.map(Into::into),
+        )?;
 
         debug!("Session {:?} initialized", id);
 
@@ -357,14 +1091,11 @@ impl DapMutation {
     async fn reset(&self, ctx: &Context<'_>, id: ID) -> async_graphql::Result<bool> {
         require_debug(ctx)?;
         let db = ctx.data_unchecked::<Database>();
-        let params = ctx
-            .data_unchecked::<ChainInfoProvider>()
-            .current_consensus_params();
 
         ctx.data_unchecked::<GraphStorage>()
             .lock()
             .await
-            .reset(&id, params, db)?;
+            .reset(&id, db)?;
 
         debug!("Session {:?} was reset", id);
 
@@ -403,13 +1134,11 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Set single stepping to {} for VM {:?}", enable, id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
-
-        vm.set_single_stepping(enable);
+        ctx.data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .set_single_stepping(&id, enable)
+            .map_err(|_| async_graphql::Error::new("VM not found"))?;
         Ok(enable)
     }
 
@@ -423,18 +1152,20 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Set breakpoint for VM {:?}", id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
-
-        vm.set_breakpoint(breakpoint.into());
+        ctx.data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .set_breakpoint(&id, breakpoint)
+            .map_err(|_| async_graphql::Error::new("VM not found"))?;
         Ok(true)
     }
 
     /// Run a single transaction in given session until it
     /// hits a breakpoint or completes.
+    ///
+    /// The predicates of the transaction are evaluated before its execution,
+    /// and breakpoints are hit inside of them as well.
+    /// `Mint` transactions are not supported.
     async fn start_tx(
         &self,
         ctx: &Context<'_>,
@@ -446,78 +1177,15 @@ impl DapMutation {
 
         let tx: Transaction = serde_json::from_str(&tx_json)
             .map_err(|_| async_graphql::Error::new("Invalid transaction JSON"))?;
+        let db = ctx.data_unchecked::<Database>();
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let params = ctx
-            .data_unchecked::<ChainInfoProvider>()
-            .current_consensus_params();
-
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
-
-        let checked_tx = tx
-            .into_checked_basic(vm.as_ref().block_height()?, &params)
-            .map_err(|err| anyhow::anyhow!("{:?}", err))?
-            .into();
+        let result = ctx
+            .data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .start_tx(&id, tx, db)?;
 
-        let gas_costs = params.gas_costs();
-        let fee_params = params.fee_params();
-
-        match checked_tx {
-            CheckedTransaction::Script(script) => {
-                let ready_tx = script
-                    .into_ready(
-                        GAS_PRICE,
-                        gas_costs,
-                        fee_params,
-                        Some(vm.as_ref().block_height()?),
-                    )
-                    .map_err(|e| {
-                        anyhow!("Failed to apply dynamic values to checked tx: {:?}", e)
-                    })?;
-                let state_ref = vm.transact(ready_tx).map_err(|err| {
-                    async_graphql::Error::new(format!("Transaction failed: {err:?}"))
-                })?;
-
-                let json_receipts = state_ref
-                    .receipts()
-                    .iter()
-                    .map(|r| {
-                        serde_json::to_string(&r).expect("JSON serialization failed")
-                    })
-                    .collect();
-
-                let dbgref = state_ref.state().debug_ref();
-                Ok(gql_types::RunResult {
-                    state: match dbgref {
-                        Some(_) => gql_types::RunState::Breakpoint,
-                        None => gql_types::RunState::Completed,
-                    },
-                    breakpoint: dbgref.and_then(|d| match d {
-                        DebugEval::Continue => None,
-                        DebugEval::Breakpoint(bp) => Some(bp.into()),
-                    }),
-                    json_receipts,
-                })
-            }
-            CheckedTransaction::Create(_) => {
-                Err(async_graphql::Error::new("`Create` is not supported"))
-            }
-            CheckedTransaction::Mint(_) => {
-                Err(async_graphql::Error::new("`Mint` is not supported"))
-            }
-            CheckedTransaction::Upgrade(_) => {
-                Err(async_graphql::Error::new("`Upgrade` is not supported"))
-            }
-            CheckedTransaction::Upload(_) => {
-                Err(async_graphql::Error::new("`Upload` is not supported"))
-            }
-            CheckedTransaction::Blob(_) => {
-                Err(async_graphql::Error::new("`Blob` is not supported"))
-            }
-        }
+        Ok(result)
     }
 
     /// Resume execution of the VM instance after a breakpoint.
@@ -530,50 +1198,15 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Continue execution of VM {:?}", id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
-
-        let receipt_count_before = vm.receipts().len();
+        let db = ctx.data_unchecked::<Database>();
 
-        let state = match vm.resume() {
-            Ok(state) => state,
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(InterpreterError::DebugStateNotInitialized) => {
-                return Ok(gql_types::RunResult {
-                    state: gql_types::RunState::Completed,
-                    breakpoint: None,
-                    json_receipts: Vec::new(),
-                })
-            }
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(err) => {
-                return Err(async_graphql::Error::new(format!("VM error: {err:?}")))
-            }
-        };
+        let result = ctx
+            .data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .continue_tx(&id, db)?;
 
-        let json_receipts = vm
-            .receipts()
-            .iter()
-            .skip(receipt_count_before)
-            .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
-            .collect();
-
-        let dbgref = state.debug_ref();
-
-        Ok(gql_types::RunResult {
-            state: match dbgref {
-                Some(_) => gql_types::RunState::Breakpoint,
-                None => gql_types::RunState::Completed,
-            },
-            breakpoint: dbgref.and_then(|d| match d {
-                DebugEval::Continue => None,
-                DebugEval::Breakpoint(bp) => Some(bp.into()),
-            }),
-            json_receipts,
-        })
+        Ok(result)
     }
 }
 
@@ -586,7 +1219,14 @@ mod gql_types {
         U64,
     };
 
-    use fuel_core_types::fuel_vm::Breakpoint as FuelBreakpoint;
+    use fuel_core_types::{
+        fuel_tx::Receipt,
+        fuel_vm::{
+            Breakpoint as FuelBreakpoint,
+            ProgramState,
+            state::DebugEval,
+        },
+    };
 
     /// Breakpoint, defined as a tuple of contract ID and relative PC offset inside it
     #[derive(Debug, Clone, Copy, InputObject)]
@@ -641,4 +1281,28 @@ mod gql_types {
         pub breakpoint: Option<OutputBreakpoint>,
         pub json_receipts: Vec<String>,
     }
+
+    impl RunResult {
+        pub fn new<'a>(
+            state: &ProgramState,
+            receipts: impl Iterator<Item = &'a Receipt>,
+        ) -> Self {
+            let json_receipts = receipts
+                .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
+                .collect();
+
+            let dbgref = state.debug_ref();
+            Self {
+                state: match dbgref {
+                    Some(_) => RunState::Breakpoint,
+                    None => RunState::Completed,
+                },
+                breakpoint: dbgref.and_then(|d| match d {
+                    DebugEval::Continue => None,
+                    DebugEval::Breakpoint(bp) => Some(bp.into()),
+                }),
+                json_receipts,
+            }
+        }
+    }
 }
@@ -1,10 +1,17 @@
 use super::scalars::{
+    U8,
     U32,
     U64,
+    U128,
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            ChainInfoProvider,
+            GasPriceHistoryProvider,
+            GasPriceProvider,
+        },
+        database::ReadView,
         query_costs,
     },
     schema::ReadViewProvider,
@@ -13,12 +20,26 @@ use async_graphql::{
     Context,
     Object,
 };
+use fuel_core_gas_price_service::common::{
+    fuel_core_storage_adapter::block_used_gas,
+    updater_metadata::UpdaterMetadata,
+};
 use fuel_core_types::{
-    blockchain::block::Block,
+    blockchain::block::{
+        Block,
+        CompressedBlock,
+    },
     fuel_tx::{
+        Mint,
         Transaction,
-        field::MintGasPrice,
+        field::{
+            MintAmount,
+            MintGasPrice,
+            Policies as _,
+        },
+        policies::PolicyType,
     },
+    fuel_types::BlockHeight,
 };
 
 pub struct LatestGasPrice {
@@ -99,6 +120,242 @@ impl EstimateGasPriceQuery {
     }
 }
 
+/// The max number of blocks that can be requested by the `gasPriceHistory` query.
+pub const GAS_PRICE_HISTORY_MAX_BLOCKS: u32 = 1024;
+
+pub struct BlockFullness {
+    used: U64,
+    capacity: U64,
+}
+
+#[Object]
+impl BlockFullness {
+    /// The approximate gas used by the block.
+    async fn used(&self) -> U64 {
+        self.used
+    }
+
+    /// The gas limit of the block.
+    async fn capacity(&self) -> U64 {
+        self.capacity
+    }
+}
+
+pub struct BlockGasPrice {
+    height: BlockHeight,
+    /// The state of the algorithm after the previous block. It defines the prices of the block.
+    previous: Option<UpdaterMetadata>,
+    /// The state of the algorithm after the block.
+    current: Option<UpdaterMetadata>,
+}
+
+#[Object]
+impl BlockGasPrice {
+    /// The height of the L2 block.
+    async fn block_height(&self) -> U32 {
+        u32::from(self.height).into()
+    }
+
+    /// The execution gas price of the block, as charged by its mint transaction.
+    #[graphql(complexity = "query_costs().block_header + query_costs().tx_get")]
+    async fn exec_gas_price(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        let query = ctx.read_view()?;
+        let (_, mint) = block_with_mint(&query, &self.height)?;
+        Ok((*mint.gas_price()).into())
+    }
+
+    /// The DA gas price of the block.
+    async fn da_gas_price(&self) -> Option<U64> {
+        self.previous
+            .as_ref()
+            .and_then(UpdaterMetadata::v1)
+            .map(|v1| v1.new_da_gas_price().into())
+    }
+
+    /// The latest known cost per byte of recording L2 blocks on the DA chain
+    /// after processing the block.
+    async fn da_cost_per_byte(&self) -> Option<U128> {
+        self.current
+            .as_ref()
+            .and_then(UpdaterMetadata::v1)
+            .map(|v1| v1.latest_da_cost_per_byte.into())
+    }
+
+    /// The cost of the DA bundles recorded on the DA chain
+    /// between the previous block and this block.
+    async fn recorded_da_cost(&self) -> Option<U128> {
+        let previous = self.previous.as_ref().and_then(UpdaterMetadata::v1)?;
+        let current = self.current.as_ref().and_then(UpdaterMetadata::v1)?;
+        Some(
+            current
+                .latest_known_total_da_cost
+                .saturating_sub(previous.latest_known_total_da_cost)
+                .into(),
+        )
+    }
+
+    /// The fullness of the block, calculated from the fee of the block
+    /// the same way as the gas price algorithm does.
+    #[graphql(complexity = "query_costs().block_header + query_costs().tx_get")]
+    async fn fullness(&self, ctx: &Context<'_>) -> async_graphql::Result<BlockFullness> {
+        let query = ctx.read_view()?;
+        let (block, mint) = block_with_mint(&query, &self.height)?;
+
+        let consensus_params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .consensus_params_at_version(
+                &block.header().consensus_parameters_version(),
+            )?;
+        let capacity = consensus_params.block_gas_limit();
+        let used = block_used_gas(
+            *mint.mint_amount(),
+            *mint.gas_price(),
+            consensus_params.fee_params().gas_price_factor(),
+            capacity,
+        )?;
+
+        Ok(BlockFullness {
+            used: used.into(),
+            capacity: capacity.into(),
+        })
+    }
+
+    /// The tips of the transactions of the block at the requested percentiles,
+    /// similar to the `reward` of the Ethereum `eth_feeHistory`.
+    /// Percentiles of the block without transactions are zero.
+    #[graphql(complexity = "query_costs().block_transactions")]
+    async fn tip_percentiles(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The percentiles of tips to return, from 0 to 100")]
+        percentiles: Vec<U8>,
+    ) -> async_graphql::Result<Vec<U64>> {
+        if let Some(percentile) = percentiles.iter().find(|p| p.0 > 100) {
+            return Err(async_graphql::Error::new(format!(
+                "Invalid percentile {}. It should be from 0 to 100",
+                percentile.0
+            )))
+        }
+
+        let query = ctx.read_view()?;
+        let block = query.block(&self.height)?;
+        let txs = query.transactions(block.transactions().to_vec()).await;
+
+        let mut tips = Vec::with_capacity(txs.len());
+        for tx in txs {
+            if let Some(tip) = transaction_tip(&tx?) {
+                tips.push(tip);
+            }
+        }
+        tips.sort_unstable();
+
+        Ok(percentiles
+            .into_iter()
+            .map(|percentile| tip_at_percentile(&tips, percentile.0).into())
+            .collect())
+    }
+}
+
+/// Returns the block at the `height` along with its mint transaction.
+fn block_with_mint(
+    query: &ReadView,
+    height: &BlockHeight,
+) -> async_graphql::Result<(CompressedBlock, Mint)> {
+    let block = query.block(height)?;
+    let mint_id = block
+        .transactions()
+        .last()
+        .ok_or(async_graphql::Error::new(
+            "The block has no mint transaction",
+        ))?;
+    let Transaction::Mint(mint) = query.transaction(mint_id)? else {
+        return Err(async_graphql::Error::new(
+            "The last transaction of the block is not a mint transaction",
+        ))
+    };
+    Ok((block, mint))
+}
+
+fn transaction_tip(tx: &Transaction) -> Option<u64> {
+    let policies = match tx {
+        Transaction::Script(tx) => tx.policies(),
+        Transaction::Create(tx) => tx.policies(),
+        Transaction::Upgrade(tx) => tx.policies(),
+        Transaction::Upload(tx) => tx.policies(),
+        Transaction::Blob(tx) => tx.policies(),
+        Transaction::Mint(_) => return None,
+    };
+    Some(policies.get(PolicyType::Tip).unwrap_or_default())
+}
+
+/// Returns the tip at the percentile using the nearest-rank method.
+fn tip_at_percentile(sorted_tips: &[u64], percentile: u8) -> u64 {
+    let rank = sorted_tips
+        .len()
+        .saturating_mul(usize::from(percentile))
+        .div_ceil(100);
+    let index = rank.saturating_sub(1);
+    sorted_tips.get(index).copied().unwrap_or_default()
+}
+
+#[derive(Default)]
+pub struct GasPriceHistoryQuery {}
+
+#[Object]
+impl GasPriceHistoryQuery {
+    /// Returns the gas prices, fullness and DA costs of the L2 blocks in the range.
+    /// The range is clamped to the latest block.
+    // The complexity can't access the latest block height, so the range is clamped
+    // to the largest range accepted by the resolver instead.
+    #[graphql(complexity = "{\
+        (query_costs().storage_read + child_complexity) \
+        * (to.0.saturating_sub(from.0) as usize + 1) \
+            .min(GAS_PRICE_HISTORY_MAX_BLOCKS as usize) \
+    }")]
+    async fn gas_price_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the first block")] from: U32,
+        #[graphql(desc = "The height of the last block, inclusive")] to: U32,
+    ) -> async_graphql::Result<Vec<BlockGasPrice>> {
+        let query = ctx.read_view()?;
+        let latest_block_height: u32 = query.latest_block_height()?.into();
+        let from = from.0;
+        let to = to.0.min(latest_block_height);
+
+        if from > to {
+            return Err(async_graphql::Error::new(format!(
+                "Invalid range. The first block {from} is after the last block {to}"
+            )))
+        }
+        if to.saturating_sub(from) >= GAS_PRICE_HISTORY_MAX_BLOCKS {
+            return Err(async_graphql::Error::new(format!(
+                "Invalid range. At most {GAS_PRICE_HISTORY_MAX_BLOCKS} blocks can be requested"
+            )))
+        }
+
+        let history = ctx.data_unchecked::<GasPriceHistoryProvider>();
+        let mut previous = match BlockHeight::from(from).pred() {
+            Some(height) => history.gas_price_metadata(&height)?,
+            None => None,
+        };
+
+        let mut blocks = Vec::new();
+        for height in from..=to {
+            let height = BlockHeight::from(height);
+            let current = history.gas_price_metadata(&height)?;
+            blocks.push(BlockGasPrice {
+                height,
+                previous: previous.take(),
+                current: current.clone(),
+            });
+            previous = current;
+        }
+
+        Ok(blocks)
+    }
+}
+
 pub trait EstimateGasPriceExt {
     fn estimate_gas_price(
         &self,
@@ -130,3 +387,20 @@ impl EstimateGasPriceExt for Context<'_> {
         Ok(gas_price)
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[], 50 => 0; "empty block")]
+    #[test_case(&[10], 0 => 10; "zero percentile")]
+    #[test_case(&[10, 20, 30, 40], 25 => 10; "lower quartile")]
+    #[test_case(&[10, 20, 30, 40], 50 => 20; "median")]
+    #[test_case(&[10, 20, 30, 40], 51 => 30; "above median")]
+    #[test_case(&[10, 20, 30, 40], 100 => 40; "max")]
+    fn tip_at_percentile__uses_nearest_rank(tips: &[u64], percentile: u8) -> u64 {
+        tip_at_percentile(tips, percentile)
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::ReadDatabase,
+        block_height_subscription,
+        query_costs,
+    },
+    graphql_api::storage::logs::{
+        IndexedLog,
+        LogKey,
+    },
+    query::LogFilter,
+    schema::{
+        ReadViewProvider,
+        scalars::{
+            ContractId,
+            LogCursor,
+            TransactionId,
+            U16,
+            U32,
+            U64,
+        },
+        tx::receipt::Receipt,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+    Subscription,
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+};
+use fuel_core_storage::iter::IterDirection;
+use futures::{
+    Stream,
+    StreamExt,
+    TryStreamExt,
+};
+
+#[derive(InputObject)]
+pub struct LogFilterInput {
+    /// The contracts that emitted the logs.
+    contracts: Vec<ContractId>,
+    /// The ids of the logs, the value of the `rb` register of the `LOG` and `LOGD` instructions.
+    /// If not specified, the logs with any id are returned.
+    log_ids: Option<Vec<U64>>,
+    /// The first height of the range, inclusive.
+    from_height: Option<U32>,
+    /// The last height of the range, inclusive.
+    to_height: Option<U32>,
+}
+
+impl TryFrom<LogFilterInput> for LogFilter {
+    type Error = async_graphql::Error;
+
+    fn try_from(input: LogFilterInput) -> Result<Self, Self::Error> {
+        if input.contracts.is_empty() {
+            return Err(anyhow!("The filter should contain at least one contract").into())
+        }
+        let filter = LogFilter {
+            contracts: input.contracts.into_iter().map(Into::into).collect(),
+            log_ids: input
+                .log_ids
+                .map(|log_ids| log_ids.into_iter().map(Into::into).collect()),
+            from_height: input.from_height.map(Into::into),
+            to_height: input.to_height.map(Into::into),
+        };
+        if let (Some(from_height), Some(to_height)) =
+            (filter.from_height, filter.to_height)
+        {
+            if from_height > to_height {
+                return Err(anyhow!(
+                    "The `fromHeight` {from_height} is greater than the `toHeight` {to_height}"
+                )
+                .into())
+            }
+        }
+        Ok(filter)
+    }
+}
+
+pub struct Log {
+    key: LogKey,
+    log: IndexedLog,
+}
+
+impl From<(LogKey, IndexedLog)> for Log {
+    fn from((key, log): (LogKey, IndexedLog)) -> Self {
+        Self { key, log }
+    }
+}
+
+#[Object]
+impl Log {
+    /// The contract that emitted the log.
+    async fn contract_id(&self) -> ContractId {
+        self.key.contract_id.into()
+    }
+
+    /// The id of the log, the value of the `rb` register.
+    async fn log_id(&self) -> U64 {
+        self.key.log_id.into()
+    }
+
+    async fn block_height(&self) -> U32 {
+        self.key.pointer.block_height.into()
+    }
+
+    /// The index of the transaction in the block.
+    async fn tx_index(&self) -> U16 {
+        self.key.pointer.tx_idx.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.log.tx_id.into()
+    }
+
+    /// The index of the receipt in the receipts of the transaction.
+    async fn receipt_index(&self) -> U16 {
+        self.key.pointer.receipt_idx.into()
+    }
+
+    /// The `Log` or `LogData` receipt.
+    async fn receipt(&self) -> Receipt {
+        self.log.receipt.clone().into()
+    }
+}
+
+#[derive(Default)]
+pub struct LogQuery;
+
+#[Object]
+impl LogQuery {
+    /// Returns the logs emitted by the `filter.contracts`, ordered by the position in the chain.
+    /// Requires the logs indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn logs(
+        &self,
+        ctx: &Context<'_>,
+        filter: LogFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<LogCursor, Log, EmptyFields, EmptyFields>> {
+        let query = ctx.read_view()?;
+        let filter = LogFilter::try_from(filter)?;
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<LogCursor>, direction| {
+                let logs = query
+                    .logs(&filter, (*start).map(Into::into), direction)?
+                    .map(|result| {
+                        result.map(|(key, log)| (key.pointer.into(), (key, log).into()))
+                    });
+                Ok(logs)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct LogSubscription;
+
+#[Subscription]
+impl LogSubscription {
+    /// Returns a stream of the logs emitted by the `filter.contracts` in the imported blocks.
+    ///
+    /// If `filter.fromHeight` is provided, the stream first replays the stored logs
+    /// starting from this height and then continues with the logs of new blocks without gaps.
+    /// Otherwise, the stream starts with the logs of the next imported block.
+    /// The stream ends after the block at `filter.toHeight`, if it is specified.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn logs<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        filter: LogFilterInput,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Log>> + 'a + use<'a>,
+    > {
+        let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let filter = LogFilter::try_from(filter)?;
+
+        let first_height = match filter.from_height {
+            Some(height) => height,
+            None => subscriber
+                .current_block_height()
+                .succ()
+                .ok_or_else(|| anyhow!("The maximum block height is reached"))?,
+        };
+        let to_height = filter.to_height;
+
+        let stream = futures::stream::unfold(Some(first_height), move |height| {
+            let filter = filter.clone();
+            async move {
+                let height =
+                    height.filter(|height| to_height.is_none_or(|to| *height <= to))?;
+                let result = async {
+                    subscriber.wait_for_block_height(height).await?;
+                    let filter = LogFilter {
+                        from_height: Some(height),
+                        to_height: Some(height),
+                        ..filter
+                    };
+                    let view = database.view()?;
+                    let logs = view
+                        .logs(&filter, None, IterDirection::Forward)?
+                        .try_collect::<Vec<_>>()
+                        .await?;
+                    Ok::<_, async_graphql::Error>(logs)
+                }
+                .await;
+
+                match result {
+                    Ok(logs) => Some((Ok(logs), height.succ())),
+                    Err(err) => Some((Err(err), None)),
+                }
+            }
+        });
+
+        Ok(stream.flat_map(|result| {
+            let logs = match result {
+                Ok(logs) => logs.into_iter().map(|log| Ok(log.into())).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            futures::stream::iter(logs)
+        }))
+    }
+}
@@ -200,4 +200,14 @@ impl IndexationFlags {
     async fn asset_metadata(&self) -> bool {
         self.contains(&IndexationKind::AssetMetadata)
     }
+
+    /// Is transactions by contract indexation enabled
+    async fn transactions_by_contract(&self) -> bool {
+        self.contains(&IndexationKind::TransactionsByContract)
+    }
+
+    /// Is logs indexation enabled
+    async fn logs(&self) -> bool {
+        self.contains(&IndexationKind::Logs)
+    }
 }
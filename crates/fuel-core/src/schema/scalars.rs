@@ -1,3 +1,4 @@
+use crate::graphql_api::storage::logs::LogPointer;
 use async_graphql::{
     InputValueError,
     InputValueResult,
@@ -160,6 +161,39 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The position of the log in the chain: `block_height#tx_index#receipt_index`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, derive_more::Into, derive_more::From)]
+pub struct LogCursor(pub LogPointer);
+
+impl CursorType for LogCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(block_height), Some(tx_idx), Some(receipt_idx), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self(LogPointer::new(
+            BlockHeight::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?,
+            tx_idx.parse().map_err(|_| "Failed to decode tx_index")?,
+            receipt_idx
+                .parse()
+                .map_err(|_| "Failed to decode receipt_index")?,
+        )))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!(
+            "{}#{}#{}",
+            self.0.block_height, self.0.tx_idx, self.0.receipt_idx
+        )
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -361,4 +395,18 @@ mod tests {
         let res = HexString::from_str(hex_data);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn log_cursor_encodes_and_decodes() {
+        let cursor = LogCursor(LogPointer::new(10.into(), 2, 3));
+        let encoded = cursor.encode_cursor();
+        assert_eq!(encoded, "10#2#3");
+        assert_eq!(LogCursor::decode_cursor(&encoded), Ok(cursor));
+    }
+
+    #[test]
+    fn log_cursor_only_parses_three_parts() {
+        assert!(LogCursor::decode_cursor("10#2").is_err());
+        assert!(LogCursor::decode_cursor("10#2#3#4").is_err());
+    }
 }
@@ -1,5 +1,12 @@
 use crate::{
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::{
+        database::{
+            ReadView,
+            ReadViewAt,
+        },
+        ports,
+        query_costs,
+    },
     graphql_api::{
         api_service::ReadDatabase,
         require_historical_execution,
@@ -21,7 +28,14 @@ use async_graphql::{
     Subscription,
 };
 use fuel_core_services::stream::Stream;
-use fuel_core_types::fuel_types;
+use fuel_core_types::{
+    fuel_merkle::sparse::proof::{
+        ExclusionLeaf,
+        ExclusionLeafData,
+        Proof,
+    },
+    fuel_types,
+};
 use futures::{
     StreamExt,
     TryStreamExt,
@@ -98,6 +112,56 @@ impl StorageQuery {
 
         Ok(stream)
     }
+
+    /// Get the sparse merkle proof of the contract storage slot at a specific block height.
+    /// The proof is an exclusion proof if the slot is not set.
+    /// Use the latest block height if not provided.
+    /// Requires historical execution config to be enabled.
+    #[graphql(complexity = "256 * query_costs().storage_read + child_complexity")]
+    async fn contract_slot_proof(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        key: Bytes32,
+        height: Option<U32>,
+    ) -> async_graphql::Result<ContractStorageProof> {
+        let view_at = view_at(ctx, height)?;
+        let proof = view_at.contract_slot_proof(contract.into(), key.into())?;
+
+        Ok(ContractStorageProof(proof))
+    }
+
+    /// Get the sparse merkle proof of the contract balance at a specific block height.
+    /// The proof is an exclusion proof if the contract doesn't have the asset.
+    /// Use the latest block height if not provided.
+    /// Requires historical execution config to be enabled.
+    #[graphql(complexity = "256 * query_costs().storage_read + child_complexity")]
+    async fn contract_balance_proof(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        asset: AssetId,
+        height: Option<U32>,
+    ) -> async_graphql::Result<ContractStorageProof> {
+        let view_at = view_at(ctx, height)?;
+        let proof = view_at.contract_balance_proof(contract.into(), asset.into())?;
+
+        Ok(ContractStorageProof(proof))
+    }
+}
+
+fn view_at(ctx: &Context<'_>, height: Option<U32>) -> async_graphql::Result<ReadViewAt> {
+    require_historical_execution(ctx)?;
+
+    let view_block_height = if let Some(height) = height {
+        height.0.into()
+    } else {
+        let read_view: &ReadView = ctx.data_unchecked();
+        read_view.latest_height()?
+    };
+
+    let read_database: &ReadDatabase = ctx.data_unchecked();
+    Ok(read_database.view_at(view_block_height)?)
 }
 
 #[derive(Default)]
@@ -164,3 +228,58 @@ impl StorageSlot {
         HexString(self.value.clone())
     }
 }
+
+pub struct ContractStorageProof(ports::ContractStorageProof);
+
+#[Object]
+impl ContractStorageProof {
+    /// The root of the sparse merkle tree of the contract at the requested height.
+    async fn root(&self) -> Bytes32 {
+        self.0.root.into()
+    }
+
+    /// The encoded value of the leaf. `null` for exclusion proofs.
+    async fn value(&self) -> Option<HexString> {
+        self.0.value.clone().map(HexString)
+    }
+
+    /// The hashes of the siblings on the path from the leaf to the root.
+    async fn proof_set(&self) -> Vec<Bytes32> {
+        let proof_set = match &self.0.proof {
+            Proof::Inclusion(proof) => &proof.proof_set,
+            Proof::Exclusion(proof) => &proof.proof_set,
+        };
+        proof_set
+            .iter()
+            .cloned()
+            .map(|array| Bytes32::from(fuel_types::Bytes32::from(array)))
+            .collect()
+    }
+
+    /// The leaf found on the path of the requested key by exclusion proofs.
+    /// `null` for inclusion proofs or if the path ends with an empty subtree.
+    async fn exclusion_leaf(&self) -> Option<SparseMerkleLeaf> {
+        match &self.0.proof {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => Some(SparseMerkleLeaf(leaf.clone())),
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+}
+
+pub struct SparseMerkleLeaf(ExclusionLeafData);
+
+#[Object]
+impl SparseMerkleLeaf {
+    /// The merkle tree key of the leaf.
+    async fn leaf_key(&self) -> Bytes32 {
+        Bytes32::from(fuel_types::Bytes32::from(self.0.leaf_key))
+    }
+
+    /// The hash of the leaf value.
+    async fn leaf_value(&self) -> Bytes32 {
+        Bytes32::from(fuel_types::Bytes32::from(self.0.leaf_value))
+    }
+}
@@ -6,6 +6,7 @@ use super::scalars::{
 };
 use crate::{
     coins_query::CoinsQueryError,
+    database::database_description::IndexationKind,
     fuel_core_graphql_api::{
         Config as GraphQLConfig,
         IntoApiResult,
@@ -32,6 +33,7 @@ use crate::{
         gas_price::EstimateGasPriceExt,
         scalars::{
             Address,
+            ContractId,
             HexString,
             SortedTxCursor,
             TransactionId,
@@ -42,6 +44,7 @@ use crate::{
                 AssembleArguments,
                 AssembleTx,
             },
+            overrides::DryRunOverridesInput,
             types::{
                 AssembleTransactionResult,
                 TransactionStatus,
@@ -86,7 +89,10 @@ use fuel_core_types::{
         EstimatePredicates,
     },
     services::{
-        executor::DryRunResult,
+        executor::{
+            DryRunOverrides,
+            DryRunResult,
+        },
         transaction_status,
     },
 };
@@ -110,6 +116,7 @@ use types::{
 mod assemble_tx;
 pub mod input;
 pub mod output;
+mod overrides;
 pub mod receipt;
 pub mod types;
 pub mod upgrade_purpose;
@@ -119,6 +126,7 @@ pub struct TxQuery;
 
 impl TxQuery {
     /// The actual logic of all different dry-run queries.
+    #[allow(clippy::too_many_arguments)]
     async fn dry_run_inner(
         &self,
         ctx: &Context<'_>,
@@ -133,6 +141,8 @@ impl TxQuery {
         block_height: Option<U32>,
         // Record storage reads, so this tx can be used with execution tracer in a local debugger.
         record_storage_reads: bool,
+        // The state and header overrides that exist only during the dry run.
+        overrides: DryRunOverrides,
     ) -> async_graphql::Result<DryRunStorageReads> {
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
         let block_producer = ctx.data_unchecked::<BlockProducer>();
@@ -169,10 +179,10 @@ impl TxQuery {
             .dry_run_txs(
                 transactions,
                 block_height.map(|x| x.into()),
-                None, // TODO(#1749): Pass parameter from API
                 utxo_validation,
                 gas_price.map(|x| x.into()),
                 record_storage_reads,
+                overrides,
             )
             .await?;
 
@@ -343,6 +353,61 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the transactions that interacted with the `contract`: used it as an input,
+    /// created it, or called it during the execution.
+    /// Requires the transactions by contract indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        use futures::stream::StreamExt;
+        let query = ctx.read_view()?;
+        if !query
+            .indexation_flags
+            .contains(&IndexationKind::TransactionsByContract)
+        {
+            return Err(anyhow::anyhow!(
+                "The transactions by contract indexation is not available"
+            )
+            .into())
+        }
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let contract_id = contract.into();
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&params.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     /// Assembles the transaction based on the provided requirements.
     /// The return transaction contains:
     /// - Input coins to cover `required_balances`
@@ -487,10 +552,10 @@ impl TxQuery {
             .dry_run_txs(
                 vec![assembled_tx],
                 None,
-                None,
                 Some(false),
                 Some(gas_price),
                 false,
+                Default::default(),
             )
             .await?
             .transactions
@@ -557,9 +622,21 @@ impl TxQuery {
         // This can be used to run the dry-run on top of a past block.
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
+        // Overrides of the state and the block header, applied only during the dry run.
+        // Allows simulating transactions against contracts and coins that don't exist yet.
+        overrides: Option<DryRunOverridesInput>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
+        let overrides = overrides.map(Into::into).unwrap_or_default();
         Ok(self
-            .dry_run_inner(ctx, txs, utxo_validation, gas_price, block_height, false)
+            .dry_run_inner(
+                ctx,
+                txs,
+                utxo_validation,
+                gas_price,
+                block_height,
+                false,
+                overrides,
+            )
             .await?
             .tx_statuses)
     }
@@ -582,8 +659,16 @@ impl TxQuery {
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
     ) -> async_graphql::Result<DryRunStorageReads> {
-        self.dry_run_inner(ctx, txs, utxo_validation, gas_price, block_height, true)
-            .await
+        self.dry_run_inner(
+            ctx,
+            txs,
+            utxo_validation,
+            gas_price,
+            block_height,
+            true,
+            Default::default(),
+        )
+        .await
     }
 
     /// Get execution trace for an already-executed block.
@@ -635,8 +720,16 @@ impl TxMutation {
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
-        TxQuery::dry_run(&TxQuery, ctx, txs, utxo_validation, gas_price, block_height)
-            .await
+        TxQuery::dry_run(
+            &TxQuery,
+            ctx,
+            txs,
+            utxo_validation,
+            gas_price,
+            block_height,
+            None,
+        )
+        .await
     }
 
     /// Submits transaction to the `TxPool`.
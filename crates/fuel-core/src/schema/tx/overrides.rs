@@ -0,0 +1,113 @@
+use crate::schema::scalars::{
+    Address,
+    AssetId,
+    Bytes32,
+    ContractId,
+    HexString,
+    Tai64Timestamp,
+    TxPointer,
+    U32,
+    U64,
+    UtxoId,
+};
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    services::executor::{
+        ContractOverride,
+        DryRunOverrides,
+        StateOverrides,
+    },
+};
+
+/// The overrides applied on top of the state and the block header used by the dry run.
+/// They only exist during the dry run and never affect the real state of the chain.
+#[derive(async_graphql::InputObject)]
+pub struct DryRunOverridesInput {
+    /// The height of the simulated block.
+    pub block_height: Option<U32>,
+    /// The timestamp of the simulated block.
+    pub timestamp: Option<Tai64Timestamp>,
+    /// The overrides of the contracts.
+    #[graphql(default)]
+    pub contracts: Vec<ContractOverrideInput>,
+    /// The coins that exist only during the dry run.
+    #[graphql(default)]
+    pub coins: Vec<CoinOverrideInput>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractOverrideInput {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The bytecode replacing the bytecode of the contract.
+    /// The contract is created if it doesn't exist.
+    pub bytecode: Option<HexString>,
+    /// The storage slots set on top of the storage of the contract.
+    #[graphql(default)]
+    pub storage_slots: Vec<StorageSlotOverrideInput>,
+    /// The balances set on top of the balances of the contract.
+    #[graphql(default)]
+    pub balances: Vec<BalanceOverrideInput>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct StorageSlotOverrideInput {
+    pub key: Bytes32,
+    pub value: HexString,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct BalanceOverrideInput {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub tx_pointer: Option<TxPointer>,
+}
+
+impl From<DryRunOverridesInput> for DryRunOverrides {
+    fn from(overrides: DryRunOverridesInput) -> Self {
+        let contracts = overrides
+            .contracts
+            .into_iter()
+            .map(|contract| ContractOverride {
+                contract_id: contract.contract_id.into(),
+                bytecode: contract.bytecode.map(|bytecode| bytecode.0),
+                storage_slots: contract
+                    .storage_slots
+                    .into_iter()
+                    .map(|slot| (slot.key.into(), slot.value.0))
+                    .collect(),
+                balances: contract
+                    .balances
+                    .into_iter()
+                    .map(|balance| (balance.asset_id.into(), balance.amount.into()))
+                    .collect(),
+            })
+            .collect();
+
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| Coin {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                tx_pointer: coin.tx_pointer.map(Into::into).unwrap_or_default(),
+            })
+            .collect();
+
+        DryRunOverrides {
+            block_height: overrides.block_height.map(|height| height.0.into()),
+            timestamp: overrides.timestamp.map(|timestamp| timestamp.0),
+            state: StateOverrides { contracts, coins },
+        }
+    }
+}
@@ -147,6 +147,7 @@ pub enum TransactionStatus {
     Success(SuccessStatus),
     PreconfirmationSuccess(PreconfirmationSuccessStatus),
     SqueezedOut(SqueezedOutStatus),
+    Replaced(ReplacedStatus),
     Failure(FailureStatus),
     PreconfirmationFailure(PreconfirmationFailureStatus),
 }
@@ -403,6 +404,28 @@ impl SqueezedOutStatus {
     }
 }
 
+#[derive(Debug)]
+pub struct ReplacedStatus {
+    pub tx_id: TxId,
+    pub status: Arc<transaction_status::statuses::Replaced>,
+}
+
+#[Object]
+impl ReplacedStatus {
+    async fn transaction_id(&self) -> TransactionId {
+        self.tx_id.into()
+    }
+
+    /// The id of the transaction that replaced this one in the TxPool.
+    async fn replaced_by(&self) -> TransactionId {
+        self.status.replaced_by.into()
+    }
+
+    async fn reason(&self) -> String {
+        self.status.reason.clone()
+    }
+}
+
 impl TransactionStatus {
     pub fn new(tx_id: TxId, tx_status: TxStatus) -> Self {
         match tx_status {
@@ -415,6 +438,9 @@ impl TransactionStatus {
             TxStatus::SqueezedOut(status) => {
                 TransactionStatus::SqueezedOut(SqueezedOutStatus { status, tx_id })
             }
+            TxStatus::Replaced(status) => {
+                TransactionStatus::Replaced(ReplacedStatus { tx_id, status })
+            }
             TxStatus::Failure(status) => {
                 TransactionStatus::Failure(FailureStatus { tx_id, status })
             }
@@ -443,7 +469,8 @@ impl TransactionStatus {
         match self {
             TransactionStatus::Success(_)
             | TransactionStatus::Failure(_)
-            | TransactionStatus::SqueezedOut(_) => true,
+            | TransactionStatus::SqueezedOut(_)
+            | TransactionStatus::Replaced(_) => true,
             TransactionStatus::Submitted(_)
             | TransactionStatus::PreconfirmationSuccess(_)
             | TransactionStatus::PreconfirmationFailure(_) => false,
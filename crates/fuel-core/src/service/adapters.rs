@@ -54,7 +54,18 @@ use fuel_core_upgradable_executor::executor::Executor;
 use tokio::time::Instant;
 
 #[cfg(feature = "p2p")]
-use fuel_core_types::services::p2p::peer_reputation::AppScore;
+use crate::{
+    combined_database::CombinedDatabase,
+    database::database_description::off_chain::OffChain,
+};
+#[cfg(feature = "p2p")]
+use fuel_core_types::{
+    fuel_types::{
+        AssetId,
+        ChainId,
+    },
+    services::p2p::peer_reputation::AppScore,
+};
 
 use crate::{
     database::{
@@ -75,6 +86,7 @@ pub mod block_importer;
 pub mod chain_state_info_provider;
 pub mod compression_adapters;
 pub mod consensus_module;
+pub mod da_derivation;
 pub mod executor;
 pub mod fuel_gas_price_provider;
 pub mod gas_price_adapters;
@@ -441,6 +453,24 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+impl MaybeRelayerAdapter {
+    /// Waits until the relayer is synced to the `da_height`.
+    /// Fails if the relayer is disabled.
+    pub async fn await_synced(
+        &self,
+        da_height: &fuel_core_types::blockchain::primitives::DaBlockHeight,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "relayer")]
+        if let Some(sync) = &self.relayer_synced {
+            return sync.await_at_least_synced(da_height).await
+        }
+
+        Err(anyhow::anyhow!(
+            "The relayer is disabled, so the events at the DA height {da_height} are unknown"
+        ))
+    }
+}
+
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
     pub block_producer: Arc<BlockProducerService>,
@@ -562,12 +592,40 @@ pub struct PeerReportConfig {
     pub bad_block_header: AppScore,
     pub missing_transactions: AppScore,
     pub invalid_transactions: AppScore,
+    pub bad_block: AppScore,
+    pub invalid_state_chunk: AppScore,
 }
 
 #[cfg(not(feature = "p2p"))]
 #[derive(Default, Clone)]
 pub struct P2PAdapter;
 
+/// Imports the state synchronized from the peers into the databases.
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct StateImporterAdapter {
+    on_chain: Database,
+    off_chain: Database<OffChain>,
+    chain_id: ChainId,
+    base_asset_id: AssetId,
+}
+
+#[cfg(feature = "p2p")]
+impl StateImporterAdapter {
+    pub fn new(
+        database: &CombinedDatabase,
+        chain_id: ChainId,
+        base_asset_id: AssetId,
+    ) -> Self {
+        Self {
+            on_chain: database.on_chain().clone(),
+            off_chain: database.off_chain().clone(),
+            chain_id,
+            base_asset_id,
+        }
+    }
+}
+
 #[cfg(feature = "p2p")]
 impl P2PAdapter {
     pub fn new(
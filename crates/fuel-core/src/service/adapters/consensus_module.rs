@@ -31,6 +31,8 @@ use fuel_core_types::{
 };
 use std::sync::Arc;
 
+#[cfg(feature = "p2p")]
+pub mod bft;
 pub mod poa;
 
 impl VerifierAdapter {
@@ -0,0 +1,134 @@
+use crate::service::adapters::{
+    BlockImporterAdapter,
+    BlockProducerAdapter,
+    ExecutorAdapter,
+    P2PAdapter,
+    SystemTime,
+};
+use fuel_core_bft::ports::{
+    BlockImporter,
+    BlockProducer,
+    BlockValidator,
+    GetTime,
+    P2pPort,
+};
+use fuel_core_importer::ports::Validator;
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
+use fuel_core_storage::transactional::Changes;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::UncommittedResult,
+        p2p::{
+            BftProposalGossipData,
+            BftVoteGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+use tokio::time::Instant;
+use tokio_stream::{
+    StreamExt,
+    wrappers::BroadcastStream,
+};
+
+#[async_trait::async_trait]
+impl BlockProducer for BlockProducerAdapter {
+    async fn produce_and_execute_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        deadline: Instant,
+    ) -> anyhow::Result<UncommittedResult<Changes>> {
+        self.block_producer
+            .produce_and_execute_block_txpool(height, block_time, deadline)
+            .await
+    }
+}
+
+impl BlockValidator for ExecutorAdapter {
+    fn validate_block(&self, block: &Block) -> anyhow::Result<()> {
+        // The block is only validated, the changes are committed by the importer.
+        let _ = Validator::validate(self, block)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for BlockImporterAdapter {
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.execute_and_commit(block).await
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo> {
+        fuel_core_poa::ports::BlockImporter::block_stream(self)
+    }
+}
+
+impl P2pPort for P2PAdapter {
+    fn broadcast_proposal(&self, proposal: Arc<SignedProposal>) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => service.broadcast_bft_proposal(proposal),
+            _ => Ok(()),
+        }
+    }
+
+    fn broadcast_vote(&self, vote: Arc<SignedVote>) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => service.broadcast_bft_vote(vote),
+            _ => Ok(()),
+        }
+    }
+
+    fn gossiped_proposals(&self) -> BoxStream<BftProposalGossipData> {
+        match &self.service {
+            Some(service) => BroadcastStream::new(service.subscribe_bft_proposals())
+                .filter_map(|result| result.ok())
+                .into_boxed(),
+            _ => tokio_stream::pending().into_boxed(),
+        }
+    }
+
+    fn gossiped_votes(&self) -> BoxStream<BftVoteGossipData> {
+        match &self.service {
+            Some(service) => BroadcastStream::new(service.subscribe_bft_votes())
+                .filter_map(|result| result.ok())
+                .into_boxed(),
+            _ => tokio_stream::pending().into_boxed(),
+        }
+    }
+
+    fn notify_gossip_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => {
+                service.notify_gossip_transaction_validity(message_info, validity)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl GetTime for SystemTime {
+    fn now(&self) -> Tai64 {
+        Tai64::now()
+    }
+}
@@ -0,0 +1,428 @@
+//! The derivation of the chain purely from the DA-compressed blocks.
+//!
+//! The service reads the compressed blocks from the source standing in for the DA layer,
+//! decompresses them against the temporal registry maintained in the compression database,
+//! re-executes them to get the full blocks, and imports them via the block importer.
+//!
+//! The derived blocks are not signed by the block producer, so the node is derivation-only:
+//! it can't run the P2P service, which would gossip and serve these blocks to other nodes.
+
+use crate::{
+    database::{
+        Database,
+        database_description::compression::CompressionDatabase,
+    },
+    service::adapters::{
+        BlockImporterAdapter,
+        ExecutorAdapter,
+        MaybeRelayerAdapter,
+    },
+};
+use anyhow::Context;
+use fuel_core_compression::{
+    VersionedBlockPayload,
+    VersionedCompressedBlock,
+    decompress::decompress,
+};
+use fuel_core_compression_service::{
+    storage::CompressedBlocks,
+    temporal_registry::{
+        CompressionStorageWrapper,
+        DecompressionContext,
+    },
+};
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_storage::{
+    StorageAsMut,
+    StorageAsRef,
+    tables::FuelBlocks,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        IntoTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::PartialFuelBlock,
+        consensus::Consensus,
+        primitives::DaBlockHeight,
+    },
+    fuel_tx::{
+        Transaction,
+        UniqueIdentifier,
+        field::{
+            InputContract,
+            MintGasPrice,
+        },
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+        ChainId,
+    },
+    services::{
+        block_importer::{
+            ImportResult,
+            UncommittedResult,
+        },
+        block_producer::Components,
+        executor::ExecutionResult,
+    },
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+/// The source of the compressed blocks posted to the DA layer.
+pub trait DaBlockSource: Send + Sync {
+    /// Returns the compressed block at the `height`, if it is already available.
+    fn get_block(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<VersionedCompressedBlock>>;
+}
+
+/// Reads the postcard encoded compressed blocks from the directory,
+/// where each block is stored in the `{height}.bin` file.
+pub struct InDirectoryCompressedBlocks {
+    path_to_directory: PathBuf,
+}
+
+impl InDirectoryCompressedBlocks {
+    pub fn new(path_to_directory: PathBuf) -> Self {
+        Self { path_to_directory }
+    }
+}
+
+impl DaBlockSource for InDirectoryCompressedBlocks {
+    fn get_block(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<VersionedCompressedBlock>> {
+        let block_path = compressed_block_path(&self.path_to_directory, (*height).into());
+        if !block_path.exists() {
+            return Ok(None);
+        }
+
+        let block_bytes = std::fs::read(block_path)?;
+        let block = postcard::from_bytes(block_bytes.as_slice())?;
+        Ok(Some(block))
+    }
+}
+
+pub fn compressed_block_path(path_to_directory: &Path, block_height: u32) -> PathBuf {
+    path_to_directory.join(format!("{}.bin", block_height))
+}
+
+pub struct Task<Source> {
+    source: Source,
+    on_chain_db: Database,
+    compression_db: Database<CompressionDatabase>,
+    executor: ExecutorAdapter,
+    relayer: MaybeRelayerAdapter,
+    block_importer: BlockImporterAdapter,
+    chain_id: ChainId,
+    decompression_config: fuel_core_compression::Config,
+    poll_interval: Duration,
+}
+
+impl<Source> Task<Source>
+where
+    Source: DaBlockSource,
+{
+    /// Derives the next block of the chain if it is available in the source.
+    /// Returns `true` if the block was imported.
+    async fn derive_next_block(&mut self) -> anyhow::Result<bool> {
+        let latest_view = self.on_chain_db.latest_view()?;
+        let latest_block = latest_view.latest_block()?;
+        let latest_height = *latest_block.header().height();
+        let next_height = latest_height
+            .succ()
+            .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))?;
+        let previous_da_height = latest_block.header().da_height();
+        let previous_time = latest_block.header().time();
+        let previous_root: Bytes32 = latest_view
+            .storage::<FuelBlocks>()
+            .root(&latest_height)?
+            .into();
+
+        let Some(compressed_block) = self.source.get_block(&next_height)? else {
+            return Ok(false);
+        };
+
+        let block_height = compressed_block.consensus_header().height;
+        if block_height != next_height {
+            return Err(anyhow::anyhow!(
+                "The compressed block has height {block_height}, but {next_height} was expected"
+            ));
+        }
+
+        // The executor takes the consensus header as is, so the block
+        // must be checked to continue the chain before its execution.
+        let consensus_header = compressed_block.consensus_header();
+        if consensus_header.prev_root != previous_root {
+            return Err(anyhow::anyhow!(
+                "The compressed block {block_height} doesn't continue the chain: \
+                its previous root is {}, but {} was expected",
+                consensus_header.prev_root,
+                previous_root,
+            ));
+        }
+        if consensus_header.time < previous_time {
+            return Err(anyhow::anyhow!(
+                "The time of the compressed block {block_height} is before the time of the previous block"
+            ));
+        }
+
+        // The decompression updates the temporal registry with the registrations
+        // of the block, so the blocks must be decompressed in sequence.
+        let mut storage_tx = self.compression_db.clone().into_transaction();
+        let decompression_context = DecompressionContext {
+            compression_storage: CompressionStorageWrapper {
+                storage_tx: &mut storage_tx,
+            },
+            onchain_db: latest_view,
+        };
+        let PartialFuelBlock {
+            header,
+            mut transactions,
+        } = decompress(
+            self.decompression_config,
+            decompression_context,
+            compressed_block.clone(),
+        )
+        .await?;
+
+        if header.application.da_height > previous_da_height {
+            self.await_da_events(block_height, header.application.da_height)
+                .await?;
+        }
+
+        // The registry already contains the block if the node stopped
+        // after updating it, but before the import of the block.
+        let registry_height = HistoricalView::latest_height(&self.compression_db);
+        if registry_height.is_none_or(|height| height < block_height) {
+            storage_tx
+                .storage_as_mut::<CompressedBlocks>()
+                .insert(&block_height, &compressed_block)?;
+            storage_tx.commit()?;
+        }
+
+        let expected_tx_ids = transactions
+            .iter()
+            .map(|tx| tx.id(&self.chain_id))
+            .collect::<Vec<_>>();
+        let Some(Transaction::Mint(mint)) = transactions.pop() else {
+            return Err(anyhow::anyhow!(
+                "The last transaction of the block {block_height} is not a mint"
+            ));
+        };
+
+        // The compressed block doesn't contain the fields generated by the execution,
+        // so we produce the block from the same transactions to get them.
+        let (
+            ExecutionResult {
+                block,
+                skipped_transactions,
+                tx_status,
+                events,
+            },
+            changes,
+        ) = self
+            .executor
+            .produce_without_commit_from_vector(Components {
+                header_to_produce: header,
+                transactions_source: transactions,
+                gas_price: *mint.gas_price(),
+                coinbase_recipient: mint.input_contract().contract_id,
+            })?
+            .into();
+
+        if let Some((tx_id, err)) = skipped_transactions.first() {
+            return Err(anyhow::anyhow!(
+                "The transaction {tx_id} of the block {block_height} is invalid: {err}"
+            ));
+        }
+
+        let actual_tx_ids = block
+            .transactions()
+            .iter()
+            .map(|tx| tx.id(&self.chain_id))
+            .collect::<Vec<_>>();
+        if actual_tx_ids != expected_tx_ids {
+            return Err(anyhow::anyhow!(
+                "The transactions of the derived block {block_height} don't match the compressed block"
+            ));
+        }
+
+        // The DA layer doesn't contain the seal of the block, so the derived
+        // blocks are imported without the signature of the block producer.
+        let sealed_block = SealedBlock {
+            entity: block,
+            consensus: Consensus::DaDerived,
+        };
+        self.block_importer
+            .block_importer
+            .commit_result(UncommittedResult::new(
+                ImportResult::new_from_network(sealed_block, tx_status, events),
+                changes,
+            ))
+            .await?;
+
+        tracing::info!("Derived the block {block_height} from the DA compressed block");
+        Ok(true)
+    }
+
+    /// Waits until the relayer is synced to the `da_height` of the block.
+    /// The executor skips the DA events if the relayer is disabled, so the block
+    /// including them can't be derived without it.
+    async fn await_da_events(
+        &self,
+        block_height: BlockHeight,
+        da_height: DaBlockHeight,
+    ) -> anyhow::Result<()> {
+        self.relayer.await_synced(&da_height).await.with_context(|| {
+            format!(
+                "The block {block_height} includes the DA events up to the DA height {da_height}"
+            )
+        })
+    }
+}
+
+impl<Source> RunnableTask for Task<Source>
+where
+    Source: DaBlockSource + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        let result = tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                return TaskNextAction::Stop
+            }
+
+            // The derivation may wait for the relayer to sync the DA events.
+            result = self.derive_next_block() => result,
+        };
+        let action = match result {
+            // The next block may already be available, so we don't wait.
+            Ok(true) => return TaskNextAction::Continue,
+            Ok(false) => TaskNextAction::Continue,
+            Err(err) => TaskNextAction::ErrorContinue(err),
+        };
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = tokio::time::sleep(self.poll_interval) => {
+                action
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Source> RunnableService for Task<Source>
+where
+    Source: DaBlockSource + 'static,
+{
+    const NAME: &'static str = "DaDerivation";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_service<Source>(
+    source: Source,
+    on_chain_db: Database,
+    compression_db: Database<CompressionDatabase>,
+    executor: ExecutorAdapter,
+    relayer: MaybeRelayerAdapter,
+    block_importer: BlockImporterAdapter,
+    chain_id: ChainId,
+    config: &crate::service::config::DaDerivationConfig,
+) -> ServiceRunner<Task<Source>>
+where
+    Source: DaBlockSource + 'static,
+{
+    ServiceRunner::new(Task {
+        source,
+        on_chain_db,
+        compression_db,
+        executor,
+        relayer,
+        block_importer,
+        chain_id,
+        decompression_config: fuel_core_compression::Config {
+            temporal_registry_retention: config.retention_duration,
+        },
+        poll_interval: config.poll_interval,
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_directory_compressed_blocks__returns_none_for_missing_block() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let source = InDirectoryCompressedBlocks::new(dir.path().to_path_buf());
+
+        // When
+        let block = source.get_block(&1u32.into()).unwrap();
+
+        // Then
+        assert!(block.is_none());
+    }
+
+    #[test]
+    fn in_directory_compressed_blocks__fails_on_invalid_block() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(compressed_block_path(dir.path(), 1), [0xff; 4]).unwrap();
+        let source = InDirectoryCompressedBlocks::new(dir.path().to_path_buf());
+
+        // When
+        let result = source.get_block(&1u32.into());
+
+        // Then
+        assert!(result.is_err());
+    }
+}
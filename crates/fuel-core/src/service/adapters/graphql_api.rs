@@ -12,13 +12,17 @@ use crate::{
     database::{
         Database,
         OnChainIterableKeyValueView,
-        database_description::compression::CompressionDatabase,
+        database_description::{
+            compression::CompressionDatabase,
+            gas_price::GasPriceDatabase,
+        },
     },
     fuel_core_graphql_api::ports::{
         BlockProducerPort,
         ChainStateProvider,
         DatabaseMessageProof,
         GasPriceEstimate,
+        GasPriceHistory,
         P2pPort,
         TxPoolPort,
         worker::{
@@ -42,6 +46,10 @@ use crate::{
 };
 use async_trait::async_trait;
 use fuel_core_compression_service::storage::CompressedBlocks;
+use fuel_core_gas_price_service::{
+    common::updater_metadata::UpdaterMetadata,
+    ports::GetMetadataStorage,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     Result as StorageResult,
@@ -51,7 +59,13 @@ use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    config::{
+        BlackList,
+        BlackListUpdate,
+    },
+};
 use fuel_core_types::{
     blockchain::header::{
         ConsensusParametersVersion,
@@ -68,17 +82,22 @@ use fuel_core_types::{
     services::{
         block_importer::SharedImportResult,
         executor::{
+            DryRunOverrides,
             DryRunResult,
             StorageReadReplayEvent,
         },
-        p2p::PeerInfo,
+        p2p::{
+            BannedPeer,
+            PeerId,
+            PeerInfo,
+        },
         transaction_status::TransactionStatus,
     },
-    tai64::Tai64,
 };
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -119,6 +138,17 @@ impl TxPoolPort for TxPoolAdapter {
     fn latest_pool_stats(&self) -> TxPoolStats {
         self.service.latest_stats()
     }
+
+    fn blacklist(&self) -> BlackList {
+        self.service.blacklist()
+    }
+
+    async fn update_blacklist(&self, update: BlackListUpdate) -> anyhow::Result<()> {
+        self.service
+            .update_blacklist(update)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -137,19 +167,19 @@ impl BlockProducerPort for BlockProducerAdapter {
         &self,
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
-        time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        overrides: DryRunOverrides,
     ) -> anyhow::Result<DryRunResult> {
         self.block_producer
             .dry_run(
                 transactions,
                 height,
-                time,
                 utxo_validation,
                 gas_price,
                 record_storage_reads,
+                overrides,
             )
             .await
     }
@@ -201,6 +231,67 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeer>> {
+        #[cfg(feature = "p2p")]
+        {
+            match &self.service {
+                Some(service) => {
+                    let peers = service.get_banned_peers().await?;
+                    Ok(peers
+                        .into_iter()
+                        .map(|(peer_id, ban)| BannedPeer {
+                            id: PeerId::from(peer_id.to_bytes()),
+                            reason: ban.reason,
+                            expires_at: ban.expires_at,
+                        })
+                        .collect())
+                }
+                _ => Ok(vec![]),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(vec![])
+        }
+    }
+
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = fuel_core_p2p::PeerId::from_bytes(peer_id.as_ref())?;
+            match &self.service {
+                Some(service) => service.ban_peer(peer_id, reason, duration).await,
+                _ => Err(anyhow::anyhow!("The P2P service is disabled")),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, reason, duration);
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = fuel_core_p2p::PeerId::from_bytes(peer_id.as_ref())?;
+            match &self.service {
+                Some(service) => service.unban_peer(peer_id).await,
+                _ => Err(anyhow::anyhow!("The P2P service is disabled")),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
 }
 
 impl worker::TxStatusCompletion for TxStatusManagerAdapter {
@@ -221,6 +312,15 @@ impl GasPriceEstimate for StaticGasPrice {
     }
 }
 
+impl GasPriceHistory for Database<GasPriceDatabase> {
+    fn gas_price_metadata(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<UpdaterMetadata>> {
+        Ok(self.get_metadata(height)?)
+    }
+}
+
 impl ChainStateProvider for ChainStateInfoProvider {
     fn current_consensus_params(&self) -> Arc<ConsensusParameters> {
         self.shared_state.latest_consensus_parameters()
@@ -36,6 +36,11 @@ use crate::{
                 TotalBalanceAmount,
             },
             coins::CoinsToSpendIndex,
+            logs::{
+                IndexedLog,
+                LogKey,
+                Logs,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
@@ -135,6 +140,31 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.contract_transactions(contract_id, start, Some(direction))
+            .into_boxed()
+    }
+
+    fn logs(
+        &self,
+        contract_id: ContractId,
+        log_id: Option<u64>,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(LogKey, IndexedLog)>> {
+        let prefix = LogKey::prefix(&contract_id, log_id);
+        self.iter_all_filtered::<Logs, _>(Some(prefix), start.as_ref(), Some(direction))
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -410,6 +440,14 @@ impl worker::OffChainDatabase for Database<OffChain> {
     fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
         self.indexation_available(IndexationKind::AssetMetadata)
     }
+
+    fn transactions_by_contract_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::TransactionsByContract)
+    }
+
+    fn logs_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::Logs)
+    }
 }
 
 impl OffChainDatabaseAt for OffChainKeyValueView {}
@@ -6,6 +6,7 @@ use crate::{
         database_description::on_chain::OnChain,
     },
     fuel_core_graphql_api::ports::{
+        ContractStorageProof,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseContracts,
@@ -18,6 +19,7 @@ use crate::{
 use fuel_core_storage::{
     ContractsAssetKey,
     ContractsStateKey,
+    Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
     iter::{
@@ -214,4 +216,116 @@ impl OnChainDatabaseAt for OnChainKeyValueView {
             .filter_map(|res| res.transpose())
             .into_boxed()
     }
+
+    fn contract_slot_proof(
+        &self,
+        contract_id: ContractId,
+        storage_slot: Bytes32,
+    ) -> StorageResult<ContractStorageProof> {
+        contract_slot_proof(self, contract_id, storage_slot)
+    }
+
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<ContractStorageProof> {
+        contract_balance_proof(self, contract_id, asset)
+    }
+}
+
+#[cfg(feature = "smt")]
+fn contract_slot_proof(
+    view: &OnChainKeyValueView,
+    contract_id: ContractId,
+    storage_slot: Bytes32,
+) -> StorageResult<ContractStorageProof> {
+    use fuel_core_storage::tables::merkle::ContractsStateMerkleData;
+
+    let key = ContractsStateKey::new(&contract_id, &storage_slot);
+    let value = view
+        .storage::<ContractsState>()
+        .get(&key)?
+        .map(|v| v.into_owned().0);
+    let root = view.storage::<ContractsState>().root(&contract_id)?;
+
+    sparse_merkle_proof::<ContractsStateMerkleData>(view, root, key.as_ref(), value)
+}
+
+#[cfg(feature = "smt")]
+fn contract_balance_proof(
+    view: &OnChainKeyValueView,
+    contract_id: ContractId,
+    asset: AssetId,
+) -> StorageResult<ContractStorageProof> {
+    use fuel_core_storage::tables::merkle::ContractsAssetsMerkleData;
+
+    let key = ContractsAssetKey::new(&contract_id, &asset);
+    // The balance is encoded by the `Primitive<8>` codec of the `ContractsAssets` table.
+    let value = view
+        .storage::<ContractsAssets>()
+        .get(&key)?
+        .map(|v| v.into_owned().to_be_bytes().to_vec());
+    let root = view.storage::<ContractsAssets>().root(&contract_id)?;
+
+    sparse_merkle_proof::<ContractsAssetsMerkleData>(view, root, key.as_ref(), value)
+}
+
+#[cfg(feature = "smt")]
+fn sparse_merkle_proof<Nodes>(
+    view: &OnChainKeyValueView,
+    root: fuel_core_storage::MerkleRoot,
+    key: &[u8],
+    value: Option<Vec<u8>>,
+) -> StorageResult<ContractStorageProof>
+where
+    Nodes: fuel_core_storage::Mappable<
+            Key = fuel_core_storage::MerkleRoot,
+            Value = fuel_core_types::fuel_merkle::sparse::Primitive,
+            OwnedValue = fuel_core_types::fuel_merkle::sparse::Primitive,
+        >,
+    OnChainKeyValueView: fuel_core_storage::StorageInspect<Nodes, Error = StorageError>,
+{
+    use fuel_core_types::fuel_merkle::sparse::{
+        MerkleTree,
+        MerkleTreeKey,
+    };
+
+    let tree: MerkleTree<Nodes, _> = MerkleTree::load(view, &root)
+        .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+    let proof = tree
+        .generate_proof(&MerkleTreeKey::new(key))
+        .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+
+    Ok(ContractStorageProof {
+        root: root.into(),
+        value,
+        proof,
+    })
+}
+
+#[cfg(not(feature = "smt"))]
+fn contract_slot_proof(
+    _: &OnChainKeyValueView,
+    _: ContractId,
+    _: Bytes32,
+) -> StorageResult<ContractStorageProof> {
+    Err(smt_is_disabled())
+}
+
+#[cfg(not(feature = "smt"))]
+fn contract_balance_proof(
+    _: &OnChainKeyValueView,
+    _: ContractId,
+    _: AssetId,
+) -> StorageResult<ContractStorageProof> {
+    Err(smt_is_disabled())
+}
+
+#[cfg(not(feature = "smt"))]
+fn smt_is_disabled() -> StorageError {
+    StorageError::Other(anyhow::anyhow!(
+        "Contract storage proofs are not supported, \
+        because the node is built without the `smt` feature"
+    ))
 }
@@ -9,20 +9,38 @@ use fuel_core_p2p::ports::{
     TxPool,
 };
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    Result as StorageResult,
+    column::Column,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+};
 use fuel_core_types::{
     blockchain::{
+        SealedBlock,
         SealedBlockHeader,
         consensus::Genesis,
     },
     fuel_tx::TxId,
     fuel_types::BlockHeight,
-    services::p2p::{
-        NetworkableTransactionPool,
-        Transactions,
+    services::{
+        block_importer::Source,
+        p2p::{
+            NetworkableTransactionPool,
+            StateEntry,
+            StateSyncTable,
+            Transactions,
+        },
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 impl P2pDb for OnChainIterableKeyValueView {
     fn get_sealed_headers(
@@ -42,6 +60,48 @@ impl P2pDb for OnChainIterableKeyValueView {
     fn get_genesis(&self) -> StorageResult<Genesis> {
         self.get_genesis()
     }
+
+    fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.maybe_latest_height()
+    }
+
+    fn iter_state_entries(
+        &self,
+        table: StateSyncTable,
+        start: Option<&[u8]>,
+    ) -> BoxedIter<'_, StorageResult<StateEntry>> {
+        self.iter_store(
+            state_sync_column(table),
+            None,
+            start,
+            IterDirection::Forward,
+        )
+        .map(|result| result.map(|(key, value)| (key, value.to_vec())))
+        .into_boxed()
+    }
+}
+
+/// Returns the column that stores the table synchronized by the state sync.
+pub fn state_sync_column(table: StateSyncTable) -> Column {
+    match table {
+        StateSyncTable::Coins => Column::Coins,
+        StateSyncTable::Messages => Column::Messages,
+        StateSyncTable::Blobs => Column::Blobs,
+        StateSyncTable::ContractsRawCode => Column::ContractsRawCode,
+        StateSyncTable::ContractsLatestUtxo => Column::ContractsLatestUtxo,
+        StateSyncTable::ContractsState => Column::ContractsState,
+        StateSyncTable::ContractsAssets => Column::ContractsAssets,
+        StateSyncTable::ProcessedTransactions => Column::ProcessedTransactions,
+        StateSyncTable::ConsensusParametersVersions => {
+            Column::ConsensusParametersVersions
+        }
+        StateSyncTable::StateTransitionBytecodeVersions => {
+            Column::StateTransitionBytecodeVersions
+        }
+        StateSyncTable::UploadedBytecodes => Column::UploadedBytecodes,
+        StateSyncTable::FuelBlockMerkleData => Column::FuelBlockMerkleData,
+        StateSyncTable::FuelBlockMerkleMetadata => Column::FuelBlockMerkleMetadata,
+    }
 }
 
 impl BlockHeightImporter for BlockImporterAdapter {
@@ -56,6 +116,19 @@ impl BlockHeightImporter for BlockImporterAdapter {
                 .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
+
+    fn produced_blocks(&self) -> BoxStream<Arc<SealedBlock>> {
+        use tokio_stream::{
+            StreamExt,
+            wrappers::BroadcastStream,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .filter(|result| result.source == Source::Local)
+                .map(|result| Arc::new(result.sealed_block.clone())),
+        )
+    }
 }
 
 impl TxPool for TxPoolAdapter {
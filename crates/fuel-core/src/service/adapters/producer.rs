@@ -65,6 +65,7 @@ use fuel_core_types::{
         executor::{
             DryRunResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
             UncommittedResult,
         },
@@ -132,12 +133,14 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_read_replay: bool,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<DryRunResult> {
         self.executor.dry_run(
             block,
             forbid_fake_coins,
             at_height,
             record_storage_read_replay,
+            state_overrides,
         )
     }
 }
@@ -2,14 +2,58 @@ use super::{
     BlockImporterAdapter,
     ConsensusAdapter,
     P2PAdapter,
+    StateImporterAdapter,
+    p2p::state_sync_column,
+};
+use crate::{
+    database::{
+        OnChainKeyValueView,
+        commit_changes_at_height,
+        database_description::IndexationKind,
+    },
+    graphql_api::{
+        storage::blocks::FuelBlockIdsToHeights,
+        worker_service,
+    },
 };
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    StorageAsMut,
+    StorageAsRef,
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
+    kv_store::KeyValueMutate,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        FuelBlocks,
+        Messages,
+        SealedBlockConsensus,
+        Transactions as TransactionsTable,
+        merkle::{
+            DenseMetadataKey,
+            FuelBlockMerkleMetadata,
+        },
+    },
+    transactional::{
+        AtomicView,
+        ReadTransaction,
+        StorageChanges,
+        WriteTransaction,
+    },
+};
 use fuel_core_sync::ports::{
     BlockImporterPort,
     ConsensusPort,
     PeerReportReason,
     PeerToPeerPort,
+    StateImporterPort,
 };
 use fuel_core_types::{
     blockchain::{
@@ -17,18 +61,36 @@ use fuel_core_types::{
         SealedBlockHeader,
         primitives::DaBlockHeight,
     },
+    fuel_tx::{
+        Bytes32,
+        UniqueIdentifier,
+    },
     fuel_types::BlockHeight,
-    services::p2p::{
-        PeerId,
-        SourcePeer,
-        Transactions,
-        peer_reputation::{
-            AppScore,
-            PeerReport,
+    services::{
+        executor::Event,
+        p2p::{
+            BlockGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            PeerId,
+            SourcePeer,
+            StateCheckpoint,
+            StateChunkRequest,
+            StateEntry,
+            StateSyncTable,
+            Transactions,
+            peer_reputation::{
+                AppScore,
+                PeerReport,
+            },
         },
     },
 };
-use std::ops::Range;
+use itertools::Itertools;
+use std::{
+    borrow::Cow,
+    ops::Range,
+};
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -97,6 +159,36 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_state_checkpoint(
+        &self,
+    ) -> anyhow::Result<SourcePeer<Option<StateCheckpoint>>> {
+        let result = match &self.service {
+            Some(service) => service.get_state_checkpoint().await,
+            _ => Err(anyhow::anyhow!("No P2P service available")),
+        };
+        match result {
+            Ok((peer_id, checkpoint)) => {
+                let peer_id: PeerId = peer_id.into();
+                Ok(peer_id.bind(checkpoint))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn get_state_chunk_from_peer(
+        &self,
+        request: SourcePeer<StateChunkRequest>,
+    ) -> anyhow::Result<Option<Vec<StateEntry>>> {
+        let SourcePeer {
+            peer_id,
+            data: request,
+        } = request;
+        match &self.service {
+            Some(service) => service.get_state_chunk_from_peer(peer_id, request).await,
+            _ => Err(anyhow::anyhow!("No P2P service available")),
+        }
+    }
+
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
         match &self.service {
             Some(service) => {
@@ -108,6 +200,32 @@ impl PeerToPeerPort for P2PAdapter {
             _ => Err(anyhow::anyhow!("No P2P service available")),
         }
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        use futures::StreamExt;
+        match &self.service {
+            Some(service) => fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::wrappers::BroadcastStream::new(service.subscribe_block())
+                    .filter_map(|r| futures::future::ready(r.ok())),
+            ),
+            _ => fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::pending(),
+            ),
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => {
+                service.notify_gossip_transaction_validity(message_info, validity)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl P2PAdapter {
@@ -126,6 +244,10 @@ impl P2PAdapter {
             PeerReportReason::InvalidTransactions => {
                 self.peer_report_config.invalid_transactions
             }
+            PeerReportReason::BadBlock => self.peer_report_config.bad_block,
+            PeerReportReason::InvalidStateChunk => {
+                self.peer_report_config.invalid_state_chunk
+            }
         };
         P2PAdapterPeerReport { score }
     }
@@ -171,3 +293,189 @@ impl ConsensusPort for ConsensusAdapter {
         .await?
     }
 }
+
+impl StateImporterAdapter {
+    /// Updates the off-chain indexes of coins and messages.
+    fn index_events(&self, events: Vec<Event>) -> anyhow::Result<()> {
+        let mut off_chain = self.off_chain.clone();
+        let mut transaction = off_chain.write_transaction();
+        worker_service::process_executor_events(
+            events.into_iter().map(Cow::Owned),
+            &mut transaction,
+            self.off_chain
+                .indexation_available(IndexationKind::Balances)?,
+            self.off_chain
+                .indexation_available(IndexationKind::CoinsToSpend)?,
+            &self.base_asset_id,
+        )?;
+        let changes = transaction.into_changes();
+        self.off_chain
+            .data
+            .commit_changes(None, StorageChanges::Changes(changes))?;
+        Ok(())
+    }
+}
+
+/// The state sync imports the chunks without changing the height of the database.
+/// Until the block of the checkpoint is committed by the [`StateImporterPort::finalize`],
+/// the node stays at the genesis height and restarts the sync after the restart.
+impl StateImporterPort for StateImporterAdapter {
+    fn should_sync(&self) -> anyhow::Result<bool> {
+        let view = self.on_chain.latest_view()?;
+        Ok(view.maybe_latest_height()? == view.genesis_height()?)
+    }
+
+    fn clear_table(&self, table: StateSyncTable) -> anyhow::Result<()> {
+        let view = self.on_chain.latest_view()?;
+        let events = match table {
+            StateSyncTable::Coins => view
+                .iter_all::<Coins>(None)
+                .map_ok(|(utxo_id, coin)| Event::CoinConsumed(coin.uncompress(utxo_id)))
+                .try_collect()?,
+            StateSyncTable::Messages => view
+                .iter_all::<Messages>(None)
+                .map_ok(|(_, message)| Event::MessageConsumed(message))
+                .try_collect()?,
+            _ => vec![],
+        };
+
+        let column = state_sync_column(table);
+        let mut transaction = view.read_transaction();
+        for key in view.iter_store_keys(column, None, None, IterDirection::Forward) {
+            transaction.delete(&key?, column)?;
+        }
+        self.on_chain
+            .data
+            .commit_changes(None, StorageChanges::Changes(transaction.into_changes()))?;
+
+        self.index_events(events)
+    }
+
+    fn import_chunk(
+        &self,
+        table: StateSyncTable,
+        entries: Vec<StateEntry>,
+    ) -> anyhow::Result<()> {
+        let events = match table {
+            StateSyncTable::Coins => decode_entries::<Coins>(&entries)?
+                .into_iter()
+                .map(|(utxo_id, coin)| Event::CoinCreated(coin.uncompress(utxo_id)))
+                .collect(),
+            StateSyncTable::Messages => decode_entries::<Messages>(&entries)?
+                .into_iter()
+                .map(|(_, message)| Event::MessageImported(message))
+                .collect(),
+            _ => vec![],
+        };
+
+        let column = state_sync_column(table);
+        let view = self.on_chain.latest_view()?;
+        let mut transaction = view.read_transaction();
+        for (key, value) in entries {
+            transaction.put(&key, column, value.into())?;
+        }
+        self.on_chain
+            .data
+            .commit_changes(None, StorageChanges::Changes(transaction.into_changes()))?;
+
+        self.index_events(events)
+    }
+
+    fn finalize(&self, block: SealedBlock) -> anyhow::Result<()> {
+        let SealedBlock {
+            entity: block,
+            consensus,
+        } = block;
+        let height = *block.header().height();
+        let prev_height = height
+            .pred()
+            .ok_or_else(|| anyhow::anyhow!("The checkpoint can't be at zero height"))?;
+
+        let view = self.on_chain.latest_view()?;
+        let mut transaction = view.read_transaction();
+
+        // The header doesn't commit to the state, but at least the synced
+        // tree of blocks should match the header of the checkpoint.
+        let prev_metadata = transaction
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(prev_height))?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The synced state doesn't have the block root at {prev_height}"
+                )
+            })?
+            .into_owned();
+        anyhow::ensure!(
+            Bytes32::from(*prev_metadata.root()) == *block.header().prev_root(),
+            "The synced tree of blocks doesn't match the block at {height}"
+        );
+
+        // The synced tree already contains the block of the checkpoint.
+        // Roll it back, so the insertion of the block appends it again.
+        transaction
+            .storage_as_mut::<FuelBlockMerkleMetadata>()
+            .remove(&DenseMetadataKey::Primary(height))?;
+        transaction
+            .storage_as_mut::<FuelBlockMerkleMetadata>()
+            .insert(&DenseMetadataKey::Latest, &prev_metadata)?;
+
+        for tx in block.transactions() {
+            transaction
+                .storage_as_mut::<TransactionsTable>()
+                .insert(&tx.id(&self.chain_id), tx)?;
+        }
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &block.compress(&self.chain_id))?;
+        transaction
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(&height, &consensus)?;
+        let on_chain_changes = transaction.into_changes();
+
+        // The off-chain database is moved first, otherwise the GraphQL worker
+        // would try to process the blocks between the genesis and the checkpoint.
+        let mut off_chain = self.off_chain.clone();
+        let mut transaction = off_chain.write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block.id(), &height)?;
+        let off_chain_changes = transaction.into_changes();
+        commit_changes_at_height(
+            &mut off_chain,
+            Some(height),
+            StorageChanges::Changes(off_chain_changes),
+        )?;
+
+        let mut on_chain = self.on_chain.clone();
+        commit_changes_at_height(
+            &mut on_chain,
+            Some(height),
+            StorageChanges::Changes(on_chain_changes),
+        )?;
+
+        Ok(())
+    }
+}
+
+fn decode_entries<T>(
+    entries: &[StateEntry],
+) -> anyhow::Result<Vec<(T::OwnedKey, T::OwnedValue)>>
+where
+    T: TableWithBlueprint,
+    T::Blueprint: BlueprintInspect<T, OnChainKeyValueView>,
+{
+    entries
+        .iter()
+        .map(|(key, value)| {
+            let key =
+                <T::Blueprint as BlueprintInspect<T, OnChainKeyValueView>>::KeyCodec::decode(
+                    key,
+                )?;
+            let value =
+                <T::Blueprint as BlueprintInspect<T, OnChainKeyValueView>>::ValueCodec::decode(
+                    value,
+                )?;
+            Ok((key, value))
+        })
+        .collect()
+}
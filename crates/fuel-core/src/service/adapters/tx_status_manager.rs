@@ -80,6 +80,9 @@ impl ProtocolPublicKey for ConsensusConfigProtocolPublicKey {
         match &self.inner {
             ConsensusConfig::PoA { signing_key } => *signing_key,
             ConsensusConfig::PoAV2(poa_v2) => poa_v2.latest_address(),
+            // The BFT validators don't delegate the pre-confirmations,
+            // so none of the delegation signatures are trusted.
+            ConsensusConfig::Bft(_) => Address::zeroed(),
         }
     }
 }
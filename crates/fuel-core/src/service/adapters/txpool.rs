@@ -250,12 +250,16 @@ impl TxStatusManager for PreconfirmationSender {
         let permit = self.sender_signature_service.try_reserve();
 
         if let Ok(permit) = permit {
-            if let TransactionStatus::SqueezedOut(status) = &tx_status {
+            let reason = match &tx_status {
+                TransactionStatus::SqueezedOut(status) => Some(status.reason.clone()),
+                TransactionStatus::Replaced(status) => Some(status.reason.clone()),
+                _ => None,
+            };
+
+            if let Some(reason) = reason {
                 let preconfirmation = Preconfirmation {
                     tx_id,
-                    status: PreconfirmationStatus::SqueezedOut {
-                        reason: status.reason.clone(),
-                    },
+                    status: PreconfirmationStatus::SqueezedOut { reason },
                 };
                 permit.send(vec![preconfirmation]);
             }
@@ -76,16 +76,24 @@ pub struct Config {
     pub block_producer: fuel_core_producer::Config,
     pub gas_price_config: GasPriceConfig,
     pub da_compression: DaCompressionMode,
+    /// Imports the blocks derived from the DA compressed blocks instead of producing them, if set.
+    pub da_derivation: Option<DaDerivationConfig>,
     pub block_importer: fuel_core_importer::Config,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
     #[cfg(feature = "p2p")]
     pub p2p: Option<P2PConfig<NotInitialized>>,
+    /// Bootstraps the node from the state checkpoint of the peers, if set.
+    #[cfg(feature = "p2p")]
+    pub snap_sync: Option<fuel_core_sync::state_sync::Config>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
     #[cfg(feature = "p2p")]
     pub pre_confirmation_signature_service:
         fuel_core_poa::pre_confirmation_signature_service::config::Config,
+    /// The timeouts of the BFT consensus rounds, used if the chain is sealed by the validators.
+    #[cfg(feature = "p2p")]
+    pub bft_timeouts: fuel_core_bft::Timeouts,
     #[cfg(feature = "shared-sequencer")]
     pub shared_sequencer: fuel_core_shared_sequencer::Config,
     pub consensus_signer: SignMode,
@@ -171,12 +179,15 @@ impl Config {
                 max_concurrent_queries: 1024,
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
+                metrics: true,
                 api_request_timeout: Duration::from_secs(60),
                 assemble_tx_dry_run_limit: 3,
                 assemble_tx_estimate_predicates_limit: 5,
                 costs: Default::default(),
                 required_fuel_block_height_tolerance: 10,
                 required_fuel_block_height_timeout: Duration::from_secs(30),
+                admin_token: None,
+                rate_limit: None,
             },
             combined_db_config,
             continue_on_error: false,
@@ -202,6 +213,7 @@ impl Config {
                 ..Default::default()
             },
             da_compression: DaCompressionMode::Disabled,
+            da_derivation: None,
             gas_price_config,
             block_importer,
             #[cfg(feature = "relayer")]
@@ -209,11 +221,15 @@ impl Config {
             #[cfg(feature = "p2p")]
             p2p: Some(P2PConfig::<NotInitialized>::default(network_name.as_str())),
             #[cfg(feature = "p2p")]
+            snap_sync: None,
+            #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
             #[cfg(feature = "p2p")]
             pre_confirmation_signature_service:
                 fuel_core_poa::pre_confirmation_signature_service::config::Config::default(
                 ),
+            #[cfg(feature = "p2p")]
+            bft_timeouts: fuel_core_bft::Timeouts::default(),
             #[cfg(feature = "shared-sequencer")]
             shared_sequencer: fuel_core_shared_sequencer::Config::local_node(),
             consensus_signer: SignMode::Key(fuel_core_types::secrecy::Secret::new(
@@ -371,3 +387,13 @@ pub enum DaCompressionMode {
     Disabled,
     Enabled(DaCompressionConfig),
 }
+
+#[derive(Debug, Clone)]
+pub struct DaDerivationConfig {
+    /// The path to the directory with the postcard encoded compressed blocks.
+    pub source_path: PathBuf,
+    /// The retention of the temporal registry used by the block producer.
+    pub retention_duration: Duration,
+    /// How often the directory is checked for the next block.
+    pub poll_interval: Duration,
+}
@@ -60,9 +60,11 @@ use fuel_core_types::{
 };
 use itertools::Itertools;
 
+pub use diff::apply_diff;
 pub use exporter::Exporter;
 pub use task_manager::NotifyCancel;
 
+mod diff;
 mod exporter;
 mod importer;
 mod progress;
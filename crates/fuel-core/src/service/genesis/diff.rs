@@ -0,0 +1,190 @@
+use crate::database::OnChainKeyValueView;
+use fuel_core_chain_config::{
+    AddTable,
+    AsTable,
+    SnapshotDiff,
+    SnapshotMetadata,
+    SnapshotReader,
+    SnapshotWriter,
+    StateConfig,
+    StateConfigBuilder,
+    TableEntry,
+};
+use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::{
+        Encode,
+        Encoder,
+    },
+    column::Column,
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        Transactions,
+        merkle::{
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+    },
+};
+use fuel_core_types::fuel_vm::BlobData;
+use itertools::Itertools;
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+};
+
+/// Applies the `diff` produced by the [`Exporter::write_diff_snapshot`](super::Exporter::write_diff_snapshot)
+/// to the `base` snapshot and writes the resulting snapshot with the `writer`.
+///
+/// The entries of the `base` snapshot should be sorted by the key,
+/// as the exporter writes them.
+pub fn apply_diff(
+    base: &SnapshotReader,
+    diff_reader: &SnapshotReader,
+    diff: &SnapshotDiff,
+    mut writer: SnapshotWriter,
+    group_size: usize,
+) -> anyhow::Result<SnapshotMetadata> {
+    let base_height = base.last_block_config().map(|block| block.block_height);
+    anyhow::ensure!(
+        base_height == Some(diff.from_height),
+        "The diff starts at the height {}, but the snapshot is at the height {:?}",
+        diff.from_height,
+        base_height
+    );
+    let last_block = diff_reader.last_block_config().cloned();
+    anyhow::ensure!(
+        last_block.as_ref().map(|block| block.block_height) == Some(diff.to_height),
+        "The diff doesn't contain the block at the height {}",
+        diff.to_height
+    );
+
+    macro_rules! apply {
+        ($($table: ty),*) => {
+            $(apply_table::<$table>(base, diff_reader, diff, &mut writer, group_size)?;)*
+        };
+    }
+    apply!(
+        Coins,
+        Messages,
+        BlobData,
+        ContractsRawCode,
+        ContractsLatestUtxo,
+        ContractsState,
+        ContractsAssets,
+        FuelBlocks,
+        FuelBlockMerkleData,
+        FuelBlockMerkleMetadata,
+        Transactions,
+        SealedBlockConsensus,
+        ProcessedTransactions
+    );
+
+    writer.close(last_block, diff_reader.chain_config())
+}
+
+/// Merges the entries of the table from the `base` snapshot with the entries from the diff.
+/// Both sides are sorted by the encoded key, so the merge doesn't load the whole table.
+fn apply_table<T>(
+    base: &SnapshotReader,
+    diff_reader: &SnapshotReader,
+    diff: &SnapshotDiff,
+    writer: &mut SnapshotWriter,
+    group_size: usize,
+) -> anyhow::Result<()>
+where
+    T: TableWithBlueprint<Column = Column>,
+    T::OwnedKey: Borrow<T::Key>,
+    T::Blueprint: BlueprintInspect<T, OnChainKeyValueView>,
+    TableEntry<T>: serde::Serialize + serde::de::DeserializeOwned,
+    StateConfig: AsTable<T>,
+    StateConfigBuilder: AddTable<T>,
+{
+    let table = T::column().name();
+
+    // `None` means that the entry was removed.
+    let mut changes = BTreeMap::<Vec<u8>, Option<TableEntry<T>>>::new();
+    for key in diff.removed_keys(&table) {
+        changes.insert(key.clone(), None);
+    }
+    for entry in diff_reader.read::<T>()?.into_iter().flatten_ok() {
+        let entry = entry?;
+        changes.insert(encode_key::<T>(&entry), Some(entry));
+    }
+    let mut changes = changes.into_iter().peekable();
+
+    let mut group = Vec::with_capacity(group_size);
+    let mut previous_key = None;
+    for entry in base.read::<T>()?.into_iter().flatten_ok() {
+        let entry = entry?;
+        let key = encode_key::<T>(&entry);
+        anyhow::ensure!(
+            previous_key.as_ref() < Some(&key),
+            "The entries of the `{table}` table in the snapshot are not sorted"
+        );
+
+        let mut replaced = false;
+        while let Some((change_key, change)) =
+            changes.next_if(|(change_key, _)| change_key <= &key)
+        {
+            replaced |= change_key == key;
+            if let Some(change) = change {
+                push_entry(&mut group, change, writer, group_size)?;
+            }
+        }
+        if !replaced {
+            push_entry(&mut group, entry, writer, group_size)?;
+        }
+        previous_key = Some(key);
+    }
+    for change in changes.filter_map(|(_, change)| change) {
+        push_entry(&mut group, change, writer, group_size)?;
+    }
+
+    if !group.is_empty() {
+        writer.write(group)?;
+    }
+
+    Ok(())
+}
+
+fn push_entry<T>(
+    group: &mut Vec<TableEntry<T>>,
+    entry: TableEntry<T>,
+    writer: &mut SnapshotWriter,
+    group_size: usize,
+) -> anyhow::Result<()>
+where
+    T: TableWithBlueprint,
+    TableEntry<T>: serde::Serialize,
+    StateConfigBuilder: AddTable<T>,
+{
+    group.push(entry);
+    if group.len() >= group_size {
+        writer.write(core::mem::take(group))?;
+    }
+    Ok(())
+}
+
+fn encode_key<T>(entry: &TableEntry<T>) -> Vec<u8>
+where
+    T: TableWithBlueprint<Column = Column>,
+    T::OwnedKey: Borrow<T::Key>,
+    T::Blueprint: BlueprintInspect<T, OnChainKeyValueView>,
+{
+    <T::Blueprint as BlueprintInspect<T, OnChainKeyValueView>>::KeyCodec::encode(
+        entry.key.borrow(),
+    )
+    .as_bytes()
+    .into_owned()
+}
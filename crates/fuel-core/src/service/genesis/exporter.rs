@@ -2,11 +2,13 @@ use crate::{
     combined_database::CombinedDatabase,
     database::{
         Database,
+        OnChainKeyValueView,
         database_description::DatabaseDescription,
     },
     fuel_core_graphql_api::storage::{
         messages::SpentMessages,
         transactions::{
+            ContractTransactions,
             OwnedTransactions,
             TransactionStatuses,
         },
@@ -16,11 +18,13 @@ use crate::{
         OldFuelBlocks,
         OldTransactions,
     },
+    state::ModifiedKeys,
 };
 use fuel_core_chain_config::{
     AddTable,
     ChainConfig,
     LastBlockConfig,
+    SnapshotDiff,
     SnapshotFragment,
     SnapshotMetadata,
     SnapshotWriter,
@@ -29,11 +33,17 @@ use fuel_core_chain_config::{
 };
 use fuel_core_poa::ports::Database as DatabaseTrait;
 use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    column::Column,
     iter::{
         IterDirection,
         IterableTable,
     },
-    kv_store::StorageColumn,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+    },
     structured_storage::TableWithBlueprint,
     tables::{
         Coins,
@@ -51,10 +61,16 @@ use fuel_core_storage::{
             FuelBlockMerkleMetadata,
         },
     },
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
 };
 use fuel_core_types::{
-    fuel_types::ContractId,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
     fuel_vm::BlobData,
 };
 use itertools::Itertools;
@@ -126,6 +142,7 @@ where
             |ctx: &Self| ctx.db.off_chain(),
             TransactionStatuses,
             OwnedTransactions,
+            ContractTransactions,
             OldFuelBlocks,
             OldFuelBlockConsensus,
             OldTransactions,
@@ -158,8 +175,98 @@ where
         Ok(())
     }
 
+    /// Writes the entries of the on-chain tables modified by the blocks
+    /// in the `(from_height, to_height]` range with their values at the `to_height`.
+    /// Returns the diff with the keys removed in the range.
+    pub async fn write_diff_snapshot(
+        mut self,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> anyhow::Result<SnapshotDiff> {
+        let latest_height = self
+            .db
+            .on_chain()
+            .latest_height()
+            .ok_or_else(|| anyhow::anyhow!("The database doesn't have any blocks"))?;
+        anyhow::ensure!(
+            from_height < to_height,
+            "The `from` height {from_height} should be less than the `to` height {to_height}"
+        );
+        anyhow::ensure!(
+            to_height <= latest_height,
+            "The `to` height {to_height} is above the latest height {latest_height}"
+        );
+
+        let mut modified_keys =
+            self.db.on_chain().modified_keys(&from_height, &to_height)?;
+        let view = self.db.on_chain().view_at(&to_height)?;
+        Self::add_modified_contracts(&view, &mut modified_keys)?;
+
+        let mut diff = SnapshotDiff::new(from_height, to_height);
+        macro_rules! export {
+            ($($table: ty),*) => {
+                $(self.spawn_diff_task::<$table>(&view, &mut modified_keys, &mut diff, to_height)?;)*
+            };
+        }
+        export!(
+            Coins,
+            Messages,
+            BlobData,
+            ContractsRawCode,
+            ContractsLatestUtxo,
+            ContractsState,
+            ContractsAssets,
+            FuelBlocks,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            Transactions,
+            SealedBlockConsensus,
+            ProcessedTransactions
+        );
+
+        let view = self.db.on_chain().latest_view()?;
+        let header = view.block_header(&to_height)?;
+        let blocks_root = view.block_header_merkle_root(&to_height)?;
+        self.finalize_with(LastBlockConfig::from_header(&header, blocks_root))
+            .await?;
+
+        Ok(diff)
+    }
+
+    /// The state config groups the contract entries by the contract code.
+    /// So the diff includes the code and the latest UTXO of each contract
+    /// with the modified state or balances.
+    fn add_modified_contracts(
+        view: &OnChainKeyValueView,
+        modified_keys: &mut ModifiedKeys,
+    ) -> anyhow::Result<()> {
+        let contract_ids = [
+            ContractsState::column().id(),
+            ContractsAssets::column().id(),
+        ]
+        .iter()
+        .filter_map(|column| modified_keys.get(column))
+        .flatten()
+        .filter_map(|key| key.get(..ContractId::LEN))
+        .map(<[u8]>::to_vec)
+        .collect::<std::collections::BTreeSet<_>>();
+
+        for contract_id in contract_ids {
+            if !view.exists(&contract_id, ContractsRawCode::column())? {
+                continue
+            }
+            for column in [ContractsRawCode::column(), ContractsLatestUtxo::column()] {
+                modified_keys
+                    .entry(column.id())
+                    .or_default()
+                    .insert(contract_id.clone());
+            }
+        }
+
+        Ok(())
+    }
+
     async fn finalize(self) -> anyhow::Result<SnapshotMetadata> {
-        let writer = self.create_writer()?;
         let view = self.db.on_chain().latest_view()?;
         let latest_block = view.latest_block()?;
         let blocks_root =
@@ -167,6 +274,14 @@ where
         let latest_block =
             LastBlockConfig::from_header(latest_block.header(), blocks_root);
 
+        self.finalize_with(latest_block).await
+    }
+
+    async fn finalize_with(
+        self,
+        latest_block: LastBlockConfig,
+    ) -> anyhow::Result<SnapshotMetadata> {
+        let writer = self.create_writer()?;
         let writer_fragment = writer.partial_close()?;
         self.task_manager
             .wait()
@@ -220,4 +335,80 @@ where
 
         Ok(())
     }
+
+    fn spawn_diff_task<T>(
+        &mut self,
+        view: &OnChainKeyValueView,
+        modified_keys: &mut ModifiedKeys,
+        diff: &mut SnapshotDiff,
+        to_height: BlockHeight,
+    ) -> anyhow::Result<()>
+    where
+        T: TableWithBlueprint<Column = Column> + 'static + Send + Sync,
+        T::Blueprint: BlueprintInspect<T, OnChainKeyValueView>,
+        TableEntry<T>: serde::Serialize,
+        StateConfigBuilder: AddTable<T>,
+    {
+        let keys = modified_keys.remove(&T::column().id()).unwrap_or_default();
+        let mut existing_keys = vec![];
+        let mut removed_keys = vec![];
+        for key in keys {
+            if view.exists(&key, T::column())? {
+                existing_keys.push(key);
+            } else {
+                removed_keys.push(key);
+            }
+        }
+        if !removed_keys.is_empty() {
+            diff.removed_keys.insert(T::column().name(), removed_keys);
+        }
+
+        let mut writer = self.create_writer()?;
+        let group_size = self.group_size;
+
+        let db = self.db.on_chain().clone();
+        let progress_tracker = self.multi_progress.table_reporter(
+            Some(existing_keys.len().div_ceil(group_size)),
+            T::column().name(),
+        );
+        self.task_manager.spawn_blocking(move |cancel| {
+            let view = db.view_at(&to_height)?;
+            existing_keys
+                .into_iter()
+                .map(|key| {
+                    let value = view.get(&key, T::column())?.ok_or_else(|| {
+                        anyhow::anyhow!("The `{}` entry is missing", T::column().name())
+                    })?;
+                    decode_entry::<T>(&key, &value)
+                })
+                .chunks(group_size)
+                .into_iter()
+                .take_while(|_| !cancel.is_cancelled())
+                .enumerate()
+                .try_for_each(|(index, chunk)| {
+                    progress_tracker.set_index(index);
+
+                    writer.write(chunk.try_collect()?)
+                })?;
+            writer.partial_close()
+        });
+
+        Ok(())
+    }
+}
+
+fn decode_entry<T>(key: &[u8], value: &[u8]) -> anyhow::Result<TableEntry<T>>
+where
+    T: TableWithBlueprint<Column = Column>,
+    T::Blueprint: BlueprintInspect<T, OnChainKeyValueView>,
+{
+    let key =
+        <T::Blueprint as BlueprintInspect<T, OnChainKeyValueView>>::KeyCodec::decode(
+            key,
+        )?;
+    let value =
+        <T::Blueprint as BlueprintInspect<T, OnChainKeyValueView>>::ValueCodec::decode(
+            value,
+        )?;
+    Ok(TableEntry { key, value })
 }
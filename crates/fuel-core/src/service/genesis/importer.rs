@@ -20,6 +20,7 @@ use crate::{
             OldTransactions,
         },
         transactions::{
+            ContractTransactions,
             OwnedTransactions,
             TransactionStatuses,
         },
@@ -140,6 +141,7 @@ impl SnapshotImporter {
 
         self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
         self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
+        self.spawn_worker_off_chain::<ContractTransactions, ContractTransactions>()?;
         self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
         self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
         self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
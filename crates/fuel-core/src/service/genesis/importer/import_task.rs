@@ -211,6 +211,7 @@ mod tests {
         state::{
             IterableKeyValueView,
             KeyValueView,
+            ModifiedKeys,
             TransactableStorage,
             in_memory::memory_store::MemoryStore,
         },
@@ -597,6 +598,14 @@ mod tests {
         fn rollback_block_to(&self, _: &BlockHeight) -> StorageResult<()> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
+
+        fn modified_keys(
+            &self,
+            _: &BlockHeight,
+            _: &BlockHeight,
+        ) -> StorageResult<ModifiedKeys> {
+            Err(anyhow::anyhow!("I refuse to work!").into())
+        }
     }
 
     #[test]
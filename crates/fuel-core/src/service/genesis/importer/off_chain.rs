@@ -16,6 +16,7 @@ use crate::{
                 OldTransactions,
             },
             transactions::{
+                ContractTransactions,
                 OwnedTransactions,
                 TransactionStatuses,
             },
@@ -132,6 +133,24 @@ impl ImportTable for Handler<OwnedTransactions, OwnedTransactions> {
     }
 }
 
+impl ImportTable for Handler<ContractTransactions, ContractTransactions> {
+    type TableInSnapshot = ContractTransactions;
+    type TableBeingWritten = ContractTransactions;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage::<ContractTransactions>()
+                .insert(&entry.key, &entry.value)?;
+        }
+        Ok(())
+    }
+}
+
 impl ImportTable for Handler<OwnedMessageIds, Messages> {
     type TableInSnapshot = Messages;
     type TableBeingWritten = OwnedMessageIds;
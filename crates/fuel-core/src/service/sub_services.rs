@@ -14,6 +14,7 @@ use fuel_core_gas_price_service::v1::{
     uninitialized_task::new_gas_price_service_v1,
 };
 
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::Trigger;
 use fuel_core_storage::{
     self,
@@ -79,6 +80,10 @@ use crate::{
             VerifierAdapter,
             chain_state_info_provider,
             consensus_module::poa::InDirectoryPredefinedBlocks,
+            da_derivation::{
+                self,
+                InDirectoryCompressedBlocks,
+            },
             fuel_gas_price_provider::FuelGasPriceProvider,
             graphql_api::GraphQLBlockImporter,
             import_result_provider::ImportResultProvider,
@@ -163,6 +168,8 @@ pub fn init_sub_services(
             bad_block_header: -100.,
             missing_transactions: -100.,
             invalid_transactions: -100.,
+            bad_block: -100.,
+            invalid_state_chunk: -100.,
         };
         P2PAdapter::new(
             p2p_externals.as_ref().map(|ext| ext.0.clone()),
@@ -329,6 +336,16 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    // The blocks of the BFT chain are produced by the validators in the consensus rounds.
+    if matches!(chain_config.consensus, ConsensusConfig::Bft(_)) {
+        production_enabled = false;
+    }
+
+    // The blocks are derived from the DA compressed blocks instead of being produced.
+    if config.da_derivation.is_some() {
+        production_enabled = false;
+    }
+
     let signer = FuelBlockSigner::new(config.consensus_signer.clone());
 
     #[cfg(feature = "shared-sequencer")]
@@ -384,17 +401,54 @@ pub fn init_sub_services(
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
     #[cfg(feature = "p2p")]
-    let sync = fuel_core_sync::service::new_service(
-        last_height,
-        p2p_adapter.clone(),
-        importer_adapter.clone(),
-        super::adapters::ConsensusAdapter::new(
+    let bft = match &chain_config.consensus {
+        ConsensusConfig::Bft(validators) => Some(fuel_core_bft::new_service(
+            &last_block_header,
+            fuel_core_bft::Config {
+                validators: validators.clone(),
+                signer: config.consensus_signer.clone(),
+                timeouts: config.bft_timeouts,
+                production_timeout: config.production_timeout,
+            },
+            p2p_adapter.clone(),
+            producer_adapter.clone(),
+            executor.clone(),
+            importer_adapter.clone(),
+            SystemTime,
+        )),
+        _ => None,
+    };
+
+    #[cfg(feature = "p2p")]
+    let sync = {
+        let consensus_adapter = super::adapters::ConsensusAdapter::new(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
-            relayer_adapter,
-        ),
-        config.sync,
-    )?;
+            relayer_adapter.clone(),
+        );
+        match config.snap_sync {
+            Some(snap_sync) => fuel_core_sync::service::new_service_with_state_sync(
+                last_height,
+                p2p_adapter.clone(),
+                importer_adapter.clone(),
+                consensus_adapter,
+                super::adapters::StateImporterAdapter::new(
+                    &database,
+                    chain_id,
+                    config.base_asset_id(),
+                ),
+                config.sync,
+                snap_sync,
+            )?,
+            None => fuel_core_sync::service::new_service(
+                last_height,
+                p2p_adapter.clone(),
+                importer_adapter.clone(),
+                consensus_adapter,
+                config.sync,
+            )?,
+        }
+    };
 
     // we allow the consumers of the database access even
     // when the compression service is disabled
@@ -419,6 +473,36 @@ pub fn init_sub_services(
         ),
     };
 
+    let da_derivation_service = match &config.da_derivation {
+        None => None,
+        Some(cfg) => {
+            // Both services maintain the temporal registry in the compression database.
+            if matches!(config.da_compression, DaCompressionMode::Enabled(_)) {
+                return Err(anyhow::anyhow!(
+                    "The DA compression can't be enabled together with the DA derivation"
+                ));
+            }
+            // The derived blocks are not signed, so they must not be gossiped
+            // or served to other nodes.
+            #[cfg(feature = "p2p")]
+            if config.p2p.is_some() {
+                return Err(anyhow::anyhow!(
+                    "The P2P can't be enabled together with the DA derivation"
+                ));
+            }
+            Some(da_derivation::new_service(
+                InDirectoryCompressedBlocks::new(cfg.source_path.clone()),
+                database.on_chain().clone(),
+                database.compression().clone(),
+                executor.clone(),
+                relayer_adapter.clone(),
+                importer_adapter.clone(),
+                chain_id,
+                cfg,
+            ))
+        }
+    };
+
     let schema = crate::schema::dap::init(build_schema(), config.debug)
         .data(database.on_chain().clone());
 
@@ -461,6 +545,7 @@ pub fn init_sub_services(
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
         Box::new(universal_gas_price_provider),
+        Box::new(database.gas_price().clone()),
         Box::new(chain_state_info_provider),
         SharedMemoryPool::new(config.memory_pool_size),
         graphql_block_height_subscription_handle,
@@ -499,8 +584,14 @@ pub fn init_sub_services(
     #[cfg(feature = "p2p")]
     {
         if let Some(network) = network.take() {
-            services.push(Box::new(network));
-            services.push(Box::new(sync));
+            let network_services: SubServices = vec![Box::new(network), Box::new(sync)];
+            if config.snap_sync.is_some() {
+                // The snap sync replaces the state of the fresh node while the sync
+                // service starts, so it should start before the services that read the state.
+                services.splice(0..0, network_services);
+            } else {
+                services.extend(network_services);
+            }
             if let Some(pre_confirmation_service) = pre_confirmation_service {
                 services.push(Box::new(pre_confirmation_service));
             }
@@ -517,10 +608,18 @@ pub fn init_sub_services(
         services.push(Box::new(compression_service));
     }
 
+    if let Some(da_derivation_service) = da_derivation_service {
+        services.push(Box::new(da_derivation_service));
+    }
+
     // always make sure that the block producer is inserted last
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
+    #[cfg(feature = "p2p")]
+    if let Some(bft) = bft {
+        services.push(Box::new(bft));
+    }
 
     Ok((services, shared))
 }
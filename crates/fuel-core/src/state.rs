@@ -15,7 +15,13 @@ use fuel_core_storage::{
     kv_store::StorageColumn,
     transactional::StorageChanges,
 };
-use std::fmt::Debug;
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    fmt::Debug,
+};
 
 pub mod data_source;
 pub mod generic_database;
@@ -32,6 +38,9 @@ pub mod rocks_db_key_iterator;
 pub type ColumnType<Description> = <Description as DatabaseDescription>::Column;
 pub type HeightType<Description> = <Description as DatabaseDescription>::Height;
 
+/// The encoded keys modified by the blocks, grouped by the column id.
+pub type ModifiedKeys = HashMap<u32, BTreeSet<Vec<u8>>>;
+
 /// A type extends the `KeyValueView`, allowing iteration over the storage.
 pub type IterableKeyValueView<Column, BlockHeight> =
     GenericDatabase<IterableKeyValueViewWrapper<Column>, BlockHeight>;
@@ -69,6 +78,9 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
 
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
 
+    /// Returns the keys modified by the blocks in the `(from, to]` range of heights.
+    fn modified_keys(&self, from: &Height, to: &Height) -> StorageResult<ModifiedKeys>;
+
     fn shutdown(&self) {
         // Do nothing by default
     }
@@ -98,4 +110,8 @@ where
     fn rollback_block_to(&self, _: &Height) -> StorageResult<()> {
         unimplemented!()
     }
+
+    fn modified_keys(&self, _: &Height, _: &Height) -> StorageResult<ModifiedKeys> {
+        unimplemented!()
+    }
 }
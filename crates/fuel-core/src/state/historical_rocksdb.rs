@@ -12,6 +12,7 @@ use crate::{
         HeightType,
         IterableKeyValueView,
         KeyValueView,
+        ModifiedKeys,
         TransactableStorage,
         historical_rocksdb::{
             description::{
@@ -134,6 +135,18 @@ where
         })
     }
 
+    pub fn default_open_read_only<P: AsRef<Path>>(
+        path: P,
+        state_rewind_policy: StateRewindPolicy,
+        database_config: DatabaseConfig,
+    ) -> DatabaseResult<Self> {
+        let db = RocksDb::<Historical<Description>>::default_open_read_only(
+            path,
+            database_config,
+        )?;
+        Self::new(db, state_rewind_policy)
+    }
+
     fn reverse_history_changes(&self, changes: &Changes) -> StorageResult<Changes> {
         let mut reverse_changes = Changes::default();
 
@@ -370,6 +383,37 @@ where
         Ok(())
     }
 
+    fn modified_keys(&self, from: u64, to: u64) -> StorageResult<ModifiedKeys> {
+        if from >= to {
+            return Err(anyhow::anyhow!(
+                "The `from` height {from} should be less than the `to` height {to}"
+            )
+            .into());
+        }
+        let has_v1_history = self.has_v1_history();
+        let tx = self.db.read_transaction();
+        let mut modified_keys = ModifiedKeys::default();
+
+        // The reverse modifications at height `X` contain all keys
+        // modified by the block at height `X`.
+        for height in from.saturating_add(1)..=to {
+            let changes = multiversion_get(&tx, height, has_v1_history)?.ok_or(
+                DatabaseError::NoHistoryForRequestedHeight {
+                    requested_height: height,
+                },
+            )?;
+
+            for (column, column_changes) in changes {
+                modified_keys
+                    .entry(column)
+                    .or_default()
+                    .extend(column_changes.into_keys().map(Into::into));
+            }
+        }
+
+        Ok(modified_keys)
+    }
+
     fn has_v1_history(&self) -> bool {
         use core::sync::atomic::Ordering;
 
@@ -400,6 +444,33 @@ where
     }
 }
 
+// Try to get the value from `ModificationsHistoryV2`, or return value from
+// `ModificationsHistoryV1`, if database still has v1 entries.
+fn multiversion_get<Description, T>(
+    storage_transaction: &StorageTransaction<T>,
+    height: u64,
+    has_v1_history: bool,
+) -> StorageResult<Option<Changes>>
+where
+    Description: DatabaseDescription,
+    T: KeyValueInspect<Column = Column<Description>>,
+{
+    let v2_changes = storage_transaction
+        .storage_as_ref::<ModificationsHistoryV2<Description>>()
+        .get(&height)?
+        .map(|changes| changes.into_owned());
+
+    if v2_changes.is_none() && has_v1_history {
+        let v1_changes = storage_transaction
+            .storage_as_ref::<ModificationsHistoryV1<Description>>()
+            .get(&height)?
+            .map(|changes| changes.into_owned());
+        Ok(v1_changes)
+    } else {
+        Ok(v2_changes)
+    }
+}
+
 // Try to take the value from `ModificationsHistoryV2`, or return value from
 // `ModificationsHistoryV1`, if database still has v1 entries.
 fn multiversion_take<Description, T>(
@@ -622,6 +693,14 @@ where
         self.rollback_block_to(height.as_u64())
     }
 
+    fn modified_keys(
+        &self,
+        from: &Description::Height,
+        to: &Description::Height,
+    ) -> StorageResult<ModifiedKeys> {
+        self.modified_keys(from.as_u64(), to.as_u64())
+    }
+
     fn shutdown(&self) {
         self.db.shutdown()
     }
@@ -1242,4 +1321,81 @@ mod tests {
             assert_eq!(entries.len(), height - 1);
         }
     }
+
+    #[test]
+    fn modified_keys__returns_keys_modified_in_the_range() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+
+        fn key(height: u8) -> ContractsAssetKey {
+            ContractsAssetKey::new(&[height; 32].into(), &[213; 32].into())
+        }
+
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&key(1), &123)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&key(2), &123)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes().into())
+            .unwrap();
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&key(1))
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(3u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // When
+        let all_blocks = historical_rocks_db.modified_keys(0, 3).unwrap();
+        let last_block = historical_rocks_db.modified_keys(2, 3).unwrap();
+
+        // Then
+        let column = ContractsAssets::column().id();
+        assert_eq!(
+            all_blocks[&column],
+            [key(1).as_ref().to_vec(), key(2).as_ref().to_vec()].into()
+        );
+        assert_eq!(last_block[&column], [key(1).as_ref().to_vec()].into());
+    }
+
+    #[test]
+    fn modified_keys__fails_without_history() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::NoRewind).unwrap();
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&key(), &123)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // When
+        let result = historical_rocks_db.modified_keys(0, 1);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(DatabaseError::NoHistoryForRequestedHeight {
+                requested_height: 1,
+            }
+            .into())
+        );
+    }
 }
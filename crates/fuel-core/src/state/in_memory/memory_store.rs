@@ -11,6 +11,7 @@ use crate::{
         IterDirection,
         IterableKeyValueView,
         KeyValueView,
+        ModifiedKeys,
         TransactableStorage,
         in_memory::memory_view::MemoryView,
         iterable_key_value_view::IterableKeyValueViewWrapper,
@@ -257,6 +258,18 @@ where
                 .into(),
         )
     }
+
+    fn modified_keys(
+        &self,
+        _: &Description::Height,
+        _: &Description::Height,
+    ) -> StorageResult<ModifiedKeys> {
+        // TODO: https://github.com/FuelLabs/fuel-core/issues/1995
+        Err(
+            anyhow::anyhow!("The historical view is not implemented for `MemoryStore`")
+                .into(),
+        )
+    }
 }
 
 #[cfg(test)]
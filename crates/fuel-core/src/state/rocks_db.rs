@@ -225,6 +225,18 @@ where
         )
     }
 
+    pub fn default_open_read_only<P: AsRef<Path>>(
+        path: P,
+        database_config: DatabaseConfig,
+    ) -> DatabaseResult<Self> {
+        Self::open_read_only(
+            path,
+            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+            false,
+            database_config,
+        )
+    }
+
     pub fn prune(path: &Path) -> DatabaseResult<()> {
         let path = path.join(Description::name());
         DB::destroy(&Options::default(), path)
@@ -14,7 +14,7 @@ pub enum Module {
     P2P,
     Producer,
     TxPool,
-    GraphQL, // TODO[RC]: Not used... yet.
+    GraphQL,
     GasPrice,
     TxStatusManager,
     Compression,
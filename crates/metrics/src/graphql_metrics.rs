@@ -8,6 +8,7 @@ use crate::{
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
@@ -21,11 +22,33 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ThrottleLabel {
+    // the name of the API key, or `ip` for clients without a key
+    client: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct FieldLabel {
+    // the top-level field of the operation
+    field: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionLabel {
+    // the limit violated by the query
+    reason: String,
+}
+
 pub struct GraphqlMetrics {
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
     requests: Family<Label, Histogram>,
     queries_complexity: Histogram,
+    fields_complexity: Family<FieldLabel, Histogram>,
+    rejected_queries: Family<RejectionLabel, Counter>,
+    active_subscriptions: Family<FieldLabel, Gauge>,
+    throttled_requests: Family<ThrottleLabel, Counter>,
 }
 
 impl GraphqlMetrics {
@@ -35,6 +58,13 @@ impl GraphqlMetrics {
         let requests = Family::<Label, Histogram>::new_with_constructor(|| {
             Histogram::new(buckets(Buckets::Timing))
         });
+        let fields_complexity =
+            Family::<FieldLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets_complexity())
+            });
+        let rejected_queries = Family::<RejectionLabel, Counter>::default();
+        let active_subscriptions = Family::<FieldLabel, Gauge>::default();
+        let throttled_requests = Family::<ThrottleLabel, Counter>::default();
         let mut registry = global_registry().registry.lock();
         registry.register("graphql_request_duration_seconds", "", requests.clone());
         registry.register(
@@ -42,6 +72,26 @@ impl GraphqlMetrics {
             "The complexity of all queries received",
             queries_complexity.clone(),
         );
+        registry.register(
+            "graphql_field_complexity",
+            "The complexity of the queries per top-level field",
+            fields_complexity.clone(),
+        );
+        registry.register(
+            "graphql_rejected_queries",
+            "The number of queries rejected by the depth, complexity and recursion limits",
+            rejected_queries.clone(),
+        );
+        registry.register(
+            "graphql_active_subscriptions",
+            "The number of active subscriptions per top-level field",
+            active_subscriptions.clone(),
+        );
+        registry.register(
+            "graphql_throttled_requests",
+            "The number of requests rejected by the rate limiter",
+            throttled_requests.clone(),
+        );
 
         registry.register(
             "importer_tx_count",
@@ -53,6 +103,10 @@ impl GraphqlMetrics {
             total_txs_count: tx_count_gauge,
             queries_complexity,
             requests,
+            fields_complexity,
+            rejected_queries,
+            active_subscriptions,
+            throttled_requests,
         }
     }
 
@@ -66,6 +120,46 @@ impl GraphqlMetrics {
     pub fn graphql_complexity_observe(&self, complexity: f64) {
         self.queries_complexity.observe(complexity);
     }
+
+    pub fn graphql_field_complexity_observe(&self, field: &str, complexity: f64) {
+        self.fields_complexity
+            .get_or_create(&FieldLabel {
+                field: field.to_string(),
+            })
+            .observe(complexity);
+    }
+
+    pub fn graphql_rejected_query(&self, reason: &str) {
+        self.rejected_queries
+            .get_or_create(&RejectionLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn graphql_subscription_started(&self, field: &str) {
+        self.active_subscriptions
+            .get_or_create(&FieldLabel {
+                field: field.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn graphql_subscription_finished(&self, field: &str) {
+        self.active_subscriptions
+            .get_or_create(&FieldLabel {
+                field: field.to_string(),
+            })
+            .dec();
+    }
+
+    pub fn graphql_throttled_request(&self, client: &str) {
+        self.throttled_requests
+            .get_or_create(&ThrottleLabel {
+                client: client.to_string(),
+            })
+            .inc();
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
@@ -0,0 +1,66 @@
+use crate::engine::TimeoutStep;
+use fuel_core_chain_config::BftConfig;
+use fuel_core_types::signer::SignMode;
+use tokio::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The validator set of the chain.
+    pub validators: BftConfig,
+    /// The key of the validator. The node only follows the consensus
+    /// if the key is unavailable or doesn't belong to the validator set.
+    pub signer: SignMode,
+    pub timeouts: Timeouts,
+    pub production_timeout: Duration,
+}
+
+/// The durations of the round steps.
+///
+/// Each subsequent round of the same height waits `delta` longer, so
+/// the validators eventually agree even if the network is slow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    pub propose: Duration,
+    pub prevote: Duration,
+    pub precommit: Duration,
+    pub delta: Duration,
+    /// The delay before the start of the next height. It gives the slow
+    /// validators the time to receive the remaining precommits.
+    pub commit: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            delta: Duration::from_millis(500),
+            commit: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Timeouts {
+    /// Returns the duration of the step in the round.
+    pub fn duration(&self, step: TimeoutStep, round: u32) -> Duration {
+        let base = match step {
+            TimeoutStep::Propose => self.propose,
+            TimeoutStep::Prevote => self.prevote,
+            TimeoutStep::Precommit => self.precommit,
+        };
+        base.saturating_add(self.delta.saturating_mul(round))
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            validators: BftConfig::new(vec![]),
+            signer: SignMode::Unavailable,
+            timeouts: Timeouts::default(),
+            production_timeout: Duration::from_secs(20),
+        }
+    }
+}
@@ -0,0 +1,671 @@
+//! The state machine of the Tendermint consensus.
+//!
+//! The engine doesn't perform any IO. It consumes the checked messages, the produced
+//! blocks and the expired timeouts and returns the [`Action`]s the caller must perform.
+//! It makes the behaviour of the engine deterministic and easy to test in
+//! a simulated network.
+
+use fuel_core_chain_config::BftConfig;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::{
+            Consensus,
+            bft::{
+                BftConsensus,
+                SignedProposal,
+                SignedVote,
+                Vote,
+                VoteStep,
+            },
+        },
+        primitives::BlockId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The max number of rounds ahead of the current round of the messages
+/// the engine accepts.
+pub const MAX_FUTURE_ROUNDS: u32 = 16;
+
+/// The max number of proposals and votes of one validator the engine stores
+/// per height. The honest validator sends at most three messages per round.
+pub const MAX_MESSAGES_PER_SIGNER: usize = 1024;
+
+/// The step of the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    /// Waiting for the proposal.
+    Propose,
+    /// Waiting for the prevotes.
+    Prevote,
+    /// Waiting for the precommits.
+    Precommit,
+    /// The block is committed, waiting for the next height.
+    Commit,
+}
+
+/// The step of the round that timed out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeoutStep {
+    Propose,
+    Prevote,
+    Precommit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Timeout {
+    pub height: BlockHeight,
+    pub round: u32,
+    pub step: TimeoutStep,
+}
+
+/// The action requested by the engine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Produce a new block and pass it to the [`Engine::propose`].
+    ProduceBlock { height: BlockHeight, round: u32 },
+    /// Sign the block and the proposal, broadcast it and pass it back to the engine.
+    SignProposal {
+        height: BlockHeight,
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    },
+    /// Sign the vote, broadcast it and pass it back to the engine.
+    SignVote(Vote),
+    /// Call the [`Engine::on_timeout`] when the timeout expires.
+    ScheduleTimeout(Timeout),
+    /// The validators committed the block. Import it and start the next height.
+    Commit(SealedBlock),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MessageError {
+    #[error("The message is for the already committed height")]
+    Stale,
+    #[error("The message is for the height {0} that is too far in the future")]
+    Future(BlockHeight),
+    #[error("The message is for the round {0} that is too far in the future")]
+    FutureRound(u32),
+    #[error("The message has an invalid signature: {0}")]
+    InvalidSignature(anyhow::Error),
+    #[error("The message is signed by {0} that is not a validator")]
+    UnknownValidator(Address),
+    #[error("The proposal is signed by {0} that is not the proposer of the round")]
+    WrongProposer(Address),
+}
+
+/// The message with the verified signature of the validator.
+#[derive(Clone, Debug)]
+pub struct Checked<T> {
+    signer: Address,
+    message: T,
+}
+
+impl<T> Checked<T> {
+    pub fn signer(&self) -> &Address {
+        &self.signer
+    }
+
+    pub fn message(&self) -> &T {
+        &self.message
+    }
+
+    pub fn into_inner(self) -> T {
+        self.message
+    }
+}
+
+struct RoundProposal {
+    proposal: SignedProposal,
+    block_id: BlockId,
+    is_block_valid: bool,
+}
+
+#[derive(Default)]
+struct VoteSet {
+    votes: BTreeMap<Address, SignedVote>,
+    total_power: u64,
+    power_by_block: HashMap<Option<BlockId>, u64>,
+}
+
+impl VoteSet {
+    /// Adds the vote of the validator. Returns `false` if the validator already voted.
+    fn insert(&mut self, signer: Address, voting_power: u64, vote: SignedVote) -> bool {
+        if self.votes.contains_key(&signer) {
+            return false
+        }
+        let power = self.power_by_block.entry(vote.vote.block_id).or_default();
+        *power = power.saturating_add(voting_power);
+        self.total_power = self.total_power.saturating_add(voting_power);
+        self.votes.insert(signer, vote);
+        true
+    }
+
+    fn power_for(&self, block_id: &Option<BlockId>) -> u64 {
+        self.power_by_block
+            .get(block_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+pub struct Engine {
+    validators: BftConfig,
+    address: Option<Address>,
+    height: BlockHeight,
+    round: u32,
+    step: Step,
+    locked: Option<(u32, BlockId)>,
+    valid: Option<(u32, Block)>,
+    proposals: HashMap<u32, RoundProposal>,
+    votes: HashMap<(u32, VoteStep), VoteSet>,
+    round_participants: BTreeMap<u32, HashSet<Address>>,
+    messages_per_signer: HashMap<Address, usize>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    valid_block_updated: bool,
+}
+
+impl Engine {
+    /// Creates the engine that waits for the [`Engine::start_height`].
+    ///
+    /// The engine only follows the consensus without voting if the `address`
+    /// doesn't belong to the validator set.
+    pub fn new(validators: BftConfig, address: Option<Address>) -> Self {
+        Self {
+            validators,
+            address,
+            height: BlockHeight::default(),
+            round: 0,
+            step: Step::Commit,
+            locked: None,
+            valid: None,
+            proposals: HashMap::new(),
+            votes: HashMap::new(),
+            round_participants: BTreeMap::new(),
+            messages_per_signer: HashMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            valid_block_updated: false,
+        }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// Returns `true` if the engine votes on the blocks.
+    pub fn is_validator(&self) -> bool {
+        self.address
+            .is_some_and(|address| self.validators.voting_power(&address).is_some())
+    }
+
+    /// Starts the agreement on the block at the `height`, dropping the state of the previous height.
+    pub fn start_height(&mut self, height: BlockHeight) -> Vec<Action> {
+        self.height = height;
+        self.locked = None;
+        self.valid = None;
+        self.proposals.clear();
+        self.votes.clear();
+        self.round_participants.clear();
+        self.messages_per_signer.clear();
+
+        let mut actions = vec![];
+        self.start_round(0, &mut actions);
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Proposes the block produced in response to the [`Action::ProduceBlock`].
+    pub fn propose(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        block: Block,
+    ) -> Vec<Action> {
+        let is_expected = height == self.height
+            && round == self.round
+            && self.step == Step::Propose
+            && self.is_proposer(round)
+            && !self.proposals.contains_key(&round);
+        if !is_expected {
+            return vec![]
+        }
+        vec![Action::SignProposal {
+            height,
+            round,
+            valid_round: None,
+            block,
+        }]
+    }
+
+    /// Verifies that the proposal is signed by the proposer of the round.
+    ///
+    /// The proposals for the next height pass the check,
+    /// but they must be handled after the start of that height.
+    pub fn check_proposal(
+        &self,
+        proposal: SignedProposal,
+    ) -> Result<Checked<SignedProposal>, MessageError> {
+        let height = proposal.proposal.height;
+        let round = proposal.proposal.round;
+        self.check_height(height)?;
+        self.check_round(height, round)?;
+        let signer = proposal.signer().map_err(MessageError::InvalidSignature)?;
+        if self.validators.proposer(height, round) != Some(signer) {
+            return Err(MessageError::WrongProposer(signer))
+        }
+        Ok(Checked {
+            signer,
+            message: proposal,
+        })
+    }
+
+    /// Verifies that the vote is signed by the validator.
+    ///
+    /// The votes for the next height pass the check,
+    /// but they must be handled after the start of that height.
+    pub fn check_vote(
+        &self,
+        vote: SignedVote,
+    ) -> Result<Checked<SignedVote>, MessageError> {
+        self.check_height(vote.vote.height)?;
+        self.check_round(vote.vote.height, vote.vote.round)?;
+        let signer = vote.signer().map_err(MessageError::InvalidSignature)?;
+        if self.validators.voting_power(&signer).is_none() {
+            return Err(MessageError::UnknownValidator(signer))
+        }
+        Ok(Checked {
+            signer,
+            message: vote,
+        })
+    }
+
+    /// Handles the proposal of the current height.
+    ///
+    /// The caller validates the proposed block by executing it
+    /// on top of the latest committed block.
+    pub fn handle_proposal(
+        &mut self,
+        proposal: Checked<SignedProposal>,
+        is_block_valid: bool,
+    ) -> Vec<Action> {
+        let Checked { signer, message } = proposal;
+        let round = message.proposal.round;
+        if message.proposal.height != self.height
+            || self.step == Step::Commit
+            || self.proposals.contains_key(&round)
+            || !self.record_message_of(signer)
+        {
+            return vec![]
+        }
+
+        let block_id = message.proposal.block.id();
+        self.proposals.insert(
+            round,
+            RoundProposal {
+                proposal: message,
+                block_id,
+                is_block_valid,
+            },
+        );
+        self.round_participants
+            .entry(round)
+            .or_default()
+            .insert(signer);
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Handles the vote of the current height.
+    pub fn handle_vote(&mut self, vote: Checked<SignedVote>) -> Vec<Action> {
+        let Checked { signer, message } = vote;
+        let Vote {
+            height,
+            round,
+            step,
+            ..
+        } = message.vote;
+        if height != self.height || self.step == Step::Commit {
+            return vec![]
+        }
+        let is_duplicate = self
+            .votes
+            .get(&(round, step))
+            .is_some_and(|votes| votes.votes.contains_key(&signer));
+        if is_duplicate || !self.record_message_of(signer) {
+            return vec![]
+        }
+
+        let voting_power = self.validators.voting_power(&signer).unwrap_or_default();
+        let inserted = self.votes.entry((round, step)).or_default().insert(
+            signer,
+            voting_power,
+            message,
+        );
+        if !inserted {
+            return vec![]
+        }
+        self.round_participants
+            .entry(round)
+            .or_default()
+            .insert(signer);
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Handles the expired timeout scheduled by the [`Action::ScheduleTimeout`].
+    pub fn on_timeout(&mut self, timeout: Timeout) -> Vec<Action> {
+        let mut actions = vec![];
+        if timeout.height != self.height || timeout.round != self.round {
+            return actions
+        }
+
+        match (timeout.step, self.step) {
+            (TimeoutStep::Propose, Step::Propose) => {
+                self.vote(VoteStep::Prevote, None, &mut actions);
+                self.step = Step::Prevote;
+            }
+            (TimeoutStep::Prevote, Step::Prevote) => {
+                self.vote(VoteStep::Precommit, None, &mut actions);
+                self.step = Step::Precommit;
+            }
+            (TimeoutStep::Precommit, step) if step != Step::Commit => {
+                self.start_round(self.round.saturating_add(1), &mut actions);
+            }
+            _ => return actions,
+        }
+
+        self.process(&mut actions);
+        actions
+    }
+
+    fn check_height(&self, height: BlockHeight) -> Result<(), MessageError> {
+        if height < self.height || (height == self.height && self.step == Step::Commit) {
+            return Err(MessageError::Stale)
+        }
+        if Some(height) > self.height.succ() {
+            return Err(MessageError::Future(height))
+        }
+        Ok(())
+    }
+
+    /// The messages for the next height are checked against its first round.
+    fn check_round(&self, height: BlockHeight, round: u32) -> Result<(), MessageError> {
+        let current_round = if height == self.height { self.round } else { 0 };
+        if round > current_round.saturating_add(MAX_FUTURE_ROUNDS) {
+            return Err(MessageError::FutureRound(round))
+        }
+        Ok(())
+    }
+
+    /// Counts the stored message of the `signer`.
+    /// Returns `false` if the signer exceeded the [`MAX_MESSAGES_PER_SIGNER`].
+    fn record_message_of(&mut self, signer: Address) -> bool {
+        let messages = self.messages_per_signer.entry(signer).or_default();
+        if *messages >= MAX_MESSAGES_PER_SIGNER {
+            return false
+        }
+        *messages = messages.saturating_add(1);
+        true
+    }
+
+    fn is_proposer(&self, round: u32) -> bool {
+        self.address.is_some()
+            && self.validators.proposer(self.height, round) == self.address
+    }
+
+    fn start_round(&mut self, round: u32, actions: &mut Vec<Action>) {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.valid_block_updated = false;
+
+        if self.is_proposer(round) {
+            match &self.valid {
+                Some((valid_round, block)) => actions.push(Action::SignProposal {
+                    height: self.height,
+                    round,
+                    valid_round: Some(*valid_round),
+                    block: block.clone(),
+                }),
+                None => actions.push(Action::ProduceBlock {
+                    height: self.height,
+                    round,
+                }),
+            }
+        }
+        self.schedule_timeout(TimeoutStep::Propose, actions);
+    }
+
+    fn schedule_timeout(&self, step: TimeoutStep, actions: &mut Vec<Action>) {
+        actions.push(Action::ScheduleTimeout(Timeout {
+            height: self.height,
+            round: self.round,
+            step,
+        }));
+    }
+
+    fn vote(&self, step: VoteStep, block_id: Option<BlockId>, actions: &mut Vec<Action>) {
+        if !self.is_validator() {
+            return
+        }
+        actions.push(Action::SignVote(Vote {
+            height: self.height,
+            round: self.round,
+            step,
+            block_id,
+        }));
+    }
+
+    fn has_quorum_for(
+        &self,
+        round: u32,
+        step: VoteStep,
+        block_id: Option<BlockId>,
+    ) -> bool {
+        self.votes
+            .get(&(round, step))
+            .is_some_and(|votes| self.validators.is_quorum(votes.power_for(&block_id)))
+    }
+
+    fn has_quorum_of_any(&self, round: u32, step: VoteStep) -> bool {
+        self.votes
+            .get(&(round, step))
+            .is_some_and(|votes| self.validators.is_quorum(votes.total_power))
+    }
+
+    /// Applies the rules of the protocol until none of them changes the state.
+    fn process(&mut self, actions: &mut Vec<Action>) {
+        while self.step != Step::Commit {
+            let progressed = self.try_commit(actions)
+                || self.try_skip_round(actions)
+                || self.try_prevote_proposal(actions)
+                || self.try_lock_proposal(actions)
+                || self.try_precommit_nil(actions)
+                || self.try_schedule_timeouts(actions);
+            if !progressed {
+                break
+            }
+        }
+    }
+
+    /// Commits the block precommitted by the quorum in any round.
+    fn try_commit(&mut self, actions: &mut Vec<Action>) -> bool {
+        let decided = self.proposals.iter().find(|(round, proposal)| {
+            proposal.is_block_valid
+                && self.has_quorum_for(
+                    **round,
+                    VoteStep::Precommit,
+                    Some(proposal.block_id),
+                )
+        });
+        let Some((round, proposal)) = decided else {
+            return false
+        };
+
+        let block_id = Some(proposal.block_id);
+        let precommits = self
+            .votes
+            .get(&(*round, VoteStep::Precommit))
+            .map(|votes| {
+                votes
+                    .votes
+                    .values()
+                    .filter(|vote| vote.vote.block_id == block_id)
+                    .map(|vote| vote.signature)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let proposal = &proposal.proposal.proposal;
+        let seal = BftConsensus::new(*round, proposal.block_signature, precommits);
+        actions.push(Action::Commit(SealedBlock {
+            entity: proposal.block.clone(),
+            consensus: Consensus::Bft(seal),
+        }));
+        self.step = Step::Commit;
+        true
+    }
+
+    /// Moves to the future round if more than 1/3 of the voting power is already there.
+    fn try_skip_round(&mut self, actions: &mut Vec<Action>) -> bool {
+        let next_round = self
+            .round_participants
+            .range(self.round.saturating_add(1)..)
+            .find(|(_, participants)| {
+                let voting_power = participants.iter().fold(0u64, |total, address| {
+                    total.saturating_add(
+                        self.validators.voting_power(address).unwrap_or_default(),
+                    )
+                });
+                self.validators.is_honest_majority_possible(voting_power)
+            })
+            .map(|(round, _)| *round);
+
+        match next_round {
+            Some(round) => {
+                self.start_round(round, actions);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Prevotes for the proposal of the current round.
+    fn try_prevote_proposal(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step != Step::Propose {
+            return false
+        }
+        let Some(proposal) = self.proposals.get(&self.round) else {
+            return false
+        };
+        let block_id = proposal.block_id;
+        let is_block_valid = proposal.is_block_valid;
+
+        let accept = match proposal.proposal.proposal.valid_round {
+            None => {
+                is_block_valid && self.locked.is_none_or(|(_, locked)| locked == block_id)
+            }
+            Some(valid_round) if valid_round < self.round => {
+                if !self.has_quorum_for(valid_round, VoteStep::Prevote, Some(block_id)) {
+                    // Wait for the prevotes that justify the re-proposal.
+                    return false
+                }
+                is_block_valid
+                    && self.locked.is_none_or(|(locked_round, locked)| {
+                        locked_round <= valid_round || locked == block_id
+                    })
+            }
+            Some(_) => false,
+        };
+
+        self.vote(VoteStep::Prevote, accept.then_some(block_id), actions);
+        self.step = Step::Prevote;
+        true
+    }
+
+    /// Locks on the proposal of the current round prevoted by the quorum.
+    fn try_lock_proposal(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step < Step::Prevote || self.valid_block_updated {
+            return false
+        }
+        let Some(proposal) = self.proposals.get(&self.round) else {
+            return false
+        };
+        let block_id = proposal.block_id;
+        if !proposal.is_block_valid
+            || !self.has_quorum_for(self.round, VoteStep::Prevote, Some(block_id))
+        {
+            return false
+        }
+        let block = proposal.proposal.proposal.block.clone();
+
+        if self.step == Step::Prevote {
+            self.locked = Some((self.round, block_id));
+            self.vote(VoteStep::Precommit, Some(block_id), actions);
+            self.step = Step::Precommit;
+        }
+        self.valid = Some((self.round, block));
+        self.valid_block_updated = true;
+        true
+    }
+
+    /// Precommits nil if the quorum prevoted nil in the current round.
+    fn try_precommit_nil(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step != Step::Prevote
+            || !self.has_quorum_for(self.round, VoteStep::Prevote, None)
+        {
+            return false
+        }
+        self.vote(VoteStep::Precommit, None, actions);
+        self.step = Step::Precommit;
+        true
+    }
+
+    /// Schedules the timeouts once the quorum voted without agreement.
+    fn try_schedule_timeouts(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step == Step::Prevote
+            && !self.prevote_timeout_scheduled
+            && self.has_quorum_of_any(self.round, VoteStep::Prevote)
+        {
+            self.prevote_timeout_scheduled = true;
+            self.schedule_timeout(TimeoutStep::Prevote, actions);
+            return true
+        }
+        if !self.precommit_timeout_scheduled
+            && self.has_quorum_of_any(self.round, VoteStep::Precommit)
+        {
+            self.precommit_timeout_scheduled = true;
+            self.schedule_timeout(TimeoutStep::Precommit, actions);
+            return true
+        }
+        false
+    }
+}
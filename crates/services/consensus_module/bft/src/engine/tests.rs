@@ -0,0 +1,465 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_chain_config::BftValidator;
+use fuel_core_types::{
+    blockchain::consensus::bft::{
+        BftMessage,
+        Proposal,
+    },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    tai64::Tai64,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use std::collections::VecDeque;
+
+/// The time of the blocks that the validators consider invalid.
+const INVALID_BLOCK_TIME: Tai64 = Tai64(666);
+
+const HEIGHT: u32 = 1;
+
+struct Node {
+    engine: Engine,
+    secret: SecretKey,
+    online: bool,
+    produces_invalid_blocks: bool,
+    timeouts: Vec<Timeout>,
+    committed: Option<SealedBlock>,
+}
+
+/// The in-memory network that delivers the messages of the validators
+/// in a deterministic order. The timeouts fire only when the network is idle.
+struct Network {
+    nodes: Vec<Node>,
+    messages: VecDeque<(usize, BftMessage)>,
+    history: Vec<BftMessage>,
+    fired_timeouts: usize,
+}
+
+impl Network {
+    fn new(size: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secrets: Vec<_> = (0..size).map(|_| SecretKey::random(&mut rng)).collect();
+        let validators = BftConfig::new(
+            secrets
+                .iter()
+                .map(|secret| BftValidator {
+                    address: Input::owner(&secret.public_key()),
+                    voting_power: 1,
+                })
+                .collect(),
+        );
+        let nodes = secrets
+            .into_iter()
+            .map(|secret| Node {
+                engine: Engine::new(
+                    validators.clone(),
+                    Some(Input::owner(&secret.public_key())),
+                ),
+                secret,
+                online: true,
+                produces_invalid_blocks: false,
+                timeouts: vec![],
+                committed: None,
+            })
+            .collect();
+
+        Self {
+            nodes,
+            messages: VecDeque::new(),
+            history: vec![],
+            fired_timeouts: 0,
+        }
+    }
+
+    fn validators(&self) -> BftConfig {
+        self.nodes[0].engine.validators.clone()
+    }
+
+    fn proposer_index(&self, round: u32) -> usize {
+        let proposer = self.validators().proposer(HEIGHT.into(), round).unwrap();
+        self.nodes
+            .iter()
+            .position(|node| Input::owner(&node.secret.public_key()) == proposer)
+            .unwrap()
+    }
+
+    fn start(&mut self) {
+        for index in 0..self.nodes.len() {
+            if self.nodes[index].online {
+                let actions = self.nodes[index].engine.start_height(HEIGHT.into());
+                self.perform(index, actions);
+            }
+        }
+    }
+
+    /// Runs the network until all online nodes commit the block.
+    fn run(&mut self) {
+        for _ in 0..100 {
+            while let Some((from, message)) = self.messages.pop_front() {
+                self.deliver(from, message);
+            }
+            let committed = self
+                .nodes
+                .iter()
+                .filter(|node| node.online)
+                .all(|node| node.committed.is_some());
+            if committed {
+                return
+            }
+            self.fire_timeouts();
+        }
+        panic!("The network didn't commit the block");
+    }
+
+    fn deliver(&mut self, from: usize, message: BftMessage) {
+        for index in 0..self.nodes.len() {
+            if index == from || !self.nodes[index].online {
+                continue
+            }
+            let node = &mut self.nodes[index];
+            // The nodes that already committed the block ignore the remaining messages.
+            let actions = match message.clone() {
+                BftMessage::Proposal(proposal) => {
+                    let is_block_valid =
+                        proposal.proposal.block.header().time() != INVALID_BLOCK_TIME;
+                    match node.engine.check_proposal(*proposal) {
+                        Ok(checked) => {
+                            node.engine.handle_proposal(checked, is_block_valid)
+                        }
+                        Err(_) => continue,
+                    }
+                }
+                BftMessage::Vote(vote) => match node.engine.check_vote(vote) {
+                    Ok(checked) => node.engine.handle_vote(checked),
+                    Err(_) => continue,
+                },
+            };
+            self.perform(index, actions);
+        }
+    }
+
+    fn fire_timeouts(&mut self) {
+        for index in 0..self.nodes.len() {
+            if !self.nodes[index].online {
+                continue
+            }
+            let timeouts = core::mem::take(&mut self.nodes[index].timeouts);
+            for timeout in timeouts {
+                self.fired_timeouts = self.fired_timeouts.saturating_add(1);
+                let actions = self.nodes[index].engine.on_timeout(timeout);
+                self.perform(index, actions);
+            }
+        }
+    }
+
+    fn perform(&mut self, index: usize, actions: Vec<Action>) {
+        let mut actions = VecDeque::from(actions);
+        while let Some(action) = actions.pop_front() {
+            let node = &mut self.nodes[index];
+            match action {
+                Action::ProduceBlock { height, round } => {
+                    let mut block = Block::default();
+                    block.header_mut().set_block_height(height);
+                    let time = if node.produces_invalid_blocks {
+                        INVALID_BLOCK_TIME
+                    } else {
+                        Tai64(u64::from(round))
+                    };
+                    block.header_mut().set_time(time);
+                    actions.extend(node.engine.propose(height, round, block));
+                }
+                Action::SignProposal {
+                    height,
+                    round,
+                    valid_round,
+                    block,
+                } => {
+                    let block_signature =
+                        Signature::sign(&node.secret, block.id().as_message());
+                    let proposal = Proposal {
+                        height,
+                        round,
+                        valid_round,
+                        block,
+                        block_signature,
+                    }
+                    .sign(&node.secret);
+                    let checked = node.engine.check_proposal(proposal.clone()).unwrap();
+                    actions.extend(node.engine.handle_proposal(checked, true));
+                    self.broadcast(index, BftMessage::Proposal(Box::new(proposal)));
+                }
+                Action::SignVote(vote) => {
+                    let vote = vote.sign(&node.secret);
+                    let checked = node.engine.check_vote(vote.clone()).unwrap();
+                    actions.extend(node.engine.handle_vote(checked));
+                    self.broadcast(index, BftMessage::Vote(vote));
+                }
+                Action::ScheduleTimeout(timeout) => node.timeouts.push(timeout),
+                Action::Commit(block) => {
+                    assert!(node.committed.is_none(), "The node committed twice");
+                    node.committed = Some(block);
+                }
+            }
+        }
+    }
+
+    fn broadcast(&mut self, from: usize, message: BftMessage) {
+        self.history.push(message.clone());
+        self.messages.push_back((from, message));
+    }
+
+    fn committed_blocks(&self) -> Vec<&SealedBlock> {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.committed.as_ref())
+            .collect()
+    }
+}
+
+fn bft_seal(block: &SealedBlock) -> &BftConsensus {
+    match &block.consensus {
+        Consensus::Bft(seal) => seal,
+        _ => panic!("Expected the BFT seal"),
+    }
+}
+
+#[test]
+fn engine__honest_validators_commit_block_in_first_round() {
+    // Given
+    let mut network = Network::new(4);
+
+    // When
+    network.start();
+    network.run();
+
+    // Then
+    let committed = network.committed_blocks();
+    assert_eq!(committed.len(), 4);
+    assert!(
+        committed
+            .iter()
+            .all(|block| block.entity == committed[0].entity)
+    );
+    assert_eq!(bft_seal(committed[0]).round, 0);
+    assert_eq!(network.fired_timeouts, 0);
+    crate::verifier::verify_seal(
+        &network.validators(),
+        committed[0].entity.header(),
+        bft_seal(committed[0]),
+    )
+    .expect("The seal should be valid");
+}
+
+#[test]
+fn engine__validators_commit_block_in_next_round_if_proposer_is_offline() {
+    // Given
+    let mut network = Network::new(4);
+    let proposer = network.proposer_index(0);
+    network.nodes[proposer].online = false;
+
+    // When
+    network.start();
+    network.run();
+
+    // Then
+    let committed = network.committed_blocks();
+    assert_eq!(committed.len(), 3);
+    assert!(
+        committed
+            .iter()
+            .all(|block| block.entity == committed[0].entity)
+    );
+    assert_eq!(bft_seal(committed[0]).round, 1);
+    crate::verifier::verify_seal(
+        &network.validators(),
+        committed[0].entity.header(),
+        bft_seal(committed[0]),
+    )
+    .expect("The seal should be valid");
+}
+
+#[test]
+fn engine__validators_prevote_nil_for_invalid_block() {
+    // Given
+    let mut network = Network::new(4);
+    let proposer = network.proposer_index(0);
+    network.nodes[proposer].produces_invalid_blocks = true;
+
+    // When
+    network.start();
+    network.run();
+
+    // Then
+    let committed = network.committed_blocks();
+    assert_eq!(committed.len(), 4);
+    assert!(
+        committed
+            .iter()
+            .all(|block| block.entity == committed[0].entity)
+    );
+    assert_eq!(bft_seal(committed[0]).round, 1);
+    assert_ne!(committed[0].entity.header().time(), INVALID_BLOCK_TIME);
+}
+
+#[test]
+fn engine__follower_commits_block_without_voting() {
+    // Given
+    let mut network = Network::new(4);
+    let validators = network.validators();
+    let mut follower = Engine::new(validators, None);
+    let _ = follower.start_height(HEIGHT.into());
+
+    // When
+    network.start();
+    network.run();
+    let mut actions = vec![];
+    for message in network.history {
+        let result = match message {
+            BftMessage::Proposal(proposal) => follower
+                .check_proposal(*proposal)
+                .map(|checked| follower.handle_proposal(checked, true)),
+            BftMessage::Vote(vote) => follower
+                .check_vote(vote)
+                .map(|checked| follower.handle_vote(checked)),
+        };
+        actions.extend(result.unwrap_or_default());
+    }
+
+    // Then
+    assert!(!follower.is_validator());
+    assert!(
+        !actions
+            .iter()
+            .any(|action| matches!(action, Action::SignVote(_)))
+    );
+    assert_eq!(follower.step(), Step::Commit);
+}
+
+#[test]
+fn check_vote__rejects_vote_of_unknown_validator() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let stranger = SecretKey::random(&mut StdRng::seed_from_u64(1));
+    let vote = Vote::prevote(HEIGHT.into(), 0, None).sign(&stranger);
+
+    // When
+    let result = network.nodes[0].engine.check_vote(vote);
+
+    // Then
+    assert!(matches!(result, Err(MessageError::UnknownValidator(_))));
+}
+
+#[test]
+fn check_vote__accepts_next_height_and_ignores_further_heights() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let secret = network.nodes[1].secret;
+    let engine = &network.nodes[0].engine;
+
+    // When
+    let next = engine.check_vote(Vote::prevote(2u32.into(), 0, None).sign(&secret));
+    let future = engine.check_vote(Vote::prevote(3u32.into(), 0, None).sign(&secret));
+    let stale = engine.check_vote(Vote::prevote(0u32.into(), 0, None).sign(&secret));
+
+    // Then
+    assert!(next.is_ok());
+    assert!(matches!(future, Err(MessageError::Future(_))));
+    assert!(matches!(stale, Err(MessageError::Stale)));
+}
+
+#[test]
+fn check_vote__rejects_rounds_too_far_in_the_future() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let secret = network.nodes[1].secret;
+    let engine = &network.nodes[0].engine;
+
+    // When
+    let last = engine
+        .check_vote(Vote::prevote(HEIGHT.into(), MAX_FUTURE_ROUNDS, None).sign(&secret));
+    let too_far = engine.check_vote(
+        Vote::prevote(HEIGHT.into(), MAX_FUTURE_ROUNDS + 1, None).sign(&secret),
+    );
+    let next_height = engine.check_vote(
+        Vote::prevote((HEIGHT + 1).into(), MAX_FUTURE_ROUNDS + 1, None).sign(&secret),
+    );
+
+    // Then
+    assert!(last.is_ok());
+    assert!(matches!(too_far, Err(MessageError::FutureRound(_))));
+    assert!(matches!(next_height, Err(MessageError::FutureRound(_))));
+}
+
+#[test]
+fn check_proposal__rejects_rounds_too_far_in_the_future() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let round = MAX_FUTURE_ROUNDS + 1;
+    let proposer = &network.nodes[network.proposer_index(round)];
+    let block = Block::default();
+    let proposal = Proposal {
+        height: HEIGHT.into(),
+        round,
+        valid_round: None,
+        block_signature: Signature::sign(&proposer.secret, block.id().as_message()),
+        block,
+    }
+    .sign(&proposer.secret);
+
+    // When
+    let result = network.nodes[0].engine.check_proposal(proposal);
+
+    // Then
+    assert!(matches!(result, Err(MessageError::FutureRound(_))));
+}
+
+#[test]
+fn handle_vote__ignores_messages_of_signer_over_the_limit() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let secret = network.nodes[1].secret;
+    let engine = &mut network.nodes[0].engine;
+    let rounds = 600;
+    while engine.round() < rounds {
+        let round = engine.round();
+        for step in [
+            TimeoutStep::Propose,
+            TimeoutStep::Prevote,
+            TimeoutStep::Precommit,
+        ] {
+            let _ = engine.on_timeout(Timeout {
+                height: HEIGHT.into(),
+                round,
+                step,
+            });
+        }
+    }
+
+    // When
+    for round in 0..rounds {
+        for vote in [
+            Vote::prevote(HEIGHT.into(), round, None),
+            Vote::precommit(HEIGHT.into(), round, None),
+        ] {
+            let checked = engine.check_vote(vote.sign(&secret)).unwrap();
+            let _ = engine.handle_vote(checked);
+        }
+    }
+
+    // Then
+    let stored_votes: usize = engine.votes.values().map(|votes| votes.votes.len()).sum();
+    assert_eq!(stored_votes, MAX_MESSAGES_PER_SIGNER);
+}
@@ -2,3 +2,18 @@
 #![deny(clippy::cast_possible_truncation)]
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
+
+pub mod config;
+pub mod engine;
+pub mod ports;
+pub mod service;
+pub mod verifier;
+
+pub use config::{
+    Config,
+    Timeouts,
+};
+pub use service::{
+    Service,
+    new_service,
+};
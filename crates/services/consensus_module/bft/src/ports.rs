@@ -0,0 +1,73 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::transactional::Changes;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::UncommittedResult as UncommittedExecutionResult,
+        p2p::{
+            BftProposalGossipData,
+            BftVoteGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+use tokio::time::Instant;
+
+#[async_trait::async_trait]
+pub trait BlockProducer: Send + Sync {
+    /// Produces the block with the transactions from the `TxPool`.
+    async fn produce_and_execute_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        deadline: Instant,
+    ) -> anyhow::Result<UncommittedExecutionResult<Changes>>;
+}
+
+pub trait BlockValidator: Send + Sync {
+    /// Validates the proposed block by executing it on top of the latest block.
+    fn validate_block(&self, block: &Block) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockImporter: Send + Sync {
+    /// Executes and commits the block sealed by the validators.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo>;
+}
+
+pub trait P2pPort: Send + Sync + 'static {
+    fn broadcast_proposal(&self, proposal: Arc<SignedProposal>) -> anyhow::Result<()>;
+
+    fn broadcast_vote(&self, vote: Arc<SignedVote>) -> anyhow::Result<()>;
+
+    /// Stream of proposals gossiped by the validators.
+    fn gossiped_proposals(&self) -> BoxStream<BftProposalGossipData>;
+
+    /// Stream of votes gossiped by the validators.
+    fn gossiped_votes(&self) -> BoxStream<BftVoteGossipData>;
+
+    /// Report the validity of the gossiped message to the network.
+    fn notify_gossip_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+}
+
+pub trait GetTime: Send + Sync {
+    fn now(&self) -> Tai64;
+}
@@ -0,0 +1,535 @@
+use anyhow::anyhow;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+};
+use tokio::time::{
+    Duration,
+    Instant,
+    sleep_until,
+};
+use tokio_stream::StreamExt;
+
+use crate::{
+    Config,
+    config::Timeouts,
+    engine::{
+        Action,
+        Checked,
+        Engine,
+        MessageError,
+        Timeout,
+    },
+    ports::{
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        GetTime,
+        P2pPort,
+    },
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+    TraceErr,
+    stream::BoxStream,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::bft::{
+            Proposal,
+            SignedProposal,
+            SignedVote,
+            Vote,
+        },
+        header::BlockHeader,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::ExecutionResult,
+        p2p::{
+            BftProposalGossipData,
+            BftVoteGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    signer::SignMode,
+    tai64::Tai64,
+};
+
+/// The maximum number of the messages for the next height
+/// buffered until the current height is committed.
+const MAX_BUFFERED_MESSAGES: usize = 4096;
+
+pub type Service<P, B, V, I, C> = ServiceRunner<MainTask<P, B, V, I, C>>;
+
+pub struct MainTask<P, B, V, I, C> {
+    engine: Engine,
+    signer: SignMode,
+    p2p: P,
+    block_producer: B,
+    block_validator: V,
+    block_importer: I,
+    clock: C,
+    proposals: BoxStream<BftProposalGossipData>,
+    votes: BoxStream<BftVoteGossipData>,
+    imported_blocks: BoxStream<BlockImportInfo>,
+    timeouts: Timeouts,
+    production_timeout: Duration,
+    scheduled_timeouts: Vec<(Instant, Timeout)>,
+    actions: VecDeque<Action>,
+    next_height_proposals: Vec<Checked<SignedProposal>>,
+    next_height_votes: Vec<Checked<SignedVote>>,
+    next_height_start: Option<Instant>,
+    last_height: BlockHeight,
+    last_timestamp: Tai64,
+}
+
+impl<P, B, V, I, C> MainTask<P, B, V, I, C>
+where
+    P: P2pPort,
+    B: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    C: GetTime,
+{
+    pub fn new(
+        last_block: &BlockHeader,
+        config: Config,
+        p2p: P,
+        block_producer: B,
+        block_validator: V,
+        block_importer: I,
+        clock: C,
+    ) -> Self {
+        let Config {
+            validators,
+            signer,
+            timeouts,
+            production_timeout,
+        } = config;
+        let address = signer
+            .address()
+            .trace_err("Failed to get the address of the validator")
+            .ok()
+            .flatten();
+        let proposals = p2p.gossiped_proposals();
+        let votes = p2p.gossiped_votes();
+        let imported_blocks = block_importer.block_stream();
+
+        Self {
+            engine: Engine::new(validators, address),
+            signer,
+            p2p,
+            block_producer,
+            block_validator,
+            block_importer,
+            clock,
+            proposals,
+            votes,
+            imported_blocks,
+            timeouts,
+            production_timeout,
+            scheduled_timeouts: vec![],
+            actions: VecDeque::new(),
+            next_height_proposals: vec![],
+            next_height_votes: vec![],
+            next_height_start: None,
+            last_height: *last_block.height(),
+            last_timestamp: last_block.time(),
+        }
+    }
+
+    /// Starts the agreement on the block following the last committed block
+    /// and replays the messages received for it in advance.
+    fn start_next_height(&mut self) {
+        self.next_height_start = None;
+        let Some(height) = self.last_height.succ() else {
+            tracing::error!("The block height overflowed");
+            return
+        };
+        self.scheduled_timeouts.clear();
+        let actions = self.engine.start_height(height);
+        self.actions.extend(actions);
+
+        let proposals = core::mem::take(&mut self.next_height_proposals);
+        for proposal in proposals {
+            if proposal.message().proposal.height == height {
+                self.handle_proposal(proposal);
+            }
+        }
+        let votes = core::mem::take(&mut self.next_height_votes);
+        for vote in votes {
+            if vote.message().vote.height == height {
+                let actions = self.engine.handle_vote(vote);
+                self.actions.extend(actions);
+            }
+        }
+    }
+
+    fn on_block_committed(&mut self, height: BlockHeight, timestamp: Tai64) {
+        if height <= self.last_height {
+            return
+        }
+        self.last_height = height;
+        self.last_timestamp = timestamp;
+        if height >= self.engine.height() {
+            self.next_height_start = Instant::now().checked_add(self.timeouts.commit);
+        }
+    }
+
+    fn handle_gossiped_proposal(&mut self, mut gossip: BftProposalGossipData) {
+        let message_info = GossipsubMessageInfo {
+            message_id: gossip.message_id,
+            peer_id: gossip.peer_id.clone(),
+        };
+        let Some(proposal) = gossip.data.take() else {
+            return
+        };
+        let result = self.engine.check_proposal(proposal);
+        let _ = self
+            .p2p
+            .notify_gossip_message_validity(message_info, acceptance(&result))
+            .trace_err("Failed to notify p2p about the gossiped proposal validity");
+
+        match result {
+            Ok(proposal)
+                if proposal.message().proposal.height == self.engine.height() =>
+            {
+                self.handle_proposal(proposal);
+            }
+            Ok(proposal) => {
+                if self.next_height_proposals.len() < MAX_BUFFERED_MESSAGES {
+                    self.next_height_proposals.push(proposal);
+                }
+            }
+            Err(err) => {
+                tracing::debug!("Ignoring the gossiped proposal: {err}");
+            }
+        }
+    }
+
+    fn handle_gossiped_vote(&mut self, mut gossip: BftVoteGossipData) {
+        let message_info = GossipsubMessageInfo {
+            message_id: gossip.message_id,
+            peer_id: gossip.peer_id.clone(),
+        };
+        let Some(vote) = gossip.data.take() else {
+            return
+        };
+        let result = self.engine.check_vote(vote);
+        let _ = self
+            .p2p
+            .notify_gossip_message_validity(message_info, acceptance(&result))
+            .trace_err("Failed to notify p2p about the gossiped vote validity");
+
+        match result {
+            Ok(vote) if vote.message().vote.height == self.engine.height() => {
+                let actions = self.engine.handle_vote(vote);
+                self.actions.extend(actions);
+            }
+            Ok(vote) => {
+                if self.next_height_votes.len() < MAX_BUFFERED_MESSAGES {
+                    self.next_height_votes.push(vote);
+                }
+            }
+            Err(err) => {
+                tracing::debug!("Ignoring the gossiped vote: {err}");
+            }
+        }
+    }
+
+    fn handle_proposal(&mut self, proposal: Checked<SignedProposal>) {
+        let is_block_valid = self
+            .validate_proposed_block(&proposal.message().proposal.block)
+            .trace_err("The proposed block is invalid")
+            .is_ok();
+        let actions = self.engine.handle_proposal(proposal, is_block_valid);
+        self.actions.extend(actions);
+    }
+
+    fn validate_proposed_block(&self, block: &Block) -> anyhow::Result<()> {
+        let header = block.header();
+        anyhow::ensure!(
+            *header.height() == self.engine.height(),
+            "The block height {} doesn't match the height of the round {}",
+            header.height(),
+            self.engine.height()
+        );
+        anyhow::ensure!(
+            header.time() >= self.last_timestamp,
+            "The block time is before the time of the previous block"
+        );
+        self.block_validator.validate_block(block)
+    }
+
+    fn on_timeouts_expired(&mut self) {
+        let now = Instant::now();
+        let (expired, pending): (Vec<_>, Vec<_>) =
+            core::mem::take(&mut self.scheduled_timeouts)
+                .into_iter()
+                .partition(|(deadline, _)| *deadline <= now);
+        self.scheduled_timeouts = pending;
+        for (_, timeout) in expired {
+            let actions = self.engine.on_timeout(timeout);
+            self.actions.extend(actions);
+        }
+    }
+
+    /// Performs the actions requested by the engine,
+    /// including the actions caused by the performed ones.
+    async fn process_actions(&mut self) {
+        while let Some(action) = self.actions.pop_front() {
+            match action {
+                Action::ProduceBlock { height, round } => {
+                    match self.produce_block(height).await {
+                        Ok(block) => {
+                            let actions = self.engine.propose(height, round, block);
+                            self.actions.extend(actions);
+                        }
+                        Err(err) => {
+                            tracing::error!(
+                                "Failed to produce the block {height} in the round {round}: {err}"
+                            );
+                        }
+                    }
+                }
+                Action::SignProposal {
+                    height,
+                    round,
+                    valid_round,
+                    block,
+                } => {
+                    let _ = self
+                        .sign_proposal(height, round, valid_round, block)
+                        .await
+                        .trace_err("Failed to sign the proposal");
+                }
+                Action::SignVote(vote) => {
+                    let _ = self
+                        .sign_vote(vote)
+                        .await
+                        .trace_err("Failed to sign the vote");
+                }
+                Action::ScheduleTimeout(timeout) => {
+                    let duration = self.timeouts.duration(timeout.step, timeout.round);
+                    match Instant::now().checked_add(duration) {
+                        Some(deadline) => {
+                            self.scheduled_timeouts.push((deadline, timeout))
+                        }
+                        None => tracing::error!("Time exceeds system limits"),
+                    }
+                }
+                Action::Commit(block) => self.commit(block).await,
+            }
+        }
+    }
+
+    async fn produce_block(&self, height: BlockHeight) -> anyhow::Result<Block> {
+        // The block time can't go backwards even if the clock of the validator is behind.
+        let block_time = self.clock.now().max(self.last_timestamp);
+        let deadline = Instant::now()
+            .checked_add(self.timeouts.propose.checked_div(2).unwrap_or_default())
+            .ok_or(anyhow!("Time exceeds system limits"))?;
+        let future = self
+            .block_producer
+            .produce_and_execute_block(height, block_time, deadline);
+
+        let result = tokio::time::timeout(self.production_timeout, future)
+            .await
+            .map_err(|_| anyhow!("Block production timed out"))??;
+        let (ExecutionResult { block, .. }, _) = result.into();
+        Ok(block)
+    }
+
+    async fn sign_proposal(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    ) -> anyhow::Result<()> {
+        let block_signature = self.signer.sign_message(block.id().into_message()).await?;
+        let proposal = Proposal {
+            height,
+            round,
+            valid_round,
+            block,
+            block_signature,
+        };
+        let signature = self.signer.sign_message(proposal.message()).await?;
+        let proposal = SignedProposal {
+            proposal,
+            signature,
+        };
+
+        // The proposer executed the block itself, so it is valid.
+        let checked = self.engine.check_proposal(proposal.clone())?;
+        let actions = self.engine.handle_proposal(checked, true);
+        self.actions.extend(actions);
+        self.p2p.broadcast_proposal(Arc::new(proposal))
+    }
+
+    async fn sign_vote(&mut self, vote: Vote) -> anyhow::Result<()> {
+        let signature = self.signer.sign_message(vote.message()).await?;
+        let vote = SignedVote { vote, signature };
+
+        let checked = self.engine.check_vote(vote.clone())?;
+        let actions = self.engine.handle_vote(checked);
+        self.actions.extend(actions);
+        self.p2p.broadcast_vote(Arc::new(vote))
+    }
+
+    async fn commit(&mut self, block: SealedBlock) {
+        let height = *block.entity.header().height();
+        let timestamp = block.entity.header().time();
+        match self.block_importer.execute_and_commit(block).await {
+            Ok(()) => self.on_block_committed(height, timestamp),
+            Err(err) => {
+                // The block could be already imported by the synchronizer,
+                // in this case the imported blocks stream starts the next height.
+                tracing::error!("Failed to import the committed block {height}: {err}");
+            }
+        }
+    }
+}
+
+fn acceptance<T>(result: &Result<T, MessageError>) -> GossipsubMessageAcceptance {
+    match result {
+        Ok(_) => GossipsubMessageAcceptance::Accept,
+        Err(
+            MessageError::Stale | MessageError::Future(_) | MessageError::FutureRound(_),
+        ) => GossipsubMessageAcceptance::Ignore,
+        Err(_) => GossipsubMessageAcceptance::Reject,
+    }
+}
+
+async fn sleep_until_some(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => sleep_until(deadline).await,
+        None => core::future::pending().await,
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, B, V, I, C> RunnableService for MainTask<P, B, V, I, C>
+where
+    Self: RunnableTask,
+    P: P2pPort,
+    B: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    C: GetTime,
+{
+    const NAME: &'static str = "BFT";
+
+    type SharedData = ();
+    type Task = MainTask<P, B, V, I, C>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.start_next_height();
+        Ok(self)
+    }
+}
+
+impl<P, B, V, I, C> RunnableTask for MainTask<P, B, V, I, C>
+where
+    P: P2pPort,
+    B: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    C: GetTime,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        self.process_actions().await;
+
+        let next_timeout = self
+            .scheduled_timeouts
+            .iter()
+            .map(|(deadline, _)| *deadline)
+            .min();
+        let next_height_start = self.next_height_start;
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+            info = self.imported_blocks.next() => {
+                let Some(info) = info else {
+                    tracing::error!("The imported blocks stream is closed");
+                    return TaskNextAction::Stop
+                };
+                let header = info.block_header;
+                self.on_block_committed(*header.height(), header.time());
+                TaskNextAction::Continue
+            }
+            _ = sleep_until_some(next_height_start) => {
+                self.start_next_height();
+                TaskNextAction::Continue
+            }
+            _ = sleep_until_some(next_timeout) => {
+                self.on_timeouts_expired();
+                TaskNextAction::Continue
+            }
+            proposal = self.proposals.next() => {
+                if let Some(proposal) = proposal {
+                    self.handle_gossiped_proposal(proposal);
+                }
+                TaskNextAction::Continue
+            }
+            vote = self.votes.next() => {
+                if let Some(vote) = vote {
+                    self.handle_gossiped_vote(vote);
+                }
+                TaskNextAction::Continue
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        tracing::info!("BFT MainTask shutting down");
+        Ok(())
+    }
+}
+
+pub fn new_service<P, B, V, I, C>(
+    last_block: &BlockHeader,
+    config: Config,
+    p2p: P,
+    block_producer: B,
+    block_validator: V,
+    block_importer: I,
+    clock: C,
+) -> Service<P, B, V, I, C>
+where
+    P: P2pPort,
+    B: BlockProducer + 'static,
+    V: BlockValidator + 'static,
+    I: BlockImporter + 'static,
+    C: GetTime + 'static,
+{
+    Service::new(MainTask::new(
+        last_block,
+        config,
+        p2p,
+        block_producer,
+        block_validator,
+        block_importer,
+        clock,
+    ))
+}
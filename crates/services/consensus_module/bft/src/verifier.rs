@@ -0,0 +1,76 @@
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_chain_config::{
+    BftConfig,
+    ConsensusConfig,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftConsensus,
+        header::BlockHeader,
+    },
+    fuel_tx::Input,
+};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests;
+
+pub fn verify_consensus(
+    consensus_config: &ConsensusConfig,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> bool {
+    match consensus_config {
+        ConsensusConfig::Bft(validators) => verify_seal(validators, header, consensus)
+            .inspect_err(|e| tracing::debug!("Invalid BFT seal: {e}"))
+            .is_ok(),
+        // The blocks of the PoA chain are sealed by a single signer.
+        ConsensusConfig::PoA { .. } | ConsensusConfig::PoAV2(_) => false,
+    }
+}
+
+/// Verifies that the block is signed by the proposer of the round and
+/// precommitted by the validators with more than 2/3 of the voting power.
+pub fn verify_seal(
+    validators: &BftConfig,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> anyhow::Result<()> {
+    let height = *header.height();
+    let block_id = header.id();
+
+    let proposer = validators
+        .proposer(height, consensus.round)
+        .ok_or(anyhow!("The validator set is empty"))?;
+    let signer = consensus
+        .proposer_signature
+        .recover(block_id.as_message())
+        .map(|public_key| Input::owner(&public_key))
+        .map_err(|e| anyhow!("Can't recover the proposer: {:?}", e))?;
+    ensure!(
+        signer == proposer,
+        "The block is signed by {signer} instead of the proposer {proposer}"
+    );
+
+    let mut signers = HashSet::new();
+    let mut voting_power = 0u64;
+    for signer in consensus.precommit_signers(height, &block_id)? {
+        let power = validators.voting_power(&signer).ok_or(anyhow!(
+            "The precommit is signed by {signer} that is not a validator"
+        ))?;
+        ensure!(
+            signers.insert(signer),
+            "The validator {signer} precommitted the block twice"
+        );
+        voting_power = voting_power.saturating_add(power);
+    }
+    ensure!(
+        validators.is_quorum(voting_power),
+        "The precommits don't reach the quorum of the voting power"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_chain_config::BftValidator;
+use fuel_core_types::{
+    blockchain::consensus::bft::Vote,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_types::BlockHeight,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+
+const ROUND: u32 = 1;
+
+struct TestContext {
+    validators: BftConfig,
+    secrets: Vec<SecretKey>,
+    header: BlockHeader,
+}
+
+impl TestContext {
+    fn new() -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+        let validators = BftConfig::new(
+            secrets
+                .iter()
+                .map(|secret| BftValidator {
+                    address: Input::owner(&secret.public_key()),
+                    voting_power: 1,
+                })
+                .collect(),
+        );
+        let mut header = BlockHeader::default();
+        header.set_block_height(7u32.into());
+
+        Self {
+            validators,
+            secrets,
+            header,
+        }
+    }
+
+    fn height(&self) -> BlockHeight {
+        *self.header.height()
+    }
+
+    fn proposer_secret(&self) -> &SecretKey {
+        let proposer = self.validators.proposer(self.height(), ROUND).unwrap();
+        self.secrets
+            .iter()
+            .find(|secret| Input::owner(&secret.public_key()) == proposer)
+            .unwrap()
+    }
+
+    fn seal(&self, proposer: &SecretKey, voters: &[&SecretKey]) -> BftConsensus {
+        let block_id = self.header.id();
+        let proposer_signature = Signature::sign(proposer, block_id.as_message());
+        let precommits = voters
+            .iter()
+            .map(|secret| {
+                Vote::precommit(self.height(), ROUND, Some(block_id))
+                    .sign(secret)
+                    .signature
+            })
+            .collect();
+        BftConsensus::new(ROUND, proposer_signature, precommits)
+    }
+}
+
+#[test]
+fn verify_seal__succeeds_with_quorum_of_precommits() {
+    // Given
+    let ctx = TestContext::new();
+    let voters: Vec<_> = ctx.secrets.iter().take(3).collect();
+    let seal = ctx.seal(ctx.proposer_secret(), &voters);
+
+    // When
+    let result = verify_seal(&ctx.validators, &ctx.header, &seal);
+
+    // Then
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn verify_seal__fails_without_quorum_of_precommits() {
+    // Given
+    let ctx = TestContext::new();
+    let voters: Vec<_> = ctx.secrets.iter().take(2).collect();
+    let seal = ctx.seal(ctx.proposer_secret(), &voters);
+
+    // When
+    let result = verify_seal(&ctx.validators, &ctx.header, &seal);
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn verify_seal__fails_if_validator_precommits_twice() {
+    // Given
+    let ctx = TestContext::new();
+    let voters = [&ctx.secrets[0], &ctx.secrets[1], &ctx.secrets[1]];
+    let seal = ctx.seal(ctx.proposer_secret(), &voters);
+
+    // When
+    let result = verify_seal(&ctx.validators, &ctx.header, &seal);
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn verify_seal__fails_if_precommit_is_signed_by_unknown_key() {
+    // Given
+    let ctx = TestContext::new();
+    let stranger = SecretKey::random(&mut StdRng::seed_from_u64(1));
+    let voters = [&ctx.secrets[0], &ctx.secrets[1], &stranger];
+    let seal = ctx.seal(ctx.proposer_secret(), &voters);
+
+    // When
+    let result = verify_seal(&ctx.validators, &ctx.header, &seal);
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn verify_seal__fails_if_block_is_not_signed_by_proposer_of_round() {
+    // Given
+    let ctx = TestContext::new();
+    let proposer = ctx.proposer_secret();
+    let not_proposer = ctx.secrets.iter().find(|s| *s != proposer).unwrap();
+    let voters: Vec<_> = ctx.secrets.iter().collect();
+    let seal = ctx.seal(not_proposer, &voters);
+
+    // When
+    let result = verify_seal(&ctx.validators, &ctx.header, &seal);
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[test]
+fn verify_consensus__rejects_bft_seal_on_poa_chain() {
+    // Given
+    let ctx = TestContext::new();
+    let voters: Vec<_> = ctx.secrets.iter().collect();
+    let seal = ctx.seal(ctx.proposer_secret(), &voters);
+    let config = ConsensusConfig::default_poa();
+
+    // When
+    let result = verify_consensus(&config, &ctx.header, &seal);
+
+    // Then
+    assert!(!result);
+}
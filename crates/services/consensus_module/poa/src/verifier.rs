@@ -37,6 +37,8 @@ pub fn verify_consensus(
                 .recover(m)
                 .is_ok_and(|k| Input::owner(&k) == signing_key)
         }
+        // The blocks of the BFT chain can't be sealed by a single signer.
+        ConsensusConfig::Bft(_) => false,
     }
 }
 
@@ -64,7 +64,7 @@ where
                     block.header(),
                 )
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::Bft(_) => {
                 let view = self.view_provider.latest_view()?;
                 fuel_core_poa::verifier::verify_block_fields(&view, block)
             }
@@ -85,6 +85,11 @@ where
                 header,
                 consensus,
             ),
+            Consensus::Bft(consensus) => fuel_core_bft::verifier::verify_consensus(
+                &self.config.consensus,
+                header,
+                consensus,
+            ),
             _ => false,
         }
     }
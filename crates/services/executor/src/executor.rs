@@ -300,17 +300,17 @@ pub fn convert_tx_execution_result_to_preconfirmation(
 /// Data that is generated after executing all transactions.
 #[derive(Default)]
 pub struct ExecutionData {
-    coinbase: u64,
-    used_gas: u64,
-    used_size: u32,
-    tx_count: u16,
-    found_mint: bool,
-    message_ids: Vec<MessageId>,
-    tx_status: Vec<TransactionExecutionStatus>,
-    events: Vec<ExecutorEvent>,
-    changes: Changes,
+    pub coinbase: u64,
+    pub used_gas: u64,
+    pub used_size: u32,
+    pub tx_count: u16,
+    pub found_mint: bool,
+    pub message_ids: Vec<MessageId>,
+    pub tx_status: Vec<TransactionExecutionStatus>,
+    pub events: Vec<ExecutorEvent>,
+    pub changes: Changes,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
-    event_inbox_root: Bytes32,
+    pub event_inbox_root: Bytes32,
 }
 
 impl ExecutionData {
@@ -475,7 +475,9 @@ where
         Ok(UncommittedValidationResult::new(result, changes))
     }
 
-    fn into_executor<N, P>(
+    /// Creates the block executor and the storage transaction for the block
+    /// with the consensus parameters of the `consensus_params_version`.
+    pub fn into_executor<N, P>(
         self,
         consensus_params_version: ConsensusParametersVersion,
         new_tx_waiter: N,
@@ -541,6 +543,15 @@ impl<R, TxWaiter, PreconfirmationSender>
     }
 }
 
+impl<R, TxWaiter, PreconfirmationSender>
+    BlockExecutor<R, TxWaiter, PreconfirmationSender>
+{
+    /// Returns the consensus parameters used by the executor.
+    pub fn consensus_params(&self) -> &ConsensusParameters {
+        &self.consensus_params
+    }
+}
+
 impl<R, N, P> BlockExecutor<R, N, P>
 where
     R: RelayerPort,
@@ -617,7 +628,9 @@ where
         Ok((partial_block, data))
     }
 
-    fn produce_mint_tx<TxSource, T>(
+    /// Creates the `Mint` transaction that collects the fees of the block
+    /// and executes it as the last transaction of the block.
+    pub fn produce_mint_tx<TxSource, T>(
         &self,
         block: &mut PartialFuelBlock,
         components: &Components<TxSource>,
@@ -700,7 +713,8 @@ where
         Ok((partial_block, data))
     }
 
-    fn process_l1_txs<T>(
+    /// Imports events from the DA layer and executes relayed transactions.
+    pub fn process_l1_txs<T>(
         &mut self,
         block: &mut PartialFuelBlock,
         coinbase_contract_id: ContractId,
@@ -854,8 +868,10 @@ where
         Ok(())
     }
 
+    /// Executes the transaction with the index `execution_data.tx_count` and
+    /// commits its changes into the `storage_tx`.
     #[allow(clippy::too_many_arguments)]
-    fn execute_transaction_and_commit<'a, W>(
+    pub fn execute_transaction_and_commit<'a, W>(
         &'a self,
         block: &'a mut PartialFuelBlock,
         storage_tx: &mut BlockStorageTransaction<W>,
@@ -944,7 +960,8 @@ where
         Ok(data)
     }
 
-    fn get_coinbase_info_from_mint_tx(
+    /// Returns the gas price and the coinbase recipient from the `Mint` transaction.
+    pub fn get_coinbase_info_from_mint_tx(
         transactions: &[Transaction],
     ) -> ExecutorResult<(u64, ContractId)> {
         if let Some(Transaction::Mint(mint)) = transactions.last() {
@@ -1026,7 +1043,8 @@ where
         Ok(forced_transactions)
     }
 
-    fn check_block_matches(
+    /// Checks that the re-executed block matches the original block.
+    pub fn check_block_matches(
         &self,
         new_partial_block: PartialFuelBlock,
         old_block: &Block,
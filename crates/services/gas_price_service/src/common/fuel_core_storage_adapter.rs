@@ -206,7 +206,8 @@ pub(crate) fn block_bytes(block: &Block<Transaction>) -> u64 {
     Postcard::encode(block).len() as u64
 }
 
-fn block_used_gas(
+/// Approximates the gas used by the block from the fee and the gas price of its mint transaction.
+pub fn block_used_gas(
     fee: u64,
     gas_price: u64,
     gas_price_factor: u64,
@@ -257,4 +257,8 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
 }
@@ -121,7 +121,15 @@ impl CachedView {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::blockchain::consensus::Genesis;
+    use fuel_core_storage::iter::BoxedIter;
+    use fuel_core_types::{
+        blockchain::consensus::Genesis,
+        fuel_types::BlockHeight,
+        services::p2p::{
+            StateEntry,
+            StateSyncTable,
+        },
+    };
     use std::sync::Arc;
     use tokio::sync::Notify;
 
@@ -169,6 +177,18 @@ mod tests {
             self.sender.notify_waiters();
             Ok(Genesis::default())
         }
+
+        fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+            unimplemented!()
+        }
+
+        fn iter_state_entries(
+            &self,
+            _: StateSyncTable,
+            _: Option<&[u8]>,
+        ) -> BoxedIter<'_, StorageResult<StateEntry>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -12,7 +12,16 @@ use crate::{
     },
     ports::P2PPreConfirmationMessage,
 };
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
+    fuel_tx::Transaction,
+};
 use std::{
     io,
     ops::Deref,
@@ -28,7 +37,13 @@ where
     Codec: Encode<Transaction, Error = io::Error>
         + Decode<Transaction, Error = io::Error>
         + Encode<P2PPreConfirmationMessage, Error = io::Error>
-        + Decode<P2PPreConfirmationMessage, Error = io::Error>,
+        + Decode<P2PPreConfirmationMessage, Error = io::Error>
+        + Encode<SealedBlock, Error = io::Error>
+        + Decode<SealedBlock, Error = io::Error>
+        + Encode<SignedProposal, Error = io::Error>
+        + Decode<SignedProposal, Error = io::Error>
+        + Encode<SignedVote, Error = io::Error>
+        + Decode<SignedVote, Error = io::Error>,
 {
     type RequestMessage = GossipsubBroadcastRequest;
     type ResponseMessage = GossipsubMessage;
@@ -41,6 +56,15 @@ where
             GossipsubBroadcastRequest::TxPreConfirmations(msg) => {
                 Ok(self.codec.encode(msg.deref())?.into_bytes())
             }
+            GossipsubBroadcastRequest::NewBlock(block) => {
+                Ok(self.codec.encode(block.deref())?.into_bytes())
+            }
+            GossipsubBroadcastRequest::BftProposal(proposal) => {
+                Ok(self.codec.encode(proposal.deref())?.into_bytes())
+            }
+            GossipsubBroadcastRequest::BftVote(vote) => {
+                Ok(self.codec.encode(vote.deref())?.into_bytes())
+            }
         }
     }
 
@@ -56,6 +80,15 @@ where
             GossipTopicTag::TxPreconfirmations => {
                 GossipsubMessage::TxPreConfirmations(self.codec.decode(encoded_data)?)
             }
+            GossipTopicTag::NewBlock => {
+                GossipsubMessage::NewBlock(self.codec.decode(encoded_data)?)
+            }
+            GossipTopicTag::BftProposal => {
+                GossipsubMessage::BftProposal(self.codec.decode(encoded_data)?)
+            }
+            GossipTopicTag::BftVote => {
+                GossipsubMessage::BftVote(self.codec.decode(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -8,27 +8,50 @@ use fuel_core_types::blockchain::consensus::Genesis;
 
 use self::{
     connection_tracker::ConnectionTracker,
-    fuel_authenticated::FuelAuthenticated,
+    fuel_authenticated::{
+        Approver,
+        FuelAuthenticated,
+    },
     fuel_upgrade::Checksum,
 };
 use fuel_core_services::seqlock::SeqLockReader;
+use futures::{
+    AsyncReadExt,
+    AsyncWriteExt,
+    future::poll_fn,
+};
 use libp2p::{
     Multiaddr,
     PeerId,
+    Transport,
+    core::{
+        ConnectedPoint,
+        muxing::{
+            StreamMuxerBox,
+            StreamMuxerExt,
+        },
+        transport::{
+            Boxed,
+            OptionalTransport,
+        },
+    },
     gossipsub,
     identity::{
         Keypair,
         secp256k1,
     },
     noise,
+    quic,
 };
 use std::{
     collections::HashSet,
+    io,
     net::{
         IpAddr,
         Ipv4Addr,
     },
     num::NonZeroU32,
+    path::PathBuf,
     time::Duration,
 };
 
@@ -71,6 +94,10 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on with the QUIC transport.
+    /// The QUIC transport is disabled if it is not set.
+    pub quic_port: Option<u16>,
+
     /// Max Size of a Block in bytes
     pub max_block_size: NonZeroU32,
     pub max_headers_per_request: usize,
@@ -106,6 +133,12 @@ pub struct Config<State = Initialized> {
     /// Max number of outgoing connection from the node to other peers.
     pub max_outgoing_connections: u32,
 
+    /// The file where the scores and bans of peers are kept across restarts.
+    /// If it is not set, the reputation is forgotten when the node stops.
+    pub reputation_path: Option<PathBuf>,
+    /// How long the peer stays banned after its reputation drops below the threshold.
+    pub ban_duration: Duration,
+
     /// The interval at which identification requests are sent to
     /// the remote on established connections after the first request
     pub identify_interval: Option<Duration>,
@@ -152,6 +185,18 @@ pub struct Config<State = Initialized> {
 
     /// If true, the node will subscribe to pre-confirmations topic
     pub subscribe_to_pre_confirmations: bool,
+
+    /// If true, the node will subscribe to new blocks topic
+    pub subscribe_to_new_blocks: bool,
+
+    /// If true, the node will subscribe to the BFT proposals and votes topics
+    pub subscribe_to_bft_consensus: bool,
+
+    /// The interval in blocks between the state checkpoints served to the peers
+    /// that bootstrap with the state sync. Each checkpoint pins the view of the database
+    /// until the next one, so the interval shouldn't be too small.
+    /// If it is not set, the node doesn't serve the state.
+    pub state_checkpoint_interval: Option<NonZeroU32>,
 }
 
 /// The initialized state can be achieved only by the `init` function because `()` is private.
@@ -173,6 +218,7 @@ impl Config<NotInitialized> {
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             max_txs_per_request: self.max_txs_per_request,
@@ -182,6 +228,8 @@ impl Config<NotInitialized> {
             max_functional_peers_connected: self.max_functional_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
             max_outgoing_connections: self.max_outgoing_connections,
+            reputation_path: self.reputation_path,
+            ban_duration: self.ban_duration,
             allow_private_addresses: self.allow_private_addresses,
             random_walk: self.random_walk,
             connection_idle_timeout: self.connection_idle_timeout,
@@ -203,6 +251,9 @@ impl Config<NotInitialized> {
             state: Initialized(()),
             subscribe_to_new_tx: self.subscribe_to_new_tx,
             subscribe_to_pre_confirmations: self.subscribe_to_pre_confirmations,
+            subscribe_to_new_blocks: self.subscribe_to_new_blocks,
+            subscribe_to_bft_consensus: self.subscribe_to_bft_consensus,
+            state_checkpoint_interval: self.state_checkpoint_interval,
         })
     }
 }
@@ -229,6 +280,7 @@ impl Config<NotInitialized> {
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
             max_txs_per_request: MAX_TXS_PER_REQUEST,
@@ -238,6 +290,8 @@ impl Config<NotInitialized> {
             max_functional_peers_connected: 50,
             max_connections_per_peer: None,
             max_outgoing_connections: 10,
+            reputation_path: None,
+            ban_duration: Duration::from_secs(60 * 60),
             allow_private_addresses: true,
             random_walk: Some(Duration::from_millis(500)),
             connection_idle_timeout: Some(Duration::from_secs(120)),
@@ -259,6 +313,9 @@ impl Config<NotInitialized> {
             state: NotInitialized,
             subscribe_to_new_tx: true,
             subscribe_to_pre_confirmations: true,
+            subscribe_to_new_blocks: true,
+            subscribe_to_bft_consensus: true,
+            state_checkpoint_interval: None,
         }
     }
 }
@@ -284,14 +341,8 @@ pub(crate) fn build_transport_function(
         let noise_authenticated =
             noise::Config::new(keypair).expect("Noise key generation failed");
 
-        let connection_state = if p2p_config.reserved_nodes_only_mode {
-            None
-        } else {
-            Some(connection_state_reader.clone())
-        };
-
         let connection_tracker =
-            ConnectionTracker::new(&p2p_config.reserved_nodes, connection_state);
+            build_connection_tracker(p2p_config, connection_state_reader);
 
         Ok(FuelAuthenticated::new(
             noise_authenticated,
@@ -301,6 +352,94 @@ pub(crate) fn build_transport_function(
     }
 }
 
+/// Transport for libp2p communication over QUIC, enabled only if the `quic_port` is set.
+/// QUIC comes with TLS encryption and multiplexing, so only the `ConnectionTracker`
+/// and the exchange of the checksums are applied on top of it.
+pub(crate) fn build_quic_transport_function(
+    p2p_config: &Config,
+    connection_state_reader: SeqLockReader<ConnectionState>,
+) -> impl FnOnce(&Keypair) -> OptionalTransport<Boxed<(PeerId, StreamMuxerBox)>> + '_ {
+    move |keypair: &Keypair| {
+        if p2p_config.quic_port.is_none() {
+            return OptionalTransport::none()
+        }
+
+        let connection_tracker =
+            build_connection_tracker(p2p_config, connection_state_reader);
+        let checksum = p2p_config.checksum;
+
+        let transport = quic::tokio::Transport::new(quic::Config::new(keypair))
+            .and_then(move |(peer_id, mut connection), endpoint| {
+                let allowed = connection_tracker.allow_peer(&peer_id);
+                async move {
+                    if !allowed {
+                        return Err(io::Error::new(
+                            io::ErrorKind::PermissionDenied,
+                            "Peer is not allowed to connect",
+                        ))
+                    }
+                    exchange_checksums(&mut connection, &endpoint, checksum).await?;
+                    Ok((peer_id, StreamMuxerBox::new(connection)))
+                }
+            })
+            .boxed();
+
+        OptionalTransport::some(transport)
+    }
+}
+
+/// QUIC skips the `FuelAuthenticated` upgrade, so the peers exchange the checksums
+/// over the first stream of the connection, before it is used by the behaviours.
+/// The connection is rejected if the checksums are different.
+async fn exchange_checksums(
+    connection: &mut quic::Connection,
+    endpoint: &ConnectedPoint,
+    checksum: Checksum,
+) -> io::Result<()> {
+    let is_dialer = matches!(
+        endpoint,
+        ConnectedPoint::Dialer { role_override, .. } if role_override.is_dialer()
+    );
+    let mut remote_checksum = [0u8; 32];
+    if is_dialer {
+        let mut stream = poll_fn(|cx| connection.poll_outbound_unpin(cx))
+            .await
+            .map_err(io::Error::other)?;
+        stream.write_all(checksum.as_ref()).await?;
+        stream.flush().await?;
+        stream.read_exact(&mut remote_checksum).await?;
+        stream.close().await?;
+    } else {
+        let mut stream = poll_fn(|cx| connection.poll_inbound_unpin(cx))
+            .await
+            .map_err(io::Error::other)?;
+        stream.read_exact(&mut remote_checksum).await?;
+        stream.write_all(checksum.as_ref()).await?;
+        stream.close().await?;
+    }
+
+    if remote_checksum.as_slice() != checksum.as_ref() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "Peer has a different checksum",
+        ))
+    }
+    Ok(())
+}
+
+fn build_connection_tracker(
+    p2p_config: &Config,
+    connection_state_reader: SeqLockReader<ConnectionState>,
+) -> ConnectionTracker {
+    let connection_state = if p2p_config.reserved_nodes_only_mode {
+        None
+    } else {
+        Some(connection_state_reader)
+    };
+
+    ConnectionTracker::new(&p2p_config.reserved_nodes, connection_state)
+}
+
 fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
     multiaddr
         .iter()
@@ -1,4 +1,7 @@
 use super::topics::{
+    BFT_PROPOSAL_GOSSIP_TOPIC,
+    BFT_VOTE_GOSSIP_TOPIC,
+    NEW_BLOCK_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
     TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
 };
@@ -54,6 +57,12 @@ const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
 const TX_PRECONFIRMATIONS_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering new blocks.
+const NEW_BLOCK_GOSSIP_WEIGHT: f64 = 0.1;
+
+// The weight applied to the score for delivering BFT proposals and votes.
+const BFT_CONSENSUS_GOSSIP_WEIGHT: f64 = 0.1;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -240,6 +249,13 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
             TX_PRECONFIRMATIONS_GOSSIP_WEIGHT,
         ));
     }
+    if p2p_config.subscribe_to_new_blocks {
+        topics.push((NEW_BLOCK_GOSSIP_TOPIC, NEW_BLOCK_GOSSIP_WEIGHT));
+    }
+    if p2p_config.subscribe_to_bft_consensus {
+        topics.push((BFT_PROPOSAL_GOSSIP_TOPIC, BFT_CONSENSUS_GOSSIP_WEIGHT));
+        topics.push((BFT_VOTE_GOSSIP_TOPIC, BFT_CONSENSUS_GOSSIP_WEIGHT));
+    }
 
     // subscribe to gossipsub topics with the network name suffix
     for (topic, weight) in topics {
@@ -1,5 +1,14 @@
 use crate::ports::P2PPreConfirmationMessage;
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
+    fuel_tx::Transaction,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -12,6 +21,9 @@ use std::sync::Arc;
 pub enum GossipTopicTag {
     NewTx,
     TxPreconfirmations,
+    NewBlock,
+    BftProposal,
+    BftVote,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -21,6 +33,9 @@ pub enum GossipTopicTag {
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
     TxPreConfirmations(Arc<P2PPreConfirmationMessage>),
+    NewBlock(Arc<SealedBlock>),
+    BftProposal(Arc<SignedProposal>),
+    BftVote(Arc<SignedVote>),
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -28,4 +43,7 @@ pub enum GossipsubBroadcastRequest {
 pub enum GossipsubMessage {
     NewTx(Transaction),
     TxPreConfirmations(P2PPreConfirmationMessage),
+    NewBlock(SealedBlock),
+    BftProposal(SignedProposal),
+    BftVote(SignedVote),
 }
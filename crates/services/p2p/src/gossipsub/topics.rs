@@ -11,6 +11,9 @@ use super::messages::{
 
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
+pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
+pub const BFT_PROPOSAL_GOSSIP_TOPIC: &str = "bft_proposal";
+pub const BFT_VOTE_GOSSIP_TOPIC: &str = "bft_vote";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash of existing topics
@@ -19,6 +22,9 @@ pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
 pub struct GossipsubTopics {
     new_tx_topic: TopicHash,
     tx_preconfirmations_topic: TopicHash,
+    new_block_topic: TopicHash,
+    bft_proposal_topic: TopicHash,
+    bft_vote_topic: TopicHash,
 }
 
 impl GossipsubTopics {
@@ -27,9 +33,18 @@ impl GossipsubTopics {
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
         let tx_preconfirmations_topic: Sha256Topic =
             Topic::new(format!("{TX_PRECONFIRMATIONS_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic: Sha256Topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
+        let bft_proposal_topic: Sha256Topic =
+            Topic::new(format!("{BFT_PROPOSAL_GOSSIP_TOPIC}/{network_name}"));
+        let bft_vote_topic: Sha256Topic =
+            Topic::new(format!("{BFT_VOTE_GOSSIP_TOPIC}/{network_name}"));
         Self {
             new_tx_topic: new_tx_topic.hash(),
             tx_preconfirmations_topic: tx_preconfirmations_topic.hash(),
+            new_block_topic: new_block_topic.hash(),
+            bft_proposal_topic: bft_proposal_topic.hash(),
+            bft_vote_topic: bft_vote_topic.hash(),
         }
     }
 
@@ -43,6 +58,9 @@ impl GossipsubTopics {
             hash if hash == &self.tx_preconfirmations_topic => {
                 Some(GossipTopicTag::TxPreconfirmations)
             }
+            hash if hash == &self.new_block_topic => Some(GossipTopicTag::NewBlock),
+            hash if hash == &self.bft_proposal_topic => Some(GossipTopicTag::BftProposal),
+            hash if hash == &self.bft_vote_topic => Some(GossipTopicTag::BftVote),
             _ => None,
         }
     }
@@ -58,6 +76,9 @@ impl GossipsubTopics {
             GossipsubBroadcastRequest::TxPreConfirmations(_) => {
                 self.tx_preconfirmations_topic.clone()
             }
+            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.clone(),
+            GossipsubBroadcastRequest::BftProposal(_) => self.bft_proposal_topic.clone(),
+            GossipsubBroadcastRequest::BftVote(_) => self.bft_vote_topic.clone(),
         }
     }
 }
@@ -65,7 +86,16 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::{
+        blockchain::{
+            SealedBlock,
+            consensus::bft::{
+                SignedVote,
+                Vote,
+            },
+        },
+        fuel_tx::Transaction,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -94,4 +124,51 @@ mod tests {
             new_tx_topic.hash()
         );
     }
+
+    #[test]
+    fn test_gossipsub_new_block_topic() {
+        let network_name = "fuel_test_network";
+        let new_block_topic: Sha256Topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(gossipsub_topics.new_block_topic, new_block_topic.hash());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_block_topic.hash()),
+            Some(GossipTopicTag::NewBlock)
+        );
+
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default()));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            new_block_topic.hash()
+        );
+    }
+
+    #[test]
+    fn test_gossipsub_bft_vote_topic() {
+        let network_name = "fuel_test_network";
+        let bft_vote_topic: Sha256Topic =
+            Topic::new(format!("{BFT_VOTE_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(gossipsub_topics.bft_vote_topic, bft_vote_topic.hash());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&bft_vote_topic.hash()),
+            Some(GossipTopicTag::BftVote)
+        );
+
+        let vote = SignedVote {
+            vote: Vote::prevote(1u32.into(), 0, None),
+            signature: Default::default(),
+        };
+        let broadcast_req = GossipsubBroadcastRequest::BftVote(Arc::new(vote));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            bft_vote_topic.hash()
+        );
+    }
 }
@@ -21,6 +21,7 @@ mod utils;
 mod cached_view;
 mod connection_limits;
 mod limited_behaviour;
+mod state_checkpoint;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::Config;
@@ -12,6 +12,7 @@ use crate::{
     },
     config::{
         Config,
+        build_quic_transport_function,
         build_transport_function,
     },
     dnsaddr_resolution::DnsResolver,
@@ -28,6 +29,10 @@ use crate::{
         ConnectionState,
         PeerManager,
         Punisher,
+        reputation_store::{
+            Ban,
+            ReputationStore,
+        },
     },
     peer_report::PeerReportEvent,
     request_response::messages::{
@@ -53,6 +58,7 @@ use libp2p::{
     PeerId,
     Swarm,
     SwarmBuilder,
+    core::transport::ListenerId,
     gossipsub::{
         self,
         MessageAcceptance,
@@ -78,7 +84,10 @@ use libp2p::{
 };
 use rand::seq::IteratorRandom;
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     time::Duration,
 };
 use tokio::sync::broadcast;
@@ -97,6 +106,10 @@ impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().unblock_peer(peer_id)
+    }
 }
 
 /// Listens to the events on the p2p network
@@ -111,6 +124,9 @@ pub struct FuelP2PService {
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// The UDP port that Swarm listens on with the QUIC transport, if it is enabled
+    quic_port: Option<u16>,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour>,
 
@@ -235,7 +251,9 @@ impl FuelP2PService {
         // configure and build P2P Service
         let (connection_state_writer, connection_state_reader) = ConnectionState::new();
         let transport_function =
-            build_transport_function(&config, connection_state_reader);
+            build_transport_function(&config, connection_state_reader.clone());
+        let quic_transport_function =
+            build_quic_transport_function(&config, connection_state_reader);
         let tcp_config = tcp::Config::new();
 
         let behaviour = FuelBehaviour::new(&config, request_response_codec)?;
@@ -248,6 +266,8 @@ impl FuelP2PService {
                 libp2p::yamux::Config::default,
             )
             .map_err(|_| anyhow::anyhow!("Failed to build Swarm"))?
+            .with_other_transport(quic_transport_function)
+            .map_err(|_| anyhow::anyhow!("Failed to build the QUIC transport"))?
             .with_dns()?;
 
         let mut libp2p_metrics_registry = None;
@@ -287,10 +307,26 @@ impl FuelP2PService {
             .filter_map(|m| m.try_to_peer_id())
             .collect();
 
+        let reputation = match config.reputation_path.clone() {
+            Some(path) => ReputationStore::open(path)?,
+            None => ReputationStore::in_memory(),
+        };
+        let mut peer_manager = PeerManager::new(
+            reserved_peers_updates,
+            reserved_peers,
+            connection_state_writer,
+            usize::try_from(config.max_discovery_peers_connected)?,
+            reputation,
+            config.ban_duration,
+        );
+        // Block the banned peers before the swarm starts accepting connections.
+        peer_manager.restore_bans(&mut swarm);
+
         Ok(Self {
             local_peer_id,
             local_address: config.address,
             tcp_port: config.tcp_port,
+            quic_port: config.quic_port,
             swarm,
             gossipsub_codec,
             outbound_requests_table: HashMap::default(),
@@ -298,45 +334,52 @@ impl FuelP2PService {
             network_metadata,
             metrics,
             libp2p_metrics_registry,
-            peer_manager: PeerManager::new(
-                reserved_peers_updates,
-                reserved_peers,
-                connection_state_writer,
-                usize::try_from(config.max_discovery_peers_connected)?,
-            ),
+            peer_manager,
         })
     }
 
     pub async fn start(&mut self) -> anyhow::Result<()> {
-        // set up node's address to listen on
-        let listen_multiaddr = {
+        // set up node's addresses to listen on
+        let mut listen_multiaddrs = vec![{
             let mut m = Multiaddr::from(self.local_address);
             m.push(Protocol::Tcp(self.tcp_port));
             m
-        };
+        }];
+        if let Some(quic_port) = self.quic_port {
+            let mut m = Multiaddr::from(self.local_address);
+            m.push(Protocol::Udp(quic_port));
+            m.push(Protocol::QuicV1);
+            listen_multiaddrs.push(m);
+        }
         let peer_id = self.local_peer_id;
 
-        tracing::info!(
-            "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
-        );
-
-        // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+        // start listening at the given addresses
+        let mut listeners = HashSet::new();
+        for listen_multiaddr in listen_multiaddrs {
+            tracing::info!(
+                "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
+            );
+            listeners.insert(self.swarm.listen_on(listen_multiaddr)?);
+        }
 
         // Wait for listener addresses.
-        tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
-            .await
-            .map_err(|_| {
-                anyhow::anyhow!("P2PService should get a new address within 5 seconds")
-            })?;
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            self.await_listeners_address(listeners),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("P2PService should get a new address within 5 seconds")
+        })?;
         Ok(())
     }
 
-    async fn await_listeners_address(&mut self) {
-        loop {
-            if let SwarmEvent::NewListenAddr { .. } = self.swarm.select_next_some().await
+    async fn await_listeners_address(&mut self, mut listeners: HashSet<ListenerId>) {
+        while !listeners.is_empty() {
+            if let SwarmEvent::NewListenAddr { listener_id, .. } =
+                self.swarm.select_next_some().await
             {
-                break;
+                listeners.remove(&listener_id);
             }
         }
     }
@@ -508,6 +551,26 @@ impl FuelP2PService {
         );
     }
 
+    /// Bans the peer for the `duration`, or permanently if the `duration` is not set
+    pub fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) {
+        self.peer_manager
+            .ban_peer(peer_id, reason, duration, &mut self.swarm);
+    }
+
+    /// Removes the ban of the peer. Returns `false` if the peer was not banned
+    pub fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.peer_manager.unban_peer(peer_id, &mut self.swarm)
+    }
+
+    pub fn banned_peers(&self) -> impl Iterator<Item = (&PeerId, &Ban)> {
+        self.peer_manager.banned_peers()
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -632,7 +695,8 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.peer_manager.update_reputation(&mut self.swarm);
             }
             PeerReportEvent::PeerConnected { peer_id } => {
                 if self.peer_manager.handle_peer_connected(&peer_id) {
@@ -740,6 +804,32 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::StateCheckpoint(c) => match response {
+                            V2ResponseMessage::StateCheckpoint(v) => {
+                                c.send(Ok((peer, Ok(v.map(|checkpoint| *checkpoint)))))
+                                    .is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send(Ok((peer, Err(ResponseError::TypeMismatch))))
+                                    .is_ok()
+                            }
+                        },
+                        ResponseSender::StateChunkFromPeer(c) => match response {
+                            V2ResponseMessage::StateChunk(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -797,6 +887,12 @@ impl FuelP2PService {
                         ResponseSender::TxPoolFullTransactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::StateCheckpoint(c) => {
+                            let _ = c.send(Ok((peer, Err(ResponseError::P2P(error)))));
+                        }
+                        ResponseSender::StateChunkFromPeer(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -2,6 +2,7 @@
 #![allow(clippy::cast_possible_truncation)]
 
 use super::{
+    FuelBehaviourEvent,
     FuelP2PService,
     PublishError,
 };
@@ -18,6 +19,9 @@ use fuel_core_p2p::{
             GossipsubMessage,
         },
         topics::{
+            BFT_PROPOSAL_GOSSIP_TOPIC,
+            BFT_VOTE_GOSSIP_TOPIC,
+            NEW_BLOCK_GOSSIP_TOPIC,
             NEW_TX_GOSSIP_TOPIC,
             TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
         },
@@ -38,9 +42,14 @@ use fuel_core_p2p::{
 };
 use fuel_core_types::{
     blockchain::{
+        SealedBlock,
         SealedBlockHeader,
         consensus::{
             Consensus,
+            bft::{
+                SignedVote,
+                Vote,
+            },
             poa::PoAConsensus,
         },
         header::BlockHeader,
@@ -55,7 +64,13 @@ use fuel_core_types::{
     services::p2p::{
         GossipsubMessageAcceptance,
         NetworkableTransactionPool,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
+        StateSyncTable,
+        TableCheckpoint,
         Transactions,
+        state_chunk_root,
     },
 };
 use futures::{
@@ -70,6 +85,7 @@ use libp2p::{
         Topic,
     },
     identity::Keypair,
+    multiaddr::Protocol,
     swarm::{
         ListenError,
         SwarmEvent,
@@ -85,6 +101,7 @@ use std::{
     sync::Arc,
     time::Duration,
 };
+use test_case::test_case;
 use tokio::sync::{
     broadcast,
     mpsc,
@@ -94,9 +111,44 @@ use tokio::sync::{
 use tracing_attributes::instrument;
 type P2PService = FuelP2PService;
 
+/// The transport used by the nodes to connect to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transport {
+    Tcp,
+    Quic,
+}
+
+impl Transport {
+    fn config(self, network_name: &str) -> Config {
+        let mut p2p_config = Config::default_initialized(network_name);
+        if self == Transport::Quic {
+            p2p_config.quic_port = Some(0);
+        }
+        p2p_config
+    }
+
+    /// Returns the addresses of the `node` that are reachable via the transport.
+    fn multiaddrs(self, node: &P2PService) -> Vec<Multiaddr> {
+        node.multiaddrs()
+            .into_iter()
+            .filter(|multiaddr| {
+                multiaddr.iter().any(|protocol| match self {
+                    Transport::Tcp => matches!(protocol, Protocol::Tcp(_)),
+                    Transport::Quic => matches!(protocol, Protocol::QuicV1),
+                })
+            })
+            .collect()
+    }
+}
+
 /// helper function for building FuelP2PService
 async fn build_service_from_config(mut p2p_config: Config) -> P2PService {
     p2p_config.keypair = Keypair::generate_secp256k1(); // change keypair for each Node
+    build_service_with_keypair_from_config(p2p_config).await
+}
+
+/// helper function for building FuelP2PService with the keypair of the `p2p_config`
+async fn build_service_with_keypair_from_config(p2p_config: Config) -> P2PService {
     let max_block_size = p2p_config.max_block_size;
     let (sender, _) =
         broadcast::channel(p2p_config.reserved_nodes.len().saturating_add(1));
@@ -114,6 +166,7 @@ async fn build_service_from_config(mut p2p_config: Config) -> P2PService {
 }
 
 async fn setup_bootstrap_nodes(
+    transport: Transport,
     p2p_config: &Config,
     bootstrap_nodes_count: usize,
 ) -> (Vec<P2PService>, Vec<Multiaddr>) {
@@ -123,7 +176,7 @@ async fn setup_bootstrap_nodes(
     .await;
     let bootstrap_multiaddrs = nodes
         .iter()
-        .flat_map(|b| b.multiaddrs())
+        .flat_map(|b| transport.multiaddrs(b))
         .collect::<Vec<_>>();
     (nodes, bootstrap_multiaddrs)
 }
@@ -142,10 +195,12 @@ fn spawn(stop: &watch::Sender<()>, mut node: P2PService) {
     });
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn p2p_service_works() {
-    build_service_from_config(Config::default_initialized("p2p_service_works")).await;
+async fn p2p_service_works(transport: Transport) {
+    build_service_from_config(transport.config("p2p_service_works")).await;
 }
 
 // Single sentry node connects to multiple reserved nodes and `max_peers_allowed` amount of non-reserved nodes.
@@ -154,18 +209,24 @@ async fn p2p_service_works() {
 // Once sentry node establishes the connection with the allowed number of nodes
 // we start the reserved node, and await for it to establish the connection.
 // This test proves that there is always an available slot for the reserved node to connect to.
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test(flavor = "multi_thread")]
 #[instrument]
-async fn reserved_nodes_reconnect_works() {
-    let p2p_config = Config::default_initialized("reserved_nodes_reconnect_works");
+async fn reserved_nodes_reconnect_works(transport: Transport) {
+    let p2p_config = transport.config("reserved_nodes_reconnect_works");
 
     // total amount will be `max_peers_allowed` + `reserved_nodes.len()`
     let max_peers_allowed: usize = 3;
 
-    let (bootstrap_nodes, bootstrap_multiaddrs) =
-        setup_bootstrap_nodes(&p2p_config, max_peers_allowed.saturating_mul(5)).await;
+    let (bootstrap_nodes, bootstrap_multiaddrs) = setup_bootstrap_nodes(
+        transport,
+        &p2p_config,
+        max_peers_allowed.saturating_mul(5),
+    )
+    .await;
     let (mut reserved_nodes, reserved_multiaddrs) =
-        setup_bootstrap_nodes(&p2p_config, max_peers_allowed).await;
+        setup_bootstrap_nodes(transport, &p2p_config, max_peers_allowed).await;
 
     let mut sentry_node = {
         let mut p2p_config = p2p_config.clone();
@@ -226,32 +287,21 @@ async fn reserved_nodes_reconnect_works() {
     stop_sender.send(()).unwrap();
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn dont_connect_to_node_with_same_peer_id() {
-    let mut p2p_config =
-        Config::default_initialized("dont_connect_to_node_with_same_peer_id");
-    let mut node_a = build_service_from_config(p2p_config.clone()).await;
+async fn dont_connect_to_node_with_same_peer_id(transport: Transport) {
+    let mut p2p_config = transport.config("dont_connect_to_node_with_same_peer_id");
     // We don't use build_service_from_config here, because we want to use the same keypair
     // to have the same PeerId
+    let mut node_a = build_service_with_keypair_from_config(p2p_config.clone()).await;
     let node_b = {
         // Given
-        p2p_config.reserved_nodes = node_a.multiaddrs();
-        let max_block_size = p2p_config.max_block_size;
-        let (sender, _) =
-            broadcast::channel(p2p_config.reserved_nodes.len().saturating_add(1));
-
-        let mut service = FuelP2PService::new(
-            sender,
-            p2p_config,
-            GossipsubMessageHandler::new(),
-            RequestResponseMessageHandler::new(max_block_size),
-        )
-        .await
-        .unwrap();
-        service.start().await.unwrap();
-        service
+        p2p_config.reserved_nodes = transport.multiaddrs(&node_a);
+        build_service_with_keypair_from_config(p2p_config).await
     };
+    assert_eq!(node_a.local_peer_id, node_b.local_peer_id);
     // When
     tokio::time::timeout(Duration::from_secs(5), async move {
         loop {
@@ -270,17 +320,19 @@ async fn dont_connect_to_node_with_same_peer_id() {
 
 // We start with two nodes, node_a and node_b, bootstrapped with `bootstrap_nodes_count` other nodes.
 // Yet node_a and node_b are only allowed to connect to specified amount of nodes.
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn max_peers_connected_works() {
-    let p2p_config = Config::default_initialized("max_peers_connected_works");
+async fn max_peers_connected_works(transport: Transport) {
+    let p2p_config = transport.config("max_peers_connected_works");
 
     let bootstrap_nodes_count = 20;
     let node_a_max_peers_allowed: usize = 3;
     let node_b_max_peers_allowed: usize = 5;
 
     let (mut nodes, nodes_multiaddrs) =
-        setup_bootstrap_nodes(&p2p_config, bootstrap_nodes_count).await;
+        setup_bootstrap_nodes(transport, &p2p_config, bootstrap_nodes_count).await;
 
     // this node is allowed to only connect to `node_a_max_peers_allowed` other nodes
     let mut node_a = {
@@ -353,16 +405,21 @@ async fn max_peers_connected_works() {
 // Simulate 2 Sets of Sentry nodes.
 // In both Sets, a single Guarded Node should only be connected to their sentry nodes.
 // While other nodes can and should connect to nodes outside of the Sentry Set.
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test(flavor = "multi_thread")]
 #[instrument]
-async fn sentry_nodes_working() {
+async fn sentry_nodes_working(transport: Transport) {
     const RESERVED_NODE_SIZE: usize = 4;
 
-    let mut p2p_config = Config::default_initialized("sentry_nodes_working");
+    let mut p2p_config = transport.config("sentry_nodes_working");
 
-    async fn build_sentry_nodes(p2p_config: Config) -> (P2PService, Vec<P2PService>) {
+    async fn build_sentry_nodes(
+        transport: Transport,
+        p2p_config: Config,
+    ) -> (P2PService, Vec<P2PService>) {
         let (reserved_nodes, reserved_multiaddrs) =
-            setup_bootstrap_nodes(&p2p_config, RESERVED_NODE_SIZE).await;
+            setup_bootstrap_nodes(transport, &p2p_config, RESERVED_NODE_SIZE).await;
 
         // set up the guraded node service with `reserved_nodes_only_mode`
         let guarded_node_service = {
@@ -378,14 +435,14 @@ async fn sentry_nodes_working() {
     }
 
     let (mut first_guarded_node, mut first_sentry_nodes) =
-        build_sentry_nodes(p2p_config.clone()).await;
+        build_sentry_nodes(transport, p2p_config.clone()).await;
     p2p_config.bootstrap_nodes = first_sentry_nodes
         .iter()
-        .flat_map(|n| n.multiaddrs())
+        .flat_map(|n| transport.multiaddrs(n))
         .collect();
 
     let (mut second_guarded_node, second_sentry_nodes) =
-        build_sentry_nodes(p2p_config).await;
+        build_sentry_nodes(transport, p2p_config).await;
 
     let first_sentry_set: HashSet<_> = first_sentry_nodes
         .iter()
@@ -448,11 +505,13 @@ async fn sentry_nodes_working() {
 
 // Simulates 2 p2p nodes that are on the same network and should connect via mDNS
 // without any additional bootstrapping
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn nodes_connected_via_mdns() {
+async fn nodes_connected_via_mdns(transport: Transport) {
     // Node A
-    let mut p2p_config = Config::default_initialized("nodes_connected_via_mdns");
+    let mut p2p_config = transport.config("nodes_connected_via_mdns");
     p2p_config.enable_mdns = true;
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
@@ -475,28 +534,67 @@ async fn nodes_connected_via_mdns() {
 
 // Simulates 2 p2p nodes that are on the same network but their Fuel Upgrade checksum is different
 // (different chain id or chain config)
-// So they are not able to connect
+// So they are not able to connect, and no message of Node B reaches Node A
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn nodes_cannot_connect_due_to_different_checksum() {
-    use libp2p::TransportError;
+async fn nodes_cannot_connect_due_to_different_checksum(transport: Transport) {
+    use libp2p::{
+        TransportError,
+        gossipsub,
+        request_response,
+    };
     // Node A
     let mut p2p_config =
-        Config::default_initialized("nodes_cannot_connect_due_to_different_checksum");
+        transport.config("nodes_cannot_connect_due_to_different_checksum");
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
+    let node_a_peer_id = node_a.local_peer_id;
 
     // different checksum
     p2p_config.checksum = [1u8; 32].into();
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     // Node B
     let mut node_b = build_service_from_config(p2p_config).await;
+    let node_b_peer_id = node_b.local_peer_id;
 
+    let mut connection_rejected = false;
+    let mut messages_sent = false;
+    let deadline = tokio::time::sleep(Duration::from_secs(3));
+    tokio::pin!(deadline);
     loop {
+        // Node B tries to reach Node A directly once the connection is rejected
+        if connection_rejected && !messages_sent {
+            messages_sent = true;
+            let tx = TransactionBuilder::script(vec![], vec![]).finalize_as_transaction();
+            let _ =
+                node_b.publish_message(GossipsubBroadcastRequest::NewTx(Arc::new(tx)));
+            let (sender, _) = oneshot::channel();
+            let _ = node_b.send_request_msg(
+                Some(node_a_peer_id),
+                RequestMessage::TxPoolAllTransactionsIds,
+                ResponseSender::TxPoolAllTransactionsIds(sender),
+            );
+        }
+
         tokio::select! {
             node_a_event = node_a.swarm.select_next_some() => {
                 tracing::info!("Node A Event: {:?}", node_a_event);
-                if let SwarmEvent::IncomingConnectionError { error: ListenError::Transport(TransportError::Other(_)), .. } = node_a_event {
-                    break
+                match node_a_event {
+                    // Both transports verify the checksum during the connection upgrade
+                    SwarmEvent::IncomingConnectionError { error: ListenError::Transport(TransportError::Other(_)), .. } => {
+                        connection_rejected = true;
+                    }
+                    SwarmEvent::ConnectionEstablished { peer_id, .. } if peer_id == node_b_peer_id => {
+                        panic!("Node A should not connect to Node B!")
+                    }
+                    SwarmEvent::Behaviour(FuelBehaviourEvent::Gossipsub(gossipsub::Event::Message { .. })) => {
+                        panic!("Node A should not receive the gossip of Node B!")
+                    }
+                    SwarmEvent::Behaviour(FuelBehaviourEvent::RequestResponse(request_response::Event::Message { .. })) => {
+                        panic!("Node A should not receive the requests of Node B!")
+                    }
+                    _ => {}
                 }
             },
             node_b_event = node_b.next_event() => {
@@ -505,23 +603,28 @@ async fn nodes_cannot_connect_due_to_different_checksum() {
                 }
                 tracing::info!("Node B Event: {:?}", node_b_event);
             },
-
+            _ = &mut deadline => {
+                assert!(connection_rejected, "Node A should reject the connection of Node B");
+                break;
+            }
         };
     }
 }
 
 // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
 // Using Identify Protocol Node C should be able to identify and connect to Node B
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn nodes_connected_via_identify() {
+async fn nodes_connected_via_identify(transport: Transport) {
     // Node A
-    let mut p2p_config = Config::default_initialized("nodes_connected_via_identify");
+    let mut p2p_config = transport.config("nodes_connected_via_identify");
 
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
     // Node C
@@ -553,16 +656,18 @@ async fn nodes_connected_via_identify() {
 // Simulates 2 p2p nodes that connect to each other and consequently exchange Peer Info
 // On successful connection, node B updates its latest BlockHeight
 // and shares it with Peer A via Heartbeat protocol
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn peer_info_updates_work() {
-    let mut p2p_config = Config::default_initialized("peer_info_updates_work");
+async fn peer_info_updates_work(transport: Transport) {
+    let mut p2p_config = transport.config("peer_info_updates_work");
 
     // Node A
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config).await;
 
     let latest_block_height = 40_u32.into();
@@ -597,13 +702,16 @@ async fn peer_info_updates_work() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn gossipsub_broadcast_tx_with_accept__new_tx() {
+async fn gossipsub_broadcast_tx_with_accept__new_tx(transport: Transport) {
     for _ in 0..100 {
         tokio::time::timeout(
             Duration::from_secs(5),
             gossipsub_broadcast(
+                transport,
                 GossipsubBroadcastRequest::NewTx(
                     Arc::new(Transaction::default_test_tx()),
                 ),
@@ -616,13 +724,16 @@ async fn gossipsub_broadcast_tx_with_accept__new_tx() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations() {
+async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations(transport: Transport) {
     for _ in 0..100 {
         tokio::time::timeout(
             Duration::from_secs(20),
             gossipsub_broadcast(
+                transport,
                 GossipsubBroadcastRequest::TxPreConfirmations(Arc::new(
                     P2PPreConfirmationMessage::default_test_confirmation(),
                 )),
@@ -635,13 +746,60 @@ async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
+#[tokio::test]
+#[instrument]
+async fn gossipsub_broadcast_tx_with_accept__new_block(transport: Transport) {
+    for _ in 0..100 {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                transport,
+                GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                GossipsubMessageAcceptance::Accept,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+}
+
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn gossipsub_broadcast_tx_with_reject__new_tx() {
+async fn gossipsub_broadcast_tx_with_accept__bft_vote(transport: Transport) {
     for _ in 0..100 {
+        let vote = SignedVote {
+            vote: Vote::prevote(1u32.into(), 0, None),
+            signature: Default::default(),
+        };
         tokio::time::timeout(
             Duration::from_secs(5),
             gossipsub_broadcast(
+                transport,
+                GossipsubBroadcastRequest::BftVote(Arc::new(vote)),
+                GossipsubMessageAcceptance::Accept,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+}
+
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
+#[tokio::test]
+#[instrument]
+async fn gossipsub_broadcast_tx_with_reject__new_tx(transport: Transport) {
+    for _ in 0..100 {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                transport,
                 GossipsubBroadcastRequest::NewTx(
                     Arc::new(Transaction::default_test_tx()),
                 ),
@@ -654,13 +812,16 @@ async fn gossipsub_broadcast_tx_with_reject__new_tx() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn gossipsub_broadcast_tx_with_reject__tx_preconfirmations() {
+async fn gossipsub_broadcast_tx_with_reject__tx_preconfirmations(transport: Transport) {
     for _ in 0..100 {
         tokio::time::timeout(
             Duration::from_secs(5),
             gossipsub_broadcast(
+                transport,
                 GossipsubBroadcastRequest::TxPreConfirmations(Arc::new(
                     P2PPreConfirmationMessage::default_test_confirmation(),
                 )),
@@ -673,11 +834,14 @@ async fn gossipsub_broadcast_tx_with_reject__tx_preconfirmations() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
 #[ignore]
-async fn gossipsub_scoring_with_accepted_messages() {
+async fn gossipsub_scoring_with_accepted_messages(transport: Transport) {
     gossipsub_scoring_tester(
+        transport,
         "gossipsub_scoring_with_accepted_messages",
         100,
         GossipsubMessageAcceptance::Accept,
@@ -687,11 +851,14 @@ async fn gossipsub_scoring_with_accepted_messages() {
 
 /// At `GRAYLIST_THRESHOLD` the node will ignore all messages from the peer
 /// And our PeerManager will ban the peer at that point - leading to disconnect
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
 #[ignore]
-async fn gossipsub_scoring_with_rejected_messages() {
+async fn gossipsub_scoring_with_rejected_messages(transport: Transport) {
     gossipsub_scoring_tester(
+        transport,
         "gossipsub_scoring_with_rejected_messages",
         100,
         GossipsubMessageAcceptance::Reject,
@@ -703,21 +870,22 @@ async fn gossipsub_scoring_with_rejected_messages() {
 /// Helper function for testing gossipsub scoring
 /// ! Dev Note: this function runs forever, its purpose is to show the scoring in action with passage of time
 async fn gossipsub_scoring_tester(
+    transport: Transport,
     test_name: &str,
     amount_of_msgs_per_second: usize,
     acceptance: GossipsubMessageAcceptance,
 ) {
-    let mut p2p_config = Config::default_initialized(test_name);
+    let mut p2p_config = transport.config(test_name);
 
     // Node A
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
     // Node C
-    p2p_config.bootstrap_nodes = node_b.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_b);
     let mut node_c = build_service_from_config(p2p_config.clone()).await;
 
     let mut interval = tokio::time::interval(Duration::from_secs(1));
@@ -795,20 +963,25 @@ async fn gossipsub_scoring_tester(
 // TODO: Move me before tests that use this function
 /// Reusable helper function for Broadcasting Gossipsub requests
 async fn gossipsub_broadcast(
+    transport: Transport,
     broadcast_request: GossipsubBroadcastRequest,
     acceptance: GossipsubMessageAcceptance,
     connection_limit: Option<u32>,
 ) {
-    let mut p2p_config = Config::default_initialized("gossipsub_exchanges_messages");
+    let mut p2p_config = transport.config("gossipsub_exchanges_messages");
 
     if let Some(connection_limit) = connection_limit {
         p2p_config.max_functional_peers_connected = connection_limit;
     }
 
     p2p_config.subscribe_to_new_tx = true;
-    
+
     p2p_config.subscribe_to_pre_confirmations = true;
 
+    p2p_config.subscribe_to_new_blocks = true;
+
+    p2p_config.subscribe_to_bft_consensus = true;
+
     let (selected_topic, selected_tag): (Sha256Topic, GossipTopicTag) = {
         let (topic, tag) = match broadcast_request {
             GossipsubBroadcastRequest::NewTx(_) => {
@@ -818,6 +991,15 @@ async fn gossipsub_broadcast(
                 TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
                 GossipTopicTag::TxPreconfirmations,
             ),
+            GossipsubBroadcastRequest::NewBlock(_) => {
+                (NEW_BLOCK_GOSSIP_TOPIC, GossipTopicTag::NewBlock)
+            }
+            GossipsubBroadcastRequest::BftProposal(_) => {
+                (BFT_PROPOSAL_GOSSIP_TOPIC, GossipTopicTag::BftProposal)
+            }
+            GossipsubBroadcastRequest::BftVote(_) => {
+                (BFT_VOTE_GOSSIP_TOPIC, GossipTopicTag::BftVote)
+            }
         };
 
         (
@@ -833,11 +1015,11 @@ async fn gossipsub_broadcast(
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
     // Node C
-    p2p_config.bootstrap_nodes = node_b.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_b);
     let mut node_c = build_service_from_config(p2p_config.clone()).await;
 
     // Node C does not connect to Node A
@@ -951,6 +1133,37 @@ fn check_message_matches_request(
             received,
             "Both messages were `Preconfirmations`, but the received message did not match the requested message"
         ),
+        (
+            GossipsubMessage::NewBlock(received),
+            GossipsubBroadcastRequest::NewBlock(requested),
+        ) => {
+            // Metadata gets skipped during serialization, so compare the block ids instead
+            assert_eq!(
+                requested.entity.id(),
+                received.entity.id(),
+                "Both messages were `NewBlock`s, but the received message did not match the requested message"
+            );
+            assert_eq!(requested.consensus, received.consensus);
+        }
+        (
+            GossipsubMessage::BftProposal(received),
+            GossipsubBroadcastRequest::BftProposal(requested),
+        ) => {
+            assert_eq!(
+                requested.proposal.block.id(),
+                received.proposal.block.id(),
+                "Both messages were `BftProposal`s, but the received message did not match the requested message"
+            );
+            assert_eq!(requested.signature, received.signature);
+        }
+        (
+            GossipsubMessage::BftVote(received),
+            GossipsubBroadcastRequest::BftVote(requested),
+        ) => assert_eq!(
+            requested.deref(),
+            received,
+            "Both messages were `BftVote`s, but the received message did not match the requested message"
+        ),
         _ => panic!(
             "Message does not match the expected request, expected: {:?}, actual: {:?}",
             expected, message
@@ -973,6 +1186,21 @@ fn arbitrary_headers_for_range(range: Range<u32>) -> Vec<SealedBlockHeader> {
     blocks
 }
 
+fn arbitrary_state_entries() -> Vec<StateEntry> {
+    (0u8..5).map(|i| (vec![i; 34], vec![i; 10])).collect()
+}
+
+fn arbitrary_state_checkpoint() -> StateCheckpoint {
+    let header = arbitrary_headers_for_range(7..8).remove(0);
+    StateCheckpoint {
+        header,
+        tables: vec![TableCheckpoint {
+            table: StateSyncTable::Coins,
+            chunk_roots: vec![state_chunk_root(&arbitrary_state_entries())],
+        }],
+    }
+}
+
 // Metadata gets skipped during serialization, so this is the fuzzy way to compare blocks
 fn eq_except_metadata(a: &SealedBlockHeader, b: &SealedBlockHeader) -> bool {
     let app_eq = match (&a.entity, &b.entity) {
@@ -986,10 +1214,11 @@ fn eq_except_metadata(a: &SealedBlockHeader, b: &SealedBlockHeader) -> bool {
 }
 
 async fn request_response_works_with(
+    transport: Transport,
     request_msg: RequestMessage,
     connection_limit: Option<u32>,
 ) {
-    let mut p2p_config = Config::default_initialized("request_response_works_with");
+    let mut p2p_config = transport.config("request_response_works_with");
 
     if let Some(connection_limit) = connection_limit {
         p2p_config.max_functional_peers_connected = connection_limit;
@@ -999,7 +1228,7 @@ async fn request_response_works_with(
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
     let (tx_test_end, mut rx_test_end) = mpsc::channel::<bool>(1);
@@ -1129,6 +1358,39 @@ async fn request_response_works_with(
                                         }
                                     });
                                 }
+                                RequestMessage::StateCheckpoint => {
+                                    let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                    assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::StateCheckpoint(tx_orchestrator)).is_ok());
+                                    let tx_test_end = tx_test_end.clone();
+                                    tokio::spawn(async move {
+                                        let response_message = rx_orchestrator.await;
+
+                                        if let Ok(Ok((_, Ok(Ok(checkpoint))))) = response_message {
+                                            let expected = arbitrary_state_checkpoint();
+                                            let check = checkpoint.height() == expected.height() && checkpoint.tables == expected.tables;
+                                            let _ = tx_test_end.send(check).await;
+                                        } else {
+                                            tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                            let _ = tx_test_end.send(false).await;
+                                        }
+                                    });
+                                }
+                                RequestMessage::StateChunk(_) => {
+                                    let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                    assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::StateChunkFromPeer(tx_orchestrator)).is_ok());
+                                    let tx_test_end = tx_test_end.clone();
+                                    tokio::spawn(async move {
+                                        let response_message = rx_orchestrator.await;
+
+                                        if let Ok((_, Ok(Ok(entries)))) = response_message {
+                                            let check = entries == arbitrary_state_entries();
+                                            let _ = tx_test_end.send(check).await;
+                                        } else {
+                                            tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                            let _ = tx_test_end.send(false).await;
+                                        }
+                                    });
+                                }
                             }
                         }
                     }
@@ -1164,6 +1426,12 @@ async fn request_response_works_with(
                             }).collect();
                             let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::TxPoolFullTransactions(Ok(txs)));
                         }
+                        RequestMessage::StateCheckpoint => {
+                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::StateCheckpoint(Ok(Box::new(arbitrary_state_checkpoint()))));
+                        }
+                        RequestMessage::StateChunk(_) => {
+                            let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::StateChunk(Ok(arbitrary_state_entries())));
+                        }
                     }
                 }
 
@@ -1173,48 +1441,96 @@ async fn request_response_works_with(
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn request_response_works_with_transactions() {
+async fn request_response_works_with_transactions(transport: Transport) {
     let arbitrary_range = 2..6;
-    request_response_works_with(RequestMessage::Transactions(arbitrary_range), None).await
+    request_response_works_with(
+        transport,
+        RequestMessage::Transactions(arbitrary_range),
+        None,
+    )
+    .await
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn request_response_works_with_sealed_headers_range_inclusive() {
+async fn request_response_works_with_sealed_headers_range_inclusive(
+    transport: Transport,
+) {
     let arbitrary_range = 2..6;
-    request_response_works_with(RequestMessage::SealedHeaders(arbitrary_range), None)
-        .await
+    request_response_works_with(
+        transport,
+        RequestMessage::SealedHeaders(arbitrary_range),
+        None,
+    )
+    .await
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn request_response_works_with_transactions_ids() {
-    request_response_works_with(RequestMessage::TxPoolAllTransactionsIds, None).await
+async fn request_response_works_with_transactions_ids(transport: Transport) {
+    request_response_works_with(transport, RequestMessage::TxPoolAllTransactionsIds, None)
+        .await
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn request_response_works_with_full_transactions() {
+async fn request_response_works_with_full_transactions(transport: Transport) {
     let tx_ids = (0..10)
         .map(|_| Transaction::default_test_tx().id(&ChainId::new(1)))
         .collect();
-    request_response_works_with(RequestMessage::TxPoolFullTransactions(tx_ids), None)
+    request_response_works_with(
+        transport,
+        RequestMessage::TxPoolFullTransactions(tx_ids),
+        None,
+    )
+    .await
+}
+
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
+#[tokio::test]
+#[instrument]
+async fn request_response_works_with_state_checkpoint(transport: Transport) {
+    request_response_works_with(transport, RequestMessage::StateCheckpoint, None).await
+}
+
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
+#[tokio::test]
+#[instrument]
+async fn request_response_works_with_state_chunk(transport: Transport) {
+    let request = StateChunkRequest {
+        height: 7.into(),
+        table: StateSyncTable::Coins,
+        index: 0,
+    };
+    request_response_works_with(transport, RequestMessage::StateChunk(request), None)
         .await
 }
 
 /// We send a request for transactions, but it's responded by only headers
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn invalid_response_type_is_detected() {
-    let mut p2p_config = Config::default_initialized("invalid_response_type_is_detected");
+async fn invalid_response_type_is_detected(transport: Transport) {
+    let mut p2p_config = transport.config("invalid_response_type_is_detected");
 
     // Node A
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
     let (tx_test_end, mut rx_test_end) = mpsc::channel::<bool>(1);
@@ -1285,10 +1601,12 @@ async fn invalid_response_type_is_detected() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
 #[instrument]
-async fn req_res_outbound_timeout_works() {
-    let mut p2p_config = Config::default_initialized("req_res_outbound_timeout_works");
+async fn req_res_outbound_timeout_works(transport: Transport) {
+    let mut p2p_config = transport.config("req_res_outbound_timeout_works");
 
     // Node A
     // setup request timeout to 1ms in order for the Request to fail
@@ -1297,7 +1615,7 @@ async fn req_res_outbound_timeout_works() {
     let mut node_a = build_service_from_config(p2p_config.clone()).await;
 
     // Node B
-    p2p_config.bootstrap_nodes = node_a.multiaddrs();
+    p2p_config.bootstrap_nodes = transport.multiaddrs(&node_a);
     p2p_config.set_request_timeout = Duration::from_secs(20);
     let mut node_b = build_service_from_config(p2p_config.clone()).await;
 
@@ -1379,11 +1697,14 @@ async fn req_res_outbound_timeout_works() {
     }
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
-async fn gossipsub_peer_limit_works() {
+async fn gossipsub_peer_limit_works(transport: Transport) {
     tokio::time::timeout(
         Duration::from_secs(5),
         gossipsub_broadcast(
+            transport,
             GossipsubBroadcastRequest::NewTx(Arc::new(Transaction::default_test_tx())),
             GossipsubMessageAcceptance::Accept,
             Some(1), // limit to 1 peer, therefore the function will timeout, as it will not be able to propagate the message
@@ -1393,14 +1714,17 @@ async fn gossipsub_peer_limit_works() {
     .expect_err("Should have timed out");
 }
 
+#[test_case(Transport::Tcp ; "tcp")]
+#[test_case(Transport::Quic ; "quic")]
 #[tokio::test]
-async fn request_response_peer_limit_works() {
-    let handle = tokio::spawn(async {
+async fn request_response_peer_limit_works(transport: Transport) {
+    let handle = tokio::spawn(async move {
         let arbitrary_range = 2..6;
 
         tokio::time::timeout(
             Duration::from_secs(5),
             request_response_works_with(
+                transport,
                 RequestMessage::Transactions(arbitrary_range),
                 Some(0), // limit to 0 peers,
             ),
@@ -1,6 +1,12 @@
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        heartbeat_data::HeartbeatData,
+        reputation_store::{
+            Ban,
+            ReputationStore,
+        },
+    },
 };
 use fuel_core_services::seqlock::{
     SeqLock,
@@ -22,20 +28,32 @@ use libp2p::{
     PeerId,
 };
 use rand::seq::IteratorRandom;
-use std::collections::{
-    HashMap,
-    HashSet,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+    },
 };
 use tracing::{
     debug,
     info,
+    warn,
 };
 
 pub mod heartbeat_data;
+pub mod reputation_store;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// How often the scores of the connected peers are written to the reputation store.
+const REPUTATION_FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -66,6 +84,16 @@ pub struct PeerManager {
     connection_state_writer: SeqLockWriter<ConnectionState>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    /// Scores and bans of peers that survive restarts of the node
+    reputation: ReputationStore,
+    /// How long the peer stays banned after its score drops below the threshold
+    ban_duration: Duration,
+    last_reputation_flush: Instant,
+    /// Set when a ban changes, so it is written on the next update of the reputation
+    /// without waiting for the flush interval.
+    bans_changed: bool,
+    /// Writes the reputation to the disk outside of the swarm event loop.
+    reputation_writer: Option<std::thread::JoinHandle<()>>,
 }
 
 impl PeerManager {
@@ -74,6 +102,8 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state_writer: SeqLockWriter<ConnectionState>,
         max_non_reserved_peers: usize,
+        reputation: ReputationStore,
+        ban_duration: Duration,
     ) -> Self {
         Self {
             score_config: ScoreConfig::default(),
@@ -83,6 +113,11 @@ impl PeerManager {
             connection_state_writer,
             max_non_reserved_peers,
             reserved_peers_updates,
+            reputation,
+            ban_duration,
+            last_reputation_flush: Instant::now(),
+            bans_changed: false,
+            reputation_writer: None,
         }
     }
 
@@ -95,7 +130,7 @@ impl PeerManager {
     }
 
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
@@ -103,7 +138,8 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
-            punisher.ban_peer(peer_id);
+            let reason = format!("gossipsub score {gossip_score} is below the threshold");
+            self.ban_peer(peer_id, reason, Some(self.ban_duration), punisher);
         }
     }
 
@@ -161,7 +197,10 @@ impl PeerManager {
                 info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
                 if new_score < self.score_config.min_app_score_allowed {
-                    punisher.ban_peer(peer_id);
+                    let reason = format!(
+                        "app score {new_score} is below the threshold after the report from {reporting_service}"
+                    );
+                    self.ban_peer(peer_id, reason, Some(self.ban_duration), punisher);
                 }
             }
             _ => {
@@ -170,6 +209,88 @@ impl PeerManager {
         }
     }
 
+    /// Bans the peer for the `duration`, or permanently if the `duration` is not set.
+    /// The ban is kept in the reputation store, so it survives restarts of the node.
+    /// The existing ban of the peer is left as is.
+    pub fn ban_peer<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+        punisher: &mut T,
+    ) {
+        let expires_at =
+            duration.and_then(|duration| SystemTime::now().checked_add(duration));
+        let ban = Ban {
+            reason: reason.clone(),
+            expires_at,
+        };
+        if !self.reputation.ban(peer_id, ban) {
+            debug!(target: "fuel-p2p", "{peer_id} is already banned: {reason}");
+            return
+        }
+        info!(target: "fuel-p2p", "Banning {peer_id}: {reason}");
+        punisher.ban_peer(peer_id);
+        self.bans_changed = true;
+    }
+
+    /// Removes the ban of the peer. Returns `false` if the peer was not banned.
+    pub fn unban_peer<T: Punisher>(
+        &mut self,
+        peer_id: &PeerId,
+        punisher: &mut T,
+    ) -> bool {
+        let unbanned = self.reputation.unban(peer_id).is_some();
+        if unbanned {
+            info!(target: "fuel-p2p", "Unbanning {peer_id}");
+            if let Some(peer_info) = self.non_reserved_connected_peers.get_mut(peer_id) {
+                peer_info.score = DEFAULT_APP_SCORE;
+            }
+            punisher.unban_peer(*peer_id);
+            self.bans_changed = true;
+        }
+        unbanned
+    }
+
+    /// Bans the peers that were banned before the restart of the node.
+    pub fn restore_bans<T: Punisher>(&mut self, punisher: &mut T) {
+        if !self
+            .reputation
+            .remove_expired_bans(SystemTime::now())
+            .is_empty()
+        {
+            self.bans_changed = true;
+        }
+        for (peer_id, _) in self.reputation.bans() {
+            punisher.ban_peer(*peer_id);
+        }
+    }
+
+    pub fn banned_peers(&self) -> impl Iterator<Item = (&PeerId, &Ban)> {
+        self.reputation.bans()
+    }
+
+    /// Lifts the expired bans and periodically writes the scores
+    /// of the connected peers to the reputation store.
+    /// The changed bans are written on the next call.
+    pub fn update_reputation<T: Punisher>(&mut self, punisher: &mut T) {
+        let unbanned = self.reputation.remove_expired_bans(SystemTime::now());
+        for peer_id in &unbanned {
+            info!(target: "fuel-p2p", "The ban of {peer_id} expired");
+            punisher.unban_peer(*peer_id);
+        }
+
+        if !unbanned.is_empty()
+            || self.bans_changed
+            || self.last_reputation_flush.elapsed() >= REPUTATION_FLUSH_INTERVAL
+        {
+            for (peer_id, peer_info) in &self.non_reserved_connected_peers {
+                self.reputation.set_score(*peer_id, peer_info.score);
+            }
+            self.flush_reputation();
+        }
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
@@ -206,9 +327,12 @@ impl PeerManager {
             let all_slots_taken = self.max_non_reserved_peers
                 == self.non_reserved_connected_peers.len().saturating_add(1);
 
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
+            let removed = self.non_reserved_connected_peers.remove(&peer_id);
+            if let Some(peer_info) = &removed {
+                self.reputation.set_score(peer_id, peer_info.score);
+            }
+
+            if removed.is_some() && all_slots_taken {
                 // since all the slots were full prior to this disconnect
                 // let's allow new peer non-reserved peers connections
                 self.connection_state_writer.write(|data| {
@@ -264,8 +388,12 @@ impl PeerManager {
                 });
             }
 
+            let mut peer_info = PeerInfo::new(HEARTBEAT_AVG_WINDOW);
+            if let Some(score) = self.reputation.score(peer_id) {
+                peer_info.score = score;
+            }
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+                .insert(*peer_id, peer_info);
         } else if is_reserved && !self.reserved_connected_peers.contains_key(peer_id) {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
@@ -276,6 +404,42 @@ impl PeerManager {
         false
     }
 
+    /// Writes the reputation to the disk in the background. If the previous write
+    /// is still in progress, the changes are written by the next flush.
+    fn flush_reputation(&mut self) {
+        if self
+            .reputation_writer
+            .as_ref()
+            .is_some_and(|writer| !writer.is_finished())
+        {
+            return
+        }
+        self.last_reputation_flush = Instant::now();
+        self.bans_changed = false;
+
+        match self.reputation.snapshot() {
+            Ok(Some(snapshot)) => {
+                let writer = std::thread::Builder::new()
+                    .name("p2p-reputation-writer".to_string())
+                    .spawn(move || {
+                        if let Err(err) = snapshot.write() {
+                            warn!(target: "fuel-p2p", "Failed to write the peer reputation: {err:?}");
+                        }
+                    });
+                match writer {
+                    Ok(writer) => self.reputation_writer = Some(writer),
+                    Err(err) => {
+                        warn!(target: "fuel-p2p", "Failed to spawn the peer reputation writer: {err:?}");
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "fuel-p2p", "Failed to encode the peer reputation: {err:?}");
+            }
+        }
+    }
+
     fn send_reserved_peers_update(&self) {
         let _ = self
             .reserved_peers_updates
@@ -392,6 +556,8 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_peer(&mut self, peer_id: PeerId);
 }
 
 #[cfg(test)]
@@ -415,9 +581,26 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state_writer,
             max_non_reserved_peers,
+            ReputationStore::in_memory(),
+            Duration::from_secs(60),
         )
     }
 
+    #[derive(Default)]
+    struct FakePunisher {
+        banned: HashSet<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned.insert(peer_id);
+        }
+
+        fn unban_peer(&mut self, peer_id: PeerId) {
+            self.banned.remove(&peer_id);
+        }
+    }
+
     #[test]
     fn only_allowed_number_of_non_reserved_peers_is_connected() {
         let max_non_reserved_peers = 5;
@@ -493,4 +676,84 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn score_is_restored_when_peer_reconnects() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(peer_id, -10.0, "test", &mut punisher);
+        peer_manager.handle_peer_disconnect(peer_id);
+        peer_manager.handle_initial_connection(&peer_id);
+
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, DEFAULT_APP_SCORE - 10.0);
+    }
+
+    #[test]
+    fn peer_with_low_score_is_banned_until_unbanned() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(
+            peer_id,
+            MIN_APP_SCORE - MAX_APP_SCORE,
+            "test",
+            &mut punisher,
+        );
+
+        assert!(punisher.banned.contains(&peer_id));
+        assert_eq!(peer_manager.banned_peers().count(), 1);
+
+        assert!(peer_manager.unban_peer(&peer_id, &mut punisher));
+
+        assert!(!punisher.banned.contains(&peer_id));
+        assert_eq!(peer_manager.banned_peers().count(), 0);
+
+        peer_manager.handle_peer_disconnect(peer_id);
+        peer_manager.handle_initial_connection(&peer_id);
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, DEFAULT_APP_SCORE);
+    }
+
+    #[test]
+    fn expired_ban_is_lifted() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+
+        peer_manager.ban_peer(
+            peer_id,
+            "test".to_string(),
+            Some(Duration::ZERO),
+            &mut punisher,
+        );
+        peer_manager.update_reputation(&mut punisher);
+
+        assert!(!punisher.banned.contains(&peer_id));
+        assert_eq!(peer_manager.banned_peers().count(), 0);
+    }
+
+    #[test]
+    fn already_banned_peer_keeps_its_ban() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.ban_peer(
+            peer_id,
+            "test".to_string(),
+            Some(Duration::from_secs(60)),
+            &mut punisher,
+        );
+        let ban = peer_manager.banned_peers().next().unwrap().1.clone();
+
+        peer_manager.ban_peer(peer_id, "test again".to_string(), None, &mut punisher);
+
+        let bans = peer_manager.banned_peers().collect::<Vec<_>>();
+        assert_eq!(bans, vec![(&peer_id, &ban)]);
+    }
 }
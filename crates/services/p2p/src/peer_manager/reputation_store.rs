@@ -0,0 +1,440 @@
+use fuel_core_types::services::p2p::peer_reputation::{
+    AppScore,
+    DEFAULT_APP_SCORE,
+};
+use libp2p::PeerId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    str::FromStr,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+use tracing::warn;
+
+/// The maximum number of peers with the stored score. When the limit is reached,
+/// the scores closest to the default are evicted first.
+const MAX_STORED_SCORES: usize = 10_000;
+
+/// The scores closer than this to the default are not stored,
+/// because the peer starts with the default score anyway.
+const NEUTRAL_SCORE_TOLERANCE: AppScore = 1.0;
+
+fn is_neutral(score: AppScore) -> bool {
+    (score - DEFAULT_APP_SCORE).abs() < NEUTRAL_SCORE_TOLERANCE
+}
+
+/// The ban of the peer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ban {
+    /// Why the peer was banned.
+    pub reason: String,
+    /// When the ban expires. The ban is permanent if it is not set.
+    pub expires_at: Option<SystemTime>,
+}
+
+impl Ban {
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Keeps the reputation of peers across restarts of the node.
+/// The reputation is stored as a JSON file if the path is set,
+/// otherwise it lives only in memory.
+#[derive(Debug, Default)]
+pub struct ReputationStore {
+    path: Option<PathBuf>,
+    scores: HashMap<PeerId, AppScore>,
+    bans: HashMap<PeerId, Ban>,
+    dirty: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoredReputation {
+    peers: Vec<StoredPeer>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredPeer {
+    peer_id: String,
+    #[serde(default)]
+    score: Option<AppScore>,
+    #[serde(default)]
+    ban: Option<StoredBan>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredBan {
+    reason: String,
+    /// Seconds since the Unix epoch.
+    #[serde(default)]
+    expires_at: Option<u64>,
+}
+
+impl ReputationStore {
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Opens the store at the `path`. The store is empty if the file doesn't exist yet.
+    pub fn open(path: PathBuf) -> anyhow::Result<Self> {
+        let mut store = Self {
+            path: Some(path.clone()),
+            ..Default::default()
+        };
+
+        if !path.exists() {
+            return Ok(store)
+        }
+
+        let file = std::fs::read(&path)?;
+        let stored: StoredReputation = serde_json::from_slice(&file)?;
+        for peer in stored.peers {
+            let Ok(peer_id) = PeerId::from_str(&peer.peer_id) else {
+                warn!(target: "fuel-p2p", "Skipping the reputation of the invalid peer id {}", peer.peer_id);
+                continue
+            };
+
+            if let Some(score) = peer.score.filter(|score| !is_neutral(*score)) {
+                store.scores.insert(peer_id, score);
+            }
+            if let Some(ban) = peer.ban {
+                let expires_at = ban
+                    .expires_at
+                    .and_then(|secs| UNIX_EPOCH.checked_add(Duration::from_secs(secs)));
+                store.bans.insert(
+                    peer_id,
+                    Ban {
+                        reason: ban.reason,
+                        expires_at,
+                    },
+                );
+            }
+        }
+        store.evict_scores_over_limit();
+
+        Ok(store)
+    }
+
+    pub fn score(&self, peer_id: &PeerId) -> Option<AppScore> {
+        self.scores.get(peer_id).copied()
+    }
+
+    /// Stores the score of the peer. The neutral score removes the stored one.
+    pub fn set_score(&mut self, peer_id: PeerId, score: AppScore) {
+        if is_neutral(score) {
+            self.reset_score(&peer_id);
+            return
+        }
+
+        if self.scores.insert(peer_id, score) != Some(score) {
+            self.dirty = true;
+        }
+        self.evict_scores_over_limit();
+    }
+
+    /// Removes the stored score of the peer, so it starts with the default score.
+    pub fn reset_score(&mut self, peer_id: &PeerId) {
+        if self.scores.remove(peer_id).is_some() {
+            self.dirty = true;
+        }
+    }
+
+    fn evict_scores_over_limit(&mut self) {
+        if self.scores.len() <= MAX_STORED_SCORES {
+            return
+        }
+        let excess = self.scores.len().saturating_sub(MAX_STORED_SCORES);
+
+        let mut by_distance = self
+            .scores
+            .iter()
+            .map(|(peer_id, score)| (*peer_id, (score - DEFAULT_APP_SCORE).abs()))
+            .collect::<Vec<_>>();
+        by_distance.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        for (peer_id, _) in by_distance.into_iter().take(excess) {
+            self.scores.remove(&peer_id);
+        }
+        self.dirty = true;
+    }
+
+    /// Bans the peer. Returns `false` without changing the existing ban
+    /// if the peer is already banned.
+    pub fn ban(&mut self, peer_id: PeerId, ban: Ban) -> bool {
+        if self.bans.contains_key(&peer_id) {
+            return false
+        }
+        self.bans.insert(peer_id, ban);
+        self.dirty = true;
+        true
+    }
+
+    /// Removes the ban of the peer and resets its score, so the peer
+    /// isn't banned again right after reconnecting. Returns the removed ban, if any.
+    pub fn unban(&mut self, peer_id: &PeerId) -> Option<Ban> {
+        let ban = self.bans.remove(peer_id);
+        if ban.is_some() {
+            self.reset_score(peer_id);
+            self.dirty = true;
+        }
+        ban
+    }
+
+    pub fn bans(&self) -> impl Iterator<Item = (&PeerId, &Ban)> {
+        self.bans.iter()
+    }
+
+    /// Removes the bans that are expired at `now` and returns the unbanned peers.
+    pub fn remove_expired_bans(&mut self, now: SystemTime) -> Vec<PeerId> {
+        let expired = self
+            .bans
+            .iter()
+            .filter(|(_, ban)| ban.is_expired(now))
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+
+        for peer_id in &expired {
+            self.unban(peer_id);
+        }
+
+        expired
+    }
+
+    /// Writes the changes to the disk.
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        match self.snapshot()? {
+            Some(snapshot) => snapshot.write(),
+            None => Ok(()),
+        }
+    }
+
+    /// Encodes the changes, so they can be written to the disk outside of the store.
+    /// Returns `None` if there is nothing to write.
+    pub fn snapshot(&mut self) -> anyhow::Result<Option<ReputationSnapshot>> {
+        let Some(path) = self.path.clone() else {
+            return Ok(None)
+        };
+        if !self.dirty {
+            return Ok(None)
+        }
+
+        let mut peers = self
+            .scores
+            .keys()
+            .chain(
+                self.bans
+                    .keys()
+                    .filter(|peer_id| !self.scores.contains_key(peer_id)),
+            )
+            .map(|peer_id| StoredPeer {
+                peer_id: peer_id.to_base58(),
+                score: self.scores.get(peer_id).copied(),
+                ban: self.bans.get(peer_id).map(|ban| StoredBan {
+                    reason: ban.reason.clone(),
+                    expires_at: ban.expires_at.map(|expires_at| {
+                        expires_at
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_secs()
+                    }),
+                }),
+            })
+            .collect::<Vec<_>>();
+        peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+
+        let encoded = serde_json::to_vec_pretty(&StoredReputation { peers })?;
+
+        self.dirty = false;
+        Ok(Some(ReputationSnapshot { path, encoded }))
+    }
+}
+
+/// The encoded reputation taken from the [`ReputationStore`].
+#[derive(Debug)]
+pub struct ReputationSnapshot {
+    path: PathBuf,
+    encoded: Vec<u8>,
+}
+
+impl ReputationSnapshot {
+    pub fn write(self) -> anyhow::Result<()> {
+        // Write to the temporary file first to not corrupt the store if the node crashes.
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, self.encoded)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open__restores_reputation_flushed_before() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peer_reputation.json");
+        let scored_peer = PeerId::random();
+        let banned_peer = PeerId::random();
+        let expires_at = UNIX_EPOCH + Duration::from_secs(2_000_000_000);
+        let ban = Ban {
+            reason: "spam".to_string(),
+            expires_at: Some(expires_at),
+        };
+
+        let mut store = ReputationStore::open(path.clone()).unwrap();
+        store.set_score(scored_peer, 42.0);
+        store.ban(banned_peer, ban.clone());
+        store.flush().unwrap();
+
+        // When
+        let store = ReputationStore::open(path).unwrap();
+
+        // Then
+        assert_eq!(store.score(&scored_peer), Some(42.0));
+        assert_eq!(store.score(&banned_peer), None);
+        assert_eq!(store.bans().collect::<Vec<_>>(), vec![(&banned_peer, &ban)]);
+    }
+
+    #[test]
+    fn remove_expired_bans__keeps_active_and_permanent_bans() {
+        // Given
+        let now = SystemTime::now();
+        let expired_peer = PeerId::random();
+        let active_peer = PeerId::random();
+        let permanent_peer = PeerId::random();
+
+        let mut store = ReputationStore::in_memory();
+        store.ban(
+            expired_peer,
+            Ban {
+                reason: "expired".to_string(),
+                expires_at: Some(now - Duration::from_secs(1)),
+            },
+        );
+        store.ban(
+            active_peer,
+            Ban {
+                reason: "active".to_string(),
+                expires_at: Some(now + Duration::from_secs(60)),
+            },
+        );
+        store.ban(
+            permanent_peer,
+            Ban {
+                reason: "permanent".to_string(),
+                expires_at: None,
+            },
+        );
+
+        // When
+        let unbanned = store.remove_expired_bans(now);
+
+        // Then
+        assert_eq!(unbanned, vec![expired_peer]);
+        let mut banned = store
+            .bans()
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        banned.sort();
+        let mut expected = vec![active_peer, permanent_peer];
+        expected.sort();
+        assert_eq!(banned, expected);
+    }
+
+    #[test]
+    fn ban__keeps_existing_ban() {
+        // Given
+        let peer_id = PeerId::random();
+        let mut store = ReputationStore::in_memory();
+        let ban = Ban {
+            reason: "spam".to_string(),
+            expires_at: Some(SystemTime::now() + Duration::from_secs(60)),
+        };
+        store.ban(peer_id, ban.clone());
+
+        // When
+        let banned = store.ban(
+            peer_id,
+            Ban {
+                reason: "spam again".to_string(),
+                expires_at: None,
+            },
+        );
+
+        // Then
+        assert!(!banned);
+        assert_eq!(store.bans().collect::<Vec<_>>(), vec![(&peer_id, &ban)]);
+    }
+
+    #[test]
+    fn set_score__does_not_store_neutral_score() {
+        // Given
+        let peer_id = PeerId::random();
+        let mut store = ReputationStore::in_memory();
+        store.set_score(peer_id, -10.0);
+
+        // When
+        store.set_score(peer_id, DEFAULT_APP_SCORE);
+
+        // Then
+        assert_eq!(store.score(&peer_id), None);
+    }
+
+    #[test]
+    fn set_score__evicts_scores_closest_to_default_over_the_limit() {
+        // Given
+        let mut store = ReputationStore::in_memory();
+        for _ in 0..MAX_STORED_SCORES {
+            store.set_score(PeerId::random(), -10.0);
+        }
+        let worst_peer = PeerId::random();
+        let neutral_peer = PeerId::random();
+        store.set_score(neutral_peer, -2.0);
+
+        // When
+        store.set_score(worst_peer, -40.0);
+
+        // Then
+        assert_eq!(store.scores.len(), MAX_STORED_SCORES);
+        assert_eq!(store.score(&worst_peer), Some(-40.0));
+        assert_eq!(store.score(&neutral_peer), None);
+    }
+
+    #[test]
+    fn unban__resets_score() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peer_reputation.json");
+        let peer_id = PeerId::random();
+        let mut store = ReputationStore::open(path.clone()).unwrap();
+        store.set_score(peer_id, -60.0);
+        store.ban(
+            peer_id,
+            Ban {
+                reason: "spam".to_string(),
+                expires_at: None,
+            },
+        );
+        store.flush().unwrap();
+
+        // When
+        store.unban(&peer_id);
+        store.flush().unwrap();
+
+        // Then
+        let store = ReputationStore::open(path).unwrap();
+        assert_eq!(store.score(&peer_id), None);
+        assert_eq!(store.bans().count(), 0);
+    }
+}
@@ -1,7 +1,11 @@
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    Result as StorageResult,
+    iter::BoxedIter,
+};
 use fuel_core_types::{
     blockchain::{
+        SealedBlock,
         SealedBlockHeader,
         consensus::Genesis,
     },
@@ -16,12 +20,17 @@ use fuel_core_types::{
         NetworkableTransactionPool,
         PreConfirmationMessage,
         ProtocolSignature,
+        StateEntry,
+        StateSyncTable,
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
-pub trait P2pDb: Send + Sync {
+pub trait P2pDb: Send + Sync + 'static {
     fn get_sealed_headers(
         &self,
         block_height_range: Range<u32>,
@@ -33,11 +42,25 @@ pub trait P2pDb: Send + Sync {
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
     fn get_genesis(&self) -> StorageResult<Genesis>;
+
+    /// Returns the height of the latest block.
+    fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns the raw entries of the `table` ordered by the key,
+    /// starting from the `start` key(inclusive).
+    fn iter_state_entries(
+        &self,
+        table: StateSyncTable,
+        start: Option<&[u8]>,
+    ) -> BoxedIter<'_, StorageResult<StateEntry>>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
+
+    /// Creates a stream of blocks produced by this node
+    fn produced_blocks(&self) -> BoxStream<Arc<SealedBlock>>;
 }
 
 pub trait TxPool: Send + Sync + Clone {
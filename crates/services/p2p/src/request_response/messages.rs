@@ -4,6 +4,9 @@ use fuel_core_types::{
     fuel_tx::TxId,
     services::p2p::{
         NetworkableTransactionPool,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
         Transactions,
     },
 };
@@ -25,6 +28,8 @@ pub enum RequestMessage {
     Transactions(Range<u32>),
     TxPoolAllTransactionsIds,
     TxPoolFullTransactions(Vec<TxId>),
+    StateCheckpoint,
+    StateChunk(StateChunkRequest),
 }
 
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +43,8 @@ pub enum ResponseMessageErrorCode {
     Timeout = 2,
     #[error("Sync processor is out of capacity")]
     SyncProcessorOutOfCapacity = 3,
+    #[error("The requested state checkpoint is not available")]
+    StateCheckpointUnavailable = 4,
     #[error("The peer sent an unknown error code")]
     #[serde(skip_serializing, other)]
     Unknown,
@@ -49,6 +56,8 @@ pub enum V1ResponseMessage {
     Transactions(Option<Vec<Transactions>>),
     TxPoolAllTransactionsIds(Option<Vec<TxId>>),
     TxPoolFullTransactions(Option<Vec<Option<NetworkableTransactionPool>>>),
+    StateCheckpoint(Option<Box<StateCheckpoint>>),
+    StateChunk(Option<Vec<StateEntry>>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,6 +68,8 @@ pub enum V2ResponseMessage {
     TxPoolFullTransactions(
         Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
     ),
+    StateCheckpoint(Result<Box<StateCheckpoint>, ResponseMessageErrorCode>),
+    StateChunk(Result<Vec<StateEntry>, ResponseMessageErrorCode>),
 }
 
 impl From<V1ResponseMessage> for V2ResponseMessage {
@@ -83,6 +94,14 @@ impl From<V1ResponseMessage> for V2ResponseMessage {
                     vec.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse),
                 )
             }
+            V1ResponseMessage::StateCheckpoint(checkpoint) => {
+                V2ResponseMessage::StateCheckpoint(
+                    checkpoint.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse),
+                )
+            }
+            V1ResponseMessage::StateChunk(entries) => V2ResponseMessage::StateChunk(
+                entries.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse),
+            ),
         }
     }
 }
@@ -102,6 +121,12 @@ impl From<V2ResponseMessage> for V1ResponseMessage {
             V2ResponseMessage::TxPoolFullTransactions(tx_pool) => {
                 V1ResponseMessage::TxPoolFullTransactions(tx_pool.ok())
             }
+            V2ResponseMessage::StateCheckpoint(checkpoint) => {
+                V1ResponseMessage::StateCheckpoint(checkpoint.ok())
+            }
+            V2ResponseMessage::StateChunk(entries) => {
+                V1ResponseMessage::StateChunk(entries.ok())
+            }
         }
     }
 }
@@ -130,6 +155,10 @@ pub enum ResponseSender {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         >,
     ),
+    StateCheckpoint(
+        OnResponseWithPeerSelection<Result<StateCheckpoint, ResponseMessageErrorCode>>,
+    ),
+    StateChunkFromPeer(OnResponse<Result<Vec<StateEntry>, ResponseMessageErrorCode>>),
 }
 
 #[derive(Debug, Error)]
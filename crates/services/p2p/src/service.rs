@@ -17,7 +17,10 @@ use crate::{
         FuelP2PEvent,
         FuelP2PService,
     },
-    peer_manager::PeerInfo,
+    peer_manager::{
+        PeerInfo,
+        reputation_store::Ban,
+    },
     ports::{
         BlockHeightImporter,
         P2PPreConfirmationGossipData,
@@ -33,6 +36,7 @@ use crate::{
         ResponseSender,
         V2ResponseMessage,
     },
+    state_checkpoint::PinnedCheckpoint,
 };
 use anyhow::anyhow;
 use fuel_core_metrics::p2p_metrics::set_blocks_requested;
@@ -49,7 +53,14 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
     fuel_tx::{
         Transaction,
         TxId,
@@ -60,12 +71,18 @@ use fuel_core_types::{
         ChainId,
     },
     services::p2p::{
+        BftProposalGossipData,
+        BftVoteGossipData,
+        BlockGossipData,
         BlockHeightHeartbeatData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         NetworkableTransactionPool,
         PeerId as FuelPeerId,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
         TransactionGossipData,
         Transactions,
         peer_reputation::{
@@ -90,6 +107,7 @@ use libp2p::{
 use std::{
     fmt::Debug,
     future::Future,
+    num::NonZeroU32,
     ops::Range,
     sync::Arc,
 };
@@ -130,10 +148,28 @@ pub enum TaskRequest {
     BroadcastTransaction(Arc<Transaction>),
     // Broadcast Preconfirmations to p2p network
     BroadcastPreConfirmations(Arc<P2PPreConfirmationMessage>),
+    // Broadcast BFT proposal to p2p network
+    BroadcastBftProposal(Arc<SignedProposal>),
+    // Broadcast BFT vote to p2p network
+    BroadcastBftVote(Arc<SignedVote>),
     // Request to get information about all connected peers
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
     },
+    // Request to get the banned peers
+    GetBannedPeers {
+        channel: oneshot::Sender<Vec<(PeerId, Ban)>>,
+    },
+    BanPeer {
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+        channel: oneshot::Sender<()>,
+    },
+    UnbanPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<bool>,
+    },
     GetSealedHeaders {
         block_height_range: Range<u32>,
         channel: OnResponseWithPeerSelection<
@@ -151,6 +187,16 @@ pub enum TaskRequest {
         from_peer: PeerId,
         channel: OnResponse<Result<Vec<Transactions>, ResponseMessageErrorCode>>,
     },
+    GetStateCheckpoint {
+        channel: OnResponseWithPeerSelection<
+            Result<StateCheckpoint, ResponseMessageErrorCode>,
+        >,
+    },
+    GetStateChunkFromPeer {
+        request: StateChunkRequest,
+        from_peer: PeerId,
+        channel: OnResponse<Result<Vec<StateEntry>, ResponseMessageErrorCode>>,
+    },
     TxPoolGetAllTxIds {
         from_peer: PeerId,
         channel: OnResponse<Result<Vec<TxId>, ResponseMessageErrorCode>>,
@@ -177,6 +223,10 @@ pub enum TaskRequest {
         response: Result<Vec<SealedBlockHeader>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
     },
+    DatabaseStateChunkLookUp {
+        response: Result<Vec<StateEntry>, ResponseMessageErrorCode>,
+        request_id: InboundRequestId,
+    },
     TxPoolAllTransactionsIds {
         response: Result<Vec<TxId>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
@@ -197,6 +247,12 @@ impl Debug for TaskRequest {
             TaskRequest::BroadcastPreConfirmations(_) => {
                 write!(f, "TaskRequest::BroadcastPreConfirmations")
             }
+            TaskRequest::BroadcastBftProposal(_) => {
+                write!(f, "TaskRequest::BroadcastBftProposal")
+            }
+            TaskRequest::BroadcastBftVote(_) => {
+                write!(f, "TaskRequest::BroadcastBftVote")
+            }
             TaskRequest::GetSealedHeaders { .. } => {
                 write!(f, "TaskRequest::GetSealedHeaders")
             }
@@ -206,6 +262,12 @@ impl Debug for TaskRequest {
             TaskRequest::GetTransactionsFromPeer { .. } => {
                 write!(f, "TaskRequest::GetTransactionsFromPeer")
             }
+            TaskRequest::GetStateCheckpoint { .. } => {
+                write!(f, "TaskRequest::GetStateCheckpoint")
+            }
+            TaskRequest::GetStateChunkFromPeer { .. } => {
+                write!(f, "TaskRequest::GetStateChunkFromPeer")
+            }
             TaskRequest::TxPoolGetAllTxIds { .. } => {
                 write!(f, "TaskRequest::TxPoolGetAllTxIds")
             }
@@ -221,12 +283,24 @@ impl Debug for TaskRequest {
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::GetBannedPeers { .. } => {
+                write!(f, "TaskRequest::GetBannedPeers")
+            }
+            TaskRequest::BanPeer { .. } => {
+                write!(f, "TaskRequest::BanPeer")
+            }
+            TaskRequest::UnbanPeer { .. } => {
+                write!(f, "TaskRequest::UnbanPeer")
+            }
             TaskRequest::DatabaseTransactionsLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseTransactionsLookUp")
             }
             TaskRequest::DatabaseHeaderLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseHeaderLookUp")
             }
+            TaskRequest::DatabaseStateChunkLookUp { .. } => {
+                write!(f, "TaskRequest::DatabaseStateChunkLookUp")
+            }
             TaskRequest::TxPoolAllTransactionsIds { .. } => {
                 write!(f, "TaskRequest::TxPoolAllTransactionsIds")
             }
@@ -282,6 +356,17 @@ pub trait TaskP2PService: Send {
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
 
+    fn get_banned_peers(&self) -> Vec<(&PeerId, &Ban)>;
+
+    fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> anyhow::Result<bool>;
+
     fn update_metrics<T>(&self, update_fn: T)
     where
         T: FnOnce();
@@ -367,6 +452,24 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn get_banned_peers(&self) -> Vec<(&PeerId, &Ban)> {
+        self.banned_peers().collect()
+    }
+
+    fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        self.ban_peer(peer_id, reason, duration);
+        Ok(())
+    }
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> anyhow::Result<bool> {
+        Ok(self.unban_peer(peer_id))
+    }
 }
 
 pub trait Broadcast: Send {
@@ -389,6 +492,15 @@ pub trait Broadcast: Send {
         confirmations: P2PPreConfirmationGossipData,
     ) -> anyhow::Result<()>;
 
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()>;
+
+    fn bft_proposal_broadcast(
+        &self,
+        proposal: BftProposalGossipData,
+    ) -> anyhow::Result<()>;
+
+    fn bft_vote_broadcast(&self, vote: BftVoteGossipData) -> anyhow::Result<()>;
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
@@ -423,6 +535,24 @@ impl Broadcast for SharedState {
         Ok(())
     }
 
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_broadcast.send(block)?;
+        Ok(())
+    }
+
+    fn bft_proposal_broadcast(
+        &self,
+        proposal: BftProposalGossipData,
+    ) -> anyhow::Result<()> {
+        self.bft_proposal_broadcast.send(proposal)?;
+        Ok(())
+    }
+
+    fn bft_vote_broadcast(&self, vote: BftVoteGossipData) -> anyhow::Result<()> {
+        self.bft_vote_broadcast.send(vote)?;
+        Ok(())
+    }
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
         self.new_tx_subscription_broadcast.send(peer_id)?;
         Ok(())
@@ -435,6 +565,8 @@ pub struct UninitializedTask<V, B, T> {
     last_height: BlockHeight,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// Blocks produced by this node that are gossiped to the network
+    produced_blocks: BoxStream<Arc<SealedBlock>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
@@ -450,6 +582,8 @@ pub struct Task<P, V, B, T> {
     p2p_service: P,
     view_provider: V,
     next_block_height: BoxStream<BlockHeight>,
+    /// Blocks produced by this node that are gossiped to the network
+    produced_blocks: BoxStream<Arc<SealedBlock>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     request_sender: mpsc::Sender<TaskRequest>,
@@ -467,6 +601,10 @@ pub struct Task<P, V, B, T> {
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
     // cached view
     cached_view: Arc<CachedView>,
+    /// The interval in blocks between the state checkpoints.
+    state_checkpoint_interval: Option<NonZeroU32>,
+    /// The latest checkpoint served to the peers.
+    state_checkpoint: Option<Arc<PinnedCheckpoint>>,
 }
 
 impl<P, V, B, T> Task<P, V, B, T>
@@ -491,6 +629,18 @@ where
                 let data = GossipData::new(confirmations, peer_id, message_id);
                 let _ = self.broadcast.pre_confirmation_broadcast(data);
             }
+            GossipsubMessage::NewBlock(block) => {
+                let data = GossipData::new(block, peer_id, message_id);
+                let _ = self.broadcast.block_broadcast(data);
+            }
+            GossipsubMessage::BftProposal(proposal) => {
+                let data = GossipData::new(proposal, peer_id, message_id);
+                let _ = self.broadcast.bft_proposal_broadcast(data);
+            }
+            GossipsubMessage::BftVote(vote) => {
+                let data = GossipData::new(vote, peer_id, message_id);
+                let _ = self.broadcast.bft_vote_broadcast(data);
+            }
         }
     }
 }
@@ -514,6 +664,7 @@ impl<V, T> UninitializedTask<V, SharedState, T> {
         tx_pool: T,
     ) -> Self {
         let next_block_height = block_importer.next_block_height();
+        let produced_blocks = block_importer.produced_blocks();
 
         Self {
             chain_id,
@@ -521,6 +672,7 @@ impl<V, T> UninitializedTask<V, SharedState, T> {
             view_provider,
             tx_pool,
             next_block_height,
+            produced_blocks,
             request_receiver,
             broadcast: shared_state,
             config,
@@ -609,7 +761,121 @@ where
             RequestMessage::TxPoolFullTransactions(tx_ids) => {
                 self.handle_full_transactions_request(tx_ids, request_id)
             }
+            RequestMessage::StateCheckpoint => {
+                self.handle_state_checkpoint_request(request_id)
+            }
+            RequestMessage::StateChunk(request) => {
+                self.handle_state_chunk_request(request, request_id)
+            }
+        }
+    }
+
+    /// Pins the view of the database if the `height` is a checkpoint height.
+    /// The layout of the checkpoint is computed by the database processor,
+    /// and the previous checkpoint is served until then.
+    fn pin_state_checkpoint_if_needed(
+        &mut self,
+        height: BlockHeight,
+    ) -> anyhow::Result<()> {
+        let Some(interval) = self.state_checkpoint_interval else {
+            return Ok(());
+        };
+        if u32::from(height).checked_rem(interval.get()) != Some(0) {
+            return Ok(());
+        }
+
+        let view = self.view_provider.latest_view()?;
+        // All peers should pin the same height, otherwise they can't agree on the checkpoint.
+        // The importer may already be ahead of the notification, in this case
+        // the checkpoint is skipped.
+        if view.get_latest_height()? != Some(height) {
+            tracing::debug!(
+                "Skipping the state checkpoint at {height}, the view is ahead"
+            );
+            return Ok(());
+        }
+
+        let pinned = Arc::new(PinnedCheckpoint::new(Box::new(view), height));
+        let result = self.db_heavy_task_processor.try_spawn({
+            let pinned = pinned.clone();
+            move || pinned.compute_layout()
+        });
+        if result.is_err() {
+            tracing::warn!("Failed to compute the state checkpoint at {height}");
+            return Ok(());
+        }
+        self.state_checkpoint = Some(pinned);
+
+        Ok(())
+    }
+
+    fn handle_state_checkpoint_request(
+        &mut self,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        let response = self
+            .state_checkpoint
+            .as_ref()
+            .and_then(|pinned| pinned.state_checkpoint())
+            .map(Box::new)
+            .ok_or(ResponseMessageErrorCode::StateCheckpointUnavailable);
+        self.p2p_service.send_response_msg(
+            request_id,
+            V2ResponseMessage::StateCheckpoint(response),
+        )?;
+        Ok(())
+    }
+
+    fn handle_state_chunk_request(
+        &mut self,
+        request: StateChunkRequest,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        let Some(pinned) = self
+            .state_checkpoint
+            .clone()
+            .filter(|pinned| pinned.height() == request.height)
+        else {
+            self.p2p_service.send_response_msg(
+                request_id,
+                V2ResponseMessage::StateChunk(Err(
+                    ResponseMessageErrorCode::StateCheckpointUnavailable,
+                )),
+            )?;
+            return Ok(());
+        };
+
+        let instant = Instant::now();
+        let timeout = self.response_timeout;
+        let response_channel = self.request_sender.clone();
+        let result = self.db_heavy_task_processor.try_spawn(move || {
+            if instant.elapsed() > timeout {
+                tracing::warn!("Request timed out");
+                return;
+            }
+
+            let response = pinned
+                .chunk(request.table, request.index)
+                .ok()
+                .flatten()
+                .ok_or(ResponseMessageErrorCode::StateCheckpointUnavailable);
+
+            let _ = response_channel
+                .try_send(TaskRequest::DatabaseStateChunkLookUp {
+                    response,
+                    request_id,
+                })
+                .trace_err("Failed to send response to the request channel");
+        });
+
+        if result.is_err() {
+            let err = Err(ResponseMessageErrorCode::SyncProcessorOutOfCapacity);
+            let _ = self
+                .p2p_service
+                .send_response_msg(request_id, V2ResponseMessage::StateChunk(err));
         }
+
+        Ok(())
     }
 
     fn handle_db_request<DbLookUpFn, ResponseSenderFn, TaskRequestFn, R>(
@@ -846,6 +1112,7 @@ where
             last_height,
             view_provider,
             next_block_height,
+            produced_blocks,
             request_receiver,
             broadcast,
             tx_pool,
@@ -865,6 +1132,7 @@ where
             database_read_threads,
             tx_pool_threads,
             metrics,
+            state_checkpoint_interval,
             ..
         } = config;
 
@@ -907,6 +1175,7 @@ where
             request_receiver,
             request_sender,
             next_block_height,
+            produced_blocks,
             broadcast,
             tx_pool,
             db_heavy_task_processor,
@@ -919,6 +1188,8 @@ where
             next_check_time,
             heartbeat_peer_reputation_config,
             cached_view: Arc::new(CachedView::new(614 * 10, metrics)),
+            state_checkpoint_interval,
+            state_checkpoint: None,
         };
         Ok(task)
     }
@@ -943,6 +1214,22 @@ where
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
+                    if let Err(e) = self.pin_state_checkpoint_if_needed(latest_block_height) {
+                        tracing::error!("Failed to pin the state checkpoint at {}: {}", latest_block_height, e);
+                    }
+                    TaskNextAction::Continue
+                } else {
+                    TaskNextAction::Stop
+                }
+            },
+            produced_block = self.produced_blocks.next() => {
+                if let Some(block) = produced_block {
+                    let height = *block.entity.header().height();
+                    let broadcast = GossipsubBroadcastRequest::NewBlock(block);
+                    let result = self.p2p_service.publish_message(broadcast);
+                    if let Err(e) = result {
+                        tracing::error!("Got an error during block {} broadcasting {}", height, e);
+                    }
                     TaskNextAction::Continue
                 } else {
                     TaskNextAction::Stop
@@ -965,6 +1252,20 @@ where
                             tracing::error!("Got an error during pre-confirmation message broadcasting {:?}: {}", broadcast, e);
                         }
                     }
+                    Some(TaskRequest::BroadcastBftProposal(proposal)) => {
+                        let broadcast = GossipsubBroadcastRequest::BftProposal(proposal);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during BFT proposal broadcasting {}", e);
+                        }
+                    }
+                    Some(TaskRequest::BroadcastBftVote(vote)) => {
+                        let broadcast = GossipsubBroadcastRequest::BftVote(vote);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during BFT vote broadcasting {}", e);
+                        }
+                    }
                     Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
@@ -992,6 +1293,21 @@ where
                         let request_msg = RequestMessage::Transactions(block_height_range);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetStateCheckpoint { channel }) => {
+                        // Any peer may serve the checkpoint, the caller compares the responses of several peers.
+                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&BlockHeight::default()) else {
+                            let _ = channel.send(Err(TaskError::NoPeerFound));
+                            return TaskNextAction::Continue
+                        };
+                        let channel = ResponseSender::StateCheckpoint(channel);
+                        let request_msg = RequestMessage::StateCheckpoint;
+                        self.p2p_service.send_request_msg(Some(peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::GetStateChunkFromPeer { request, from_peer, channel }) => {
+                        let channel = ResponseSender::StateChunkFromPeer(channel);
+                        let request_msg = RequestMessage::StateChunk(request);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
                     Some(TaskRequest::TxPoolGetAllTxIds { from_peer, channel }) => {
                         let channel = ResponseSender::TxPoolAllTransactionsIds(channel);
                         let request_msg = RequestMessage::TxPoolAllTransactionsIds;
@@ -1018,12 +1334,37 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::GetBannedPeers { channel }) => {
+                        let peers = self.p2p_service.get_banned_peers()
+                            .into_iter()
+                            .map(|(id, ban)| (*id, ban.clone()))
+                            .collect::<Vec<_>>();
+                        let _ = channel.send(peers);
+                    }
+                    Some(TaskRequest::BanPeer { peer_id, reason, duration, channel }) => {
+                        let res = self.p2p_service.ban_peer(peer_id, reason, duration);
+                        if let Err(err) = res {
+                            return TaskNextAction::ErrorContinue(err)
+                        }
+                        let _ = channel.send(());
+                    }
+                    Some(TaskRequest::UnbanPeer { peer_id, channel }) => {
+                        match self.p2p_service.unban_peer(&peer_id) {
+                            Ok(unbanned) => {
+                                let _ = channel.send(unbanned);
+                            }
+                            Err(err) => return TaskNextAction::ErrorContinue(err),
+                        }
+                    }
                     Some(TaskRequest::DatabaseTransactionsLookUp { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::Transactions(response));
                     }
                     Some(TaskRequest::DatabaseHeaderLookUp { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::SealedHeaders(response));
                     }
+                    Some(TaskRequest::DatabaseStateChunkLookUp { response, request_id }) => {
+                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::StateChunk(response));
+                    }
                     Some(TaskRequest::TxPoolAllTransactionsIds { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::TxPoolAllTransactionsIds(response));
                     }
@@ -1107,6 +1448,12 @@ pub struct SharedState {
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
     /// Sender of p2p transaction preconfirmations used for subscribing.
     pre_confirmations_broadcast: broadcast::Sender<P2PPreConfirmationGossipData>,
+    /// Sender of p2p blocks used for subscribing.
+    block_broadcast: broadcast::Sender<BlockGossipData>,
+    /// Sender of p2p BFT proposals used for subscribing.
+    bft_proposal_broadcast: broadcast::Sender<BftProposalGossipData>,
+    /// Sender of p2p BFT votes used for subscribing.
+    bft_vote_broadcast: broadcast::Sender<BftVoteGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -1241,6 +1588,68 @@ impl SharedState {
         }
     }
 
+    /// Requests the latest state checkpoint from a random peer.
+    pub async fn get_state_checkpoint(
+        &self,
+    ) -> anyhow::Result<(Vec<u8>, Option<StateCheckpoint>)> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetStateCheckpoint { channel: sender })
+            .await?;
+
+        let (peer_id, response) = receiver
+            .await
+            .map_err(|e| anyhow!("{e}"))?
+            .map_err(|e| anyhow!("{e}"))?;
+
+        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
+        if let Err(ref response_error_code) = data {
+            warn!(
+                "Peer {peer_id:?} failed to respond with state checkpoint: {response_error_code:?}"
+            );
+        };
+
+        Ok((peer_id.to_bytes(), data.ok()))
+    }
+
+    pub async fn get_state_chunk_from_peer(
+        &self,
+        peer_id: FuelPeerId,
+        request: StateChunkRequest,
+    ) -> anyhow::Result<Option<Vec<StateEntry>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(peer_id.as_ref()).expect("Valid PeerId");
+
+        let request = TaskRequest::GetStateChunkFromPeer {
+            request,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (response_from_peer, response) =
+            receiver.await.map_err(|e| anyhow!("{e}"))?;
+        debug_assert_eq!(
+            peer_id.as_ref(),
+            response_from_peer.to_bytes(),
+            "Bug: response from non-requested peer"
+        );
+
+        match response {
+            Err(request_response_protocol_error) => Err(anyhow!(
+                "Invalid response from peer {request_response_protocol_error:?}"
+            )),
+            Ok(Err(response_error_code)) => {
+                warn!(
+                    "Peer {peer_id:?} failed to respond with state chunk: {response_error_code:?}"
+                );
+                Ok(None)
+            }
+            Ok(Ok(entries)) => Ok(Some(entries)),
+        }
+    }
+
     pub async fn get_all_transactions_ids_from_peer(
         &self,
         peer_id: FuelPeerId,
@@ -1329,6 +1738,21 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn broadcast_bft_proposal(
+        &self,
+        proposal: Arc<SignedProposal>,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastBftProposal(proposal))?;
+        Ok(())
+    }
+
+    pub fn broadcast_bft_vote(&self, vote: Arc<SignedVote>) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastBftVote(vote))?;
+        Ok(())
+    }
+
     pub async fn get_all_peers(&self) -> anyhow::Result<Vec<(PeerId, PeerInfo)>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -1339,6 +1763,51 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_banned_peers(&self) -> anyhow::Result<Vec<(PeerId, Ban)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetBannedPeers { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Bans the peer for the `duration`, or permanently if the `duration` is not set.
+    pub async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::BanPeer {
+                peer_id,
+                reason,
+                duration,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Removes the ban of the peer. Returns `false` if the peer was not banned.
+    pub async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::UnbanPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
         self.new_tx_subscription_broadcast.subscribe()
     }
@@ -1353,6 +1822,18 @@ impl SharedState {
         self.pre_confirmations_broadcast.subscribe()
     }
 
+    pub fn subscribe_block(&self) -> broadcast::Receiver<BlockGossipData> {
+        self.block_broadcast.subscribe()
+    }
+
+    pub fn subscribe_bft_proposals(&self) -> broadcast::Receiver<BftProposalGossipData> {
+        self.bft_proposal_broadcast.subscribe()
+    }
+
+    pub fn subscribe_bft_votes(&self) -> broadcast::Receiver<BftVoteGossipData> {
+        self.bft_vote_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -1396,6 +1877,9 @@ pub fn build_shared_state(
     let (request_sender, request_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (tx_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (preconfirmations_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (block_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (bft_proposal_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (bft_vote_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (new_tx_subscription_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (block_height_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
 
@@ -1413,6 +1897,9 @@ pub fn build_shared_state(
             new_tx_subscription_broadcast,
             tx_broadcast,
             pre_confirmations_broadcast: preconfirmations_broadcast,
+            block_broadcast,
+            bft_proposal_broadcast,
+            bft_vote_broadcast,
             reserved_peers_broadcast,
             block_height_broadcast,
             max_txs_per_request: config.max_txs_per_request,
@@ -1,7 +1,10 @@
 #![allow(non_snake_case)]
 
 use super::*;
-use fuel_core_types::services::p2p::PreConfirmationMessage;
+use fuel_core_types::{
+    blockchain::consensus::bft::Vote,
+    services::p2p::PreConfirmationMessage,
+};
 
 fn arb_shared_state() -> SharedState {
     let config = Config::default("test network");
@@ -30,3 +33,46 @@ async fn shared_state__broadcast__tx_preconfirmations() {
     let actual = preconfirmations_receiver.try_recv().unwrap().data.unwrap();
     assert_eq!(confirmations, actual);
 }
+
+#[tokio::test]
+async fn shared_state__broadcast__new_block() {
+    // given
+    let broadcast = arb_shared_state();
+    let block = SealedBlock::default();
+    let block_gossip_data = BlockGossipData {
+        data: Some(block.clone()),
+        peer_id: FuelPeerId::from(PeerId::random().to_bytes().to_vec()),
+        message_id: vec![1, 2, 3, 4],
+    };
+    let mut block_receiver = broadcast.subscribe_block();
+
+    // when
+    broadcast.block_broadcast(block_gossip_data).unwrap();
+
+    // then
+    let actual = block_receiver.try_recv().unwrap().data.unwrap();
+    assert_eq!(block, actual);
+}
+
+#[tokio::test]
+async fn shared_state__broadcast__bft_vote() {
+    // given
+    let broadcast = arb_shared_state();
+    let vote = SignedVote {
+        vote: Vote::precommit(1u32.into(), 0, None),
+        signature: Default::default(),
+    };
+    let vote_gossip_data = BftVoteGossipData {
+        data: Some(vote.clone()),
+        peer_id: FuelPeerId::from(PeerId::random().to_bytes().to_vec()),
+        message_id: vec![1, 2, 3, 4],
+    };
+    let mut vote_receiver = broadcast.subscribe_bft_votes();
+
+    // when
+    broadcast.bft_vote_broadcast(vote_gossip_data).unwrap();
+
+    // then
+    let actual = vote_receiver.try_recv().unwrap().data.unwrap();
+    assert_eq!(vote, actual);
+}
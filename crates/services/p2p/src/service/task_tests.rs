@@ -4,17 +4,27 @@ use crate::ports::P2pDb;
 use super::*;
 
 use crate::{
-    gossipsub::topics::TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
+    gossipsub::topics::{
+        NEW_BLOCK_GOSSIP_TOPIC,
+        TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
+    },
     peer_manager::heartbeat_data::HeartbeatData,
 };
 use fuel_core_services::{
     Service,
     State,
 };
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    Result as StorageResult,
+    iter::BoxedIter,
+};
 use fuel_core_types::{
     blockchain::consensus::Genesis,
     fuel_types::BlockHeight,
+    services::p2p::{
+        StateEntry,
+        StateSyncTable,
+    },
 };
 use futures::FutureExt;
 use libp2p::gossipsub::TopicHash;
@@ -52,6 +62,18 @@ impl P2pDb for FakeDb {
     fn get_genesis(&self) -> StorageResult<Genesis> {
         Ok(Default::default())
     }
+
+    fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+        unimplemented!()
+    }
+
+    fn iter_state_entries(
+        &self,
+        _: StateSyncTable,
+        _: Option<&[u8]>,
+    ) -> BoxedIter<'_, StorageResult<StateEntry>> {
+        unimplemented!()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -61,6 +83,10 @@ impl BlockHeightImporter for FakeBlockImporter {
     fn next_block_height(&self) -> BoxStream<BlockHeight> {
         Box::pin(fuel_core_services::stream::pending())
     }
+
+    fn produced_blocks(&self) -> BoxStream<Arc<SealedBlock>> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -172,6 +198,23 @@ impl TaskP2PService for FakeP2PService {
     fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn get_banned_peers(&self) -> Vec<(&PeerId, &Ban)> {
+        vec![]
+    }
+
+    fn ban_peer(
+        &mut self,
+        _peer_id: PeerId,
+        _reason: String,
+        _duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    fn unban_peer(&mut self, _peer_id: &PeerId) -> anyhow::Result<bool> {
+        todo!()
+    }
 }
 
 #[derive(Clone)]
@@ -203,11 +246,24 @@ impl P2pDb for FakeDB {
     fn get_genesis(&self) -> StorageResult<Genesis> {
         todo!()
     }
+
+    fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+        todo!()
+    }
+
+    fn iter_state_entries(
+        &self,
+        _: StateSyncTable,
+        _: Option<&[u8]>,
+    ) -> BoxedIter<'_, StorageResult<StateEntry>> {
+        todo!()
+    }
 }
 
 struct FakeBroadcast {
     pub peer_reports: mpsc::Sender<(FuelPeerId, AppScore, String)>,
     pub confirmation_gossip_broadcast: mpsc::Sender<P2PPreConfirmationGossipData>,
+    pub block_gossip_broadcast: mpsc::Sender<BlockGossipData>,
 }
 
 impl Broadcast for FakeBroadcast {
@@ -241,6 +297,22 @@ impl Broadcast for FakeBroadcast {
         Ok(())
     }
 
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_gossip_broadcast.try_send(block)?;
+        Ok(())
+    }
+
+    fn bft_proposal_broadcast(
+        &self,
+        _proposal: BftProposalGossipData,
+    ) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    fn bft_vote_broadcast(&self, _vote: BftVoteGossipData) -> anyhow::Result<()> {
+        todo!()
+    }
+
     fn new_tx_subscription_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
         todo!()
     }
@@ -279,6 +351,7 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
     let broadcast = FakeBroadcast {
         peer_reports: report_sender,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        block_gossip_broadcast: mpsc::channel(100).0,
     };
 
     // Less than actual
@@ -298,6 +371,7 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
         p2p_service,
         view_provider: FakeDB,
         next_block_height: FakeBlockImporter.next_block_height(),
+        produced_blocks: FakeBlockImporter.produced_blocks(),
         tx_pool: FakeTxPool,
         request_receiver,
         request_sender,
@@ -312,6 +386,8 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        state_checkpoint_interval: None,
+        state_checkpoint: None,
     };
     let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
     let mut watcher = StateWatcher::from(watch_receiver);
@@ -373,6 +449,7 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
     let broadcast = FakeBroadcast {
         peer_reports: report_sender,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        block_gossip_broadcast: mpsc::channel(100).0,
     };
 
     // Greater than actual
@@ -393,6 +470,7 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
         view_provider: FakeDB,
         tx_pool: FakeTxPool,
         next_block_height: FakeBlockImporter.next_block_height(),
+        produced_blocks: FakeBlockImporter.produced_blocks(),
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
@@ -406,6 +484,8 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        state_checkpoint_interval: None,
+        state_checkpoint: None,
     };
     let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
     let mut watcher = StateWatcher::from(watch_receiver);
@@ -465,6 +545,7 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
     let broadcast = FakeBroadcast {
         peer_reports: mpsc::channel(100).0,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        block_gossip_broadcast: mpsc::channel(100).0,
     };
     let mut task = Task {
         chain_id: Default::default(),
@@ -473,6 +554,7 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
         tx_pool: FakeTxPool,
         view_provider: FakeDB,
         next_block_height,
+        produced_blocks: FakeBlockImporter.produced_blocks(),
         request_receiver,
         request_sender,
         db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
@@ -486,6 +568,8 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: Default::default(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        state_checkpoint_interval: None,
+        state_checkpoint: None,
     };
     let mut watcher = StateWatcher::started();
     // End of initialization
@@ -529,6 +613,7 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
     let broadcast = FakeBroadcast {
         peer_reports: mpsc::channel(100).0,
         confirmation_gossip_broadcast: preconfirmations_sender,
+        block_gossip_broadcast: mpsc::channel(100).0,
     };
     let (request_sender, request_receiver) = mpsc::channel(100);
     let mut task = Task {
@@ -537,6 +622,7 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
         p2p_service,
         view_provider: FakeDB,
         next_block_height: FakeBlockImporter.next_block_height(),
+        produced_blocks: FakeBlockImporter.produced_blocks(),
         tx_pool: FakeTxPool,
         request_receiver,
         request_sender,
@@ -551,6 +637,8 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: Default::default(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        state_checkpoint_interval: None,
+        state_checkpoint: None,
     };
 
     // when
@@ -568,3 +656,74 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
     };
     assert_eq!(expected, actual);
 }
+
+fn arb_new_block_gossip_message() -> FuelP2PEvent {
+    let peer_id = PeerId::random();
+    let message_id = vec![1, 2, 3, 4, 5].into();
+    let topic_hash = TopicHash::from_raw(NEW_BLOCK_GOSSIP_TOPIC);
+    let message = GossipsubMessage::NewBlock(SealedBlock::default());
+    FuelP2PEvent::GossipsubMessage {
+        peer_id,
+        message_id,
+        topic_hash,
+        message,
+    }
+}
+
+#[tokio::test]
+async fn run__gossip_message_from_p2p_service_is_broadcasted__new_block() {
+    // given
+    let gossip_message_event = arb_new_block_gossip_message();
+    let events = vec![gossip_message_event.clone()];
+    let event_stream = futures::stream::iter(events);
+    let p2p_service = FakeP2PService {
+        peer_info: vec![],
+        next_event_stream: Box::pin(event_stream),
+    };
+    let (block_sender, mut block_receiver) = mpsc::channel(100);
+    let broadcast = FakeBroadcast {
+        peer_reports: mpsc::channel(100).0,
+        confirmation_gossip_broadcast: mpsc::channel(100).0,
+        block_gossip_broadcast: block_sender,
+    };
+    let (request_sender, request_receiver) = mpsc::channel(100);
+    let mut task = Task {
+        chain_id: Default::default(),
+        response_timeout: Default::default(),
+        p2p_service,
+        view_provider: FakeDB,
+        next_block_height: FakeBlockImporter.next_block_height(),
+        produced_blocks: FakeBlockImporter.produced_blocks(),
+        tx_pool: FakeTxPool,
+        request_receiver,
+        request_sender,
+        db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
+        tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
+        broadcast,
+        max_headers_per_request: 0,
+        max_txs_per_request: 100,
+        heartbeat_check_interval: Duration::from_secs(0),
+        heartbeat_max_avg_interval: Default::default(),
+        heartbeat_max_time_since_last: Default::default(),
+        next_check_time: Instant::now(),
+        heartbeat_peer_reputation_config: Default::default(),
+        cached_view: Arc::new(CachedView::new(100, false)),
+        state_checkpoint_interval: None,
+        state_checkpoint: None,
+    };
+
+    // when
+    let mut watcher = StateWatcher::started();
+    let _ = task.run(&mut watcher).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // then
+    let actual = block_receiver.try_recv().unwrap().data.unwrap();
+    let FuelP2PEvent::GossipsubMessage { message, .. } = gossip_message_event else {
+        panic!("Expected GossipsubMessage event");
+    };
+    let GossipsubMessage::NewBlock(expected) = message else {
+        panic!("Expected NewBlock message");
+    };
+    assert_eq!(expected, actual);
+}
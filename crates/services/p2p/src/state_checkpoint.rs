@@ -0,0 +1,301 @@
+use crate::ports::P2pDb;
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::{
+        StateCheckpoint,
+        StateEntry,
+        StateSyncTable,
+        TableCheckpoint,
+        state_chunk_root,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::OnceLock,
+};
+
+/// The maximum number of entries in one chunk of the table.
+pub const MAX_CHUNK_ENTRIES: usize = 4096;
+
+/// The chunk is closed once the size of its entries reaches this limit.
+/// It is far below the `MAX_RESPONSE_SIZE`, so even a chunk with
+/// the single huge entry(like the contract's bytecode) fits into the response.
+pub const MAX_CHUNK_BYTES: usize = 4 * 1024 * 1024;
+
+/// The layout of the tables at the checkpoint.
+struct CheckpointLayout {
+    checkpoint: StateCheckpoint,
+    /// The first key of each chunk of the table.
+    chunk_starts: HashMap<StateSyncTable, Vec<Vec<u8>>>,
+}
+
+/// The view of the database pinned at the height of the checkpoint.
+///
+/// The view is served to the peers that bootstrap with the state sync
+/// until the next checkpoint replaces it.
+pub(crate) struct PinnedCheckpoint {
+    height: BlockHeight,
+    view: Box<dyn P2pDb>,
+    layout: OnceLock<Option<CheckpointLayout>>,
+}
+
+impl PinnedCheckpoint {
+    pub fn new(view: Box<dyn P2pDb>, height: BlockHeight) -> Self {
+        Self {
+            height,
+            view,
+            layout: OnceLock::new(),
+        }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    /// Splits all tables into chunks and calculates their roots.
+    /// It iterates over the whole state, so it should be called from the
+    /// database processor.
+    pub fn compute_layout(&self) {
+        self.layout.get_or_init(|| {
+            compute_layout(self.view.as_ref(), self.height)
+                .inspect(|layout| {
+                    tracing::info!(
+                        "Computed the state checkpoint at {} with the root {}",
+                        self.height,
+                        layout.checkpoint.root()
+                    );
+                })
+                .inspect_err(|err| {
+                    tracing::error!(
+                        "Failed to compute the state checkpoint at {}: {err}",
+                        self.height
+                    );
+                })
+                .ok()
+        });
+    }
+
+    /// Returns the checkpoint if its layout is already computed.
+    pub fn state_checkpoint(&self) -> Option<StateCheckpoint> {
+        self.layout
+            .get()?
+            .as_ref()
+            .map(|layout| layout.checkpoint.clone())
+    }
+
+    /// Returns the entries of the chunk with the `index`.
+    pub fn chunk(
+        &self,
+        table: StateSyncTable,
+        index: u32,
+    ) -> StorageResult<Option<Vec<StateEntry>>> {
+        let Some(Some(layout)) = self.layout.get() else {
+            return Ok(None);
+        };
+        let Some(start) = layout
+            .chunk_starts
+            .get(&table)
+            .and_then(|starts| starts.get(index as usize))
+        else {
+            return Ok(None);
+        };
+
+        let mut entries = self.view.iter_state_entries(table, Some(start.as_slice()));
+        take_chunk(&mut entries).map(Some)
+    }
+}
+
+fn compute_layout(
+    view: &dyn P2pDb,
+    height: BlockHeight,
+) -> anyhow::Result<CheckpointLayout> {
+    let start = u32::from(height);
+    let header = view
+        .get_sealed_headers(start..start.saturating_add(1))?
+        .and_then(|headers| headers.into_iter().next())
+        .ok_or_else(|| anyhow::anyhow!("The header at {height} is not found"))?;
+
+    let mut tables = Vec::with_capacity(StateSyncTable::ALL.len());
+    let mut chunk_starts = HashMap::with_capacity(StateSyncTable::ALL.len());
+    for table in StateSyncTable::ALL {
+        let mut entries = view.iter_state_entries(table, None);
+        let mut chunk_roots = vec![];
+        let mut starts = vec![];
+        loop {
+            let chunk = take_chunk(&mut entries)?;
+            let Some((first_key, _)) = chunk.first() else {
+                break;
+            };
+            starts.push(first_key.clone());
+            chunk_roots.push(state_chunk_root(&chunk));
+        }
+        tables.push(TableCheckpoint { table, chunk_roots });
+        chunk_starts.insert(table, starts);
+    }
+
+    Ok(CheckpointLayout {
+        checkpoint: StateCheckpoint { header, tables },
+        chunk_starts,
+    })
+}
+
+/// Takes the entries of the next chunk from the iterator.
+/// Both, the calculation of the layout and the serving of the chunk use it,
+/// so the boundaries of the chunks are the same.
+fn take_chunk<I>(entries: &mut I) -> StorageResult<Vec<StateEntry>>
+where
+    I: Iterator<Item = StorageResult<StateEntry>>,
+{
+    let mut chunk = vec![];
+    let mut size = 0usize;
+    while chunk.len() < MAX_CHUNK_ENTRIES && size < MAX_CHUNK_BYTES {
+        let Some(entry) = entries.next() else {
+            break;
+        };
+        let (key, value) = entry?;
+        size = size.saturating_add(key.len()).saturating_add(value.len());
+        chunk.push((key, value));
+    }
+    Ok(chunk)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::iter::{
+        BoxedIter,
+        IntoBoxedIter,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            SealedBlockHeader,
+            consensus::Genesis,
+        },
+        services::p2p::Transactions,
+    };
+    use std::{
+        collections::BTreeMap,
+        ops::Range,
+    };
+
+    struct FakeDb {
+        coins: BTreeMap<Vec<u8>, Vec<u8>>,
+    }
+
+    impl P2pDb for FakeDb {
+        fn get_sealed_headers(
+            &self,
+            range: Range<u32>,
+        ) -> StorageResult<Option<Vec<SealedBlockHeader>>> {
+            let headers = range
+                .map(|height| {
+                    let mut header = SealedBlockHeader::default();
+                    header.entity.set_block_height(height.into());
+                    header
+                })
+                .collect();
+            Ok(Some(headers))
+        }
+
+        fn get_transactions(
+            &self,
+            _: Range<u32>,
+        ) -> StorageResult<Option<Vec<Transactions>>> {
+            unimplemented!()
+        }
+
+        fn get_genesis(&self) -> StorageResult<Genesis> {
+            unimplemented!()
+        }
+
+        fn get_latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+            unimplemented!()
+        }
+
+        fn iter_state_entries(
+            &self,
+            table: StateSyncTable,
+            start: Option<&[u8]>,
+        ) -> BoxedIter<'_, StorageResult<StateEntry>> {
+            let start = start.map(<[u8]>::to_vec).unwrap_or_default();
+            let coins = self.coins.range(start..);
+            coins
+                .filter(move |_| table == StateSyncTable::Coins)
+                .map(|(key, value)| Ok((key.clone(), value.clone())))
+                .into_boxed()
+        }
+    }
+
+    fn pinned_checkpoint(number_of_coins: u32) -> PinnedCheckpoint {
+        let coins = (0..number_of_coins)
+            .map(|i| (i.to_be_bytes().to_vec(), vec![1; 8]))
+            .collect();
+        PinnedCheckpoint::new(Box::new(FakeDb { coins }), 10.into())
+    }
+
+    #[test]
+    fn state_checkpoint__not_available_until_layout_is_computed() {
+        // Given
+        let pinned = pinned_checkpoint(10);
+
+        // When
+        let checkpoint = pinned.state_checkpoint();
+
+        // Then
+        assert_eq!(checkpoint, None);
+    }
+
+    #[test]
+    fn compute_layout__splits_tables_into_chunks() {
+        // Given
+        let number_of_coins = u32::try_from(MAX_CHUNK_ENTRIES)
+            .unwrap()
+            .saturating_mul(2)
+            .saturating_add(1);
+        let pinned = pinned_checkpoint(number_of_coins);
+
+        // When
+        pinned.compute_layout();
+
+        // Then
+        let checkpoint = pinned.state_checkpoint().unwrap();
+        assert_eq!(checkpoint.height(), 10.into());
+        assert_eq!(checkpoint.chunk_roots(StateSyncTable::Coins).len(), 3);
+        assert!(checkpoint.chunk_roots(StateSyncTable::Messages).is_empty());
+    }
+
+    #[test]
+    fn chunk__matches_the_root_of_the_checkpoint() {
+        // Given
+        let number_of_coins = u32::try_from(MAX_CHUNK_ENTRIES).unwrap().saturating_add(5);
+        let pinned = pinned_checkpoint(number_of_coins);
+        pinned.compute_layout();
+        let checkpoint = pinned.state_checkpoint().unwrap();
+
+        // When
+        let first = pinned.chunk(StateSyncTable::Coins, 0).unwrap().unwrap();
+        let second = pinned.chunk(StateSyncTable::Coins, 1).unwrap().unwrap();
+
+        // Then
+        let roots = checkpoint.chunk_roots(StateSyncTable::Coins);
+        assert_eq!(first.len(), MAX_CHUNK_ENTRIES);
+        assert_eq!(second.len(), 5);
+        assert_eq!(state_chunk_root(&first), roots[0]);
+        assert_eq!(state_chunk_root(&second), roots[1]);
+    }
+
+    #[test]
+    fn chunk__returns_none_for_unknown_index() {
+        // Given
+        let pinned = pinned_checkpoint(10);
+        pinned.compute_layout();
+
+        // When
+        let chunk = pinned.chunk(StateSyncTable::Coins, 1).unwrap();
+
+        // Then
+        assert_eq!(chunk, None);
+    }
+}
@@ -1,28 +1,82 @@
-use crate::config::Config;
-use fuel_core_storage::transactional::Changes;
+use crate::{
+    config::Config,
+    scheduler::{
+        self,
+        Batch,
+        ScheduledTransaction,
+        Segment,
+    },
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::KeyValueInspect,
+    transactional::{
+        AtomicView,
+        Changes,
+        HistoricalView,
+        IntoTransaction,
+        Modifiable,
+        StorageTransaction,
+    },
+};
 use fuel_core_types::{
-    blockchain::block::Block,
-    fuel_tx::Transaction,
+    blockchain::{
+        block::{
+            Block,
+            PartialFuelBlock,
+        },
+        header::PartialBlockHeader,
+        primitives::DaBlockHeight,
+        transaction::TransactionExt,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+        Transaction,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+    fuel_vm::interpreter::MemoryInstance,
     services::{
         Uncommitted,
         block_producer::Components,
         executor::{
+            Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             ValidationResult,
         },
+        relayer::Event,
     },
 };
 use fuel_core_upgradable_executor::{
     executor::Executor as UpgradableExecutor,
-    native_executor::ports::TransactionsSource,
+    native_executor::{
+        executor::{
+            BlockExecutor,
+            ExecutionData,
+            ExecutionInstance,
+            ExecutionOptions,
+            OnceTransactionsSource,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            max_tx_count,
+        },
+        ports::{
+            MaybeCheckedTransaction,
+            RelayerPort,
+            TransactionsSource,
+        },
+    },
 };
 use std::{
+    collections::btree_map,
     num::NonZeroUsize,
     sync::{
         Arc,
         RwLock,
+        mpsc,
     },
 };
 use tokio::runtime::Runtime;
@@ -33,10 +87,16 @@ use fuel_core_upgradable_executor::error::UpgradableError;
 #[cfg(feature = "wasm-executor")]
 use fuel_core_types::fuel_merkle::common::Bytes32;
 
+/// The block executor that executes non-conflicting transactions in parallel.
+///
+/// The result of the execution is identical to the result of the
+/// [`fuel_core_upgradable_executor::executor::Executor`]. Blocks with
+/// a state transition version different from the native one are
+/// delegated to the upgradable executor.
 pub struct Executor<S, R> {
-    _executor: Arc<RwLock<UpgradableExecutor<S, R>>>,
+    executor: Arc<RwLock<UpgradableExecutor<S, R>>>,
     runtime: Option<Runtime>,
-    _number_of_cores: NonZeroUsize,
+    number_of_cores: NonZeroUsize,
 }
 
 // Shutdown the tokio runtime to avoid panic if executor is already
@@ -68,47 +128,924 @@ impl<S, R> Executor<S, R> {
         let number_of_cores = config.number_of_cores;
 
         Self {
-            _executor: Arc::new(RwLock::new(executor)),
+            executor: Arc::new(RwLock::new(executor)),
             runtime: Some(runtime),
-            _number_of_cores: number_of_cores,
+            number_of_cores,
         }
     }
 }
 
-impl<S, R> Executor<S, R> {
+/// The way the executor handles transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExecutionMode {
+    /// Invalid transactions are skipped.
+    Production,
+    /// Invalid transactions are skipped, and the VM doesn't fail on missing inputs.
+    DryRun,
+    /// Any invalid transaction fails the execution of the block.
+    Validation,
+}
+
+impl ExecutionMode {
+    fn is_dry_run(&self) -> bool {
+        matches!(self, Self::DryRun)
+    }
+}
+
+/// The data shared between all workers executing the same segment of transactions.
+struct SegmentContext {
+    header: PartialBlockHeader,
+    consensus_params: ConsensusParameters,
+    options: ExecutionOptions,
+    mode: ExecutionMode,
+    gas_price: u64,
+    coinbase_contract_id: ContractId,
+}
+
+/// The transaction executed by a worker along with the execution data it produced.
+struct ExecutedTransaction {
+    position: usize,
+    transaction: Transaction,
+    data: ExecutionData,
+}
+
+/// The result of the execution of one batch of the segment.
+struct BatchOutput {
+    executed: Vec<ExecutedTransaction>,
+    changes: Changes,
+}
+
+/// The first transaction of the batch that failed to execute.
+struct BatchFailure {
+    position: usize,
+    tx_id: TxId,
+    error: ExecutorError,
+}
+
+/// The relayer used by the workers. Workers execute only L2 transactions,
+/// so they never need events from the DA layer.
+#[derive(Debug, Clone, Copy)]
+struct DisabledRelayer;
+
+impl RelayerPort for DisabledRelayer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn get_events(&self, _: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        Ok(vec![])
+    }
+}
+
+type WorkerExecutor =
+    BlockExecutor<DisabledRelayer, TimeoutOnlyTxWaiter, TransparentPreconfirmationSender>;
+
+type SequentialExecutor<Relayer> =
+    BlockExecutor<Relayer, TimeoutOnlyTxWaiter, TransparentPreconfirmationSender>;
+
+impl<S, R> Executor<S, R>
+where
+    S: HistoricalView<Height = BlockHeight>,
+    S::LatestView: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    S::ViewAtHeight: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    R: AtomicView,
+    R::LatestView: RelayerPort + Send + Sync + 'static,
+{
     /// Produces the block and returns the result of the execution without committing the changes.
     pub fn produce_without_commit_with_source<TxSource>(
         &self,
-        _components: Components<TxSource>,
+        components: Components<TxSource>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
-        unimplemented!("Not implemented yet");
+        let executor = self.read_executor()?;
+
+        let block_version = components
+            .header_to_produce
+            .state_transition_bytecode_version;
+        if block_version != executor.native_executor_version() {
+            return executor.produce_without_commit_with_source_direct_resolve(components)
+        }
+
+        let options = executor.config.as_ref().into();
+        let relayer = executor.relayer_view_provider.latest_view()?;
+
+        if let Some(previous_block_height) = components.header_to_produce.height().pred()
+        {
+            let database = executor
+                .storage_view_provider
+                .view_at(&previous_block_height)?;
+            self.produce_inner(
+                relayer,
+                database,
+                components,
+                options,
+                ExecutionMode::Production,
+            )
+        } else {
+            let database = executor.storage_view_provider.latest_view()?;
+            self.produce_inner(
+                relayer,
+                database,
+                components,
+                options,
+                ExecutionMode::Production,
+            )
+        }
     }
 
     pub fn validate(
         &self,
-        _block: &Block,
+        block: &Block,
     ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
-        unimplemented!("Not implemented yet");
+        let executor = self.read_executor()?;
+
+        let block_version = block.header().state_transition_bytecode_version();
+        if block_version != executor.native_executor_version() {
+            return executor.validate(block)
+        }
+
+        let options = executor.config.as_ref().into();
+        let relayer = executor.relayer_view_provider.latest_view()?;
+
+        if let Some(previous_block_height) = block.header().height().pred() {
+            let database = executor
+                .storage_view_provider
+                .view_at(&previous_block_height)?;
+            self.validate_inner(relayer, database, block, options)
+        } else {
+            let database = executor.storage_view_provider.latest_view()?;
+            self.validate_inner(relayer, database, block, options)
+        }
     }
 
     #[cfg(feature = "wasm-executor")]
     pub fn validate_uploaded_wasm(
         &self,
-        _wasm_root: &Bytes32,
+        wasm_root: &Bytes32,
     ) -> Result<(), UpgradableError> {
-        unimplemented!("Not implemented yet");
+        self.read_executor()?.validate_uploaded_wasm(wasm_root)
     }
 
     /// Executes the block and returns the result of the execution without committing
     /// the changes in the dry run mode.
     pub fn dry_run(
         &self,
-        _component: Components<Vec<Transaction>>,
-        _utxo_validation: Option<bool>,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        unimplemented!("Not implemented yet");
+        let executor = self.read_executor()?;
+
+        let block_version = component
+            .header_to_produce
+            .state_transition_bytecode_version;
+        if block_version != executor.native_executor_version() {
+            let result = executor.dry_run(
+                component,
+                utxo_validation,
+                None,
+                false,
+                Default::default(),
+            )?;
+            return Ok(result
+                .transactions
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect())
+        }
+
+        // fallback to service config value if no utxo_validation override is provided
+        let forbid_fake_coins =
+            utxo_validation.unwrap_or(executor.config.forbid_fake_coins_default);
+
+        let options = ExecutionOptions {
+            forbid_fake_coins,
+            backtrace: false,
+        };
+
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(
+                component.transactions_source,
+            ),
+            coinbase_recipient: Default::default(),
+            gas_price: component.gas_price,
+        };
+
+        let relayer = executor.relayer_view_provider.latest_view()?;
+        let database = executor.storage_view_provider.latest_view()?;
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = self
+            .produce_inner(relayer, database, component, options, ExecutionMode::DryRun)?
+            .into_result();
+
+        // If any of the transactions fails, return an error.
+        if let Some((_, err)) = skipped_transactions.into_iter().next() {
+            return Err(err)
+        }
+
+        Ok(tx_status)
+    }
+}
+
+impl<S, R> Executor<S, R> {
+    fn read_executor(
+        &self,
+    ) -> ExecutorResult<std::sync::RwLockReadGuard<'_, UpgradableExecutor<S, R>>> {
+        self.executor.read().map_err(|_| {
+            ExecutorError::Other("The lock on the underlying executor is poisoned".into())
+        })
     }
+
+    fn produce_inner<Relayer, D, TxSource>(
+        &self,
+        relayer: Relayer,
+        database: D,
+        components: Components<TxSource>,
+        options: ExecutionOptions,
+        mode: ExecutionMode,
+    ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
+    where
+        Relayer: RelayerPort,
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+        TxSource: TransactionsSource,
+    {
+        let consensus_params_version = components.consensus_parameters_version();
+        let (mut block_executor, mut storage_tx) =
+            ExecutionInstance::new(relayer, database, options.clone()).into_executor(
+                consensus_params_version,
+                TimeoutOnlyTxWaiter,
+                TransparentPreconfirmationSender,
+                mode.is_dry_run(),
+            )?;
+
+        let mut partial_block =
+            PartialFuelBlock::new(components.header_to_produce, vec![]);
+        let mut data = ExecutionData::new();
+        let mut memory = MemoryInstance::new();
+
+        if mode == ExecutionMode::Production {
+            block_executor.process_l1_txs(
+                &mut partial_block,
+                components.coinbase_recipient,
+                &mut storage_tx,
+                &mut data,
+                &mut memory,
+            )?;
+        }
+
+        let context = Arc::new(SegmentContext {
+            header: partial_block.header,
+            consensus_params: block_executor.consensus_params().clone(),
+            options,
+            mode,
+            gas_price: components.gas_price,
+            coinbase_contract_id: components.coinbase_recipient,
+        });
+
+        let block_gas_limit = context.consensus_params.block_gas_limit();
+        let block_transaction_size_limit = context
+            .consensus_params
+            .block_transaction_size_limit()
+            .try_into()
+            .unwrap_or(u32::MAX);
+
+        // The same loop as in the native executor: the source is asked for the
+        // next batch of transactions only after the previous batch is executed.
+        loop {
+            let remaining_gas_limit = block_gas_limit.saturating_sub(data.used_gas);
+            let remaining_block_transaction_size_limit =
+                block_transaction_size_limit.saturating_sub(data.used_size);
+            let remaining_tx_count = max_tx_count().saturating_sub(data.tx_count);
+
+            let transactions = components
+                .transactions_source
+                .next(
+                    remaining_gas_limit,
+                    remaining_tx_count,
+                    remaining_block_transaction_size_limit,
+                )
+                .into_iter()
+                .take(remaining_tx_count as usize)
+                .collect::<Vec<_>>();
+
+            if transactions.is_empty() {
+                break
+            }
+
+            storage_tx = self.execute_transactions(
+                &block_executor,
+                &context,
+                transactions,
+                &mut partial_block,
+                storage_tx,
+                &mut data,
+                &mut memory,
+            )?;
+        }
+
+        if mode == ExecutionMode::Production {
+            block_executor.produce_mint_tx(
+                &mut partial_block,
+                &components,
+                &mut storage_tx,
+                &mut data,
+                &mut memory,
+            )?;
+            debug_assert!(data.found_mint, "Mint transaction is not found");
+        }
+
+        let ExecutionData {
+            message_ids,
+            event_inbox_root,
+            events,
+            tx_status,
+            skipped_transactions,
+            coinbase,
+            used_gas,
+            used_size,
+            ..
+        } = data;
+
+        let block = partial_block
+            .generate(
+                &message_ids[..],
+                event_inbox_root,
+                #[cfg(feature = "fault-proving")]
+                &context.consensus_params.chain_id(),
+            )
+            .map_err(ExecutorError::BlockHeaderError)?;
+
+        tracing::debug!(
+            "Block {:#x} fees: {} gas: {} tx_size: {}",
+            block.id(),
+            coinbase,
+            used_gas,
+            used_size
+        );
+
+        let result = ExecutionResult {
+            block,
+            skipped_transactions,
+            tx_status,
+            events,
+        };
+
+        Ok(Uncommitted::new(result, storage_tx.into_changes()))
+    }
+
+    fn validate_inner<Relayer, D>(
+        &self,
+        relayer: Relayer,
+        database: D,
+        block: &Block,
+        options: ExecutionOptions,
+    ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>>
+    where
+        Relayer: RelayerPort,
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    {
+        let consensus_params_version = block.header().consensus_parameters_version();
+        let (mut block_executor, mut storage_tx) =
+            ExecutionInstance::new(relayer, database, options.clone()).into_executor(
+                consensus_params_version,
+                TimeoutOnlyTxWaiter,
+                TransparentPreconfirmationSender,
+                false,
+            )?;
+
+        let mut data = ExecutionData::new();
+        let mut partial_block =
+            PartialFuelBlock::new(PartialBlockHeader::from(block.header()), vec![]);
+        let transactions = block.transactions();
+        let mut memory = MemoryInstance::new();
+
+        let (gas_price, coinbase_contract_id) =
+            SequentialExecutor::<Relayer>::get_coinbase_info_from_mint_tx(transactions)?;
+
+        block_executor.process_l1_txs(
+            &mut partial_block,
+            coinbase_contract_id,
+            &mut storage_tx,
+            &mut data,
+            &mut memory,
+        )?;
+        let processed_l1_tx_count = partial_block.transactions.len();
+
+        let context = Arc::new(SegmentContext {
+            header: partial_block.header,
+            consensus_params: block_executor.consensus_params().clone(),
+            options,
+            mode: ExecutionMode::Validation,
+            gas_price,
+            coinbase_contract_id,
+        });
+
+        let l2_transactions = transactions
+            .iter()
+            .skip(processed_l1_tx_count)
+            .map(|tx| MaybeCheckedTransaction::Transaction(tx.clone()))
+            .collect();
+
+        let storage_tx = self.execute_transactions(
+            &block_executor,
+            &context,
+            l2_transactions,
+            &mut partial_block,
+            storage_tx,
+            &mut data,
+            &mut memory,
+        )?;
+
+        block_executor.check_block_matches(partial_block, block, &data)?;
+
+        let ExecutionData {
+            coinbase,
+            used_gas,
+            used_size,
+            tx_status,
+            events,
+            ..
+        } = data;
+
+        tracing::debug!(
+            "Block {:#x} fees: {} gas: {} tx_size: {}",
+            block.id(),
+            coinbase,
+            used_gas,
+            used_size
+        );
+
+        let result = ValidationResult { tx_status, events };
+
+        Ok(Uncommitted::new(result, storage_tx.into_changes()))
+    }
+
+    /// Executes `transactions` one segment after another. Batches of the parallel
+    /// segment are executed on the runtime of the executor. If the parallel execution
+    /// can't reproduce the result of the sequential execution, the segment
+    /// is re-executed sequentially.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_transactions<Relayer, D>(
+        &self,
+        block_executor: &SequentialExecutor<Relayer>,
+        context: &Arc<SegmentContext>,
+        transactions: Vec<MaybeCheckedTransaction>,
+        partial_block: &mut PartialFuelBlock,
+        mut storage_tx: StorageTransaction<D>,
+        data: &mut ExecutionData,
+        memory: &mut MemoryInstance,
+    ) -> ExecutorResult<StorageTransaction<D>>
+    where
+        Relayer: RelayerPort,
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    {
+        // The native executor calculates the max gas of the transaction before its
+        // execution and fails the whole block if it is impossible.
+        let max_gas = if context.mode == ExecutionMode::Validation {
+            vec![]
+        } else {
+            transactions
+                .iter()
+                .map(|tx| tx.max_gas(&context.consensus_params))
+                .collect::<ExecutorResult<Vec<_>>>()?
+        };
+
+        let chain_id = context.consensus_params.chain_id();
+        let segments = scheduler::schedule(transactions, &chain_id, self.number_of_cores);
+
+        for segment in segments {
+            let parallel = match &segment {
+                Segment::Parallel(batches) => batches.len() > 1 && !data.found_mint,
+                Segment::Barrier(_) => false,
+            };
+
+            if !parallel {
+                execute_sequentially(
+                    block_executor,
+                    context,
+                    &max_gas,
+                    segment.into_transactions(),
+                    partial_block,
+                    &mut storage_tx,
+                    data,
+                    memory,
+                )?;
+                continue
+            }
+
+            let transactions = segment.clone().into_transactions();
+            let Segment::Parallel(batches) = segment else {
+                unreachable!("Only parallel segments are executed in parallel; qed")
+            };
+
+            let (storage, executed) =
+                self.execute_in_parallel(context, &max_gas, batches, storage_tx, data)?;
+            storage_tx = storage;
+
+            match executed {
+                Some((executed, changes)) => {
+                    storage_tx.commit_changes(changes)?;
+                    for ExecutedTransaction {
+                        transaction,
+                        data: tx_data,
+                        ..
+                    } in executed
+                    {
+                        merge_execution_data(data, tx_data)?;
+                        partial_block.transactions.push(transaction);
+                    }
+                }
+                None => {
+                    tracing::debug!(
+                        "The parallel execution of the segment with {} transactions \
+                        requires sequential re-execution",
+                        transactions.len()
+                    );
+                    execute_sequentially(
+                        block_executor,
+                        context,
+                        &max_gas,
+                        transactions,
+                        partial_block,
+                        &mut storage_tx,
+                        data,
+                        memory,
+                    )?;
+                }
+            }
+        }
+
+        Ok(storage_tx)
+    }
+
+    /// Executes batches in parallel on top of the `storage_tx`.
+    ///
+    /// Returns executed transactions sorted by the position with the combined
+    /// changes if the outcome is the same as for the sequential execution.
+    /// Otherwise, returns `None`.
+    #[allow(clippy::type_complexity)]
+    fn execute_in_parallel<D>(
+        &self,
+        context: &Arc<SegmentContext>,
+        max_gas: &[u64],
+        batches: Vec<Batch>,
+        storage_tx: StorageTransaction<D>,
+        data: &ExecutionData,
+    ) -> ExecutorResult<(
+        StorageTransaction<D>,
+        Option<(Vec<ExecutedTransaction>, Changes)>,
+    )>
+    where
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    {
+        let runtime = self.runtime.as_ref().ok_or_else(|| {
+            ExecutorError::Other("The runtime of the executor is stopped".into())
+        })?;
+
+        let first_position = batches
+            .iter()
+            .flatten()
+            .map(|tx| tx.position)
+            .min()
+            .unwrap_or_default();
+        let first_tx_index = data.tx_count;
+        let number_of_batches = batches.len();
+
+        let storage = Arc::new(storage_tx);
+        let (sender, receiver) = mpsc::channel();
+
+        for batch in batches {
+            let executor = WorkerExecutor::new(
+                DisabledRelayer,
+                context.options.clone(),
+                context.consensus_params.clone(),
+                TimeoutOnlyTxWaiter,
+                TransparentPreconfirmationSender,
+                context.mode.is_dry_run(),
+            )?;
+            let storage = storage.clone();
+            let context = context.clone();
+            let sender = sender.clone();
+
+            runtime.spawn(async move {
+                let result = execute_batch(
+                    &executor,
+                    &context,
+                    storage,
+                    batch,
+                    first_position,
+                    first_tx_index,
+                );
+                let _ = sender.send(result);
+            });
+        }
+        drop(sender);
+
+        // The channel is closed when all workers are finished.
+        let results = receiver.iter().collect::<Vec<_>>();
+
+        let storage_tx = Arc::try_unwrap(storage).map_err(|_| {
+            ExecutorError::Other(
+                "The storage is still used by the workers of the parallel executor"
+                    .into(),
+            )
+        })?;
+
+        if results.len() != number_of_batches {
+            return Err(ExecutorError::Other(
+                "The worker of the parallel executor has panicked".into(),
+            ))
+        }
+
+        let mut outputs = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(output) => outputs.push(output),
+                Err(BatchFailure {
+                    position,
+                    tx_id,
+                    error,
+                }) => {
+                    tracing::debug!(
+                        "The transaction {:#x} at position {} failed during \
+                        the parallel execution: {}",
+                        tx_id,
+                        position,
+                        error
+                    );
+                    return Ok((storage_tx, None))
+                }
+            }
+        }
+
+        let mut executed = vec![];
+        let mut changes = Vec::with_capacity(outputs.len());
+        for output in outputs {
+            executed.extend(output.executed);
+            changes.push(output.changes);
+        }
+        executed.sort_by_key(|tx| tx.position);
+
+        if !follows_block_limits(context, max_gas, data, &executed) {
+            return Ok((storage_tx, None))
+        }
+
+        let Some(changes) = merge_changes(changes) else {
+            return Ok((storage_tx, None))
+        };
+
+        Ok((storage_tx, Some((executed, changes))))
+    }
+}
+
+/// Executes the batch of transactions on top of the `storage`.
+/// Each transaction gets the index in the block as if all transactions
+/// of the segment before it were included into the block.
+fn execute_batch<D>(
+    executor: &WorkerExecutor,
+    context: &SegmentContext,
+    storage: Arc<StorageTransaction<D>>,
+    batch: Batch,
+    first_position: usize,
+    first_tx_index: u16,
+) -> Result<BatchOutput, BatchFailure>
+where
+    D: KeyValueInspect<Column = Column>,
+{
+    let chain_id = context.consensus_params.chain_id();
+    let mut storage_tx = storage.into_transaction();
+    let mut block = PartialFuelBlock::new(context.header, vec![]);
+    let mut memory = MemoryInstance::new();
+    let mut executed = Vec::with_capacity(batch.len());
+
+    for ScheduledTransaction {
+        position,
+        transaction,
+    } in batch
+    {
+        let tx_id = transaction.id(&chain_id);
+        let tx_index = u16::try_from(position.saturating_sub(first_position))
+            .ok()
+            .and_then(|offset| first_tx_index.checked_add(offset))
+            .ok_or(BatchFailure {
+                position,
+                tx_id,
+                error: ExecutorError::TooManyTransactions,
+            })?;
+
+        let mut data = ExecutionData::new();
+        data.tx_count = tx_index;
+
+        executor
+            .execute_transaction_and_commit(
+                &mut block,
+                &mut storage_tx,
+                &mut data,
+                transaction,
+                context.gas_price,
+                context.coinbase_contract_id,
+                &mut memory,
+            )
+            .map_err(|error| BatchFailure {
+                position,
+                tx_id,
+                error,
+            })?;
+
+        let transaction = block
+            .transactions
+            .pop()
+            .expect("Shouldn't happen as we just added a transaction; qed");
+
+        executed.push(ExecutedTransaction {
+            position,
+            transaction,
+            data,
+        });
+    }
+
+    Ok(BatchOutput {
+        executed,
+        changes: storage_tx.into_changes(),
+    })
+}
+
+/// Executes transactions one by one in the same way as the native executor does.
+#[allow(clippy::too_many_arguments)]
+fn execute_sequentially<Relayer, D>(
+    block_executor: &SequentialExecutor<Relayer>,
+    context: &SegmentContext,
+    max_gas: &[u64],
+    transactions: Vec<ScheduledTransaction>,
+    partial_block: &mut PartialFuelBlock,
+    storage_tx: &mut StorageTransaction<D>,
+    data: &mut ExecutionData,
+    memory: &mut MemoryInstance,
+) -> ExecutorResult<()>
+where
+    Relayer: RelayerPort,
+    D: KeyValueInspect<Column = Column>,
+{
+    let chain_id = context.consensus_params.chain_id();
+    let block_gas_limit = context.consensus_params.block_gas_limit();
+
+    for ScheduledTransaction {
+        position,
+        transaction,
+    } in transactions
+    {
+        if context.mode == ExecutionMode::Validation {
+            block_executor.execute_transaction_and_commit(
+                partial_block,
+                storage_tx,
+                data,
+                transaction,
+                context.gas_price,
+                context.coinbase_contract_id,
+                memory,
+            )?;
+            continue
+        }
+
+        let tx_id = transaction.id(&chain_id);
+        let tx_max_gas = max_gas[position];
+        let remaining_gas_limit = block_gas_limit.saturating_sub(data.used_gas);
+        if tx_max_gas > remaining_gas_limit {
+            data.skipped_transactions.push((
+                tx_id,
+                ExecutorError::GasOverflow(
+                    format!(
+                        "Transaction cannot fit in remaining gas limit: ({remaining_gas_limit})."
+                    ),
+                    tx_max_gas,
+                    remaining_gas_limit,
+                ),
+            ));
+            continue;
+        }
+
+        if let Err(err) = block_executor.execute_transaction_and_commit(
+            partial_block,
+            storage_tx,
+            data,
+            transaction,
+            context.gas_price,
+            context.coinbase_contract_id,
+            memory,
+        ) {
+            data.skipped_transactions.push((tx_id, err));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the sequential execution of `executed` transactions would include
+/// all of them into the block without overflows of the block's counters.
+fn follows_block_limits(
+    context: &SegmentContext,
+    max_gas: &[u64],
+    data: &ExecutionData,
+    executed: &[ExecutedTransaction],
+) -> bool {
+    let block_gas_limit = context.consensus_params.block_gas_limit();
+    let mut coinbase = data.coinbase;
+    let mut used_gas = data.used_gas;
+    let mut used_size = data.used_size;
+    let mut tx_count = data.tx_count;
+
+    for ExecutedTransaction {
+        position,
+        data: tx_data,
+        ..
+    } in executed
+    {
+        if context.mode != ExecutionMode::Validation {
+            let tx_max_gas = max_gas[*position];
+            if tx_max_gas > block_gas_limit.saturating_sub(used_gas) {
+                return false
+            }
+        }
+
+        let next = coinbase
+            .checked_add(tx_data.coinbase)
+            .zip(used_gas.checked_add(tx_data.used_gas))
+            .zip(used_size.checked_add(tx_data.used_size))
+            .zip(tx_count.checked_add(1));
+        let Some((((next_coinbase, next_used_gas), next_used_size), next_tx_count)) =
+            next
+        else {
+            return false
+        };
+
+        coinbase = next_coinbase;
+        used_gas = next_used_gas;
+        used_size = next_used_size;
+        tx_count = next_tx_count;
+    }
+
+    true
+}
+
+/// Combines changes of the batches. Batches don't share any declared storage
+/// entries, so their changes are expected to be disjoint. If two batches modified
+/// the same entry differently, the order of the execution matters, and `None`
+/// is returned.
+fn merge_changes(changes: Vec<Changes>) -> Option<Changes> {
+    let mut merged = Changes::default();
+
+    for changes in changes {
+        for (column, operations) in changes {
+            let merged_column = merged.entry(column).or_default();
+            for (key, operation) in operations {
+                match merged_column.entry(key) {
+                    btree_map::Entry::Vacant(vacant) => {
+                        vacant.insert(operation);
+                    }
+                    btree_map::Entry::Occupied(occupied) => {
+                        if occupied.get() != &operation {
+                            return None
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Some(merged)
+}
+
+/// Appends the execution data of one transaction to the data of the block.
+fn merge_execution_data(
+    data: &mut ExecutionData,
+    tx_data: ExecutionData,
+) -> ExecutorResult<()> {
+    data.coinbase = data
+        .coinbase
+        .checked_add(tx_data.coinbase)
+        .ok_or(ExecutorError::FeeOverflow)?;
+    data.used_gas = data.used_gas.checked_add(tx_data.used_gas).ok_or_else(|| {
+        ExecutorError::GasOverflow(
+            "Execution used gas overflowed.".into(),
+            data.used_gas,
+            tx_data.used_gas,
+        )
+    })?;
+    data.used_size = data
+        .used_size
+        .checked_add(tx_data.used_size)
+        .ok_or(ExecutorError::TxSizeOverflow)?;
+    data.tx_count = data
+        .tx_count
+        .checked_add(1)
+        .ok_or(ExecutorError::TooManyTransactions)?;
+    data.message_ids.extend(tx_data.message_ids);
+    data.tx_status.extend(tx_data.tx_status);
+    data.events.extend(tx_data.events);
+    Ok(())
 }
@@ -1,2 +1,6 @@
 pub mod config;
 pub mod executor;
+pub mod scheduler;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,528 @@
+//! The scheduler splits the transactions of the block into groups that can be
+//! executed in parallel without affecting each other.
+//!
+//! The Fuel transaction declares all storage entries it can modify in its
+//! inputs and outputs: spent coins and messages, produced coins, and used or
+//! created contracts. Two transactions that don't share any of these entries
+//! can be executed in any order with the same result. Transactions that share
+//! an entry are placed into the same group and executed sequentially in the
+//! order of the block.
+//!
+//! `Upgrade`, `Upload`, `Blob` and `Mint` transactions modify the global state
+//! of the chain (consensus parameters, state transition bytecode, blobs that
+//! any predicate or script can load, and coinbase). They are scheduled as
+//! barriers and executed alone.
+
+use fuel_core_types::{
+    blockchain::transaction::TransactionExt,
+    fuel_tx::{
+        ContractId,
+        Input,
+        Output,
+        TxId,
+        UtxoId,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+    },
+    fuel_types::{
+        ChainId,
+        Nonce,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+};
+use fuel_core_upgradable_executor::native_executor::ports::MaybeCheckedTransaction;
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+};
+
+/// The storage entry that the transaction reads or modifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ConflictKey {
+    /// The transaction itself, to detect duplicates.
+    Transaction(TxId),
+    /// The coin spent or produced by the transaction.
+    Coin(UtxoId),
+    /// The message spent by the transaction.
+    Message(Nonce),
+    /// The contract used or created by the transaction.
+    Contract(ContractId),
+}
+
+/// The transaction with its position in the list of transactions
+/// passed to the scheduler.
+#[derive(Debug, Clone)]
+pub struct ScheduledTransaction {
+    pub position: usize,
+    pub transaction: MaybeCheckedTransaction,
+}
+
+/// The list of transactions that should be executed sequentially.
+/// Transactions are sorted by the position.
+pub type Batch = Vec<ScheduledTransaction>;
+
+/// The group of batches that don't conflict with each other and
+/// can be executed in parallel.
+#[derive(Debug, Clone)]
+pub enum Segment {
+    /// Batches of non-conflicting transactions.
+    Parallel(Vec<Batch>),
+    /// The transaction that modifies the global state and must be executed alone.
+    Barrier(ScheduledTransaction),
+}
+
+impl Segment {
+    /// Returns the number of transactions in the segment.
+    pub fn len(&self) -> usize {
+        match self {
+            Segment::Parallel(batches) => batches.iter().map(Vec::len).sum(),
+            Segment::Barrier(_) => 1,
+        }
+    }
+
+    /// Returns `true` if the segment doesn't have any transactions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all transactions of the segment in the order of the positions.
+    pub fn into_transactions(self) -> Vec<ScheduledTransaction> {
+        match self {
+            Segment::Parallel(batches) => {
+                let mut transactions = batches.into_iter().flatten().collect::<Vec<_>>();
+                transactions.sort_by_key(|tx| tx.position);
+                transactions
+            }
+            Segment::Barrier(transaction) => vec![transaction],
+        }
+    }
+}
+
+/// Splits `transactions` into segments that should be executed one after another.
+/// Each parallel segment contains at most `number_of_batches` batches.
+pub fn schedule(
+    transactions: Vec<MaybeCheckedTransaction>,
+    chain_id: &ChainId,
+    number_of_batches: NonZeroUsize,
+) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut current = vec![];
+
+    for (position, transaction) in transactions.into_iter().enumerate() {
+        let scheduled = ScheduledTransaction {
+            position,
+            transaction,
+        };
+
+        if is_barrier(&scheduled.transaction) {
+            if !current.is_empty() {
+                let transactions = core::mem::take(&mut current);
+                segments.push(Segment::Parallel(split_into_batches(
+                    transactions,
+                    chain_id,
+                    number_of_batches,
+                )));
+            }
+            segments.push(Segment::Barrier(scheduled));
+        } else {
+            current.push(scheduled);
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push(Segment::Parallel(split_into_batches(
+            current,
+            chain_id,
+            number_of_batches,
+        )));
+    }
+
+    segments
+}
+
+fn is_barrier(transaction: &MaybeCheckedTransaction) -> bool {
+    match transaction {
+        MaybeCheckedTransaction::CheckedTransaction(tx, _) => !matches!(
+            tx,
+            CheckedTransaction::Script(_) | CheckedTransaction::Create(_)
+        ),
+        MaybeCheckedTransaction::Transaction(tx) => !(tx.is_script() || tx.is_create()),
+    }
+}
+
+fn conflict_keys(
+    transaction: &MaybeCheckedTransaction,
+    chain_id: &ChainId,
+) -> Vec<ConflictKey> {
+    let tx_id = transaction.id(chain_id);
+    let inputs = transaction.inputs();
+    let outputs = transaction.outputs();
+
+    let mut keys =
+        Vec::with_capacity(inputs.len().saturating_add(outputs.len()).saturating_add(1));
+    keys.push(ConflictKey::Transaction(tx_id));
+
+    for input in inputs.iter() {
+        let key = match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                ConflictKey::Coin(*utxo_id)
+            }
+            Input::Contract(Contract { contract_id, .. }) => {
+                ConflictKey::Contract(*contract_id)
+            }
+            Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+            | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                ConflictKey::Message(*nonce)
+            }
+        };
+        keys.push(key);
+    }
+
+    for (index, output) in outputs.iter().enumerate() {
+        match output {
+            Output::Coin { .. } | Output::Change { .. } | Output::Variable { .. } => {
+                // The number of outputs is limited by the consensus parameters
+                // and fits into `u16`. If it is not the case, the transaction
+                // will be rejected by the executor anyway.
+                let index = u16::try_from(index).unwrap_or(u16::MAX);
+                keys.push(ConflictKey::Coin(UtxoId::new(tx_id, index)));
+            }
+            Output::ContractCreated { contract_id, .. } => {
+                keys.push(ConflictKey::Contract(*contract_id));
+            }
+            Output::Contract(_) => {
+                // The contract is already registered by the corresponding input.
+            }
+        }
+    }
+
+    keys
+}
+
+/// Groups transactions that share at least one conflict key, and then distributes
+/// groups between `number_of_batches` batches, trying to keep batches of equal size.
+fn split_into_batches(
+    transactions: Vec<ScheduledTransaction>,
+    chain_id: &ChainId,
+    number_of_batches: NonZeroUsize,
+) -> Vec<Batch> {
+    let mut groups = DisjointSet::new(transactions.len());
+    let mut owners = HashMap::<ConflictKey, usize>::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        for key in conflict_keys(&transaction.transaction, chain_id) {
+            match owners.get(&key) {
+                Some(owner) => groups.union(*owner, index),
+                None => {
+                    owners.insert(key, index);
+                }
+            }
+        }
+    }
+
+    // Groups are ordered by the position of the first transaction.
+    let mut group_to_batch = HashMap::<usize, usize>::new();
+    let mut grouped: Vec<Vec<ScheduledTransaction>> = vec![];
+    for (index, transaction) in transactions.into_iter().enumerate() {
+        let root = groups.find(index);
+        let group = *group_to_batch.entry(root).or_insert_with(|| {
+            grouped.push(vec![]);
+            grouped.len().saturating_sub(1)
+        });
+        grouped[group].push(transaction);
+    }
+
+    let number_of_batches = number_of_batches.get().min(grouped.len());
+    let mut batches: Vec<Batch> = vec![vec![]; number_of_batches];
+    for group in grouped {
+        let smallest = batches
+            .iter_mut()
+            .min_by_key(|batch| batch.len())
+            .expect("The number of batches is not zero when there are groups; qed");
+        smallest.extend(group);
+    }
+
+    for batch in batches.iter_mut() {
+        batch.sort_by_key(|tx| tx.position);
+    }
+
+    batches
+}
+
+/// The union-find structure to group conflicting transactions.
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+        }
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            let grandparent = self.parents[self.parents[index]];
+            self.parents[index] = grandparent;
+            index = grandparent;
+        }
+        index
+    }
+
+    fn union(&mut self, left: usize, right: usize) {
+        let left = self.find(left);
+        let right = self.find(right);
+        if left != right {
+            // The smaller index becomes the root to keep roots stable.
+            let (root, child) = if left < right {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            self.parents[child] = root;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::op,
+        fuel_tx::{
+            AssetId,
+            Bytes32,
+            TransactionBuilder,
+            TxPointer,
+            UniqueIdentifier,
+            UpgradePurpose,
+        },
+    };
+
+    fn chain_id() -> ChainId {
+        ChainId::default()
+    }
+
+    fn script(inputs: Vec<Input>, outputs: Vec<Output>) -> MaybeCheckedTransaction {
+        let mut builder =
+            TransactionBuilder::script(vec![op::ret(1)].into_iter().collect(), vec![]);
+        for input in inputs {
+            builder.add_input(input);
+        }
+        for output in outputs {
+            builder.add_output(output);
+        }
+        MaybeCheckedTransaction::Transaction(builder.finalize_as_transaction())
+    }
+
+    fn coin(utxo_id: UtxoId) -> Input {
+        Input::coin_signed(
+            utxo_id,
+            Default::default(),
+            100,
+            AssetId::BASE,
+            TxPointer::default(),
+            0,
+        )
+    }
+
+    fn contract(contract_id: ContractId) -> Input {
+        Input::contract(
+            Default::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        )
+    }
+
+    fn utxo(byte: u8) -> UtxoId {
+        UtxoId::new([byte; 32].into(), 0)
+    }
+
+    fn positions(segment: &Segment) -> Vec<Vec<usize>> {
+        match segment {
+            Segment::Parallel(batches) => batches
+                .iter()
+                .map(|batch| batch.iter().map(|tx| tx.position).collect())
+                .collect(),
+            Segment::Barrier(tx) => vec![vec![tx.position]],
+        }
+    }
+
+    #[test]
+    fn schedule__independent_transactions_are_split_between_batches() {
+        // Given
+        let transactions = (0..4)
+            .map(|i| script(vec![coin(utxo(i))], vec![]))
+            .collect();
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(segments.len(), 1);
+        assert_eq!(
+            positions(&segments[0]),
+            vec![vec![0], vec![1], vec![2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn schedule__number_of_batches_is_limited() {
+        // Given
+        let transactions = (0..5)
+            .map(|i| script(vec![coin(utxo(i))], vec![]))
+            .collect();
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(2).unwrap());
+
+        // Then
+        assert_eq!(segments.len(), 1);
+        assert_eq!(positions(&segments[0]), vec![vec![0, 2, 4], vec![1, 3]]);
+    }
+
+    #[test]
+    fn schedule__transactions_with_the_same_contract_are_in_the_same_batch() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let transactions = vec![
+            script(
+                vec![coin(utxo(0)), contract(contract_id)],
+                vec![Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed())],
+            ),
+            script(vec![coin(utxo(1))], vec![]),
+            script(
+                vec![coin(utxo(2)), contract(contract_id)],
+                vec![Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed())],
+            ),
+        ];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(segments.len(), 1);
+        assert_eq!(positions(&segments[0]), vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn schedule__transaction_spending_output_of_another_is_in_the_same_batch() {
+        // Given
+        let first = script(
+            vec![coin(utxo(0))],
+            vec![Output::coin(Default::default(), 10, AssetId::BASE)],
+        );
+        let MaybeCheckedTransaction::Transaction(first_tx) = &first else {
+            unreachable!()
+        };
+        let first_id = first_tx.id(&chain_id());
+        let second = script(vec![coin(UtxoId::new(first_id, 0))], vec![]);
+        let transactions = vec![first, script(vec![coin(utxo(1))], vec![]), second];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(segments.len(), 1);
+        assert_eq!(positions(&segments[0]), vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn schedule__transactions_spending_the_same_message_are_in_the_same_batch() {
+        // Given
+        let nonce = Nonce::from([7; 32]);
+        let message = Input::message_coin_signed(
+            Default::default(),
+            Default::default(),
+            100,
+            nonce,
+            0,
+        );
+        let transactions = vec![
+            script(vec![message.clone()], vec![]),
+            script(vec![coin(utxo(1))], vec![]),
+            script(vec![message, coin(utxo(2))], vec![]),
+        ];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(positions(&segments[0]), vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn schedule__transitive_conflicts_are_merged_into_one_batch() {
+        // Given
+        let transactions = vec![
+            script(vec![coin(utxo(0)), coin(utxo(1))], vec![]),
+            script(vec![coin(utxo(2)), coin(utxo(3))], vec![]),
+            script(vec![coin(utxo(1)), coin(utxo(2))], vec![]),
+            script(vec![coin(utxo(4))], vec![]),
+        ];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(positions(&segments[0]), vec![vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn schedule__barrier_splits_segments() {
+        // Given
+        let upgrade = MaybeCheckedTransaction::Transaction(
+            TransactionBuilder::upgrade(UpgradePurpose::StateTransition {
+                root: Bytes32::zeroed(),
+            })
+            .finalize_as_transaction(),
+        );
+        let transactions = vec![
+            script(vec![coin(utxo(0))], vec![]),
+            script(vec![coin(utxo(1))], vec![]),
+            upgrade,
+            script(vec![coin(utxo(2))], vec![]),
+        ];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(segments.len(), 3);
+        assert_eq!(positions(&segments[0]), vec![vec![0], vec![1]]);
+        assert!(matches!(segments[1], Segment::Barrier(_)));
+        assert_eq!(positions(&segments[1]), vec![vec![2]]);
+        assert_eq!(positions(&segments[2]), vec![vec![3]]);
+    }
+
+    #[test]
+    fn schedule__duplicated_transactions_are_in_the_same_batch() {
+        // Given
+        let tx = script(vec![], vec![]);
+        let transactions = vec![tx.clone(), script(vec![coin(utxo(1))], vec![]), tx];
+
+        // When
+        let segments = schedule(transactions, &chain_id(), NonZeroUsize::new(4).unwrap());
+
+        // Then
+        assert_eq!(positions(&segments[0]), vec![vec![0, 2], vec![1]]);
+    }
+}
@@ -0,0 +1,706 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    config::Config,
+    executor::Executor,
+};
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+    column::Column,
+    kv_store::{
+        KeyValueInspect,
+        Value,
+    },
+    structured_storage::test::InMemoryStorage,
+    tables::ConsensusParametersVersions,
+    transactional::{
+        AtomicView,
+        Changes,
+        HistoricalView,
+        Modifiable,
+        WriteTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::{
+            ApplicationHeader,
+            ConsensusHeader,
+            PartialBlockHeader,
+        },
+        primitives::{
+            DaBlockHeight,
+            Empty,
+        },
+        transaction::TransactionExt,
+    },
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        BlobBody,
+        BlobId,
+        BlobIdExt,
+        ConsensusParameters,
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        TxPointer,
+        UniqueIdentifier,
+        UtxoId,
+        field::Outputs,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+        canonical::Serialize,
+    },
+    fuel_vm::Call,
+    services::{
+        block_producer::Components,
+        executor::{
+            ExecutionResult,
+            TransactionExecutionStatus,
+            ValidationResult,
+        },
+        relayer::Event,
+    },
+    tai64::Tai64,
+    test_helpers::create_contract,
+};
+use fuel_core_upgradable_executor::{
+    executor::Executor as UpgradableExecutor,
+    native_executor::{
+        executor::OnceTransactionsSource,
+        ports::RelayerPort,
+    },
+};
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+use std::num::NonZeroUsize;
+
+const CONSENSUS_PARAMETERS_VERSION: u32 = 0;
+
+const NUMBER_OF_CORES: [usize; 4] = [1, 2, 4, 8];
+
+#[derive(Clone, Debug)]
+struct Storage(InMemoryStorage<Column>);
+
+impl AtomicView for Storage {
+    type LatestView = InMemoryStorage<Column>;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(self.0.clone())
+    }
+}
+
+impl HistoricalView for Storage {
+    type Height = BlockHeight;
+    type ViewAtHeight = Self::LatestView;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        None
+    }
+
+    fn view_at(&self, _: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        self.latest_view()
+    }
+}
+
+impl KeyValueInspect for Storage {
+    type Column = Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.0.get(key, column)
+    }
+}
+
+impl Modifiable for Storage {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        self.0.commit_changes(changes)
+    }
+}
+
+#[derive(Copy, Clone)]
+struct DisabledRelayer;
+
+impl RelayerPort for DisabledRelayer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn get_events(&self, _: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        unimplemented!()
+    }
+}
+
+impl AtomicView for DisabledRelayer {
+    type LatestView = Self;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(*self)
+    }
+}
+
+fn storage(consensus_parameters: &ConsensusParameters) -> Storage {
+    let mut storage = Storage(InMemoryStorage::default());
+    let mut tx = storage.write_transaction();
+    tx.storage_as_mut::<ConsensusParametersVersions>()
+        .insert(&CONSENSUS_PARAMETERS_VERSION, consensus_parameters)
+        .unwrap();
+    tx.commit().unwrap();
+
+    storage
+}
+
+fn header() -> PartialBlockHeader {
+    PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: Default::default(),
+            consensus_parameters_version: CONSENSUS_PARAMETERS_VERSION,
+            state_transition_bytecode_version: UpgradableExecutor::<
+                Storage,
+                DisabledRelayer,
+            >::VERSION,
+            generated: Empty,
+        },
+        consensus: ConsensusHeader {
+            prev_root: Default::default(),
+            height: Default::default(),
+            time: Tai64::now(),
+            generated: Empty,
+        },
+    }
+}
+
+fn components(
+    header: PartialBlockHeader,
+    transactions: Vec<Transaction>,
+) -> Components<OnceTransactionsSource> {
+    Components {
+        header_to_produce: header,
+        transactions_source: OnceTransactionsSource::new(transactions),
+        coinbase_recipient: Default::default(),
+        gas_price: 0,
+    }
+}
+
+fn dry_run_components(
+    header: PartialBlockHeader,
+    transactions: Vec<Transaction>,
+) -> Components<Vec<Transaction>> {
+    Components {
+        header_to_produce: header,
+        transactions_source: transactions,
+        coinbase_recipient: Default::default(),
+        gas_price: 0,
+    }
+}
+
+/// Runs the same block on the sequential and on the parallel executors
+/// with the different number of cores and checks that the outcome is the same.
+struct TestContext {
+    consensus_parameters: ConsensusParameters,
+    header: PartialBlockHeader,
+}
+
+impl TestContext {
+    fn new() -> Self {
+        Self::with_consensus_parameters(ConsensusParameters::default())
+    }
+
+    fn with_consensus_parameters(consensus_parameters: ConsensusParameters) -> Self {
+        Self {
+            consensus_parameters,
+            header: header(),
+        }
+    }
+
+    fn sequential(&self) -> UpgradableExecutor<Storage, DisabledRelayer> {
+        UpgradableExecutor::new(
+            storage(&self.consensus_parameters),
+            DisabledRelayer,
+            Default::default(),
+        )
+    }
+
+    fn parallel(&self, number_of_cores: usize) -> Executor<Storage, DisabledRelayer> {
+        let config = Config {
+            number_of_cores: NonZeroUsize::new(number_of_cores).unwrap(),
+            executor_config: Default::default(),
+        };
+        Executor::new(storage(&self.consensus_parameters), DisabledRelayer, config)
+    }
+
+    /// Produces the block with `transactions` and returns the result
+    /// of the sequential execution.
+    fn assert_same_production(
+        &self,
+        transactions: Vec<Transaction>,
+    ) -> (ExecutionResult, Changes) {
+        let expected = self
+            .sequential()
+            .produce_without_commit_with_source_direct_resolve(components(
+                self.header,
+                transactions.clone(),
+            ))
+            .expect("Sequential production should succeed")
+            .into();
+
+        for number_of_cores in NUMBER_OF_CORES {
+            let actual = self
+                .parallel(number_of_cores)
+                .produce_without_commit_with_source(components(
+                    self.header,
+                    transactions.clone(),
+                ))
+                .expect("Parallel production should succeed")
+                .into();
+
+            assert_same_execution_result(&expected, &actual, number_of_cores);
+        }
+
+        expected
+    }
+
+    fn assert_same_validation(&self, block: &Block) {
+        let (expected_result, expected_changes): (ValidationResult, Changes) = self
+            .sequential()
+            .validate(block)
+            .expect("Sequential validation should succeed")
+            .into();
+
+        for number_of_cores in NUMBER_OF_CORES {
+            let (actual_result, actual_changes): (ValidationResult, Changes) = self
+                .parallel(number_of_cores)
+                .validate(block)
+                .expect("Parallel validation should succeed")
+                .into();
+
+            assert_eq!(
+                postcard::to_stdvec(&expected_result).unwrap(),
+                postcard::to_stdvec(&actual_result).unwrap(),
+                "Validation results are different with {number_of_cores} cores"
+            );
+            assert_eq!(
+                without_empty_columns(expected_changes.clone()),
+                without_empty_columns(actual_changes),
+                "Changes are different with {number_of_cores} cores"
+            );
+        }
+    }
+
+    fn assert_same_dry_run(&self, transactions: Vec<Transaction>) {
+        let expected = self
+            .sequential()
+            .dry_run(
+                dry_run_components(self.header, transactions.clone()),
+                None,
+                None,
+                false,
+                Default::default(),
+            )
+            .map(|result| {
+                result
+                    .transactions
+                    .into_iter()
+                    .map(|(_, status)| status)
+                    .collect::<Vec<_>>()
+            });
+
+        for number_of_cores in NUMBER_OF_CORES {
+            let actual = self
+                .parallel(number_of_cores)
+                .dry_run(dry_run_components(self.header, transactions.clone()), None);
+
+            match (&expected, &actual) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_same_statuses(expected, actual, number_of_cores)
+                }
+                (Err(expected), Err(actual)) => assert_eq!(
+                    expected.to_string(),
+                    actual.to_string(),
+                    "Dry run errors are different with {number_of_cores} cores"
+                ),
+                _ => panic!(
+                    "Dry run outcomes are different with {number_of_cores} cores: \
+                    {expected:?} vs {actual:?}"
+                ),
+            }
+        }
+    }
+}
+
+fn assert_same_execution_result(
+    (expected_result, expected_changes): &(ExecutionResult, Changes),
+    (actual_result, actual_changes): &(ExecutionResult, Changes),
+    number_of_cores: usize,
+) {
+    assert_eq!(
+        expected_result.block.id(),
+        actual_result.block.id(),
+        "Blocks are different with {number_of_cores} cores"
+    );
+    assert_eq!(
+        postcard::to_stdvec(expected_result).unwrap(),
+        postcard::to_stdvec(actual_result).unwrap(),
+        "Execution results are different with {number_of_cores} cores"
+    );
+    assert_eq!(
+        without_empty_columns(expected_changes.clone()),
+        without_empty_columns(actual_changes.clone()),
+        "Changes are different with {number_of_cores} cores"
+    );
+}
+
+fn assert_same_statuses(
+    expected: &[TransactionExecutionStatus],
+    actual: &[TransactionExecutionStatus],
+    number_of_cores: usize,
+) {
+    assert_eq!(
+        postcard::to_stdvec(expected).unwrap(),
+        postcard::to_stdvec(actual).unwrap(),
+        "Statuses are different with {number_of_cores} cores"
+    );
+}
+
+fn without_empty_columns(mut changes: Changes) -> Changes {
+    changes.retain(|_, operations| !operations.is_empty());
+    changes
+}
+
+fn transfer(rng: &mut StdRng) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.r#gen(),
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.r#gen(), 500, AssetId::BASE))
+        .add_output(Output::change(rng.r#gen(), 0, AssetId::BASE))
+        .script_gas_limit(10_000)
+        .finalize_as_transaction()
+}
+
+fn spend_output(
+    rng: &mut StdRng,
+    parent: &Transaction,
+    chain_id: &ChainId,
+) -> Transaction {
+    let parent_id = parent.id(chain_id);
+    let amount = match parent {
+        Transaction::Script(script) => script.outputs()[0].amount().unwrap(),
+        _ => unreachable!("Only scripts are used as parents"),
+    };
+
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            UtxoId::new(parent_id, 0),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.r#gen(), amount, AssetId::BASE))
+        .script_gas_limit(10_000)
+        .finalize_as_transaction()
+}
+
+fn deploy(rng: &mut StdRng) -> (Transaction, ContractId) {
+    let bytecode: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let (tx, contract_id) = create_contract(&bytecode, rng);
+    (tx.into(), contract_id)
+}
+
+fn call(rng: &mut StdRng, contract_id: ContractId) -> Transaction {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .add_input(Input::contract(
+            rng.r#gen(),
+            Default::default(),
+            Default::default(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        // The id excludes the UTXO id of the contract input, so the coin makes it unique.
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.r#gen(),
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .script_gas_limit(100_000)
+        .finalize_as_transaction()
+}
+
+fn blob(rng: &mut StdRng) -> Transaction {
+    let program: Vec<u8> = (0..128).map(|_| rng.r#gen::<u8>()).collect();
+    TransactionBuilder::blob(BlobBody {
+        id: BlobId::compute(program.as_slice()),
+        witness_index: 0,
+    })
+    .add_witness(program.into())
+    .add_fee_input()
+    .finalize_as_transaction()
+}
+
+#[test]
+fn produce__independent_transactions_match_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let transactions = (0..32).map(|_| transfer(&mut rng)).collect::<Vec<_>>();
+
+    // When
+    let (result, _) = context.assert_same_production(transactions.clone());
+
+    // Then
+    assert!(result.skipped_transactions.is_empty());
+    // All transactions plus the mint transaction.
+    assert_eq!(result.block.transactions().len(), transactions.len() + 1);
+}
+
+#[test]
+fn produce__chained_transactions_match_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+    let chain_id = context.consensus_parameters.chain_id();
+
+    // Given
+    let mut transactions = vec![];
+    for _ in 0..4 {
+        let parent = transfer(&mut rng);
+        let child = spend_output(&mut rng, &parent, &chain_id);
+        let grandchild = spend_output(&mut rng, &child, &chain_id);
+        transactions.extend([parent, child, grandchild]);
+    }
+
+    // When
+    let (result, _) = context.assert_same_production(transactions.clone());
+
+    // Then
+    assert_eq!(result.block.transactions().len(), transactions.len() + 1);
+}
+
+#[test]
+fn produce__contract_deployment_and_calls_match_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let mut transactions = vec![];
+    let mut contracts = vec![];
+    for _ in 0..3 {
+        let (deployment, contract_id) = deploy(&mut rng);
+        transactions.push(deployment);
+        contracts.push(contract_id);
+    }
+    for i in 0..12 {
+        transactions.push(call(&mut rng, contracts[i % contracts.len()]));
+        transactions.push(transfer(&mut rng));
+    }
+
+    // When
+    let (result, _) = context.assert_same_production(transactions.clone());
+
+    // Then
+    assert!(result.skipped_transactions.is_empty());
+    assert_eq!(result.block.transactions().len(), transactions.len() + 1);
+}
+
+#[test]
+fn produce__blob_transactions_are_executed_in_order() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let mut transactions = vec![];
+    for _ in 0..3 {
+        transactions.extend((0..5).map(|_| transfer(&mut rng)));
+        transactions.push(blob(&mut rng));
+    }
+    transactions.extend((0..5).map(|_| transfer(&mut rng)));
+
+    // When
+    let (result, _) = context.assert_same_production(transactions.clone());
+
+    // Then
+    assert!(result.skipped_transactions.is_empty());
+    assert_eq!(result.block.transactions().len(), transactions.len() + 1);
+}
+
+#[test]
+fn produce__skipped_transactions_match_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let duplicate = transfer(&mut rng);
+    let mut transactions = (0..8).map(|_| transfer(&mut rng)).collect::<Vec<_>>();
+    transactions.insert(2, duplicate.clone());
+    transactions.insert(6, duplicate);
+
+    // When
+    let (result, _) = context.assert_same_production(transactions.clone());
+
+    // Then
+    assert_eq!(result.skipped_transactions.len(), 1);
+    assert_eq!(result.block.transactions().len(), transactions.len());
+}
+
+#[test]
+fn produce__transactions_exceeding_block_gas_limit_match_sequential_execution() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut consensus_parameters = ConsensusParameters::default();
+    let max_gas = transfer(&mut rng)
+        .max_gas(&consensus_parameters)
+        .expect("Max gas of the transfer should be computable");
+    consensus_parameters.set_block_gas_limit(max_gas * 5 + max_gas / 2);
+    let context = TestContext::with_consensus_parameters(consensus_parameters);
+
+    // Given
+    let transactions = (0..16).map(|_| transfer(&mut rng)).collect::<Vec<_>>();
+
+    // When
+    let (result, _) = context.assert_same_production(transactions);
+
+    // Then
+    assert!(!result.skipped_transactions.is_empty());
+}
+
+#[test]
+fn validate__produced_block_matches_sequential_validation() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+    let chain_id = context.consensus_parameters.chain_id();
+
+    // Given
+    let (deployment, contract_id) = deploy(&mut rng);
+    let mut transactions = vec![deployment];
+    for _ in 0..8 {
+        let parent = transfer(&mut rng);
+        let child = spend_output(&mut rng, &parent, &chain_id);
+        transactions.extend([parent, child, call(&mut rng, contract_id)]);
+    }
+    let (result, _) = context.assert_same_production(transactions);
+
+    // When
+    // Then
+    context.assert_same_validation(&result.block);
+}
+
+#[test]
+fn dry_run__matches_sequential_dry_run() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let (deployment, contract_id) = deploy(&mut rng);
+    let mut transactions = vec![deployment];
+    transactions.extend((0..8).map(|_| call(&mut rng, contract_id)));
+    transactions.extend((0..8).map(|_| transfer(&mut rng)));
+
+    // When
+    // Then
+    context.assert_same_dry_run(transactions);
+}
+
+#[test]
+fn dry_run__failing_transaction_returns_the_same_error() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestContext::new();
+
+    // Given
+    let duplicate = transfer(&mut rng);
+    let transactions = vec![duplicate.clone(), transfer(&mut rng), duplicate];
+
+    // When
+    // Then
+    context.assert_same_dry_run(transactions);
+}
+
+#[test]
+fn produce__random_blocks_match_sequential_execution() {
+    let chain_id = ConsensusParameters::default().chain_id();
+
+    for seed in 0..8 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let context = TestContext::new();
+
+        // Given
+        let mut transactions: Vec<Transaction> = vec![];
+        let mut contracts = vec![];
+        let mut parents = vec![];
+        for _ in 0..rng.gen_range(8..48) {
+            let tx = match rng.gen_range(0..10) {
+                0 => {
+                    let (deployment, contract_id) = deploy(&mut rng);
+                    contracts.push(contract_id);
+                    deployment
+                }
+                1 | 2 if !contracts.is_empty() => {
+                    let contract_id = contracts[rng.gen_range(0..contracts.len())];
+                    call(&mut rng, contract_id)
+                }
+                3 | 4 if !parents.is_empty() => {
+                    let parent: Transaction =
+                        parents.swap_remove(rng.gen_range(0..parents.len()));
+                    spend_output(&mut rng, &parent, &chain_id)
+                }
+                5 if !transactions.is_empty() => {
+                    transactions[rng.gen_range(0..transactions.len())].clone()
+                }
+                6 => blob(&mut rng),
+                _ => {
+                    let tx = transfer(&mut rng);
+                    parents.push(tx.clone());
+                    tx
+                }
+            };
+            transactions.push(tx);
+        }
+
+        // When
+        let (result, _) = context.assert_same_production(transactions);
+
+        // Then
+        context.assert_same_validation(&result.block);
+    }
+}
+
+#[test]
+fn produce__empty_block_matches_sequential_execution() {
+    let context = TestContext::new();
+
+    // Given
+    let transactions = vec![];
+
+    // When
+    let (result, _) = context.assert_same_production(transactions);
+
+    // Then
+    assert_eq!(result.block.transactions().len(), 1);
+}
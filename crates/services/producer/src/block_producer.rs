@@ -43,6 +43,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunOverrides,
             DryRunResult,
             StorageReadReplayEvent,
             UncommittedResult,
@@ -336,15 +337,24 @@ where
     /// Simulates multiple transactions without altering any state. Does not acquire the production lock.
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
+    ///
+    /// The `overrides` replace the fields of the simulated block header and are layered on top
+    /// of the state used by the simulation.
     pub async fn dry_run(
         &self,
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
-        time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        overrides: DryRunOverrides,
     ) -> anyhow::Result<DryRunResult> {
+        let DryRunOverrides {
+            block_height: simulated_height_override,
+            timestamp: time,
+            state: state_overrides,
+        } = overrides;
+
         let view = self.view_provider.latest_view()?;
         let latest_height = view.latest_height().unwrap_or_default();
 
@@ -360,7 +370,10 @@ where
                 .unwrap_or(Tai64::UNIX_EPOCH)
         });
 
-        let header = self.new_header(simulated_height, simulated_time, &view)?;
+        let mut header = self.new_header(simulated_height, simulated_time, &view)?;
+        if let Some(simulated_height) = simulated_height_override {
+            header.consensus.height = simulated_height;
+        }
 
         let gas_price = if let Some(inner) = gas_price {
             inner
@@ -384,7 +397,13 @@ where
 
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let result = tokio_rayon::spawn_fifo(move || {
-            executor.dry_run(component, utxo_validation, height, record_storage_reads)
+            executor.dry_run(
+                component,
+                utxo_validation,
+                height,
+                record_storage_reads,
+                state_overrides,
+            )
         })
         .await?;
 
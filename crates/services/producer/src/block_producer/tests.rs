@@ -598,6 +598,7 @@ mod produce_and_execute_block_txpool {
 // Tests for the `dry_run` method.
 mod dry_run {
     use super::*;
+    use fuel_core_types::services::executor::DryRunOverrides;
 
     #[tokio::test]
     async fn dry_run__executes_with_given_timestamp() {
@@ -609,7 +610,17 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None, false)
+            .dry_run(
+                vec![],
+                None,
+                None,
+                None,
+                false,
+                DryRunOverrides {
+                    timestamp: Some(simulated_block_time),
+                    ..Default::default()
+                },
+            )
             .await;
 
         // Then
@@ -630,7 +641,17 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None, false)
+            .dry_run(
+                vec![],
+                None,
+                None,
+                None,
+                false,
+                DryRunOverrides {
+                    timestamp: Some(simulated_block_time),
+                    ..Default::default()
+                },
+            )
             .await;
 
         // Then
@@ -650,7 +671,7 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, None, None, None, false)
+            .dry_run(vec![], None, None, None, false, Default::default())
             .await;
 
         // Then
@@ -677,13 +698,47 @@ mod dry_run {
 
         // When
         let result = producer
-            .dry_run(vec![], Some(SAME_HEIGHT.into()), None, None, None, false)
+            .dry_run(
+                vec![],
+                Some(SAME_HEIGHT.into()),
+                None,
+                None,
+                false,
+                Default::default(),
+            )
             .await;
 
         // Then
         assert!(result.is_ok(), "{:?}", result);
     }
 
+    #[tokio::test]
+    async fn dry_run__executes_with_overridden_block_height() {
+        // Given
+        let simulated_height = BlockHeight::from(1_000u32);
+        let executor = MockExecutorWithCapture::default();
+        let ctx = TestContext::default_from_executor(executor.clone());
+
+        // When
+        let _ = ctx
+            .producer()
+            .dry_run(
+                vec![],
+                None,
+                None,
+                None,
+                false,
+                DryRunOverrides {
+                    block_height: Some(simulated_height),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        // Then
+        assert_eq!(executor.captured_block_height(), simulated_height);
+    }
+
     impl MockExecutorWithCapture {
         fn captured_block_timestamp(&self) -> Tai64 {
             *self
@@ -695,6 +750,17 @@ mod dry_run {
                 .header_to_produce
                 .time()
         }
+
+        fn captured_block_height(&self) -> BlockHeight {
+            *self
+                .captured
+                .lock()
+                .unwrap()
+                .as_ref()
+                .expect("should have captured a block")
+                .header_to_produce
+                .height()
+        }
     }
 }
 
@@ -24,6 +24,7 @@ use fuel_core_types::{
         executor::{
             DryRunResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
             UncommittedResult,
         },
@@ -107,12 +108,14 @@ pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `forbid_fake_coins` field can be used to enable/disable the validation
     /// of utxos during execution. The `at_height` field can be used to dry run on top of a past block.
+    /// The `state_overrides` are applied on top of the state used by the dry run.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_read_replay: bool,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<DryRunResult>;
 }
 
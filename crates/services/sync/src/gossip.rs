@@ -0,0 +1,157 @@
+//! # Gossip task
+//! Validates blocks gossiped by the network and hands them over
+//! to the import task, so new blocks are imported without waiting for
+//! the next block height heartbeat.
+
+use std::sync::Arc;
+
+use fuel_core_services::{
+    SharedMutex,
+    TraceErr,
+    stream::{
+        BoxStream,
+        IntoBoxStream,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        PeerId,
+    },
+};
+use futures::stream::StreamExt;
+use tokio::sync::Notify;
+
+use crate::{
+    import::cache::Cache,
+    ports::{
+        ConsensusPort,
+        PeerReportReason,
+        PeerToPeerPort,
+    },
+    state::State,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) struct GossipedBlocks<P, C> {
+    block_stream: BoxStream<BlockGossipData>,
+    state: SharedMutex<State>,
+    notify: Arc<Notify>,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+    cache: Cache,
+}
+
+impl<P, C> GossipedBlocks<P, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        block_stream: BoxStream<BlockGossipData>,
+        state: SharedMutex<State>,
+        notify: Arc<Notify>,
+        p2p: Arc<P>,
+        consensus: Arc<C>,
+        cache: Cache,
+    ) -> Self {
+        Self {
+            block_stream,
+            state,
+            notify,
+            p2p,
+            consensus,
+            cache,
+        }
+    }
+
+    /// Waits for the next gossiped block.
+    /// This future is cancel safe.
+    pub(crate) async fn next(&mut self) -> BlockGossipData {
+        match self.block_stream.next().await {
+            Some(block) => block,
+            None => {
+                // The network doesn't gossip blocks anymore,
+                // the import continues based on the observed heights.
+                self.block_stream = futures::stream::pending().into_boxed();
+                futures::future::pending().await
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    /// Validates the gossiped block and schedules its import.
+    /// Returns `true` if the block was accepted.
+    pub(crate) async fn process(&mut self, mut gossip: BlockGossipData) -> bool {
+        let message_info = GossipsubMessageInfo {
+            message_id: gossip.message_id,
+            peer_id: gossip.peer_id.clone(),
+        };
+        let Some(block) = gossip.data.take() else {
+            return false
+        };
+        let acceptance = self.validate(&gossip.peer_id, &block).await;
+
+        let _ = self
+            .p2p
+            .notify_gossip_block_validity(message_info, acceptance)
+            .trace_err("Failed to notify p2p about the gossiped block validity");
+
+        if acceptance != GossipsubMessageAcceptance::Accept {
+            return false
+        }
+
+        let height = **block.entity.header().height();
+        self.cache.insert_block(block);
+        if self.state.apply(|s| s.observe(height)) {
+            self.notify.notify_one();
+        }
+        true
+    }
+
+    async fn validate(
+        &self,
+        peer_id: &PeerId,
+        block: &SealedBlock,
+    ) -> GossipsubMessageAcceptance {
+        let height = **block.entity.header().height();
+        if !self.state.apply(|s| s.is_uncommitted(height)) {
+            tracing::debug!("Ignoring gossiped block {height} that is already committed");
+            return GossipsubMessageAcceptance::Ignore
+        }
+
+        let header = SealedBlockHeader {
+            entity: block.entity.header().clone(),
+            consensus: block.consensus.clone(),
+        };
+        let valid_consensus = self
+            .consensus
+            .check_sealed_header(&header)
+            .trace_err("Failed to check consensus on gossiped block")
+            .unwrap_or(false);
+        let valid_transactions = header
+            .entity
+            .validate_transactions(block.entity.transactions());
+
+        if !valid_consensus || !valid_transactions {
+            tracing::warn!("Peer {peer_id} gossiped an invalid block {height}");
+            let _ = self
+                .p2p
+                .report_peer(peer_id.clone(), PeerReportReason::BadBlock)
+                .trace_err("Failed to report the peer");
+            return GossipsubMessageAcceptance::Reject
+        }
+
+        // The import waits for the DA layer to sync before executing the block,
+        // so the block can be propagated without waiting.
+        GossipsubMessageAcceptance::Accept
+    }
+}
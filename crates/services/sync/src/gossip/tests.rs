@@ -0,0 +1,115 @@
+#![allow(non_snake_case)]
+
+use fuel_core_types::blockchain::block::Block;
+use futures::stream;
+
+use crate::{
+    import::test_helpers::{
+        empty_header,
+        random_peer,
+    },
+    ports::{
+        MockConsensusPort,
+        MockPeerToPeerPort,
+    },
+};
+
+use super::*;
+
+fn gossiped_block(height: u32) -> BlockGossipData {
+    let header = empty_header(height);
+    let block = Block::try_from_executed(header.entity, vec![])
+        .expect("The header matches empty transactions");
+    let block = SealedBlock {
+        entity: block,
+        consensus: header.consensus,
+    };
+    BlockGossipData::new(block, random_peer(), vec![1, 2, 3, 4])
+}
+
+fn gossiped_blocks(
+    p2p: MockPeerToPeerPort,
+    consensus: MockConsensusPort,
+    committed: u32,
+) -> GossipedBlocks<MockPeerToPeerPort, MockConsensusPort> {
+    GossipedBlocks::new(
+        stream::pending().into_boxed(),
+        SharedMutex::new(State::new(committed, None)),
+        Arc::new(Notify::new()),
+        Arc::new(p2p),
+        Arc::new(consensus),
+        Cache::new(),
+    )
+}
+
+#[tokio::test]
+async fn process__valid_block_is_accepted_and_scheduled_for_import() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Accept)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    // The block is accepted without waiting for the DA layer.
+    consensus.expect_await_da_height().never();
+    let mut gossiped_blocks = gossiped_blocks(p2p, consensus, 4);
+
+    // when
+    let accepted = gossiped_blocks.process(gossiped_block(5)).await;
+
+    // then
+    assert!(accepted);
+    assert_eq!(
+        gossiped_blocks.state.apply(|s| s.process_range()),
+        Some(5..=5)
+    );
+}
+
+#[tokio::test]
+async fn process__block_with_invalid_consensus_is_rejected_and_peer_is_reported() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_report_peer()
+        .withf(|_, report| *report == PeerReportReason::BadBlock)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    p2p.expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Reject)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(false));
+    let mut gossiped_blocks = gossiped_blocks(p2p, consensus, 4);
+
+    // when
+    let accepted = gossiped_blocks.process(gossiped_block(5)).await;
+
+    // then
+    assert!(!accepted);
+    assert_eq!(gossiped_blocks.state.apply(|s| s.process_range()), None);
+}
+
+#[tokio::test]
+async fn process__already_committed_block_is_ignored() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Ignore)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let consensus = MockConsensusPort::default();
+    let mut gossiped_blocks = gossiped_blocks(p2p, consensus, 4);
+
+    // when
+    let accepted = gossiped_blocks.process(gossiped_block(4)).await;
+
+    // then
+    assert!(!accepted);
+    assert_eq!(gossiped_blocks.state.apply(|s| s.process_range()), None);
+}
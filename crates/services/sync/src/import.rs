@@ -17,6 +17,7 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
         block::Block,
+        primitives::DaBlockHeight,
     },
     fuel_types::BlockHeight,
     services::p2p::{
@@ -59,7 +60,7 @@ use crate::{
     state::State,
 };
 
-mod cache;
+pub(crate) mod cache;
 
 #[cfg(any(test, feature = "benchmarking"))]
 /// Accessories for testing the sync. Available only when compiling under test
@@ -144,10 +145,15 @@ impl<P, E, C> Import<P, E, C> {
     pub fn notify_one(&self) {
         self.notify.notify_one()
     }
+
+    /// The cache of already validated headers and blocks used by the import.
+    pub(crate) fn cache(&self) -> Cache {
+        self.cache.clone()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Batch<T> {
+pub(crate) struct Batch<T> {
     peer: Option<PeerId>,
     range: Range<u32>,
     results: Vec<T>,
@@ -463,7 +469,14 @@ fn get_block_stream<
                 let mut cache = cache.clone();
                 async move {
                     match headers.await {
-                        BlockHeaderData::Cached(CachedDataBatch::Blocks(batch)) => batch,
+                        BlockHeaderData::Cached(CachedDataBatch::Blocks(batch)) => {
+                            // The gossiped blocks are cached without waiting for the DA layer.
+                            if let Some(block) = batch.results.last() {
+                                await_da_height(&block.entity.header().da_height(), &consensus)
+                                    .await;
+                            }
+                            batch
+                        }
                         BlockHeaderData::Cached(CachedDataBatch::Headers(batch))
                         | BlockHeaderData::Fetched(batch) => {
                             let Batch {
@@ -474,13 +487,11 @@ fn get_block_stream<
                             if results.is_empty() {
                                 SealedBlockBatch::new(peer, range, vec![])
                             } else {
-                                await_da_height(
-                                    results
-                                        .last()
-                                        .expect("We checked headers are not empty above"),
-                                    &consensus,
-                                )
-                                .await;
+                                let last_header = results
+                                    .last()
+                                    .expect("We checked headers are not empty above");
+                                await_da_height(&last_header.entity.da_height(), &consensus)
+                                    .await;
                                 let headers =
                                     SealedHeaderBatch::new(peer, range.clone(), results);
                                 let batch = get_blocks(&p2p, headers).await;
@@ -522,11 +533,11 @@ fn check_sealed_header<
 }
 
 async fn await_da_height<C: ConsensusPort + Send + Sync + 'static>(
-    header: &SealedBlockHeader,
+    da_height: &DaBlockHeight,
     consensus: &Arc<C>,
 ) {
     let _ = consensus
-        .await_da_height(&header.entity.da_height())
+        .await_da_height(da_height)
         .await
         .trace_err("Failed to wait for DA layer to sync");
 }
@@ -55,6 +55,11 @@ impl Cache {
         }
     }
 
+    pub fn insert_block(&mut self, block: SealedBlock) {
+        let mut lock = self.0.lock();
+        lock.insert(**block.entity.header().height(), CachedData::Block(block));
+    }
+
     pub fn insert_headers(&mut self, batch: Batch<SealedBlockHeader>) {
         let mut lock = self.0.lock();
         for header in batch.results {
@@ -10,13 +10,22 @@ use crate::{
         PeerToPeerPort,
     },
 };
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
         Transactions,
     },
 };
@@ -73,6 +82,19 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_transactions(block_ids).await
     }
 
+    async fn get_state_checkpoint(
+        &self,
+    ) -> anyhow::Result<SourcePeer<Option<StateCheckpoint>>> {
+        self.p2p.get_state_checkpoint().await
+    }
+
+    async fn get_state_chunk_from_peer(
+        &self,
+        request: SourcePeer<StateChunkRequest>,
+    ) -> anyhow::Result<Option<Vec<StateEntry>>> {
+        self.p2p.get_state_chunk_from_peer(request).await
+    }
+
     fn report_peer(
         &self,
         _peer: PeerId,
@@ -80,6 +102,18 @@ impl PeerToPeerPort for PressurePeerToPeer {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        futures::stream::pending().into_boxed()
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl PressurePeerToPeer {
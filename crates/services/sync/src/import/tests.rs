@@ -261,10 +261,11 @@ async fn import__keep_data_asked_in_fail_ask_header_cases() {
         .expect_check_sealed_header()
         .times(3)
         .returning(|_| Ok(true));
-    // No reask on da height on all of the blocks
+    // The fetched blocks wait for the DA height once,
+    // and the cached blocks wait for it again on the retry.
     consensus_port
         .expect_await_da_height()
-        .times(3)
+        .times(5)
         .returning(|_| Box::pin(async move { Ok(()) }));
 
     let mut p2p = MockPeerToPeerPort::default();
@@ -364,10 +365,11 @@ async fn import__keep_data_asked_in_fail_ask_transactions_cases() {
         .expect_check_sealed_header()
         .times(3)
         .returning(|_| Ok(true));
-    // One reask on the da_height after reask of the transactions for block 4
+    // One reask on the da_height after reask of the transactions for block 4,
+    // and the cached blocks wait for it again on the retry.
     consensus_port
         .expect_await_da_height()
-        .times(4)
+        .times(6)
         .returning(|_| Box::pin(async move { Ok(()) }));
 
     let mut p2p = MockPeerToPeerPort::default();
@@ -470,10 +472,11 @@ async fn import__keep_data_asked_in_fail_execution() {
         .expect_check_sealed_header()
         .times(4)
         .returning(|_| Ok(true));
-    // Data is re-ask for the block 4 because his execution failed
+    // Data is re-ask for the block 4 because his execution failed,
+    // and the cached blocks wait for the DA height again on the retry.
     consensus_port
         .expect_await_da_height()
-        .times(4)
+        .times(6)
         .returning(|_| Box::pin(async move { Ok(()) }));
 
     let mut p2p = MockPeerToPeerPort::default();
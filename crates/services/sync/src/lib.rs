@@ -8,10 +8,12 @@
 //! # Sync Service
 //! Responsible for syncing the blockchain from the network.
 
+mod gossip;
 pub mod import;
 pub mod ports;
 pub mod service;
 pub mod state;
+pub mod state_sync;
 pub mod sync;
 
 pub use import::Config;
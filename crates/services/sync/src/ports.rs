@@ -9,8 +9,15 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
+        StateSyncTable,
         Transactions,
     },
 };
@@ -32,6 +39,10 @@ pub enum PeerReportReason {
     MissingTransactions,
     /// Received invalid transactions
     InvalidTransactions,
+    /// Report a peer for gossiping a block that failed validation
+    BadBlock,
+    /// Report a peer for serving a state chunk that doesn't match the checkpoint
+    InvalidStateChunk,
 }
 
 #[async_trait::async_trait]
@@ -60,8 +71,29 @@ pub trait PeerToPeerPort {
         block_ids: SourcePeer<Range<u32>>,
     ) -> anyhow::Result<Option<Vec<Transactions>>>;
 
+    /// Request the latest state checkpoint from the network.
+    async fn get_state_checkpoint(
+        &self,
+    ) -> anyhow::Result<SourcePeer<Option<StateCheckpoint>>>;
+
+    /// Request the chunk of the state checkpoint from the source peer.
+    async fn get_state_chunk_from_peer(
+        &self,
+        request: SourcePeer<StateChunkRequest>,
+    ) -> anyhow::Result<Option<Vec<StateEntry>>>;
+
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+
+    /// Stream of sealed blocks gossiped by the network.
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData>;
+
+    /// Report the validity of a block received from the network.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -89,3 +121,25 @@ pub trait BlockImporterPort {
         block: SealedBlock,
     ) -> impl core::future::Future<Output = anyhow::Result<()>> + Send;
 }
+
+#[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
+/// Port for importing the state synchronized from the network.
+pub trait StateImporterPort {
+    /// Returns `true` if the node doesn't have blocks after the genesis,
+    /// so its state can be replaced with the state of the checkpoint.
+    fn should_sync(&self) -> anyhow::Result<bool>;
+
+    /// Removes all entries of the table.
+    fn clear_table(&self, table: StateSyncTable) -> anyhow::Result<()>;
+
+    /// Inserts the entries of the chunk into the table.
+    fn import_chunk(
+        &self,
+        table: StateSyncTable,
+        entries: Vec<StateEntry>,
+    ) -> anyhow::Result<()>;
+
+    /// Commits the block of the checkpoint, so the node continues
+    /// to sync blocks from its height.
+    fn finalize(&self, block: SealedBlock) -> anyhow::Result<()>;
+}
@@ -2,6 +2,7 @@
 use std::sync::Arc;
 
 use crate::{
+    gossip::GossipedBlocks,
     import::{
         Config,
         Import,
@@ -11,8 +12,13 @@ use crate::{
         BlockImporterPort,
         ConsensusPort,
         PeerToPeerPort,
+        StateImporterPort,
     },
     state::State,
+    state_sync::{
+        self,
+        StateSync,
+    },
     sync::SyncHeights,
 };
 
@@ -29,8 +35,14 @@ use fuel_core_services::{
         IntoBoxStream,
     },
 };
-use fuel_core_types::fuel_types::BlockHeight;
-use futures::StreamExt;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::BlockGossipData,
+};
+use futures::{
+    StreamExt,
+    future::BoxFuture,
+};
 use tokio::sync::Notify;
 
 #[cfg(test)]
@@ -50,20 +62,66 @@ where
     C: ports::ConsensusPort + Send + Sync + 'static,
 {
     let height_stream = p2p.height_stream();
+    let gossiped_block_stream = p2p.gossiped_block_stream();
+    let committed_height_stream = executor.committed_height_stream();
+    let state = State::new(Some(current_fuel_block_height.into()), None);
+    Ok(ServiceRunner::new(SyncTask::new(
+        height_stream,
+        committed_height_stream,
+        gossiped_block_stream,
+        state,
+        params,
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus),
+        None,
+    )?))
+}
+
+/// Creates an instance of runnable sync service that imports the state
+/// checkpoint from the peers before syncing the blocks.
+#[allow(clippy::too_many_arguments)]
+pub fn new_service_with_state_sync<P, E, C, S>(
+    current_fuel_block_height: BlockHeight,
+    p2p: P,
+    executor: E,
+    consensus: C,
+    state_importer: S,
+    params: Config,
+    state_sync_params: state_sync::Config,
+) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
+where
+    P: ports::PeerToPeerPort + Send + Sync + 'static,
+    E: ports::BlockImporterPort + Send + Sync + 'static,
+    C: ports::ConsensusPort + Send + Sync + 'static,
+    S: StateImporterPort + Send + Sync + 'static,
+{
+    let height_stream = p2p.height_stream();
+    let gossiped_block_stream = p2p.gossiped_block_stream();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
+    let p2p = Arc::new(p2p);
+    let consensus = Arc::new(consensus);
+    let state_sync = StateSync::new(
+        state_sync_params,
+        p2p.clone(),
+        consensus.clone(),
+        state_importer,
+    );
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
+        gossiped_block_stream,
         state,
         params,
         p2p,
-        executor,
+        Arc::new(executor),
         consensus,
+        Some(Box::pin(state_sync.sync())),
     )?))
 }
 
-/// Task for syncing heights.
+/// Task for syncing heights and validating gossiped blocks.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
 where
@@ -72,7 +130,11 @@ where
     C: ConsensusPort + Send + Sync + 'static,
 {
     sync_heights: SyncHeights,
+    gossiped_blocks: GossipedBlocks<P, C>,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    state: SharedMutex<State>,
+    /// Imports the state checkpoint before the import task is started.
+    state_sync: Option<BoxFuture<'static, anyhow::Result<Option<BlockHeight>>>>,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -83,31 +145,49 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
+        gossiped_block_stream: BoxStream<BlockGossipData>,
         state: State,
         params: Config,
-        p2p: P,
-        executor: E,
-        consensus: C,
+        p2p: Arc<P>,
+        executor: Arc<E>,
+        consensus: Arc<C>,
+        state_sync: Option<BoxFuture<'static, anyhow::Result<Option<BlockHeight>>>>,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
-        let p2p = Arc::new(p2p);
-        let executor = Arc::new(executor);
-        let consensus = Arc::new(consensus);
         let sync_heights = SyncHeights::new(
             height_stream,
             committed_height_stream,
             state.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let import = Import::new(
+            state.clone(),
+            notify.clone(),
+            params,
+            p2p.clone(),
+            executor,
+            consensus.clone(),
+        );
+        let gossiped_blocks = GossipedBlocks::new(
+            gossiped_block_stream,
+            state.clone(),
+            notify,
+            p2p,
+            consensus,
+            import.cache(),
+        );
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
+            gossiped_blocks,
             import_task_handle,
+            state,
+            state_sync,
         })
     }
 }
@@ -119,9 +199,16 @@ where
     C: ConsensusPort + Send + Sync + 'static,
 {
     async fn run(&mut self, _: &mut StateWatcher) -> TaskNextAction {
-        match self.sync_heights.sync().await {
-            None => TaskNextAction::Stop,
-            Some(_) => TaskNextAction::Continue,
+        tokio::select! {
+            biased;
+            sync = self.sync_heights.sync() => match sync {
+                None => TaskNextAction::Stop,
+                Some(_) => TaskNextAction::Continue,
+            },
+            gossip = self.gossiped_blocks.next() => {
+                self.gossiped_blocks.process(gossip).await;
+                TaskNextAction::Continue
+            }
         }
     }
 
@@ -152,6 +239,32 @@ where
         watcher: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        if let Some(state_sync) = self.state_sync.take() {
+            let mut stop_watcher = watcher.clone();
+            let stopped = async move {
+                loop {
+                    let state = stop_watcher.borrow_and_update().clone();
+                    if state.stopping() || state.stopped() {
+                        break
+                    }
+                    if stop_watcher.changed().await.is_err() {
+                        break
+                    }
+                }
+            };
+            tokio::select! {
+                synced = state_sync => {
+                    if let Some(height) = synced? {
+                        self.state.apply(|s| s.commit(*height));
+                    }
+                }
+                _ = stopped => {
+                    tracing::info!("The service is stopped during the state sync");
+                    return Ok(self)
+                }
+            }
+        }
+
         let mut sync_watcher = watcher.clone();
         self.import_task_handle.start_and_await().await?;
         let mut import_watcher = self.import_task_handle.state_watcher();
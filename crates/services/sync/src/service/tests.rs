@@ -38,6 +38,8 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
+    p2p.expect_gossiped_block_stream()
+        .returning(|| stream::pending().into_boxed());
     p2p.expect_get_sealed_block_headers().returning(|range| {
         Box::pin(async move {
             let peer = random_peer();
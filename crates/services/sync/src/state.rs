@@ -145,6 +145,15 @@ impl State {
         status_change
     }
 
+    /// Returns `true` if the block at this height is not committed yet.
+    pub fn is_uncommitted(&self, height: u32) -> bool {
+        match &self.status {
+            Status::Uninitialized => true,
+            Status::Processing(range) => height >= *range.start(),
+            Status::Committed(committed) => height > *committed,
+        }
+    }
+
     #[tracing::instrument]
     /// Record that a range of blocks have failed to process.
     pub fn failed_to_process(&mut self, range: RangeInclusive<u32>) {
@@ -0,0 +1,259 @@
+//! # State sync
+//! Bootstraps the node from the state checkpoint served by the peers
+//! instead of executing all blocks since the genesis.
+//!
+//! The block header doesn't commit to the state, so the checkpoint can't be
+//! verified against the chain alone. Instead, the operator provides the root of
+//! the checkpoint obtained from a trusted node. The node accepts only the checkpoint
+//! with this root, and the root commits to the header and to the roots of all chunks,
+//! so each chunk is verified against it. The number of peers serving the same
+//! checkpoint is irrelevant, so the sync can't be hijacked by sybil peers.
+//!
+//! If no peer serves the trusted checkpoint in time, the node falls back
+//! to the sync of the blocks.
+
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+    services::p2p::{
+        PeerId,
+        SourcePeer,
+        StateCheckpoint,
+        StateChunkRequest,
+        StateEntry,
+        StateSyncTable,
+        state_chunk_root,
+    },
+};
+
+use crate::ports::{
+    ConsensusPort,
+    PeerReportReason,
+    PeerToPeerPort,
+    StateImporterPort,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Debug)]
+/// Parameters for the state sync.
+pub struct Config {
+    /// The [`StateCheckpoint::root`] of the checkpoint to sync.
+    pub trusted_root: Bytes32,
+    /// The time to find the peers serving the trusted checkpoint.
+    /// After it, the node falls back to the sync of the blocks.
+    pub timeout: Duration,
+    /// The number of attempts to fetch one chunk before the sync fails.
+    pub max_chunk_attempts: usize,
+    /// The delay between the requests of the checkpoint.
+    pub retry_interval: Duration,
+}
+
+impl Config {
+    /// Creates the parameters to sync the checkpoint with the `trusted_root`.
+    pub fn new(trusted_root: Bytes32) -> Self {
+        Self {
+            trusted_root,
+            timeout: Duration::from_secs(60),
+            max_chunk_attempts: 10,
+            retry_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Downloads the state checkpoint from the peers and imports it.
+pub struct StateSync<P, C, S> {
+    config: Config,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+    state_importer: S,
+}
+
+impl<P, C, S> StateSync<P, C, S>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+    S: StateImporterPort + Send + Sync + 'static,
+{
+    /// Creates a new state sync.
+    pub fn new(
+        config: Config,
+        p2p: Arc<P>,
+        consensus: Arc<C>,
+        state_importer: S,
+    ) -> Self {
+        Self {
+            config,
+            p2p,
+            consensus,
+            state_importer,
+        }
+    }
+
+    /// Imports the trusted state checkpoint.
+    /// Returns the height of the imported checkpoint, or `None` if the node
+    /// already has blocks after the genesis or no peer serves the checkpoint in time.
+    pub async fn sync(self) -> anyhow::Result<Option<BlockHeight>> {
+        if !self.state_importer.should_sync()? {
+            return Ok(None);
+        }
+
+        let Ok((checkpoint, peer_id)) =
+            tokio::time::timeout(self.config.timeout, self.trusted_checkpoint()).await
+        else {
+            tracing::warn!(
+                "No peer served the state checkpoint with the root {} in {:?}, \
+                falling back to the sync of the blocks",
+                self.config.trusted_root,
+                self.config.timeout
+            );
+            return Ok(None);
+        };
+        let height = checkpoint.height();
+        tracing::info!("Syncing the state checkpoint at {height}");
+        anyhow::ensure!(
+            self.consensus.check_sealed_header(&checkpoint.header)?,
+            "The header of the state checkpoint at {height} has invalid consensus"
+        );
+
+        let mut peers = vec![peer_id];
+        for table in StateSyncTable::ALL {
+            // The table contains the state of the genesis or of the interrupted sync.
+            self.state_importer.clear_table(table)?;
+            for (index, root) in checkpoint.chunk_roots(table).iter().enumerate() {
+                let request = StateChunkRequest {
+                    height,
+                    table,
+                    index: u32::try_from(index)?,
+                };
+                let entries = self.fetch_chunk(&mut peers, request, root).await?;
+                self.state_importer.import_chunk(table, entries)?;
+            }
+            tracing::debug!("Synced the `{table:?}` table");
+        }
+
+        let peer = peers
+            .first()
+            .expect("The peer that served the last chunk is kept; qed")
+            .clone();
+        let block = self.fetch_block(peer, checkpoint).await?;
+        self.state_importer.finalize(block)?;
+        tracing::info!("Synced the state checkpoint at {height}");
+
+        Ok(Some(height))
+    }
+
+    /// Requests the checkpoints from the random peers until one of them
+    /// serves the trusted checkpoint. The peers serving other checkpoints are ignored,
+    /// because honest peers may already serve a newer checkpoint.
+    async fn trusted_checkpoint(&self) -> (StateCheckpoint, PeerId) {
+        loop {
+            match self.p2p.get_state_checkpoint().await {
+                Ok(SourcePeer {
+                    peer_id,
+                    data: Some(checkpoint),
+                }) if checkpoint.root() == self.config.trusted_root => {
+                    return (checkpoint, peer_id)
+                }
+                response => {
+                    tracing::debug!(
+                        "Failed to get the trusted state checkpoint: {response:?}"
+                    );
+                    tokio::time::sleep(self.config.retry_interval).await;
+                }
+            }
+        }
+    }
+
+    /// Fetches the chunk from the `peers` serving the trusted checkpoint.
+    /// The peers serving invalid chunks are removed, and new peers are found
+    /// when none is left.
+    async fn fetch_chunk(
+        &self,
+        peers: &mut Vec<PeerId>,
+        request: StateChunkRequest,
+        root: &Bytes32,
+    ) -> anyhow::Result<Vec<StateEntry>> {
+        for _ in 0..self.config.max_chunk_attempts {
+            if peers.is_empty() {
+                let (_, peer_id) =
+                    tokio::time::timeout(self.config.timeout, self.trusted_checkpoint())
+                        .await
+                        .map_err(|_| {
+                            anyhow::anyhow!(
+                                "No peer serves the state checkpoint with the root {}",
+                                self.config.trusted_root
+                            )
+                        })?;
+                peers.push(peer_id);
+            }
+            peers.rotate_left(1);
+            let peer_id = peers.last().expect("Checked above; qed").clone();
+            let response = self
+                .p2p
+                .get_state_chunk_from_peer(peer_id.clone().bind(request))
+                .await;
+            match response {
+                Ok(Some(entries)) if state_chunk_root(&entries) == *root => {
+                    return Ok(entries)
+                }
+                Ok(Some(_)) => {
+                    tracing::warn!("Peer {peer_id} served an invalid chunk {request:?}");
+                    let _ = self.p2p.report_peer(
+                        peer_id.clone(),
+                        PeerReportReason::InvalidStateChunk,
+                    );
+                    peers.retain(|peer| *peer != peer_id);
+                }
+                response => {
+                    tracing::debug!(
+                        "Failed to get the chunk {request:?} from {peer_id}: {response:?}"
+                    );
+                }
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Failed to fetch the chunk {request:?} after {} attempts",
+            self.config.max_chunk_attempts
+        ))
+    }
+
+    async fn fetch_block(
+        &self,
+        peer_id: PeerId,
+        checkpoint: StateCheckpoint,
+    ) -> anyhow::Result<SealedBlock> {
+        let height = u32::from(checkpoint.height());
+        let range = height..height.saturating_add(1);
+        let transactions = self
+            .p2p
+            .get_transactions_from_peer(peer_id.bind(range))
+            .await?
+            .and_then(|transactions| transactions.into_iter().next())
+            .ok_or_else(|| {
+                anyhow::anyhow!("Failed to get the transactions of the block at {height}")
+            })?;
+
+        let header = checkpoint.header;
+        let block =
+            Block::try_from_executed(header.entity, transactions.0).ok_or_else(|| {
+                anyhow::anyhow!("The transactions don't match the block at {height}")
+            })?;
+        Ok(SealedBlock {
+            entity: block,
+            consensus: header.consensus,
+        })
+    }
+}
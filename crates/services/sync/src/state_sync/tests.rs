@@ -0,0 +1,264 @@
+#![allow(non_snake_case)]
+
+use fuel_core_types::services::p2p::{
+    TableCheckpoint,
+    Transactions,
+};
+use std::sync::Mutex;
+
+use crate::{
+    import::test_helpers::empty_header,
+    ports::{
+        MockConsensusPort,
+        MockPeerToPeerPort,
+        MockStateImporterPort,
+    },
+};
+
+use super::*;
+
+const HEIGHT: u32 = 5;
+
+fn peer(i: u8) -> PeerId {
+    PeerId::from(vec![i; 32])
+}
+
+fn coins() -> Vec<StateEntry> {
+    vec![(vec![1], vec![10]), (vec![2], vec![20])]
+}
+
+fn checkpoint() -> StateCheckpoint {
+    StateCheckpoint {
+        header: empty_header(HEIGHT),
+        tables: vec![TableCheckpoint {
+            table: StateSyncTable::Coins,
+            chunk_roots: vec![state_chunk_root(&coins())],
+        }],
+    }
+}
+
+fn config() -> Config {
+    Config {
+        trusted_root: checkpoint().root(),
+        timeout: Duration::from_secs(1),
+        max_chunk_attempts: 3,
+        retry_interval: Duration::from_millis(1),
+    }
+}
+
+/// Each call returns the checkpoint from the next peer,
+/// and no checkpoint once the peers are exhausted.
+fn p2p_with_checkpoints(
+    checkpoints: Vec<(PeerId, Option<StateCheckpoint>)>,
+) -> MockPeerToPeerPort {
+    let checkpoints = Mutex::new(checkpoints.into_iter());
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_state_checkpoint().returning(move || {
+        let (peer_id, checkpoint) = checkpoints
+            .lock()
+            .unwrap()
+            .next()
+            .unwrap_or((peer(0), None));
+        Box::pin(async move { Ok(peer_id.bind(checkpoint)) })
+    });
+    p2p.expect_get_transactions_from_peer()
+        .returning(|_| Box::pin(async { Ok(Some(vec![Transactions::default()])) }));
+    p2p
+}
+
+fn consensus() -> MockConsensusPort {
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus
+}
+
+fn state_importer(imported: Arc<Mutex<Vec<StateEntry>>>) -> MockStateImporterPort {
+    let mut state_importer = MockStateImporterPort::default();
+    state_importer.expect_should_sync().returning(|| Ok(true));
+    state_importer
+        .expect_clear_table()
+        .times(StateSyncTable::ALL.len())
+        .returning(|_| Ok(()));
+    state_importer
+        .expect_import_chunk()
+        .returning(move |_, entries| {
+            imported.lock().unwrap().extend(entries);
+            Ok(())
+        });
+    state_importer
+        .expect_finalize()
+        .withf(|block| *block.entity.header().height() == HEIGHT.into())
+        .times(1)
+        .returning(|_| Ok(()));
+    state_importer
+}
+
+#[tokio::test]
+async fn sync__does_nothing_if_node_has_blocks() {
+    // given
+    let mut state_importer = MockStateImporterPort::default();
+    state_importer.expect_should_sync().returning(|| Ok(false));
+    let state_sync = StateSync::new(
+        config(),
+        Arc::new(MockPeerToPeerPort::default()),
+        Arc::new(MockConsensusPort::default()),
+        state_importer,
+    );
+
+    // when
+    let synced = state_sync.sync().await.unwrap();
+
+    // then
+    assert_eq!(synced, None);
+}
+
+#[tokio::test]
+async fn sync__imports_trusted_checkpoint() {
+    // given
+    let mut other_checkpoint = checkpoint();
+    other_checkpoint.tables[0].chunk_roots = vec![Bytes32::zeroed()];
+    let mut p2p = p2p_with_checkpoints(vec![
+        (peer(1), Some(other_checkpoint)),
+        (peer(2), None),
+        (peer(3), Some(checkpoint())),
+    ]);
+    p2p.expect_get_state_chunk_from_peer()
+        .withf(|request| {
+            request.peer_id == peer(3)
+                && request.data.table == StateSyncTable::Coins
+                && request.data.index == 0
+        })
+        .times(1)
+        .returning(|_| Box::pin(async { Ok(Some(coins())) }));
+    let imported = Arc::new(Mutex::new(vec![]));
+    let state_sync = StateSync::new(
+        config(),
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        state_importer(imported.clone()),
+    );
+
+    // when
+    let synced = state_sync.sync().await.unwrap();
+
+    // then
+    assert_eq!(synced, Some(HEIGHT.into()));
+    assert_eq!(*imported.lock().unwrap(), coins());
+}
+
+#[tokio::test]
+async fn sync__reports_peer_with_invalid_chunk_and_retries() {
+    // given
+    let mut p2p = p2p_with_checkpoints(vec![
+        (peer(1), Some(checkpoint())),
+        (peer(2), Some(checkpoint())),
+    ]);
+    p2p.expect_get_state_chunk_from_peer()
+        .withf(|request| request.peer_id == peer(1))
+        .returning(|_| Box::pin(async { Ok(Some(vec![(vec![1], vec![11])])) }));
+    p2p.expect_get_state_chunk_from_peer()
+        .withf(|request| request.peer_id == peer(2))
+        .returning(|_| Box::pin(async { Ok(Some(coins())) }));
+    p2p.expect_report_peer()
+        .withf(|peer_id, report| {
+            *peer_id == peer(1) && *report == PeerReportReason::InvalidStateChunk
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let imported = Arc::new(Mutex::new(vec![]));
+    let state_sync = StateSync::new(
+        config(),
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        state_importer(imported.clone()),
+    );
+
+    // when
+    let synced = state_sync.sync().await.unwrap();
+
+    // then
+    assert_eq!(synced, Some(HEIGHT.into()));
+    assert_eq!(*imported.lock().unwrap(), coins());
+}
+
+#[tokio::test]
+async fn sync__fails_if_checkpoint_has_invalid_consensus() {
+    // given
+    let p2p = p2p_with_checkpoints(vec![
+        (peer(1), Some(checkpoint())),
+        (peer(2), Some(checkpoint())),
+    ]);
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(false));
+    let mut state_importer = MockStateImporterPort::default();
+    state_importer.expect_should_sync().returning(|| Ok(true));
+    state_importer.expect_clear_table().never();
+    let state_sync =
+        StateSync::new(config(), Arc::new(p2p), Arc::new(consensus), state_importer);
+
+    // when
+    let result = state_sync.sync().await;
+
+    // then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn sync__ignores_checkpoints_agreed_by_untrusted_peers() {
+    // given
+    let mut other_checkpoint = checkpoint();
+    other_checkpoint.tables[0].chunk_roots = vec![Bytes32::zeroed()];
+    let mut p2p = p2p_with_checkpoints(
+        (1..=10)
+            .map(|i| (peer(i), Some(other_checkpoint.clone())))
+            .collect(),
+    );
+    p2p.expect_get_state_chunk_from_peer().never();
+    let mut state_importer = MockStateImporterPort::default();
+    state_importer.expect_should_sync().returning(|| Ok(true));
+    state_importer.expect_clear_table().never();
+    let state_sync = StateSync::new(
+        Config {
+            timeout: Duration::from_millis(100),
+            ..config()
+        },
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        state_importer,
+    );
+
+    // when
+    let synced = state_sync.sync().await.unwrap();
+
+    // then
+    assert_eq!(synced, None);
+}
+
+#[tokio::test]
+async fn sync__falls_back_to_blocks_sync_if_no_peer_serves_checkpoint() {
+    // given
+    let p2p = p2p_with_checkpoints(vec![]);
+    let mut state_importer = MockStateImporterPort::default();
+    state_importer.expect_should_sync().returning(|| Ok(true));
+    state_importer.expect_clear_table().never();
+    state_importer.expect_finalize().never();
+    let state_sync = StateSync::new(
+        Config {
+            timeout: Duration::from_millis(100),
+            ..config()
+        },
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        state_importer,
+    );
+
+    // when
+    let synced = state_sync.sync().await.unwrap();
+
+    // then
+    assert_eq!(synced, None);
+}
@@ -276,6 +276,10 @@ mod tests {
         )
     }
 
+    fn replaced() -> TransactionStatus {
+        TransactionStatus::replaced(Default::default(), "cheaper tx".to_string())
+    }
+
     fn failure() -> TransactionStatus {
         TransactionStatus::Failure(Default::default())
     }
@@ -289,6 +293,7 @@ mod tests {
     #[test_case(preconfirmation_success() => true)]
     #[test_case(squeezed_out() => true)]
     #[test_case(preconfirmation_squeezed_out() => true)]
+    #[test_case(replaced() => true)]
     #[test_case(failure() => true)]
     #[test_case(preconfirmation_failure() => true)]
     fn is_prunable(status: TransactionStatus) -> bool {
@@ -47,6 +47,7 @@ pub fn transaction_status_strategy() -> impl Strategy<Value = TransactionStatus>
         Just(TransactionStatus::PreConfirmationSqueezedOut(
             Default::default()
         )),
+        Just(TransactionStatus::Replaced(Default::default())),
     ]
 }
 
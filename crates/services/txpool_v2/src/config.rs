@@ -1,5 +1,6 @@
 use std::{
     collections::HashSet,
+    num::NonZeroUsize,
     time::Duration,
 };
 
@@ -28,6 +29,28 @@ use fuel_core_types::{
 
 use crate::error::BlacklistedError;
 
+/// A single entry of the [`BlackList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackListEntry {
+    /// Blacklisted address.
+    Owner(Address),
+    /// Blacklisted UTXO id.
+    Coin(UtxoId),
+    /// Blacklisted message by `Nonce`.
+    Message(Nonce),
+    /// Blacklisted contract.
+    Contract(ContractId),
+}
+
+/// The modification of the [`BlackList`] applied at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlackListUpdate {
+    /// Adds entries to the blacklist and evicts transactions that use them.
+    Add(Vec<BlackListEntry>),
+    /// Removes entries from the blacklist.
+    Remove(Vec<BlackListEntry>),
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct BlackList {
     /// Blacklisted addresses.
@@ -56,6 +79,28 @@ impl BlackList {
         }
     }
 
+    /// Adds the `entry` to the blacklist.
+    /// Returns `false` if the entry was already blacklisted.
+    pub fn insert(&mut self, entry: BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.insert(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.insert(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.insert(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.insert(contract_id),
+        }
+    }
+
+    /// Removes the `entry` from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    pub fn remove(&mut self, entry: &BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.remove(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.remove(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.remove(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.remove(contract_id),
+        }
+    }
+
     /// Check if the transaction has blacklisted inputs.
     pub fn check_blacklisting(
         &self,
@@ -142,10 +187,33 @@ pub struct Config {
     pub pending_pool_tx_ttl: Duration,
     /// Maximum percentage of the pool size to be used for the pending pool.
     pub max_pending_pool_size_percentage: u16,
+    /// The algorithm used to select transactions for the block.
+    pub selection_algorithm: SelectionAlgorithmConfig,
+    /// Minimum percentage by which the tip of a replacement transaction must exceed
+    /// the tip of each transaction it replaces.
+    pub min_replacement_tip_bump_percentage: u16,
     /// Enable metrics when set to true
     pub metrics: bool,
 }
 
+/// The policy used by the `TxPool` to select transactions for the block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionAlgorithmConfig {
+    /// Transactions with the highest tip/gas ratio are selected first.
+    #[default]
+    RatioTipGas,
+    /// Transactions are selected in the order they arrived in the pool.
+    Fifo,
+    /// Transactions with the highest absolute tip are selected first.
+    TipFirst,
+    /// Transactions are selected by tip/gas ratio, but one owner can't get
+    /// more than `max_txs_per_owner` transactions into the block.
+    Fair {
+        /// Maximum number of transactions per owner in one block.
+        max_txs_per_owner: NonZeroUsize,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub struct PoolLimits {
     /// Maximum number of transactions in the pool.
@@ -204,6 +272,8 @@ impl Default for Config {
             },
             pending_pool_tx_ttl: Duration::from_secs(3),
             max_pending_pool_size_percentage: 50,
+            selection_algorithm: SelectionAlgorithmConfig::default(),
+            min_replacement_tip_bump_percentage: 10,
             metrics: false,
         }
     }
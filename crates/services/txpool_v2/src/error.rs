@@ -80,10 +80,18 @@ pub enum RemovedReason {
         fmt = "Transaction was removed because it was less worth than a new one (id: {_0}) that has been inserted"
     )]
     LessWorth(TxId),
+    #[display(
+        fmt = "Transaction was replaced by a new one (id: {_0}) that spends the same input and pays a higher tip"
+    )]
+    Replaced(TxId),
     #[display(
         fmt = "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
     )]
     Ttl,
+    #[display(
+        fmt = "Transaction was removed because its input was added to the blacklist: {_0}"
+    )]
+    Blacklisted(BlacklistedError),
 }
 
 #[derive(Clone, Debug, derive_more::Display)]
@@ -198,6 +206,28 @@ pub enum CollisionReason {
         fmt = "This transaction have dependencies and is colliding with multiple transactions"
     )]
     MultipleCollisions,
+    #[display(
+        fmt = "The tip {provided} is too low to replace the transaction (id: {replaced}), \
+        it should be at least {required}"
+    )]
+    InsufficientTipBump {
+        /// The id of the transaction that was about to be replaced.
+        replaced: TxId,
+        /// The minimal tip required to replace the transaction.
+        required: Word,
+        /// The tip of the replacement transaction.
+        provided: Word,
+    },
+    #[display(
+        fmt = "The tip {provided} doesn't cover the tips of the transactions it evicts, \
+        it should be at least {required}"
+    )]
+    EvictedTipsNotCovered {
+        /// The sum of the tips of the evicted transactions and their dependents.
+        required: Word,
+        /// The tip of the replacement transaction.
+        provided: Word,
+    },
 }
 
 impl From<CheckError> for Error {
@@ -28,6 +28,7 @@ use fuel_core_types::{
 use tokio::sync::mpsc::Sender;
 
 use crate::{
+    config::BlackList,
     error::{
         Error,
         InputValidationError,
@@ -47,6 +48,7 @@ use crate::{
 // Deletion rules:
 // - If the transaction missing inputs hasn't been known for a certain amount of time.
 // - If the transaction missing inputs becomes known.
+// - If the transaction uses the blacklisted inputs.
 pub(crate) struct PendingPool {
     ttl: Duration,
     pending_txs_by_inputs: HashMap<MissingInput, HashSet<TxId>>,
@@ -191,21 +193,8 @@ impl PendingPool {
                 tx,
                 insertion_source,
                 missing_inputs,
-            }) = self.pending_inputs_by_tx.remove(&tx_id)
+            }) = self.remove_transaction(&tx_id)
             {
-                self.decrease_pool_size(&tx);
-                for input in &missing_inputs {
-                    // Remove tx_id from the list of unresolved transactions for this input
-                    // If the list becomes empty, remove the entry
-                    if let Entry::Occupied(mut tx_ids) =
-                        self.pending_txs_by_inputs.entry(*input)
-                    {
-                        tx_ids.get_mut().remove(&tx_id);
-                        if tx_ids.get().is_empty() {
-                            tx_ids.remove();
-                        }
-                    }
-                }
                 if let Some(missing_input) = missing_inputs.first() {
                     if let Err(e) =
                         notification_sender.try_send(PoolNotification::ErrorInsertion {
@@ -225,6 +214,60 @@ impl PendingPool {
         }
     }
 
+    /// Removes the transactions using the inputs blacklisted by the `black_list`.
+    pub fn remove_blacklisted_transactions(
+        &mut self,
+        black_list: &BlackList,
+        notification_sender: Sender<PoolNotification>,
+    ) {
+        let blacklisted = self
+            .pending_inputs_by_tx
+            .iter()
+            .filter_map(|(tx_id, pending_tx)| {
+                black_list
+                    .check_blacklisting(&pending_tx.tx)
+                    .err()
+                    .map(|error| (*tx_id, error))
+            })
+            .collect::<Vec<_>>();
+
+        for (tx_id, error) in blacklisted {
+            if let Some(PendingTx {
+                tx,
+                insertion_source,
+                ..
+            }) = self.remove_transaction(&tx_id)
+            {
+                if let Err(e) =
+                    notification_sender.try_send(PoolNotification::ErrorInsertion {
+                        tx_id: tx.id(),
+                        source: insertion_source,
+                        error: Error::Blacklisted(error),
+                    })
+                {
+                    tracing::error!("Failed to send error insertion notification: {}", e);
+                }
+            }
+        }
+    }
+
+    fn remove_transaction(&mut self, tx_id: &TxId) -> Option<PendingTx> {
+        let pending_tx = self.pending_inputs_by_tx.remove(tx_id)?;
+        self.decrease_pool_size(&pending_tx.tx);
+        for input in &pending_tx.missing_inputs {
+            // Remove tx_id from the list of unresolved transactions for this input
+            // If the list becomes empty, remove the entry
+            if let Entry::Occupied(mut tx_ids) = self.pending_txs_by_inputs.entry(*input)
+            {
+                tx_ids.get_mut().remove(tx_id);
+                if tx_ids.get().is_empty() {
+                    tx_ids.remove();
+                }
+            }
+        }
+        Some(pending_tx)
+    }
+
     fn decrease_pool_size(&mut self, tx: &ArcPoolTx) {
         self.current_bytes = self.current_bytes.saturating_sub(tx.metered_bytes_size());
         self.current_gas = self.current_gas.saturating_sub(tx.max_gas());
@@ -614,4 +657,54 @@ mod tests {
         assert_eq!(buf.len(), 2);
         assert!(pending_pool.is_empty());
     }
+
+    #[test]
+    fn remove_blacklisted_transactions__removes_tx_with_blacklisted_input() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let mut pending_pool = PendingPool::new(Duration::from_secs(1));
+
+        // Given
+        let blacklisted_utxo = UtxoId::new([1; 32].into(), 0);
+        let allowed_utxo = UtxoId::new([2; 32].into(), 0);
+        let blacklisted_tx =
+            create_pool_tx(vec![MissingInput::Utxo(blacklisted_utxo)], vec![], &mut rng);
+        let allowed_tx =
+            create_pool_tx(vec![MissingInput::Utxo(allowed_utxo)], vec![], &mut rng);
+        for (tx, utxo) in [
+            (blacklisted_tx.clone(), blacklisted_utxo),
+            (allowed_tx.clone(), allowed_utxo),
+        ] {
+            pending_pool.insert_transaction(
+                tx,
+                InsertionSource::RPC {
+                    response_channel: None,
+                },
+                vec![MissingInput::Utxo(utxo)],
+            );
+        }
+        let mut black_list = BlackList::default();
+        black_list.coins.insert(blacklisted_utxo);
+
+        // When
+        let (tx, mut rx) = tokio::sync::mpsc::channel(20);
+        pending_pool.remove_blacklisted_transactions(&black_list, tx);
+
+        // Then
+        let notification = rx.recv().now_or_never().unwrap().unwrap();
+        assert!(matches!(
+            notification,
+            PoolNotification::ErrorInsertion {
+                tx_id,
+                error: Error::Blacklisted(_),
+                ..
+            } if tx_id == blacklisted_tx.id()
+        ));
+        assert_eq!(pending_pool.current_txs, 1);
+        let allowed_output = Output::coin(Address::default(), 10, AssetId::default());
+        let resolved_txs =
+            pending_pool.new_known_tx(std::iter::once((allowed_utxo, &allowed_output)));
+        assert_eq!(resolved_txs.len(), 1);
+        assert_eq!(resolved_txs[0].0.id(), allowed_tx.id());
+        assert!(pending_pool.is_empty());
+    }
 }
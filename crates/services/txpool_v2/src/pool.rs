@@ -37,7 +37,10 @@ use crate::{
         CollisionManager,
         Collisions,
     },
-    config::Config,
+    config::{
+        BlackListUpdate,
+        Config,
+    },
     error::{
         DependencyError,
         Error,
@@ -191,7 +194,14 @@ where
             removed_transactions.extend(removed);
         }
 
-        for collided_tx in collisions.keys() {
+        let mut replaced_transactions = vec![];
+        for (collided_tx, reasons) in collisions.iter() {
+            if collisions::is_replacement(reasons) {
+                let replaced = Storage::get(&self.storage, collided_tx)
+                    .map(|data| data.transaction.id());
+                replaced_transactions.extend(replaced);
+            }
+
             let removed = self
                 .storage
                 .remove_transaction_and_dependents_subtree(*collided_tx);
@@ -241,17 +251,23 @@ where
             self.new_executable_txs_notifier.send_replace(());
         }
 
+        let replaced_reason = Error::Removed(RemovedReason::Replaced(tx_id)).to_string();
+        for replaced_tx_id in replaced_transactions.iter() {
+            self.tx_status_manager.status_update(
+                *replaced_tx_id,
+                TransactionStatus::replaced(tx_id, replaced_reason.clone()),
+            );
+        }
+
         let status = statuses::SqueezedOut {
             reason: Error::Removed(RemovedReason::LessWorth(tx_id)).to_string(),
         };
 
         let removed_transactions = removed_transactions
             .into_iter()
-            .map(|data| {
-                let removed_tx_id = data.transaction.id();
-
-                (removed_tx_id, status.clone())
-            })
+            .map(|data| data.transaction.id())
+            .filter(|removed_tx_id| !replaced_transactions.contains(removed_tx_id))
+            .map(|removed_tx_id| (removed_tx_id, status.clone()))
             .collect::<Vec<_>>();
         if !removed_transactions.is_empty() {
             self.tx_status_manager
@@ -320,6 +336,7 @@ where
             .check_collision_requirements(
                 checked_transaction.tx(),
                 has_dependencies,
+                self.config.min_replacement_tip_bump_percentage,
                 &self.storage,
             )
             .map_err(Error::Collided)?;
@@ -646,6 +663,46 @@ where
         self.update_stats();
     }
 
+    /// Applies the `update` to the blacklist of the pool.
+    /// Transactions that use newly blacklisted inputs are removed with their dependents.
+    pub fn update_blacklist(&mut self, update: BlackListUpdate) {
+        match update {
+            BlackListUpdate::Add(entries) => {
+                for entry in entries {
+                    self.config.black_list.insert(entry);
+                }
+                self.remove_blacklisted_transactions();
+            }
+            BlackListUpdate::Remove(entries) => {
+                for entry in entries.iter() {
+                    self.config.black_list.remove(entry);
+                }
+            }
+        }
+    }
+
+    fn remove_blacklisted_transactions(&mut self) {
+        let blacklisted = self
+            .tx_id_to_storage_id
+            .iter()
+            .filter_map(|(tx_id, storage_id)| {
+                let data = Storage::get(&self.storage, storage_id)?;
+                self.config
+                    .black_list
+                    .check_blacklisting(&data.transaction)
+                    .err()
+                    .map(|error| (*tx_id, error))
+            })
+            .collect::<Vec<_>>();
+
+        for (tx_id, error) in blacklisted {
+            let tx_status = statuses::SqueezedOut {
+                reason: Error::Removed(RemovedReason::Blacklisted(error)).to_string(),
+            };
+            self.remove_transactions_and_dependents(iter::once(tx_id), tx_status);
+        }
+    }
+
     pub fn remove_skipped_transaction(&mut self, tx_id: TxId, reason: String) {
         // If pre confirmation comes from the block producer node via p2p,
         // transaction still may be inside of the TxPool.
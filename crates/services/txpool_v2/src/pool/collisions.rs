@@ -1,7 +1,10 @@
 use crate::{
     collision_manager::Collisions,
     error::CollisionReason,
-    storage::Storage,
+    storage::{
+        Storage,
+        StorageData,
+    },
 };
 use fuel_core_types::services::txpool::PoolTransaction;
 use num_rational::Ratio;
@@ -14,6 +17,7 @@ pub trait CollisionsExt<S> {
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
+        min_tip_bump_percentage: u16,
         storage: &S,
     ) -> Result<(), CollisionReason>;
 }
@@ -32,25 +36,31 @@ where
     /// - A transaction doesn't have dependencies:
     ///     - A new transaction can be accepted if its profitability is higher
     ///         than the collided subtrees'.
+    /// - A collided transaction spending the same coin or message as the new one
+    ///     is replaced by it. The tip of the new transaction should be bigger
+    ///     than the tip of the replaced transaction by at least `min_tip_bump_percentage`
+    ///     and should cover the cumulative tip of all replaced subtrees.
+    /// - Collisions on the created contract or the blob only compare the profitability.
     fn check_collision_requirements(
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
+        min_tip_bump_percentage: u16,
         storage: &S,
     ) -> Result<(), CollisionReason> {
         if has_dependencies && self.len() > 1 {
             return Err(CollisionReason::MultipleCollisions);
         }
 
+        let mut evicted_tip: u64 = 0;
         for (collision, reason) in self.iter() {
-            if !is_better_than_collision(tx, collision, storage)? {
+            let colliding_tx = storage.get(collision).ok_or(CollisionReason::Unknown)?;
+
+            if !is_better_than_collision(tx, colliding_tx) {
                 tracing::info!(
                     "Transaction {} collided with {} because of {:?}",
                     tx.id(),
-                    storage
-                        .get(collision)
-                        .map(|d| d.transaction.id().to_string())
-                        .unwrap_or(String::from("Error")),
+                    colliding_tx.transaction.id(),
                     reason
                 );
                 if let Some(reason) = reason.first() {
@@ -59,25 +69,58 @@ where
                     return Err(CollisionReason::Unknown);
                 }
             }
+
+            if is_replacement(reason) {
+                evicted_tip =
+                    evicted_tip.saturating_add(colliding_tx.dependents_cumulative_tip);
+
+                let required = min_replacement_tip(
+                    colliding_tx.transaction.tip(),
+                    min_tip_bump_percentage,
+                );
+                if tx.tip() < required {
+                    return Err(CollisionReason::InsufficientTipBump {
+                        replaced: colliding_tx.transaction.id(),
+                        required,
+                        provided: tx.tip(),
+                    });
+                }
+            }
+        }
+
+        if tx.tip() < evicted_tip {
+            return Err(CollisionReason::EvictedTipsNotCovered {
+                required: evicted_tip,
+                provided: tx.tip(),
+            });
         }
 
         Ok(())
     }
 }
 
-fn is_better_than_collision<S>(
-    tx: &PoolTransaction,
-    collision: &S::StorageIndex,
-    storage: &S,
-) -> Result<bool, CollisionReason>
-where
-    S: Storage,
-{
+/// Returns `true` if the collision is caused by spending the same coin or message,
+/// meaning that the collided transaction is replaced by the new one.
+pub(crate) fn is_replacement(reasons: &[CollisionReason]) -> bool {
+    reasons.iter().any(|reason| {
+        matches!(
+            reason,
+            CollisionReason::Utxo(_) | CollisionReason::Message(_)
+        )
+    })
+}
+
+fn min_replacement_tip(tip: u64, min_tip_bump_percentage: u16) -> u64 {
+    let percentage = u128::from(min_tip_bump_percentage).saturating_add(100);
+    let required = u128::from(tip).saturating_mul(percentage).div_ceil(100);
+    u64::try_from(required).unwrap_or(u64::MAX)
+}
+
+fn is_better_than_collision(tx: &PoolTransaction, colliding_tx: &StorageData) -> bool {
     let new_tx_ratio = Ratio::new(tx.tip(), tx.max_gas());
-    let colliding_tx = storage.get(collision).ok_or(CollisionReason::Unknown)?;
     let colliding_tx_ratio = Ratio::new(
         colliding_tx.dependents_cumulative_tip,
         colliding_tx.dependents_cumulative_gas,
     );
-    Ok(new_tx_ratio > colliding_tx_ratio)
+    new_tx_ratio > colliding_tx_ratio
 }
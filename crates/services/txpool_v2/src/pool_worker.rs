@@ -42,7 +42,11 @@ use tokio::{
 
 use crate::{
     Constraints,
-    config::ServiceChannelLimits,
+    config::{
+        BlackList,
+        BlackListUpdate,
+        ServiceChannelLimits,
+    },
     error::{
         Error,
         InsertionErrorType,
@@ -53,6 +57,7 @@ use crate::{
         TxStatusManager as TxStatusManagerTrait,
     },
     service::{
+        Shared,
         TxInfo,
         TxPool,
     },
@@ -93,6 +98,7 @@ impl PoolWorkerInterface {
     pub fn new<View, TxStatusManager>(
         tx_pool: TxPool<TxStatusManager>,
         view_provider: Arc<dyn AtomicView<LatestView = View>>,
+        blacklist: Shared<BlackList>,
         limits: &ServiceChannelLimits,
     ) -> Self
     where
@@ -137,6 +143,7 @@ impl PoolWorkerInterface {
                     pending_pool: PendingPool::new(tx_pool.config.pending_pool_tx_ttl),
                     pool: tx_pool,
                     view_provider,
+                    blacklist,
                 };
 
                 tokio_runtime.block_on(async {
@@ -216,8 +223,16 @@ pub(super) enum PoolExtractBlockTransactions {
 }
 
 pub(super) enum PoolUpdateRequest {
-    ProcessBlock { block_result: SharedImportResult },
-    ExpiredTransactions { expired_txs: Vec<TxId> },
+    ProcessBlock {
+        block_result: SharedImportResult,
+    },
+    ExpiredTransactions {
+        expired_txs: Vec<TxId>,
+    },
+    UpdateBlackList {
+        update: BlackListUpdate,
+        response_channel: oneshot::Sender<()>,
+    },
 }
 pub(super) enum PoolReadRequest {
     NonExistingTxs {
@@ -271,6 +286,7 @@ pub(super) struct PoolWorker<View, TxStatusManager> {
     pending_pool: PendingPool,
     view_provider: Arc<dyn AtomicView<LatestView = View>>,
     notification_sender: Sender<PoolNotification>,
+    blacklist: Shared<BlackList>,
 }
 
 impl<View, TxStatusManager> PoolWorker<View, TxStatusManager>
@@ -322,6 +338,9 @@ where
                         PoolUpdateRequest::ExpiredTransactions { expired_txs } => {
                             self.remove_expired_transactions(expired_txs);
                         }
+                        PoolUpdateRequest::UpdateBlackList { update, response_channel } => {
+                            self.update_blacklist(update, response_channel);
+                        }
                     }
                 }
             }
@@ -524,6 +543,24 @@ where
         );
     }
 
+    fn update_blacklist(
+        &mut self,
+        update: BlackListUpdate,
+        response_channel: oneshot::Sender<()>,
+    ) {
+        self.pool.update_blacklist(update);
+        self.pending_pool.remove_blacklisted_transactions(
+            &self.pool.config.black_list,
+            self.notification_sender.clone(),
+        );
+        // The verification of new transactions happens outside of the worker,
+        // so it uses a copy of the blacklist.
+        *self.blacklist.write() = self.pool.config.black_list.clone();
+        if response_channel.send(()).is_err() {
+            tracing::error!("Failed to send the blacklist update response");
+        }
+    }
+
     fn process_preconfirmed_transaction(
         &mut self,
         tx_id: TxId,
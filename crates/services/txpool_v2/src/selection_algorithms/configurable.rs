@@ -0,0 +1,142 @@
+use crate::{
+    config::SelectionAlgorithmConfig,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
+};
+
+use super::{
+    Constraints,
+    SelectionAlgorithm,
+    fifo::FifoSelection,
+    ratio_tip_gas::{
+        RatioTipGasSelection,
+        RatioTipGasSelectionAlgorithmStorage,
+    },
+    tip_first::TipFirstSelection,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// The selection algorithm chosen at runtime by the [`SelectionAlgorithmConfig`].
+pub enum ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    RatioTipGas(RatioTipGasSelection<S>),
+    Fifo(FifoSelection<S>),
+    TipFirst(TipFirstSelection<S>),
+}
+
+impl<S> ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    pub fn new(
+        config: SelectionAlgorithmConfig,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> Self {
+        match config {
+            SelectionAlgorithmConfig::RatioTipGas => {
+                Self::RatioTipGas(RatioTipGasSelection::new(new_executable_txs_notifier))
+            }
+            SelectionAlgorithmConfig::Fifo => {
+                Self::Fifo(FifoSelection::new(new_executable_txs_notifier))
+            }
+            SelectionAlgorithmConfig::TipFirst => {
+                Self::TipFirst(TipFirstSelection::new(new_executable_txs_notifier))
+            }
+            SelectionAlgorithmConfig::Fair { max_txs_per_owner } => Self::RatioTipGas(
+                RatioTipGasSelection::new(new_executable_txs_notifier)
+                    .with_max_txs_per_owner(max_txs_per_owner),
+            ),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RatioTipGas(selection) => selection.is_empty(),
+            Self::Fifo(selection) => selection.is_empty(),
+            Self::TipFirst(selection) => selection.is_empty(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        match self {
+            Self::RatioTipGas(selection) => selection.assert_integrity(expected_txs),
+            Self::Fifo(selection) => selection.assert_integrity(expected_txs),
+            Self::TipFirst(selection) => selection.assert_integrity(expected_txs),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+            Self::TipFirst(selection) => selection.gather_best_txs(constraints, storage),
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::TipFirst(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+            Self::TipFirst(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        let iter: Box<dyn Iterator<Item = &Self::StorageIndex> + '_> = match self {
+            Self::RatioTipGas(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::Fifo(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::TipFirst(selection) => Box::new(selection.get_less_worth_txs()),
+        };
+        iter
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+            Self::TipFirst(selection) => selection.on_removed_transaction(storage_entry),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    cmp::Ordering,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::StorageData;
+
+use super::sorted::{
+    SortedSelection,
+    SortingKey,
+};
+
+/// Key used to sort transactions by their arrival to the pool.
+/// It first compares the creation instant and then the transaction id.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Key {
+    creation_instant: SystemTime,
+    tx_id: TxId,
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let instant_cmp = other.creation_instant.cmp(&self.creation_instant);
+        if instant_cmp == Ordering::Equal {
+            self.tx_id.cmp(&other.tx_id)
+        } else {
+            instant_cmp
+        }
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl SortingKey for Key {
+    fn new(store_entry: &StorageData) -> Self {
+        Key {
+            creation_instant: store_entry.creation_instant,
+            tx_id: store_entry.transaction.id(),
+        }
+    }
+
+    #[cfg(test)]
+    fn tx_id(&self) -> &TxId {
+        &self.tx_id
+    }
+}
+
+/// The selection algorithm that selects the oldest transactions first.
+pub type FifoSelection<S> = SortedSelection<S, Key>;
@@ -7,7 +7,11 @@ use crate::storage::{
     StorageData,
 };
 
+pub mod configurable;
+pub mod fifo;
 pub mod ratio_tip_gas;
+pub mod sorted;
+pub mod tip_first;
 
 /// Constraints that the selection algorithm has to respect.
 pub struct Constraints {
@@ -46,7 +50,8 @@ pub trait SelectionAlgorithm {
     /// Returns the number of executable transactions
     fn number_of_executable_transactions(&self) -> usize;
 
-    /// Get less worth transactions iterator
+    /// Get less worth transactions iterator, ordered by the tip/gas ratio
+    /// regardless of the order of the selection.
     fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex>;
 
     /// Inform the selection algorithm that a transaction was removed from the pool.
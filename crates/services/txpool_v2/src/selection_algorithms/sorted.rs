@@ -0,0 +1,316 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fmt::Debug,
+    num::NonZeroUsize,
+};
+
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::txpool::PoolTransaction,
+};
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    Constraints,
+    SelectionAlgorithm,
+    ratio_tip_gas::{
+        self,
+        RatioTipGasSelectionAlgorithmStorage,
+    },
+};
+
+#[cfg(test)]
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::ArcPoolTx,
+};
+
+/// The key defines the order in which executable transactions are selected.
+/// The greatest key is selected first.
+pub trait SortingKey: Ord + Copy + Debug {
+    /// Creates the key for the transaction stored in the pool.
+    fn new(store_entry: &StorageData) -> Self;
+
+    /// The id of the transaction behind the key.
+    #[cfg(test)]
+    fn tx_id(&self) -> &TxId;
+}
+
+/// The selection algorithm that selects executable transactions in the order
+/// defined by the `K` key.
+///
+/// Optionally, it limits the number of transactions that one owner can
+/// get into the block.
+///
+/// Regardless of the selection order, the transactions are evicted from
+/// the full pool starting from the lowest tip/gas ratio.
+pub struct SortedSelection<S, K>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    executable_transactions_sorted:
+        BTreeMap<Reverse<K>, (S::StorageIndex, ratio_tip_gas::Key)>,
+    /// The executable transactions sorted by the tip/gas ratio, the less worth first.
+    eviction_order: BTreeMap<ratio_tip_gas::Key, S::StorageIndex>,
+    new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    max_txs_per_owner: Option<NonZeroUsize>,
+}
+
+impl<S, K> SortedSelection<S, K>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+    K: SortingKey,
+{
+    pub fn new(new_executable_txs_notifier: tokio::sync::watch::Sender<()>) -> Self {
+        Self {
+            executable_transactions_sorted: BTreeMap::new(),
+            eviction_order: BTreeMap::new(),
+            new_executable_txs_notifier,
+            max_txs_per_owner: None,
+        }
+    }
+
+    /// Limits the number of transactions of one owner selected for the block.
+    pub fn with_max_txs_per_owner(mut self, max_txs_per_owner: NonZeroUsize) -> Self {
+        self.max_txs_per_owner = Some(max_txs_per_owner);
+        self
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions_sorted.is_empty()
+    }
+
+    fn on_removed_transaction_inner(&mut self, key: K) {
+        if let Some((_, eviction_key)) =
+            self.executable_transactions_sorted.remove(&Reverse(key))
+        {
+            self.eviction_order.remove(&eviction_key);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
+            .iter()
+            .map(|tx| (tx.id(), tx.clone()))
+            .collect();
+        for key in self.executable_transactions_sorted.keys() {
+            expected_txs.remove(key.0.tx_id()).unwrap_or_else(|| {
+                panic!(
+                    "Transaction with id {:?} is not in the expected transactions.",
+                    key.0.tx_id()
+                )
+            });
+        }
+        assert!(
+            expected_txs.is_empty(),
+            "Some transactions are missing from the selection algorithm: {:?}",
+            expected_txs.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            self.eviction_order.len(),
+            self.executable_transactions_sorted.len(),
+            "The eviction order doesn't match the executable transactions"
+        );
+    }
+}
+
+/// Returns the unique owners of the coins and messages spent by the transaction.
+fn owners(transaction: &PoolTransaction) -> Vec<Address> {
+    let mut owners = transaction
+        .inputs()
+        .iter()
+        .filter_map(|input| input.input_owner())
+        .copied()
+        .collect::<Vec<_>>();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
+impl<S, K> SelectionAlgorithm for SortedSelection<S, K>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+    K: SortingKey,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let mut gas_left = constraints.max_gas;
+        let mut space_left = constraints.maximum_block_size as usize;
+        let mut nb_left = constraints.maximum_txs;
+        let mut txs_per_owner = HashMap::<Address, usize>::new();
+        let mut result = Vec::new();
+
+        // Take iterate over all transactions with the highest priority. If transaction
+        // fits in the gas limit select it and mark all its dependents to be promoted.
+        // Do that until end of the list or gas limit is reached. If gas limit is not
+        // reached, but we have promoted transactions we can start again from the beginning.
+        // Otherwise, we can break the loop.
+        // It is done in this way to minimize number of iteration of the list of executable
+        // transactions.
+        let mut add_new_executable = false;
+        while gas_left > 0
+            && nb_left > 0
+            && space_left > 0
+            && !self.executable_transactions_sorted.is_empty()
+        {
+            let mut clean_up_list = Vec::new();
+            let mut transactions_to_remove = Vec::new();
+            let mut transactions_to_promote = Vec::new();
+
+            'outer: for (key, (storage_id, _)) in &self.executable_transactions_sorted {
+                if nb_left == 0 || gas_left == 0 || space_left == 0 {
+                    break;
+                }
+
+                let Some(stored_transaction) = storage.get(storage_id) else {
+                    debug_assert!(
+                        false,
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    tracing::warn!(
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    transactions_to_remove.push(*key);
+                    continue
+                };
+
+                for input in stored_transaction.transaction.inputs() {
+                    if let fuel_core_types::fuel_tx::Input::Contract(contract) = input {
+                        if constraints
+                            .excluded_contracts
+                            .contains(&contract.contract_id)
+                        {
+                            continue 'outer;
+                        }
+                    }
+                }
+
+                let less_price = stored_transaction.transaction.max_gas_price()
+                    < constraints.minimal_gas_price;
+
+                if less_price {
+                    continue;
+                }
+
+                let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
+                let too_big_tx =
+                    stored_transaction.transaction.metered_bytes_size() > space_left;
+
+                if not_enough_gas || too_big_tx {
+                    continue;
+                }
+
+                if let Some(max_txs_per_owner) = self.max_txs_per_owner {
+                    let owners = owners(&stored_transaction.transaction);
+                    let owner_is_full = owners.iter().any(|owner| {
+                        txs_per_owner.get(owner).copied().unwrap_or_default()
+                            >= max_txs_per_owner.get()
+                    });
+
+                    if owner_is_full {
+                        continue;
+                    }
+
+                    for owner in owners {
+                        let count = txs_per_owner.entry(owner).or_default();
+                        *count = count.saturating_add(1);
+                    }
+                }
+
+                gas_left =
+                    gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+                space_left = space_left
+                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+                nb_left = nb_left.saturating_sub(1);
+
+                let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
+                debug_assert!(!storage.has_dependencies(storage_id));
+                let removed = storage.remove(storage_id).expect(
+                    "We just get the transaction from the storage above, it should exist.",
+                );
+                clean_up_list.push(*key);
+                result.push(removed);
+
+                for dependent in dependents {
+                    if !storage.has_dependencies(&dependent) {
+                        transactions_to_promote.push(dependent);
+                    }
+                }
+            }
+
+            for remove in transactions_to_remove {
+                let key = remove.0;
+                self.on_removed_transaction_inner(key);
+            }
+
+            // If no transaction fits in the gas limit and no one to promote, we can break the loop
+            if clean_up_list.is_empty() && transactions_to_promote.is_empty() {
+                break;
+            }
+
+            for key in clean_up_list {
+                let key = key.0;
+                // Remove selected transactions from the sorted list
+                self.on_removed_transaction_inner(key);
+            }
+
+            if transactions_to_promote.is_empty() {
+                continue;
+            }
+            for promote in transactions_to_promote {
+                let storage = storage.get(&promote).expect(
+                    "We just get the dependent from the storage, it should exist.",
+                );
+                self.new_executable_transaction(promote, storage);
+            }
+            add_new_executable = true;
+        }
+
+        if add_new_executable {
+            self.new_executable_txs_notifier.send_replace(());
+        }
+
+        result
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        let key = K::new(store_entry);
+        let eviction_key = ratio_tip_gas::Key::new(store_entry);
+        self.executable_transactions_sorted
+            .insert(Reverse(key), (storage_id, eviction_key));
+        self.eviction_order.insert(eviction_key, storage_id);
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        self.eviction_order.values()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        let key = K::new(storage_entry);
+        self.on_removed_transaction_inner(key)
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted.len()
+    }
+}
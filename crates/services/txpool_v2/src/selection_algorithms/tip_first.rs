@@ -0,0 +1,63 @@
+use std::{
+    cmp::Ordering,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::StorageData;
+
+use super::sorted::{
+    SortedSelection,
+    SortingKey,
+};
+
+/// Key used to sort transactions by the absolute tip.
+/// It first compares the tip, then the creation instant and finally the transaction id.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Key {
+    tip: u64,
+    creation_instant: SystemTime,
+    tx_id: TxId,
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let cmp = self.tip.cmp(&other.tip);
+        if cmp == Ordering::Equal {
+            let instant_cmp = other.creation_instant.cmp(&self.creation_instant);
+            if instant_cmp == Ordering::Equal {
+                self.tx_id.cmp(&other.tx_id)
+            } else {
+                instant_cmp
+            }
+        } else {
+            cmp
+        }
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl SortingKey for Key {
+    fn new(store_entry: &StorageData) -> Self {
+        let transaction = &store_entry.transaction;
+        Key {
+            tip: transaction.tip(),
+            creation_instant: store_entry.creation_instant,
+            tx_id: transaction.id(),
+        }
+    }
+
+    #[cfg(test)]
+    fn tx_id(&self) -> &TxId {
+        &self.tx_id
+    }
+}
+
+/// The selection algorithm that selects transactions with the highest tip first.
+pub type TipFirstSelection<S> = SortedSelection<S, Key>;
@@ -44,7 +44,7 @@ use fuel_core_txpool::{
         TxStatusManager as TxStatusManagerTrait,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
         memory::MemoryPool,
         pruner::TransactionPruner,
@@ -115,7 +115,7 @@ pub type TxPool<TxStatusManager> = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    ConfigurableSelection<GraphStorage>,
     TxStatusManager,
 >;
 
@@ -749,13 +749,14 @@ where
     };
 
     let storage_provider = Arc::new(ps_provider);
+    let blacklist = Arc::new(RwLock::new(config.black_list.clone()));
     let verification = Verification {
         persistent_storage_provider: storage_provider.clone(),
         chain_state_info_provider: Arc::new(chain_state_info_provider),
         gas_price_provider: Arc::new(gas_price_provider),
         wasm_checker: Arc::new(wasm_checker),
         memory_pool: MemoryPool::new(),
-        blacklist: config.black_list.clone(),
+        blacklist: blacklist.clone(),
     };
 
     let pruner = TransactionPruner {
@@ -789,7 +790,7 @@ where
             max_txs_chain_count: config.max_txs_chain_count,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(new_txs_notifier.clone()),
+        ConfigurableSelection::new(config.selection_algorithm, new_txs_notifier.clone()),
         config,
         pool_stats_sender,
         new_txs_notifier.clone(),
@@ -800,11 +801,17 @@ where
     let (current_height_writer, current_height_reader) =
         unsafe { SeqLock::new(current_height) };
 
-    let pool_worker =
-        PoolWorkerInterface::new(txpool, storage_provider, &service_channel_limits);
+    let pool_worker = PoolWorkerInterface::new(
+        txpool,
+        storage_provider,
+        blacklist.clone(),
+        &service_channel_limits,
+    );
 
     let shared_state = SharedState {
         request_read_sender: pool_worker.request_read_sender.clone(),
+        request_update_sender: pool_worker.request_update_sender.clone(),
+        blacklist,
         write_pool_requests_sender,
         select_transactions_requests_sender: pool_worker
             .extract_block_transactions_sender
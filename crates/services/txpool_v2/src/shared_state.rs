@@ -18,13 +18,19 @@ use tokio::sync::{
 
 use crate::{
     Constraints,
+    config::{
+        BlackList,
+        BlackListUpdate,
+    },
     error::Error,
     pool::TxPoolStats,
     pool_worker::{
         self,
         PoolReadRequest,
+        PoolUpdateRequest,
     },
     service::{
+        Shared,
         TxInfo,
         WritePoolRequest,
     },
@@ -36,6 +42,8 @@ pub struct SharedState {
     pub(crate) select_transactions_requests_sender:
         mpsc::Sender<pool_worker::PoolExtractBlockTransactions>,
     pub(crate) request_read_sender: mpsc::Sender<PoolReadRequest>,
+    pub(crate) request_update_sender: mpsc::Sender<PoolUpdateRequest>,
+    pub(crate) blacklist: Shared<BlackList>,
     pub(crate) new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
     pub(crate) latest_stats: tokio::sync::watch::Receiver<TxPoolStats>,
 }
@@ -137,6 +145,30 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Returns the current blacklist of the pool.
+    pub fn blacklist(&self) -> BlackList {
+        self.blacklist.read().clone()
+    }
+
+    /// Updates the blacklist of the pool. Transactions with newly blacklisted
+    /// inputs and their dependents are removed from the pool
+    /// before the function returns.
+    pub async fn update_blacklist(&self, update: BlackListUpdate) -> Result<(), Error> {
+        let (response_channel, result_receiver) = oneshot::channel();
+
+        self.request_update_sender
+            .send(PoolUpdateRequest::UpdateBlackList {
+                update,
+                response_channel,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
     /// Get a notifier that is notified when new executable transactions are added to the pool.
     pub fn get_new_executable_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_executable_txs_notifier.subscribe()
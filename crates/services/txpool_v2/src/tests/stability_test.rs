@@ -6,12 +6,17 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::arithmetic_side_effects)]
 
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
 use crate::{
     config::{
         Config,
         PoolLimits,
+        SelectionAlgorithmConfig,
     },
     selection_algorithms::Constraints,
     tests::universe::TestPoolUniverse,
@@ -154,7 +159,8 @@ fn stability_test(limits: Limits, config: Config) {
     }
 }
 
-fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
+/// Runs the stability test and returns the ids of the transactions extracted for each block.
+fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) -> Vec<Vec<TxId>> {
     let mut rng = StdRng::seed_from_u64(seed);
 
     let mut errors = 0;
@@ -180,6 +186,7 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
 
     assert_ne!(ROUNDS_PER_TXPOOL, errors);
 
+    let mut blocks = Vec::new();
     loop {
         let result = txpool.write().extract_transactions_for_block(Constraints {
             max_gas: limits.max_block_gas,
@@ -194,6 +201,7 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
         }
 
         txpool.read().storage.check_integrity();
+        blocks.push(result.iter().map(|tx| tx.id()).collect());
     }
 
     {
@@ -205,6 +213,73 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
         assert!(txpool.storage.is_empty());
         assert!(txpool.collision_manager.is_empty());
     }
+
+    blocks
+}
+
+const MAX_TXS_PER_OWNER: usize = 3;
+
+fn selection_algorithms() -> [SelectionAlgorithmConfig; 4] {
+    [
+        SelectionAlgorithmConfig::RatioTipGas,
+        SelectionAlgorithmConfig::Fifo,
+        SelectionAlgorithmConfig::TipFirst,
+        SelectionAlgorithmConfig::Fair {
+            max_txs_per_owner: NonZeroUsize::new(MAX_TXS_PER_OWNER).unwrap(),
+        },
+    ]
+}
+
+/// Runs the same stream of transactions against all selection algorithms.
+/// Pool limits are not reached, so the pool contains the same transactions for all
+/// algorithms, and all of them should be extracted at the end.
+fn selection_algorithms_comparative_test(limits: Limits, config: Config) {
+    use rand::RngCore;
+    let seed = rand::thread_rng().next_u64();
+
+    let result = std::panic::catch_unwind(|| {
+        let mut extracted_by_algorithm = vec![];
+        for selection_algorithm in selection_algorithms() {
+            let config = Config {
+                selection_algorithm,
+                ..config.clone()
+            };
+            let blocks = stability_test_with_seed(seed, limits, config);
+
+            if let SelectionAlgorithmConfig::Fair { max_txs_per_owner } =
+                selection_algorithm
+            {
+                // All transactions in the test belong to the same owner.
+                for block in &blocks {
+                    assert!(block.len() <= max_txs_per_owner.get());
+                }
+            }
+
+            let extracted = blocks.into_iter().flatten().collect::<HashSet<_>>();
+            extracted_by_algorithm.push((selection_algorithm, extracted));
+        }
+
+        let (_, expected) = &extracted_by_algorithm[0];
+        for (selection_algorithm, extracted) in &extracted_by_algorithm {
+            assert_eq!(
+                expected, extracted,
+                "{:?} extracted different transactions",
+                selection_algorithm
+            );
+        }
+    });
+
+    if let Err(err) = result {
+        tracing::error!(
+            "Comparative test failed with seed: {}; err: {:?}",
+            seed,
+            err
+        );
+        panic!(
+            "Comparative test failed with seed: {}; err: {:?}",
+            seed, err
+        );
+    }
 }
 
 const ROUNDS_PER_TEST: usize = 10;
@@ -380,3 +455,101 @@ fn stability_test__wide_chain_of_transactions_with_conflicts() {
         stability_test(limit, config.clone());
     }
 }
+
+#[test]
+fn stability_test__selection_algorithms__average_transactions() {
+    let config = Config {
+        utxo_validation: false,
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 4,
+        min_outputs: 1,
+        max_outputs: 4,
+        utxo_id_range: 12,
+        gas_limit_range: 1000,
+        max_block_gas: 10_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        selection_algorithms_comparative_test(limit, config.clone());
+    }
+}
+
+#[test]
+fn stability_test__selection_algorithms__many_dependencies() {
+    let config = Config {
+        utxo_validation: false,
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 200,
+        min_outputs: 1,
+        max_outputs: 10,
+        utxo_id_range: 255,
+        gas_limit_range: 1_000,
+        max_block_gas: 10_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        selection_algorithms_comparative_test(limit, config.clone());
+    }
+}
+
+#[test]
+fn stability_test__selection_algorithms__long_chain_of_transactions() {
+    let config = Config {
+        utxo_validation: false,
+        max_txs_chain_count: 128,
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 2,
+        min_outputs: 1,
+        max_outputs: 2,
+        utxo_id_range: 255,
+        gas_limit_range: 100,
+        max_block_gas: 10_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        selection_algorithms_comparative_test(limit, config.clone());
+    }
+}
+
+#[test]
+fn stability_test__selection_algorithms__many_conflicting_transactions() {
+    let config = Config {
+        utxo_validation: false,
+        pool_limits: PoolLimits {
+            max_txs: 32,
+            max_gas: 80_000,
+            max_bytes_size: 1_000_000,
+        },
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 200,
+        min_outputs: 1,
+        max_outputs: 10,
+        utxo_id_range: 255,
+        gas_limit_range: 10_000,
+        max_block_gas: 10_000,
+    };
+
+    // Pool limits are reached here, so the eviction depends on the algorithm
+    // and we only check that every algorithm keeps the pool consistent.
+    for _ in 0..ROUNDS_PER_TEST {
+        for selection_algorithm in selection_algorithms() {
+            let config = Config {
+                selection_algorithm,
+                ..config.clone()
+            };
+            stability_test(limit, config);
+        }
+    }
+}
@@ -1,9 +1,15 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+};
 
 use crate::{
     config::{
+        BlackListEntry,
+        BlackListUpdate,
         Config,
         PoolLimits,
+        SelectionAlgorithmConfig,
     },
     error::{
         BlacklistedError,
@@ -103,6 +109,33 @@ fn insert__tx_with_blacklisted_utxo_id() {
     universe.assert_pool_integrity(&[]);
 }
 
+#[test]
+fn insert__tx_with_owner_blacklisted_after_verification() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let owner_addr = *coin.input_owner().unwrap();
+    universe
+        .get_pool()
+        .write()
+        .update_blacklist(BlackListUpdate::Add(vec![BlackListEntry::Owner(
+            owner_addr,
+        )]));
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+
+    // When
+    // The verification of the universe doesn't use the blacklist of the pool.
+    let err = universe.verify_and_insert(tx).unwrap_err();
+
+    // Then
+    assert!(
+        matches!(err, Error::Blacklisted(BlacklistedError::BlacklistedOwner(id)) if id == owner_addr)
+    );
+    universe.assert_pool_integrity(&[]);
+}
+
 #[test]
 fn insert__tx_with_blacklisted_owner() {
     let mut universe = TestPoolUniverse::default();
@@ -347,11 +380,98 @@ async fn insert__higher_priced_tx_removes_lower_priced_tx() {
 
     // Then
     universe
-        .await_expected_tx_statuses_squeeze_out(vec![tx_id])
+        .await_expected_tx_statuses_replaced(vec![tx_id], result.id())
         .await;
     universe.assert_pool_integrity(&[result]);
 }
 
+#[test]
+fn insert__replacement_with_insufficient_tip_bump_returns_error() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        min_replacement_tip_bump_percentage: 20,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 11);
+    let pool_tx = universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let err = universe.verify_and_insert(tx2).unwrap_err();
+
+    // Then
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::InsufficientTipBump {
+            replaced,
+            required: 12,
+            provided: 11,
+        }) if replaced == tx1_id
+    ));
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert__replacement_with_sufficient_tip_bump_is_accepted() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        min_replacement_tip_bump_percentage: 20,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 12);
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let pool_tx = result.unwrap();
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert__replacement_not_covering_evicted_dependents_tips_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(
+        Some(vec![common_coin.clone()]),
+        Some(vec![output]),
+        10,
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 100);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 100);
+
+    // When
+    let err = universe.verify_and_insert(tx3).unwrap_err();
+
+    // Then
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::EvictedTipsNotCovered {
+            required: 110,
+            provided: 100,
+        })
+    ));
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
 #[test]
 fn insert__colliding_dependent_and_underpriced_returns_error() {
     let mut universe = TestPoolUniverse::default();
@@ -450,7 +570,10 @@ async fn insert_more_priced_tx3_removes_tx1_and_dependent_tx2() {
     // Then
     let pool_tx = result3.unwrap();
     universe
-        .await_expected_tx_statuses_squeeze_out(vec![tx1_id, tx2_id])
+        .await_expected_tx_statuses_replaced(vec![tx1_id], pool_tx.id())
+        .await;
+    universe
+        .await_expected_tx_statuses_squeeze_out(vec![tx2_id])
         .await;
     universe.assert_pool_integrity(&[pool_tx]);
 }
@@ -473,19 +596,21 @@ async fn insert_more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
         universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 11);
     let tx2_id = tx2.id(&ChainId::default());
 
-    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 12);
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 13);
 
     // When
     let result2 = universe.verify_and_insert(tx2);
     let result3 = universe.verify_and_insert(tx3);
 
     // Then
-    assert!(result2.is_ok());
-    assert!(result3.is_ok());
+    let tx2 = result2.unwrap();
+    let pool_tx = result3.unwrap();
     universe
-        .await_expected_tx_statuses_squeeze_out(vec![tx1_id, tx2_id])
+        .await_expected_tx_statuses_replaced(vec![tx1_id], tx2.id())
+        .await;
+    universe
+        .await_expected_tx_statuses_replaced(vec![tx2_id], pool_tx.id())
         .await;
-    let pool_tx = result3.unwrap();
     universe.assert_pool_integrity(&[pool_tx]);
 }
 
@@ -585,6 +710,56 @@ fn insert__tx_bytes_limit() {
     universe.assert_pool_integrity(&[pool_tx]);
 }
 
+#[test]
+fn insert__full_pool_evicts_lowest_ratio_tx_for_each_selection_algorithm() {
+    let selection_algorithms = [
+        SelectionAlgorithmConfig::RatioTipGas,
+        SelectionAlgorithmConfig::Fifo,
+        SelectionAlgorithmConfig::TipFirst,
+        SelectionAlgorithmConfig::Fair {
+            max_txs_per_owner: NonZeroUsize::new(1).unwrap(),
+        },
+    ];
+
+    for selection_algorithm in selection_algorithms {
+        let mut universe = TestPoolUniverse::default().config(Config {
+            pool_limits: PoolLimits {
+                max_txs: 3,
+                max_bytes_size: 1000000000,
+                max_gas: 100_000_000_000,
+            },
+            selection_algorithm,
+            ..Default::default()
+        });
+        universe.build_pool();
+
+        // Given
+        // The oldest transaction with the highest gas has the lowest tip/gas ratio,
+        // while the newest one and the one with the lowest tip have higher ratios.
+        let lowest_ratio_tx = universe.build_script_transaction_with_gas_limit(
+            None,
+            None,
+            1000,
+            GAS_LIMIT * 10,
+        );
+        let lowest_tip_tx = universe.build_script_transaction(None, None, 500);
+        let newest_tx = universe.build_script_transaction(None, None, 2000);
+        let new_tx = universe.build_script_transaction(None, None, 600);
+        universe.verify_and_insert(lowest_ratio_tx).unwrap();
+        let lowest_tip_tx = universe.verify_and_insert(lowest_tip_tx).unwrap();
+        let newest_tx = universe.verify_and_insert(newest_tx).unwrap();
+
+        // When
+        let result = universe.verify_and_insert(new_tx);
+
+        // Then
+        let new_tx = result.unwrap_or_else(|err| {
+            panic!("{selection_algorithm:?} rejected the transaction: {err:?}")
+        });
+        universe.assert_pool_integrity(&[lowest_tip_tx, newest_tx, new_tx]);
+    }
+}
+
 #[test]
 fn insert__dependency_chain_length_hit() {
     let mut universe = TestPoolUniverse::default().config(Config {
@@ -1019,7 +1194,7 @@ async fn insert_tx_tip_higher_than_another_tx_with_same_message_id() {
     assert!(result2.is_ok());
     let pool_tx = result2.unwrap();
     universe
-        .await_expected_tx_statuses_squeeze_out(vec![tx_high_id])
+        .await_expected_tx_statuses_replaced(vec![tx_high_id], pool_tx.id())
         .await;
     universe.assert_pool_integrity(&[pool_tx]);
 }
@@ -1270,6 +1445,48 @@ fn insert_tx_with_blob_already_insert_at_lower_tip() {
     universe.assert_pool_integrity(&[result.unwrap()]);
 }
 
+#[test]
+fn insert__blob_collision_with_higher_ratio_is_accepted_without_covering_evicted_tip() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let program = vec![123; 123];
+    let blob_id = BlobId::compute(program.as_slice());
+    let padding = vec![0; 50_000];
+    let tx = TransactionBuilder::blob(BlobBody {
+        id: blob_id,
+        witness_index: 0,
+    })
+    .add_witness(program.clone().into())
+    .add_witness(padding.into())
+    .add_input(universe.setup_coin().1)
+    .tip(20)
+    .max_fee_limit(20)
+    .finalize_as_transaction();
+    universe.verify_and_insert(tx).unwrap();
+
+    let same_blob_tx = TransactionBuilder::blob(BlobBody {
+        id: blob_id,
+        witness_index: 0,
+    })
+    .add_input(universe.setup_coin().1)
+    .add_witness(program.into())
+    .tip(10)
+    .max_fee_limit(10)
+    .finalize_as_transaction();
+
+    // When
+    let result = universe.verify_and_insert(same_blob_tx);
+
+    // Then
+    let pool_tx = result.unwrap();
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
 #[test]
 fn verify_and_insert__when_dependent_tx_is_extracted_new_tx_still_accepted() {
     let mut universe = TestPoolUniverse::default();
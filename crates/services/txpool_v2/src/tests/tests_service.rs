@@ -21,7 +21,13 @@ use std::{
 
 use crate::{
     Constraints,
-    config::Config,
+    config::{
+        BlackList,
+        BlackListEntry,
+        BlackListUpdate,
+        Config,
+    },
+    error::Error,
     tests::{
         mocks::MockImporter,
         universe::{
@@ -618,3 +624,77 @@ async fn pending_pool__returns_error_after_timeout_for_transaction_that_spends_u
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn update_blacklist__removes_blacklisted_transaction_and_dependents() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let owner = *coin_input.input_owner().unwrap();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 =
+        universe.build_script_transaction(Some(vec![coin_input]), Some(vec![output]), 10);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.insert(tx1).await.unwrap();
+    service.shared.insert(tx2).await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+
+    // When
+    service
+        .shared
+        .update_blacklist(BlackListUpdate::Add(vec![BlackListEntry::Owner(owner)]))
+        .await
+        .unwrap();
+
+    // Then
+    universe
+        .await_expected_tx_statuses(ids.clone(), |status| {
+            matches!(status, TransactionStatus::SqueezedOut(s)
+                if s.reason.contains("was added to the blacklist"))
+        })
+        .await
+        .unwrap();
+    let found = service.shared.find(ids).await.unwrap();
+    assert!(found.iter().all(Option::is_none));
+    assert!(service.shared.blacklist().owners.contains(&owner));
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_blacklist__removed_entry_is_accepted_again() {
+    let mut universe = TestPoolUniverse::default();
+    let (_, coin_input) = universe.setup_coin();
+    let utxo_id = *coin_input.utxo_id().unwrap();
+    universe.config.black_list.coins.insert(utxo_id);
+    let tx = universe.build_script_transaction(Some(vec![coin_input]), None, 10);
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    let result = service.shared.insert(tx.clone()).await;
+    assert!(matches!(result, Err(Error::Blacklisted(_))));
+
+    // Given
+    service
+        .shared
+        .update_blacklist(BlackListUpdate::Remove(vec![BlackListEntry::Coin(utxo_id)]))
+        .await
+        .unwrap();
+
+    // When
+    let result = service.shared.insert(tx).await;
+
+    // Then
+    assert!(result.is_ok());
+    assert_eq!(service.shared.blacklist(), BlackList::default());
+
+    service.stop_and_await().await.unwrap();
+}
@@ -67,10 +67,7 @@ use crate::{
     GasPrice,
     Service,
     collision_manager::basic::BasicCollisionManager,
-    config::{
-        BlackList,
-        Config,
-    },
+    config::Config,
     error::{
         Error,
         InsertionErrorType,
@@ -80,7 +77,7 @@ use crate::{
         Pool,
         TxPoolStats,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
         Shared,
         TxPool,
@@ -183,7 +180,10 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(tx_new_executable_txs.clone()),
+            ConfigurableSelection::new(
+                self.config.selection_algorithm,
+                tx_new_executable_txs.clone(),
+            ),
             self.config.clone(),
             tx,
             tx_new_executable_txs,
@@ -241,13 +241,23 @@ impl TestPoolUniverse {
         inputs: Option<Vec<Input>>,
         outputs: Option<Vec<Output>>,
         tip: u64,
+    ) -> Transaction {
+        self.build_script_transaction_with_gas_limit(inputs, outputs, tip, GAS_LIMIT)
+    }
+
+    pub fn build_script_transaction_with_gas_limit(
+        &mut self,
+        inputs: Option<Vec<Input>>,
+        outputs: Option<Vec<Output>>,
+        tip: u64,
+        gas_limit: Word,
     ) -> Transaction {
         let mut inputs = inputs.unwrap_or_default();
         let (_, gas_coin) = self.setup_coin();
         inputs.push(gas_coin);
         let outputs = outputs.unwrap_or_default();
         let mut tx_builder = TransactionBuilder::script(vec![], vec![]);
-        tx_builder.script_gas_limit(GAS_LIMIT);
+        tx_builder.script_gas_limit(gas_limit);
         for input in inputs {
             tx_builder.add_input(input);
         }
@@ -310,7 +320,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info_provider),
                     wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -352,7 +362,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info),
                     wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -393,7 +403,7 @@ impl TestPoolUniverse {
                     chain_state_info_provider: Arc::new(mock_chain_state_info_provider),
                     wasm_checker: Arc::new(wasm_checker),
                     memory_pool: MemoryPool::new(),
-                    blacklist: BlackList::default(),
+                    blacklist: Default::default(),
                 };
                 let tx = verification.perform_all_verifications(
                     tx,
@@ -541,6 +551,18 @@ impl TestPoolUniverse {
         .unwrap();
     }
 
+    pub(crate) async fn await_expected_tx_statuses_replaced(
+        &mut self,
+        tx_ids: Vec<TxId>,
+        replaced_by: TxId,
+    ) {
+        self.await_expected_tx_statuses(tx_ids, |status| {
+            matches!(status, TransactionStatus::Replaced(s) if s.replaced_by == replaced_by)
+        })
+        .await
+        .unwrap();
+    }
+
     pub(crate) async fn await_expected_tx_statuses(
         &mut self,
         tx_ids: Vec<TxId>,
@@ -2,6 +2,7 @@
 use crate::error::UpgradableError;
 use crate::{
     config::Config,
+    state_overrides::apply_state_overrides,
     storage_access_recorder::StorageAccessRecorder,
 };
 use fuel_core_executor::{
@@ -47,6 +48,7 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
             ValidationResult,
         },
@@ -109,8 +111,16 @@ enum ProduceBlockMode {
     DryRun {
         height: BlockHeightSelection,
         record_storage_reads: bool,
+        state_overrides: StateOverrides,
     },
 }
+
+/// The empty overrides used by the block production.
+static NO_STATE_OVERRIDES: StateOverrides = StateOverrides {
+    contracts: Vec::new(),
+    coins: Vec::new(),
+};
+
 impl ProduceBlockMode {
     fn is_dry_run(&self) -> bool {
         matches!(self, Self::DryRun { .. })
@@ -125,6 +135,15 @@ impl ProduceBlockMode {
             } => *record_storage_reads,
         }
     }
+
+    fn state_overrides(&self) -> &StateOverrides {
+        match self {
+            Self::Produce => &NO_STATE_OVERRIDES,
+            Self::DryRun {
+                state_overrides, ..
+            } => state_overrides,
+        }
+    }
 }
 
 /// Result of various `produce_*` functions
@@ -394,6 +413,7 @@ where
             ProduceBlockMode::DryRun {
                 height: BlockHeightSelection::Latest,
                 record_storage_reads: false,
+                state_overrides: Default::default(),
             },
         )
         .map(|r| r.map_result(|produced| produced.result))
@@ -445,13 +465,15 @@ where
     }
 
     /// Executes the block and returns the result of the execution without committing
-    /// the changes in the dry run mode.
+    /// the changes in the dry run mode. The `state_overrides` are applied on top of
+    /// the state used by the dry run.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_reads: bool,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<DryRunResult> {
         if at_height.is_some() && !self.config.allow_historical_execution {
             return Err(ExecutorError::Other(
@@ -459,6 +481,13 @@ where
             ));
         }
 
+        if record_storage_reads && !state_overrides.is_empty() {
+            return Err(ExecutorError::Other(
+                "The storage reads can't be recorded with the state overrides"
+                    .to_string(),
+            ));
+        }
+
         // fallback to service config value if no utxo_validation override is provided
         let forbid_fake_coins =
             forbid_fake_coins.unwrap_or(self.config.forbid_fake_coins_default);
@@ -496,6 +525,7 @@ where
                         None => BlockHeightSelection::Latest,
                     },
                     record_storage_reads,
+                    state_overrides,
                 },
             )?
             .into_result();
@@ -853,6 +883,7 @@ where
         let mut storage_rec = Default::default();
         let instance_without_input = if let Some(previous_block_height) = db_height {
             let storage = self.storage_view_provider.view_at(&previous_block_height)?;
+            let storage = apply_state_overrides(storage, mode.state_overrides())?;
             if mode.record_storage_reads() {
                 let storage = StorageAccessRecorder::new(storage);
                 storage_rec = storage.record.clone();
@@ -862,6 +893,7 @@ where
             }
         } else {
             let storage = self.storage_view_provider.latest_view()?;
+            let storage = apply_state_overrides(storage, mode.state_overrides())?;
             if mode.record_storage_reads() {
                 let storage = StorageAccessRecorder::new(storage);
                 storage_rec = storage.record.clone();
@@ -1006,6 +1038,7 @@ where
 
         let result = if let Some(previous_block_height) = db_height {
             let database = self.storage_view_provider.view_at(&previous_block_height)?;
+            let database = apply_state_overrides(database, mode.state_overrides())?;
             if mode.record_storage_reads() {
                 let database = StorageAccessRecorder::new(database);
                 storage_rec = database.record.clone();
@@ -1029,6 +1062,7 @@ where
             }
         } else {
             let database = self.storage_view_provider.latest_view()?;
+            let database = apply_state_overrides(database, mode.state_overrides())?;
             if mode.record_storage_reads() {
                 let database = StorageAccessRecorder::new(database);
                 storage_rec = database.record.clone();
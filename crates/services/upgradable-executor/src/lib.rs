@@ -6,7 +6,9 @@
 pub mod config;
 pub mod error;
 pub mod executor;
+pub mod replay;
 
+mod state_overrides;
 mod storage_access_recorder;
 pub use fuel_core_executor as native_executor;
 
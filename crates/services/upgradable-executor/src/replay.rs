@@ -0,0 +1,278 @@
+//! The re-execution of blocks and transactions on top of the storage reads recorded
+//! by the `storage_read_replay` and `dry_run` with `record_storage_reads`.
+//! It doesn't require access to the database, so the execution of the block
+//! can be reproduced anywhere using the same executor.
+
+use fuel_core_executor::ports::RelayerPort;
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+    column::Column,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+    services::{
+        executor::{
+            StorageReadReplayEvent,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        relayer::Event,
+    },
+};
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    sync::Arc,
+};
+
+/// The recorded values by the column and the key.
+type RecordedReads = HashMap<(u32, Vec<u8>), Option<Value>>;
+
+/// The storage that serves only the recorded reads.
+/// Reading a key that wasn't recorded is an error, because it means
+/// that the execution diverged from the recorded one.
+#[derive(Debug, Clone, Default)]
+pub struct StorageReadReplay {
+    reads: Arc<RecordedReads>,
+}
+
+impl StorageReadReplay {
+    pub fn new(reads: impl IntoIterator<Item = StorageReadReplayEvent>) -> Self {
+        let reads = reads
+            .into_iter()
+            .map(|event| {
+                let value = event.value.map(Into::into);
+                ((event.column, event.key), value)
+            })
+            .collect();
+        Self {
+            reads: Arc::new(reads),
+        }
+    }
+}
+
+impl KeyValueInspect for StorageReadReplay {
+    type Column = Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.reads
+            .get(&(column.id(), key.to_vec()))
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::Other(anyhow::anyhow!(
+                    "The read of the key `0x{}` from the column `{}` wasn't recorded",
+                    to_hex(key),
+                    column.name()
+                ))
+            })
+    }
+}
+
+impl AtomicView for StorageReadReplay {
+    type LatestView = Self;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(self.clone())
+    }
+}
+
+impl HistoricalView for StorageReadReplay {
+    type Height = BlockHeight;
+    type ViewAtHeight = Self;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        None
+    }
+
+    fn view_at(&self, _: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(self.clone())
+    }
+}
+
+/// The relayer that serves only the recorded events.
+#[derive(Debug, Clone, Default)]
+pub struct RelayerEventsReplay {
+    events: Arc<HashMap<DaBlockHeight, Vec<Event>>>,
+}
+
+impl RelayerEventsReplay {
+    pub fn new(events: impl IntoIterator<Item = (DaBlockHeight, Vec<Event>)>) -> Self {
+        Self {
+            events: Arc::new(events.into_iter().collect()),
+        }
+    }
+}
+
+impl RelayerPort for RelayerEventsReplay {
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn get_events(&self, da_height: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        self.events.get(da_height).cloned().ok_or_else(|| {
+            anyhow::anyhow!("The events at the DA height `{da_height}` weren't recorded")
+        })
+    }
+}
+
+impl AtomicView for RelayerEventsReplay {
+    type LatestView = Self;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(self.clone())
+    }
+}
+
+/// Compares the statuses of the transactions and returns the human-readable
+/// description of each difference. The empty result means that they are the same.
+pub fn diff_tx_statuses(
+    expected: &[TransactionExecutionStatus],
+    actual: &[TransactionExecutionStatus],
+) -> Vec<String> {
+    let mut diff = vec![];
+
+    if expected.len() != actual.len() {
+        diff.push(format!(
+            "Expected {} transactions, but {} were executed",
+            expected.len(),
+            actual.len()
+        ));
+    }
+
+    for (index, (expected, actual)) in expected.iter().zip(actual.iter()).enumerate() {
+        if expected.id != actual.id {
+            diff.push(format!(
+                "Transaction #{index}: expected id {}, got {}",
+                expected.id, actual.id
+            ));
+            continue;
+        }
+
+        let id = &expected.id;
+        let expected = &expected.result;
+        let actual = &actual.result;
+
+        if is_success(expected) != is_success(actual) {
+            diff.push(format!(
+                "Transaction {id}: expected success `{}`, got `{}`",
+                is_success(expected),
+                is_success(actual)
+            ));
+        }
+        if expected.total_gas() != actual.total_gas() {
+            diff.push(format!(
+                "Transaction {id}: expected total gas {}, got {}",
+                expected.total_gas(),
+                actual.total_gas()
+            ));
+        }
+        if expected.total_fee() != actual.total_fee() {
+            diff.push(format!(
+                "Transaction {id}: expected total fee {}, got {}",
+                expected.total_fee(),
+                actual.total_fee()
+            ));
+        }
+        if expected.receipts() != actual.receipts() {
+            diff.push(format!(
+                "Transaction {id}: expected receipts {:?}, got {:?}",
+                expected.receipts(),
+                actual.receipts()
+            ));
+        }
+    }
+
+    diff
+}
+
+fn is_success(result: &TransactionExecutionResult) -> bool {
+    matches!(result, TransactionExecutionResult::Success { .. })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::Bytes32;
+
+    fn status(id: u8, total_gas: u64) -> TransactionExecutionStatus {
+        TransactionExecutionStatus {
+            id: Bytes32::from([id; 32]),
+            result: TransactionExecutionResult::Success {
+                result: None,
+                receipts: vec![],
+                total_gas,
+                total_fee: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn storage_read_replay__returns_recorded_reads() {
+        // Given
+        let storage = StorageReadReplay::new(vec![
+            StorageReadReplayEvent {
+                column: Column::Metadata.id(),
+                key: vec![1],
+                value: Some(vec![2]),
+            },
+            StorageReadReplayEvent {
+                column: Column::Metadata.id(),
+                key: vec![3],
+                value: None,
+            },
+        ]);
+
+        // When
+        let existing = storage.get(&[1], Column::Metadata).unwrap();
+        let missing = storage.get(&[3], Column::Metadata).unwrap();
+
+        // Then
+        assert_eq!(existing.as_deref(), Some([2].as_slice()));
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn storage_read_replay__fails_on_not_recorded_read() {
+        // Given
+        let storage = StorageReadReplay::new(vec![]);
+
+        // When
+        let result = storage.get(&[1], Column::Metadata);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn diff_tx_statuses__reports_only_differences() {
+        // Given
+        let expected = vec![status(1, 10), status(2, 20)];
+        let actual = vec![status(1, 10), status(2, 30)];
+
+        // When
+        let diff = diff_tx_statuses(&expected, &actual);
+
+        // Then
+        assert_eq!(diff.len(), 1);
+        assert!(diff[0].contains("total gas"));
+    }
+}
@@ -0,0 +1,183 @@
+use fuel_core_storage::{
+    ContractsAssetKey,
+    ContractsStateKey,
+    StorageAsMut,
+    StorageAsRef,
+    column::Column,
+    kv_store::KeyValueInspect,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+    },
+    transactional::{
+        ConflictPolicy,
+        IntoTransaction,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::services::executor::{
+    Result as ExecutorResult,
+    StateOverrides,
+};
+
+/// Layers the `overrides` on top of the `storage`.
+///
+/// The overrides are written into the storage transaction that is only used by
+/// the dry run, so they are never committed to the database.
+pub fn apply_state_overrides<S>(
+    storage: S,
+    overrides: &StateOverrides,
+) -> ExecutorResult<StorageTransaction<S>>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    let mut storage = storage
+        .into_transaction()
+        .with_policy(ConflictPolicy::Overwrite);
+
+    for contract in &overrides.contracts {
+        let contract_id = &contract.contract_id;
+
+        if let Some(bytecode) = &contract.bytecode {
+            storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(contract_id, bytecode)?;
+
+            let exists = storage
+                .storage_as_ref::<ContractsLatestUtxo>()
+                .contains_key(contract_id)?;
+            if !exists {
+                storage
+                    .storage_as_mut::<ContractsLatestUtxo>()
+                    .insert(contract_id, &Default::default())?;
+            }
+        }
+
+        for (key, value) in &contract.storage_slots {
+            storage
+                .storage_as_mut::<ContractsState>()
+                .insert(&ContractsStateKey::new(contract_id, key), value)?;
+        }
+
+        for (asset_id, amount) in &contract.balances {
+            storage
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(contract_id, asset_id), amount)?;
+        }
+    }
+
+    for coin in &overrides.coins {
+        storage
+            .storage_as_mut::<Coins>()
+            .insert(&coin.utxo_id, &coin.compress())?;
+    }
+
+    Ok(storage)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        structured_storage::test::InMemoryStorage,
+        transactional::{
+            ReadTransaction,
+            WriteTransaction,
+        },
+    };
+    use fuel_core_types::{
+        entities::coins::coin::Coin,
+        fuel_tx::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            UtxoId,
+        },
+        services::executor::ContractOverride,
+    };
+
+    #[test]
+    fn apply_state_overrides__layers_overrides_without_modifying_the_storage() {
+        // Given
+        let mut storage = InMemoryStorage::<Column>::default();
+        let contract_id = ContractId::from([1; 32]);
+        let slot = Bytes32::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let mut tx = storage.write_transaction();
+        tx.storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &10)
+            .unwrap();
+        tx.commit().unwrap();
+
+        let coin = Coin {
+            utxo_id: UtxoId::new([4; 32].into(), 0),
+            owner: Default::default(),
+            amount: 100,
+            asset_id,
+            tx_pointer: Default::default(),
+        };
+        let overrides = StateOverrides {
+            contracts: vec![ContractOverride {
+                contract_id,
+                bytecode: Some(vec![5; 8]),
+                storage_slots: vec![(slot, vec![6; 32])],
+                balances: vec![(asset_id, 20)],
+            }],
+            coins: vec![coin],
+        };
+
+        // When
+        let overridden = apply_state_overrides(&storage, &overrides).unwrap();
+
+        // Then
+        let balance_key = ContractsAssetKey::new(&contract_id, &asset_id);
+        assert_eq!(
+            overridden
+                .storage_as_ref::<ContractsAssets>()
+                .get(&balance_key)
+                .unwrap()
+                .unwrap()
+                .into_owned(),
+            20
+        );
+        assert!(
+            overridden
+                .storage_as_ref::<ContractsLatestUtxo>()
+                .contains_key(&contract_id)
+                .unwrap()
+        );
+        assert!(
+            overridden
+                .storage_as_ref::<ContractsState>()
+                .contains_key(&ContractsStateKey::new(&contract_id, &slot))
+                .unwrap()
+        );
+        assert!(
+            overridden
+                .storage_as_ref::<Coins>()
+                .contains_key(&coin.utxo_id)
+                .unwrap()
+        );
+        assert_eq!(
+            storage
+                .read_transaction()
+                .storage_as_ref::<ContractsAssets>()
+                .get(&balance_key)
+                .unwrap()
+                .unwrap()
+                .into_owned(),
+            10
+        );
+        assert!(
+            !storage
+                .read_transaction()
+                .storage_as_ref::<ContractsRawCode>()
+                .contains_key(&contract_id)
+                .unwrap()
+        );
+    }
+}
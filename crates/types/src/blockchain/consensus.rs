@@ -1,4 +1,4 @@
-//! Consensus configuration, including specific consensus types like PoA
+//! Consensus configuration, including specific consensus types like PoA and BFT
 
 use crate::{
     blockchain::primitives::BlockId,
@@ -10,8 +10,10 @@ use crate::{
 };
 
 // Different types of consensus are represented as separate modules
+pub mod bft;
 pub mod poa;
 
+use bft::BftConsensus;
 use poa::PoAConsensus;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,6 +26,11 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Byzantine fault tolerant consensus of multiple validators
+    Bft(BftConsensus),
+    /// The block was derived from the DA compressed block, which doesn't
+    /// contain the seal of the block producer.
+    DaDerived,
 }
 
 impl Consensus {
@@ -39,6 +46,17 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::Bft(bft_data) => {
+                let public_key = bft_data
+                    .proposer_signature
+                    .recover(block_id.as_message())
+                    .map_err(|e| anyhow::anyhow!("Can't recover public key: {:?}", e))?;
+                let address = Input::owner(&public_key);
+                Ok(address)
+            }
+            Consensus::DaDerived => Err(anyhow::anyhow!(
+                "The block derived from the DA layer doesn't have the seal of the block producer"
+            )),
         }
     }
 }
@@ -54,6 +72,8 @@ impl Default for Consensus {
 pub enum ConsensusType {
     /// Proof of authority
     PoA,
+    /// Byzantine fault tolerant consensus
+    Bft,
 }
 
 /// A sealed entity with consensus info.
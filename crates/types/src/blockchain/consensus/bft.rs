@@ -0,0 +1,275 @@
+//! Byzantine fault tolerant consensus
+//!
+//! The validators agree on the next block in the rounds of the Tendermint protocol.
+//! Each round has a proposer that broadcasts the block, and the validators vote
+//! for it in two steps: prevote and precommit. The block is committed when
+//! the validators with more than 2/3 of the voting power precommit it.
+
+use crate::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+
+/// The domain separator of the signed proposals.
+const PROPOSAL_DOMAIN: &[u8] = b"fuel-bft-proposal";
+/// The domain separator of the signed votes.
+const VOTE_DOMAIN: &[u8] = b"fuel-bft-vote";
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data that doesn't live on the
+/// header.
+pub struct BftConsensus {
+    /// The round in which the block was committed.
+    pub round: u32,
+    /// The signature of the block id by the proposer of the round.
+    pub proposer_signature: Signature,
+    /// The precommit signatures of the validators that committed the block,
+    /// one signature per validator. The signatures are not aggregated.
+    pub precommits: Vec<Signature>,
+}
+
+impl BftConsensus {
+    /// Create a new block consensus.
+    pub fn new(
+        round: u32,
+        proposer_signature: Signature,
+        precommits: Vec<Signature>,
+    ) -> Self {
+        Self {
+            round,
+            proposer_signature,
+            precommits,
+        }
+    }
+
+    /// Recovers the addresses of the validators that precommitted the block.
+    pub fn precommit_signers(
+        &self,
+        height: BlockHeight,
+        block_id: &BlockId,
+    ) -> anyhow::Result<Vec<Address>> {
+        let message = Vote::precommit(height, self.round, Some(*block_id)).message();
+        self.precommits
+            .iter()
+            .map(|signature| recover_address(signature, &message))
+            .collect()
+    }
+}
+
+/// The step of the round the vote belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteStep {
+    /// The first voting step, the validator accepts the proposal.
+    Prevote,
+    /// The second voting step, the validator commits to the proposal.
+    Precommit,
+}
+
+/// The vote of the validator for the block in the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vote {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the vote.
+    pub round: u32,
+    /// The step of the vote.
+    pub step: VoteStep,
+    /// The id of the block, or `None` for the nil vote.
+    pub block_id: Option<BlockId>,
+}
+
+impl Vote {
+    /// Creates a new prevote.
+    pub fn prevote(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            height,
+            round,
+            step: VoteStep::Prevote,
+            block_id,
+        }
+    }
+
+    /// Creates a new precommit.
+    pub fn precommit(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            height,
+            round,
+            step: VoteStep::Precommit,
+            block_id,
+        }
+    }
+
+    /// The message signed by the validator.
+    pub fn message(&self) -> Message {
+        let step: u8 = match self.step {
+            VoteStep::Prevote => 0,
+            VoteStep::Precommit => 1,
+        };
+        let mut bytes = Vec::with_capacity(96);
+        bytes.extend_from_slice(VOTE_DOMAIN);
+        bytes.extend_from_slice(&u32::from(self.height).to_be_bytes());
+        bytes.extend_from_slice(&self.round.to_be_bytes());
+        bytes.push(step);
+        match &self.block_id {
+            Some(block_id) => {
+                bytes.push(1);
+                bytes.extend_from_slice(block_id.as_slice());
+            }
+            None => bytes.push(0),
+        }
+        Message::new(bytes)
+    }
+
+    /// Signs the vote with the secret key of the validator.
+    pub fn sign(self, secret: &SecretKey) -> SignedVote {
+        let signature = Signature::sign(secret, &self.message());
+        SignedVote {
+            vote: self,
+            signature,
+        }
+    }
+}
+
+/// The vote along with the signature of the validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedVote {
+    /// The vote.
+    pub vote: Vote,
+    /// The signature of the vote message.
+    pub signature: Signature,
+}
+
+impl SignedVote {
+    /// Recovers the address of the validator that signed the vote.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        recover_address(&self.signature, &self.vote.message())
+    }
+}
+
+/// The block proposed by the proposer of the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the proposal.
+    pub round: u32,
+    /// The round in which the block got the quorum of prevotes,
+    /// if the proposer re-proposes the block from the previous round.
+    pub valid_round: Option<u32>,
+    /// The proposed block.
+    pub block: Block,
+    /// The signature of the block id by the proposer.
+    /// It becomes a part of the [`BftConsensus`] if the block is committed.
+    pub block_signature: Signature,
+}
+
+impl Proposal {
+    /// The message signed by the proposer.
+    pub fn message(&self) -> Message {
+        let mut bytes = Vec::with_capacity(96);
+        bytes.extend_from_slice(PROPOSAL_DOMAIN);
+        bytes.extend_from_slice(&u32::from(self.height).to_be_bytes());
+        bytes.extend_from_slice(&self.round.to_be_bytes());
+        match self.valid_round {
+            Some(valid_round) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&valid_round.to_be_bytes());
+            }
+            None => bytes.push(0),
+        }
+        bytes.extend_from_slice(self.block.id().as_slice());
+        Message::new(bytes)
+    }
+
+    /// Signs the proposal with the secret key of the proposer.
+    pub fn sign(self, secret: &SecretKey) -> SignedProposal {
+        let signature = Signature::sign(secret, &self.message());
+        SignedProposal {
+            proposal: self,
+            signature,
+        }
+    }
+}
+
+/// The proposal along with the signature of the proposer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedProposal {
+    /// The proposal.
+    pub proposal: Proposal,
+    /// The signature of the proposal message.
+    pub signature: Signature,
+}
+
+impl SignedProposal {
+    /// Recovers the address of the proposer.
+    ///
+    /// Fails if the proposal and the block are signed by different keys.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        let signer = recover_address(&self.signature, &self.proposal.message())?;
+        let block_id = self.proposal.block.id();
+        let block_signer =
+            recover_address(&self.proposal.block_signature, block_id.as_message())?;
+        anyhow::ensure!(
+            signer == block_signer,
+            "The proposal and the block are signed by different keys"
+        );
+        Ok(signer)
+    }
+}
+
+/// The messages exchanged by the validators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BftMessage {
+    /// The block proposed in the round.
+    Proposal(Box<SignedProposal>),
+    /// The vote for the block in the round.
+    Vote(SignedVote),
+}
+
+impl BftMessage {
+    /// The height of the block the message is about.
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.proposal.height,
+            BftMessage::Vote(vote) => vote.vote.height,
+        }
+    }
+
+    /// The round of the message.
+    pub fn round(&self) -> u32 {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.proposal.round,
+            BftMessage::Vote(vote) => vote.vote.round,
+        }
+    }
+}
+
+fn recover_address(signature: &Signature, message: &Message) -> anyhow::Result<Address> {
+    let public_key = signature
+        .recover(message)
+        .map_err(|e| anyhow::anyhow!("Can't recover public key: {:?}", e))?;
+    Ok(Input::owner(&public_key))
+}
@@ -45,11 +45,13 @@ pub mod fuel_vm {
         checked_transaction,
         constraints,
         consts,
+        context,
         crypto,
         double_key,
         error::PredicateVerificationFailed,
         interpreter,
         pool::VmMemoryPool,
+        predicate::RuntimePredicate,
         prelude::{
             Backtrace,
             Call,
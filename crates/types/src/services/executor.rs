@@ -22,6 +22,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
         ContractId,
@@ -34,6 +35,7 @@ use crate::{
         checked_transaction::CheckError,
     },
     services::Uncommitted,
+    tai64::Tai64,
 };
 
 #[cfg(feature = "alloc")]
@@ -284,6 +286,48 @@ pub struct DryRunResult {
     pub storage_reads: Vec<StorageReadReplayEvent>,
 }
 
+/// The overrides of the dry run. They are layered on top of the storage view used
+/// by the dry run and are never committed or used during the block production.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DryRunOverrides {
+    /// The height of the simulated block.
+    pub block_height: Option<BlockHeight>,
+    /// The timestamp of the simulated block.
+    pub timestamp: Option<Tai64>,
+    /// The overrides of the state.
+    pub state: StateOverrides,
+}
+
+/// The overrides of the state used by the dry run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateOverrides {
+    /// The overrides of the contracts.
+    pub contracts: Vec<ContractOverride>,
+    /// The coins that exist only during the dry run.
+    pub coins: Vec<Coin>,
+}
+
+impl StateOverrides {
+    /// Returns `true` if there is nothing to override.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty() && self.coins.is_empty()
+    }
+}
+
+/// The overrides of the contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractOverride {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The bytecode replacing the bytecode of the contract.
+    /// The contract is created if it doesn't exist.
+    pub bytecode: Option<Vec<u8>>,
+    /// The storage slots set on top of the storage of the contract.
+    pub storage_slots: Vec<(Bytes32, Vec<u8>)>,
+    /// The balances set on top of the balances of the contract.
+    pub balances: Vec<(AssetId, u64)>,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq, derive_more::Display, derive_more::From)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -15,8 +15,20 @@ use super::{
 #[cfg(feature = "test-helpers")]
 use crate::services::preconfirmation::PreconfirmationStatus;
 use crate::{
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+        consensus::bft::{
+            SignedProposal,
+            SignedVote,
+        },
+    },
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
     fuel_tx::Transaction,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
 use std::{
     collections::HashSet,
@@ -83,6 +95,15 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Sealed blocks gossiped by peers right after they were produced
+pub type BlockGossipData = GossipData<SealedBlock>;
+
+/// Block proposals gossiped by the BFT validators
+pub type BftProposalGossipData = GossipData<SignedProposal>;
+
+/// Votes gossiped by the BFT validators
+pub type BftVoteGossipData = GossipData<SignedVote>;
+
 /// A value and an associated signature
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -269,6 +290,16 @@ pub struct PeerInfo {
     pub app_score: f64,
 }
 
+/// Contains the ban of the peer
+pub struct BannedPeer {
+    /// The libp2p peer id
+    pub id: PeerId,
+    /// why the peer was banned
+    pub reason: String,
+    /// when the ban expires, the ban is permanent if it is not set
+    pub expires_at: Option<SystemTime>,
+}
+
 /// Contains information from the most recent heartbeat received by the peer
 pub struct HeartbeatData {
     /// The currently reported block height of the peer
@@ -376,11 +407,152 @@ impl TryFrom<NetworkableTransactionPool> for Transaction {
     }
 }
 
+/// The on-chain table that can be downloaded from the peers during the state sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StateSyncTable {
+    /// The `Coins` table.
+    Coins,
+    /// The `Messages` table.
+    Messages,
+    /// The `Blobs` table.
+    Blobs,
+    /// The `ContractsRawCode` table.
+    ContractsRawCode,
+    /// The `ContractsLatestUtxo` table.
+    ContractsLatestUtxo,
+    /// The `ContractsState` table.
+    ContractsState,
+    /// The `ContractsAssets` table.
+    ContractsAssets,
+    /// The `ProcessedTransactions` table.
+    ProcessedTransactions,
+    /// The `ConsensusParametersVersions` table.
+    ConsensusParametersVersions,
+    /// The `StateTransitionBytecodeVersions` table.
+    StateTransitionBytecodeVersions,
+    /// The `UploadedBytecodes` table.
+    UploadedBytecodes,
+    /// The `FuelBlockMerkleData` table.
+    FuelBlockMerkleData,
+    /// The `FuelBlockMerkleMetadata` table.
+    FuelBlockMerkleMetadata,
+}
+
+impl StateSyncTable {
+    /// All tables required to continue the import of blocks from the checkpoint.
+    pub const ALL: [Self; 13] = [
+        Self::Coins,
+        Self::Messages,
+        Self::Blobs,
+        Self::ContractsRawCode,
+        Self::ContractsLatestUtxo,
+        Self::ContractsState,
+        Self::ContractsAssets,
+        Self::ProcessedTransactions,
+        Self::ConsensusParametersVersions,
+        Self::StateTransitionBytecodeVersions,
+        Self::UploadedBytecodes,
+        Self::FuelBlockMerkleData,
+        Self::FuelBlockMerkleMetadata,
+    ];
+}
+
+/// The raw encoded key and value of the entry in the table.
+pub type StateEntry = (Vec<u8>, Vec<u8>);
+
+/// The chunks of the table at the checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableCheckpoint {
+    /// The table.
+    pub table: StateSyncTable,
+    /// The roots of the chunks of the table, calculated by the [`state_chunk_root`].
+    pub chunk_roots: Vec<Bytes32>,
+}
+
+/// The state of the on-chain tables at the height of the `header`.
+///
+/// The block header doesn't commit to the state, so the roots of the chunks
+/// can't be verified against the header. Instead, the node accepts the checkpoint
+/// only when its [`StateCheckpoint::root`] matches the root provided by the operator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateCheckpoint {
+    /// The sealed header of the block at the checkpoint height.
+    pub header: SealedBlockHeader,
+    /// The chunks of each table.
+    pub tables: Vec<TableCheckpoint>,
+}
+
+impl StateCheckpoint {
+    /// The height of the checkpoint.
+    pub fn height(&self) -> BlockHeight {
+        *self.header.entity.height()
+    }
+
+    /// Returns the chunks of the `table`.
+    pub fn chunk_roots(&self, table: StateSyncTable) -> &[Bytes32] {
+        self.tables
+            .iter()
+            .find(|checkpoint| checkpoint.table == table)
+            .map(|checkpoint| checkpoint.chunk_roots.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The merkle root that commits to the block and the roots of all chunks.
+    pub fn root(&self) -> Bytes32 {
+        let mut tree = MerkleRootCalculator::new();
+        tree.push(self.header.entity.id().as_ref());
+        for checkpoint in &self.tables {
+            for chunk_root in &checkpoint.chunk_roots {
+                let mut leaf = [0u8; 33];
+                leaf[0] = checkpoint.table as u8;
+                leaf[1..].copy_from_slice(chunk_root.as_ref());
+                tree.push(&leaf);
+            }
+        }
+        tree.root().into()
+    }
+}
+
+/// The request for the chunk of the table at the checkpoint height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateChunkRequest {
+    /// The height of the checkpoint.
+    pub height: BlockHeight,
+    /// The table.
+    pub table: StateSyncTable,
+    /// The index of the chunk in the [`TableCheckpoint::chunk_roots`].
+    pub index: u32,
+}
+
+/// Calculates the merkle root of the chunk of entries.
+pub fn state_chunk_root(entries: &[StateEntry]) -> Bytes32 {
+    let mut tree = MerkleRootCalculator::new();
+    for (key, value) in entries {
+        // The length prefix makes the boundary between the key and the value unambiguous.
+        let key_len = u32::try_from(key.len()).unwrap_or(u32::MAX);
+        let mut leaf = Vec::with_capacity(
+            4usize.saturating_add(key.len()).saturating_add(value.len()),
+        );
+        leaf.extend_from_slice(&key_len.to_be_bytes());
+        leaf.extend_from_slice(key);
+        leaf.extend_from_slice(value);
+        tree.push(&leaf);
+    }
+    tree.root().into()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         fuel_tx::Transaction,
-        services::p2p::NetworkableTransactionPool,
+        services::p2p::{
+            NetworkableTransactionPool,
+            state_chunk_root,
+        },
     };
 
     #[test]
@@ -396,4 +568,18 @@ mod tests {
         // Then
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn state_chunk_root_depends_on_key_value_boundary() {
+        // Given
+        let entries = vec![(vec![1, 2], vec![3])];
+        let shifted_entries = vec![(vec![1], vec![2, 3])];
+
+        // When
+        let root = state_chunk_root(&entries);
+        let shifted_root = state_chunk_root(&shifted_entries);
+
+        // Then
+        assert_ne!(root, shifted_root);
+    }
 }
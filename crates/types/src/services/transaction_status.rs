@@ -4,6 +4,7 @@ use crate::{
     fuel_tx::{
         Output,
         Receipt,
+        TxId,
         TxPointer,
     },
     fuel_vm::ProgramState,
@@ -151,6 +152,9 @@ pub enum TransactionStatus {
     SqueezedOut(Arc<statuses::SqueezedOut>),
     /// Transaction was squeezed out
     PreConfirmationSqueezedOut(Arc<statuses::PreConfirmationSqueezedOut>),
+    /// Transaction was replaced in the TxPool by another transaction
+    /// that spends the same input and pays a higher tip
+    Replaced(Arc<statuses::Replaced>),
     /// Transaction was included in a block, but the execution has failed
     Failure(Arc<statuses::Failure>),
     /// Transaction was included in a block by block producer, but the execution has failed
@@ -164,7 +168,8 @@ impl TransactionStatus {
             TransactionStatus::Success(_)
             | TransactionStatus::Failure(_)
             | TransactionStatus::SqueezedOut(_)
-            | TransactionStatus::PreConfirmationSqueezedOut(_) => true,
+            | TransactionStatus::PreConfirmationSqueezedOut(_)
+            | TransactionStatus::Replaced(_) => true,
             TransactionStatus::Submitted(_)
             | TransactionStatus::PreConfirmationSuccess(_)
             | TransactionStatus::PreConfirmationFailure(_) => false,
@@ -177,7 +182,8 @@ impl TransactionStatus {
             TransactionStatus::Submitted(_)
             | TransactionStatus::Success(_)
             | TransactionStatus::Failure(_)
-            | TransactionStatus::SqueezedOut(_) => false,
+            | TransactionStatus::SqueezedOut(_)
+            | TransactionStatus::Replaced(_) => false,
             TransactionStatus::PreConfirmationSuccess(_)
             | TransactionStatus::PreConfirmationFailure(_)
             | TransactionStatus::PreConfirmationSqueezedOut(_) => true,
@@ -199,6 +205,17 @@ impl TransactionStatus {
         Self::SqueezedOut(statuses::SqueezedOut { reason }.into())
     }
 
+    /// Creates a new `TransactionStatus::Replaced` variant.
+    pub fn replaced(replaced_by: TxId, reason: String) -> Self {
+        Self::Replaced(
+            statuses::Replaced {
+                replaced_by,
+                reason,
+            }
+            .into(),
+        )
+    }
+
     /// Creates a new `TransactionStatus::PreConfirmationSqueezedOut` variant.
     pub fn preconfirmation_squeezed_out(reason: String) -> Self {
         Self::PreConfirmationSqueezedOut(
@@ -365,6 +382,31 @@ pub mod statuses {
         }
     }
 
+    /// Transaction was replaced in the TxPool by another transaction
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Replaced {
+        /// The id of the transaction that replaced this one
+        pub replaced_by: TxId,
+        /// The reason why the transaction was replaced
+        pub reason: String,
+    }
+
+    impl Default for Replaced {
+        fn default() -> Self {
+            Self {
+                replaced_by: Default::default(),
+                reason: "Default reason".to_string(),
+            }
+        }
+    }
+
+    impl From<Replaced> for TransactionStatus {
+        fn from(value: Replaced) -> Self {
+            TransactionStatus::Replaced(value.into())
+        }
+    }
+
     /// Transaction was squeezed out from block producer
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Clone, Debug, PartialEq, Eq)]
@@ -596,3 +596,40 @@ async fn contract_balances_in_the_past() {
         contract_id.asset_id(&sub_asset_id)
     );
 }
+
+#[tokio::test]
+async fn contract_balance_proof__verifies_against_the_root_at_height() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2322u64);
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Instant;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let sub_asset_id = SubAssetId::new([1u8; 32]);
+    let amount = 1234;
+
+    let (deployed_height, contract_id) = mint_contract::deploy(&client, &mut rng).await;
+    let minted_height =
+        mint_contract::mint(&client, &mut rng, contract_id, sub_asset_id, amount).await;
+    let asset_id = contract_id.asset_id(&sub_asset_id);
+
+    // When
+    let proof_at_deployed = client
+        .contract_balance_proof(&contract_id, &asset_id, Some(deployed_height))
+        .await
+        .unwrap();
+    let proof_at_minted = client
+        .contract_balance_proof(&contract_id, &asset_id, Some(minted_height))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(proof_at_deployed.balance(), None);
+    assert!(proof_at_deployed.verify_balance(&contract_id, &asset_id));
+    assert_eq!(proof_at_minted.balance(), Some(amount));
+    assert!(proof_at_minted.verify_balance(&contract_id, &asset_id));
+    assert_ne!(proof_at_deployed.root, proof_at_minted.root);
+}
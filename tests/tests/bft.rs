@@ -0,0 +1,92 @@
+use fuel_core::p2p_test_helpers::*;
+use fuel_core_client::client::{
+    FuelClient,
+    types::{
+        Block,
+        Consensus,
+    },
+};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+async fn wait_for_height(node: &Node, height: u32) {
+    let client = FuelClient::from(node.node.bound_address);
+    let wait = async {
+        loop {
+            let chain = client.chain_info().await.unwrap();
+            if chain.latest_block.header.height >= height {
+                break
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    };
+    tokio::time::timeout(TIMEOUT, wait)
+        .await
+        .unwrap_or_else(|_| {
+            panic!("{} didn't reach the height {height}", node.config.name)
+        });
+}
+
+async fn block_at_height(node: &Node, height: u32) -> Block {
+    FuelClient::from(node.node.bound_address)
+        .block_by_height(height.into())
+        .await
+        .unwrap()
+        .expect("The block should exist")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__validators_and_followers_agree_on_blocks() {
+    // Given
+    let BftNodes {
+        validators,
+        followers,
+        bootstrap: _dont_drop,
+    } = make_bft_nodes(BftSetup::new(4).with_followers(1), None).await;
+
+    // When
+    for node in validators.iter().chain(followers.iter()) {
+        wait_for_height(node, 3).await;
+    }
+
+    // Then
+    for height in 1..=3 {
+        let expected = block_at_height(&validators[0], height).await;
+        let Consensus::BftConsensus(seal) = &expected.consensus else {
+            panic!("The block {height} should be sealed by the validators");
+        };
+        assert!(seal.precommits.len() >= 3);
+
+        for node in validators.iter().chain(followers.iter()) {
+            let block = block_at_height(node, height).await;
+            assert_eq!(block.id, expected.id);
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__validators_commit_blocks_if_one_validator_is_offline() {
+    // Given
+    let BftNodes {
+        mut validators,
+        bootstrap: _dont_drop,
+        ..
+    } = make_bft_nodes(BftSetup::new(4), None).await;
+    let mut offline = validators.pop().unwrap();
+    offline.shutdown().await;
+
+    // When
+    for node in &validators {
+        wait_for_height(node, 4).await;
+    }
+
+    // Then
+    for height in 1..=4 {
+        let expected = block_at_height(&validators[0], height).await;
+        for node in &validators {
+            let block = block_at_height(node, height).await;
+            assert_eq!(block.id, expected.id);
+        }
+    }
+}
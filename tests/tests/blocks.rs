@@ -512,3 +512,119 @@ mod full_block {
         );
     }
 }
+
+mod subscriptions {
+    use super::*;
+    use fuel_core_types::fuel_types::BlockHeight;
+    use futures::StreamExt;
+
+    async fn node() -> (FuelService, FuelClient) {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        (srv, client)
+    }
+
+    #[tokio::test]
+    async fn subscribe_blocks__replays_stored_blocks_and_continues_with_new_ones() {
+        let (_srv, client) = node().await;
+
+        // Given
+        client.produce_blocks(3, None).await.unwrap();
+
+        // When
+        let stream = client
+            .subscribe_blocks(Some(BlockHeight::from(1)))
+            .await
+            .unwrap();
+        client.produce_blocks(2, None).await.unwrap();
+
+        // Then
+        let heights = stream
+            .take(5)
+            .map(|block| block.unwrap().header.height)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn subscribe_blocks__without_height_starts_from_the_next_block() {
+        let (_srv, client) = node().await;
+
+        // Given
+        client.produce_blocks(2, None).await.unwrap();
+
+        // When
+        let stream = client.subscribe_blocks(None).await.unwrap();
+        client.produce_blocks(2, None).await.unwrap();
+
+        // Then
+        let heights = stream
+            .take(2)
+            .map(|block| block.unwrap().header.height)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(heights, vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn subscribe_headers__replays_stored_headers() {
+        let (_srv, client) = node().await;
+
+        // Given
+        client.produce_blocks(3, None).await.unwrap();
+        let expected_header = client
+            .block_by_height(BlockHeight::from(2))
+            .await
+            .unwrap()
+            .unwrap()
+            .header;
+
+        // When
+        let stream = client
+            .subscribe_headers(Some(BlockHeight::from(2)))
+            .await
+            .unwrap();
+
+        // Then
+        let headers = stream
+            .take(2)
+            .map(|header| header.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(headers[0], expected_header);
+        assert_eq!(headers[1].height, 3);
+    }
+
+    #[tokio::test]
+    async fn subscribe_blocks_with_transactions__contains_transactions_with_receipts() {
+        let (_srv, client) = node().await;
+
+        // Given
+        let tx = Transaction::default_test_tx();
+        client.submit_and_await_commit(&tx).await.unwrap();
+
+        // When
+        let mut stream = Box::pin(
+            client
+                .subscribe_blocks_with_transactions(Some(BlockHeight::from(1)))
+                .await
+                .unwrap(),
+        );
+
+        // Then
+        let block = stream.next().await.unwrap().unwrap();
+        assert_eq!(block.block.header.height, 1);
+        // The submitted transaction and the mint transaction.
+        assert_eq!(block.transactions.len(), 2);
+        assert_eq!(block.block.transactions.len(), 2);
+        match &block.transactions[0].status {
+            TransactionStatus::Success { receipts, .. } => {
+                assert!(!receipts.is_empty())
+            }
+            status => panic!("Unexpected status {status:?}"),
+        }
+    }
+}
@@ -5,15 +5,20 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        adapters::da_derivation::compressed_block_path,
         config::{
             DaCompressionConfig,
             DaCompressionMode,
+            DaDerivationConfig,
         },
     },
 };
 use fuel_core_client::client::{
     FuelClient,
-    types::TransactionStatus,
+    types::{
+        Consensus,
+        TransactionStatus,
+    },
 };
 use fuel_core_compression::{
     VersionedCompressedBlock,
@@ -35,6 +40,7 @@ use fuel_core_types::{
     },
     fuel_crypto::SecretKey,
     fuel_tx::{
+        Bytes32,
         Input,
         UniqueIdentifier,
     },
@@ -147,6 +153,161 @@ async fn can_fetch_da_compressed_block_from_graphql() {
     }
 }
 
+/// Produces the blocks with one script each and writes their DA compressed
+/// blocks to the directory, which stands in for the DA layer.
+async fn produce_da_compressed_blocks(
+    blocks: usize,
+    retention_duration: Duration,
+) -> (FuelService, tempfile::TempDir, BlockHeight) {
+    let mut producer_config = config_with_fee();
+    producer_config.da_compression = DaCompressionMode::Enabled(DaCompressionConfig {
+        retention_duration,
+        starting_height: None,
+        metrics: false,
+    });
+    let producer = FuelService::new_node(producer_config).await.unwrap();
+    let producer_client = FuelClient::from(producer.bound_address);
+
+    let wallet_secret =
+        SecretKey::from_str(TESTNET_WALLET_SECRETS[1]).expect("Expected valid secret");
+    let mut block_height = BlockHeight::from(0u32);
+    for _ in 0..blocks {
+        let status = producer_client
+            .run_script(
+                vec![op::ret(RegId::ONE)],
+                vec![],
+                SigningAccount::Wallet(wallet_secret),
+            )
+            .await
+            .unwrap();
+        block_height = match status {
+            TransactionStatus::Success { block_height, .. } => block_height,
+            other => {
+                panic!("unexpected result {other:?}")
+            }
+        };
+    }
+    producer
+        .await_compression_synced_until(&block_height)
+        .await
+        .unwrap();
+
+    let da_directory = tempfile::tempdir().unwrap();
+    for height in 1..=u32::from(block_height) {
+        let block = producer_client
+            .da_compressed_block(height.into())
+            .await
+            .unwrap()
+            .expect("Unable to get compressed block");
+        std::fs::write(compressed_block_path(da_directory.path(), height), block)
+            .unwrap();
+    }
+
+    (producer, da_directory, block_height)
+}
+
+async fn start_derivation_node(
+    da_directory: &tempfile::TempDir,
+    retention_duration: Duration,
+) -> FuelService {
+    let mut derivation_config = config_with_fee();
+    derivation_config.da_derivation = Some(DaDerivationConfig {
+        source_path: da_directory.path().to_path_buf(),
+        retention_duration,
+        poll_interval: Duration::from_millis(100),
+    });
+    FuelService::new_node(derivation_config).await.unwrap()
+}
+
+#[tokio::test]
+async fn da_derivation__imports_blocks_from_da_compressed_blocks_of_producer() {
+    // Given
+    let retention_duration = Duration::from_secs(3600);
+    let (producer, da_directory, block_height) =
+        produce_da_compressed_blocks(3, retention_duration).await;
+    let producer_client = FuelClient::from(producer.bound_address);
+
+    // When
+    let derivation_node = start_derivation_node(&da_directory, retention_duration).await;
+    let derivation_client = FuelClient::from(derivation_node.bound_address);
+
+    tokio::time::timeout(Duration::from_secs(20), async {
+        while derivation_client
+            .block_by_height(block_height)
+            .await
+            .unwrap()
+            .is_none()
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The blocks were not derived in time");
+
+    // Then
+    for height in 1..=u32::from(block_height) {
+        let expected = producer_client
+            .block_by_height(height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        let actual = derivation_client
+            .block_by_height(height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(expected.id, actual.id);
+        assert_eq!(expected.transactions, actual.transactions);
+        assert_eq!(actual.consensus, Consensus::DaDerivedConsensus);
+    }
+}
+
+#[tokio::test]
+async fn da_derivation__rejects_block_with_wrong_prev_root() {
+    // Given
+    let retention_duration = Duration::from_secs(3600);
+    let (_producer, da_directory, block_height) =
+        produce_da_compressed_blocks(2, retention_duration).await;
+    let block_path = compressed_block_path(da_directory.path(), block_height.into());
+    let mut block: VersionedCompressedBlock =
+        postcard::from_bytes(&std::fs::read(&block_path).unwrap()).unwrap();
+    match &mut block {
+        VersionedCompressedBlock::V0(block) => {
+            block.header.consensus.prev_root = Bytes32::zeroed();
+        }
+        #[cfg(feature = "fault-proving")]
+        VersionedCompressedBlock::V1(block) => {
+            block.header.consensus.prev_root = Bytes32::zeroed();
+        }
+    }
+    std::fs::write(&block_path, postcard::to_allocvec(&block).unwrap()).unwrap();
+    let previous_height = block_height.pred().unwrap();
+
+    // When
+    let derivation_node = start_derivation_node(&da_directory, retention_duration).await;
+    let derivation_client = FuelClient::from(derivation_node.bound_address);
+    tokio::time::timeout(Duration::from_secs(20), async {
+        while derivation_client
+            .block_by_height(previous_height)
+            .await
+            .unwrap()
+            .is_none()
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The valid blocks were not derived in time");
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    // Then
+    let rejected = derivation_client
+        .block_by_height(block_height)
+        .await
+        .unwrap();
+    assert!(rejected.is_none());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
     let mut rng = StdRng::seed_from_u64(line!() as u64);
@@ -1,10 +1,36 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    service::{
+        Config,
+        FuelService,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_tx::{
+        AssetId,
+        BlobBody,
+        BlobId,
+        BlobIdExt,
+        Create,
+        Finalizable,
+        Input,
+        Receipt,
+        Transaction,
+        TransactionBuilder,
+        TxPointer,
+        UniqueIdentifier,
+        field::Outputs,
+    },
     fuel_types::ContractId,
 };
 
@@ -44,3 +70,197 @@ async fn debugger_integration() {
     let result = client.end_session(session_id).await.unwrap();
     assert!(result);
 }
+
+#[tokio::test]
+async fn debugger_executes_create_transaction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    // Given
+    let create: Transaction =
+        TransactionBuilder::create(vec![].into(), Default::default(), vec![])
+            .add_contract_created()
+            .add_fee_input()
+            .finalize_as_transaction();
+
+    // When
+    let status = client.start_tx(session_id, &create).await.unwrap();
+
+    // Then
+    assert!(status.breakpoint.is_none());
+}
+
+#[tokio::test]
+async fn debugger_stops_on_breakpoint_inside_of_predicate() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    // Given
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(10_000)
+        .add_input(Input::coin_predicate(
+            Default::default(),
+            owner,
+            1000,
+            AssetId::BASE,
+            TxPointer::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .finalize_as_transaction();
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 0)
+        .await
+        .unwrap();
+
+    // When
+    let predicate_status = client.start_tx(session_id, &tx).await.unwrap();
+    let script_status = client.continue_tx(session_id).await.unwrap();
+    let final_status = client.continue_tx(session_id).await.unwrap();
+
+    // Then
+    assert!(predicate_status.breakpoint.is_some());
+    assert_eq!(predicate_status.receipts().count(), 0);
+    assert!(script_status.breakpoint.is_some());
+    assert!(final_status.breakpoint.is_none());
+}
+
+#[tokio::test]
+async fn debugger_session_at_height_uses_state_before_the_block() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Instant;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let create: Create =
+        TransactionBuilder::create(vec![].into(), Default::default(), vec![])
+            .add_contract_created()
+            .add_fee_input()
+            .finalize();
+    let contract_id = *create.outputs()[0].contract_id().unwrap();
+    let TransactionStatus::Success { block_height, .. } = client
+        .submit_and_await_commit(&create.clone().into())
+        .await
+        .unwrap()
+    else {
+        panic!("Failed to deploy the contract");
+    };
+    client.produce_blocks(1, None).await.unwrap();
+    assert!(client.contract(&contract_id).await.unwrap().is_some());
+
+    // When
+    let session = client.start_session_at(block_height).await.unwrap();
+    let session_id = session.as_str();
+    let executed = client.execute(session_id, &op::bhei(0x10)).await.unwrap();
+    let height = client.register(session_id, 0x10).await.unwrap();
+    let status = client.start_tx(session_id, &create.into()).await;
+
+    // Then
+    assert!(executed);
+    assert_eq!(height, u64::from(*block_height));
+    // The contract doesn't exist before the block, so it can be deployed again.
+    assert!(status.unwrap().breakpoint.is_none());
+}
+
+#[tokio::test]
+async fn debugger_session_at_height_applies_preceding_transactions_of_the_block() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let blob_data = vec![op::ret(RegId::ONE)].into_iter().collect::<Vec<u8>>();
+    let blob_id = BlobId::compute(&blob_data);
+    let blob: Transaction = TransactionBuilder::blob(BlobBody {
+        id: blob_id,
+        witness_index: 0,
+    })
+    .add_witness(blob_data.into())
+    .tip(100)
+    .max_fee_limit(100)
+    .add_fee_input()
+    .finalize_as_transaction();
+    // The script panics if the blob doesn't exist.
+    let script: Transaction = TransactionBuilder::script(
+        vec![
+            op::gtf_args(0x11, RegId::ZERO, GTFArgs::ScriptData),
+            op::bsiz(0x20, 0x11),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+        blob_id.to_bytes(),
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize_as_transaction();
+    client.submit(&blob).await.unwrap();
+    client.submit(&script).await.unwrap();
+    let block_height = client.produce_blocks(1, None).await.unwrap();
+    let status = client
+        .transaction_status(&script.id(&Default::default()))
+        .await
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "The blob should be uploaded before the script in the block"
+    );
+
+    // When
+    let session = client.start_session_at(block_height).await.unwrap();
+    let status = client.start_tx(session.as_str(), &script).await.unwrap();
+
+    // Then
+    let panicked = status
+        .receipts()
+        .any(|receipt| matches!(receipt, Receipt::Panic { .. }));
+    assert!(
+        !panicked,
+        "The blob of the preceding transaction should be applied"
+    );
+}
+
+#[tokio::test]
+async fn debugger_session_at_height_rejects_already_applied_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let first: Transaction = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    let second: Transaction = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    client.submit(&first).await.unwrap();
+    client.submit(&second).await.unwrap();
+    let block_height = client.produce_blocks(1, None).await.unwrap();
+    let session = client.start_session_at(block_height).await.unwrap();
+    let session_id = session.as_str();
+    client.start_tx(session_id, &second).await.unwrap();
+
+    // When
+    let already_applied = client.start_tx(session_id, &first).await;
+    client.reset(session_id).await.unwrap();
+    let after_reset = client.start_tx(session_id, &first).await;
+
+    // Then
+    assert!(already_applied.is_err());
+    assert!(after_reset.is_ok());
+}
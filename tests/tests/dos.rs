@@ -5,9 +5,16 @@ use std::time::{
     Instant,
 };
 
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    fuel_core_graphql_api::rate_limit::{
+        ApiKey,
+        Quota,
+        RateLimitConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::blockchain::header::LATEST_STATE_TRANSITION_VERSION;
@@ -445,6 +452,73 @@ async fn concurrency_limit_0_allows_unthrottled_queries() {
     assert_eq!(result.status(), 200);
 }
 
+fn rate_limited_config() -> Config {
+    let mut config = Config::local_node();
+    config.graphql_config.rate_limit = Some(RateLimitConfig {
+        per_ip: Some(Quota {
+            capacity: 1,
+            refill_per_second: 1,
+        }),
+        api_keys: vec![ApiKey {
+            name: "partner".to_string(),
+            key: "secret".to_string(),
+            quota: Quota {
+                capacity: 1_000_000,
+                refill_per_second: 1_000_000,
+            },
+        }],
+    });
+    config
+}
+
+#[tokio::test]
+async fn rate_limit__exhausted_ip_quota__returns_too_many_requests() {
+    // Given
+    let node = FuelService::new_node(rate_limited_config()).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+    let client = reqwest::Client::new();
+    let query = r#"{ "query": "{ chain { name } }" }"#;
+    let first = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .body(query)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(first.status(), 200);
+
+    // When
+    let second = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .body(query)
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(second.status(), 429);
+    assert_eq!(second.headers()["Retry-After"], "1");
+    let body = second.text().await.unwrap();
+    assert!(body.contains("Rate limit exceeded"), "{}", body);
+}
+
+#[tokio::test]
+async fn rate_limit__api_key__uses_own_quota() {
+    // Given
+    let node = FuelService::new_node(rate_limited_config()).await.unwrap();
+    let mut client = FuelClient::from(node.bound_address);
+    client.with_api_key("secret");
+
+    // When
+    for _ in 0..10 {
+        let result = client.chain_info().await;
+
+        // Then
+        assert!(result.is_ok(), "{:?}", result);
+    }
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
 async fn concurrency_limit_1_prevents_concurrent_queries() {
     // Given
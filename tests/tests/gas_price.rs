@@ -250,6 +250,75 @@ async fn produce_block__raises_gas_price() {
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn gas_price_history__returns_prices_fullness_and_tips_of_blocks() {
+    // given
+    let block_gas_limit = 3_000_000;
+    let chain_config = ChainConfig {
+        consensus_parameters: ConsensusParameters::V1(ConsensusParametersV1 {
+            block_gas_limit,
+            ..Default::default()
+        }),
+        ..ChainConfig::local_testnet()
+    };
+    let mut node_config =
+        Config::local_node_with_configs(chain_config, StateConfig::local_testnet());
+    node_config.block_producer.coinbase_recipient = Some([5; 32].into());
+    node_config.gas_price_config.starting_exec_gas_price = 1_000_000_000;
+    node_config.block_production = Trigger::Never;
+
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2322u64);
+
+    let arb_tx_count = 4;
+    for i in 0..arb_tx_count {
+        let tx = arb_large_tx(18902800 + i as Word, &mut rng, None);
+        let _status = client.submit(&tx).await.unwrap();
+    }
+    let _ = client.produce_blocks(2, None).await.unwrap();
+
+    // when
+    let history = client
+        .gas_price_history(1.into(), 2.into(), &[0, 50, 100])
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(history.len(), 2);
+    let first = &history[0];
+    assert_eq!(first.block_height, 1.into());
+    assert_eq!(first.gas_capacity, block_gas_limit);
+    assert!(first.gas_used > 0);
+    assert_eq!(first.tip_percentiles, vec![0, 0, 0]);
+
+    let second = &history[1];
+    assert_eq!(second.block_height, 2.into());
+    let latest = client.latest_gas_price().await.unwrap();
+    assert_eq!(second.exec_gas_price, latest.gas_price);
+}
+
+#[tokio::test]
+async fn gas_price_history__clamps_range_to_latest_block() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.block_production = Trigger::Never;
+
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let _ = client.produce_blocks(2, None).await.unwrap();
+
+    // when
+    let history = client
+        .gas_price_history(1.into(), u32::MAX.into(), &[])
+        .await
+        .unwrap();
+
+    // then
+    let heights: Vec<_> = history.iter().map(|block| block.block_height).collect();
+    assert_eq!(heights, vec![1.into(), 2.into()]);
+}
+
 #[tokio::test]
 async fn produce_block__lowers_gas_price() {
     // given
@@ -73,6 +73,8 @@ mod tx;
 #[cfg(not(feature = "only-p2p"))]
 mod vm_storage;
 
+#[cfg(feature = "only-p2p")]
+mod bft;
 #[cfg(feature = "only-p2p")]
 mod preconfirmations_gossip;
 #[cfg(feature = "only-p2p")]
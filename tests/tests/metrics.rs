@@ -94,3 +94,60 @@ async fn metrics_include_real_query_name_instead_of_alias() {
         .unwrap();
     assert!(!resp.contains(ALIAS))
 }
+
+#[tokio::test]
+async fn metrics_include_complexity_per_top_level_field() {
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+
+    // Given
+    let query = r#"
+        query {
+          nodeInfo {
+            nodeVersion
+          }
+        }
+    "#;
+
+    // When
+    send_graph_ql_query(&url, query).await;
+
+    // Then
+    let resp = reqwest::get(format!("http://{}/v1/metrics", node.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(resp.contains(r#"graphql_field_complexity_count{field="nodeInfo"}"#));
+}
+
+#[tokio::test]
+async fn metrics_count_rejected_queries_by_reason() {
+    let mut config = Config::local_node();
+    config.graphql_config.max_queries_complexity = 1;
+    let node = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+
+    // Given
+    let query = r#"
+        query {
+          chain {
+            name
+          }
+        }
+    "#;
+
+    // When
+    let result = send_graph_ql_query(&url, query).await;
+
+    // Then
+    assert!(result.contains("Query is too complex."));
+    let resp = reqwest::get(format!("http://{}/v1/metrics", node.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(resp.contains(r#"graphql_rejected_queries_total{reason="complexity"}"#));
+}
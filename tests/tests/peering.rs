@@ -1,18 +1,25 @@
 #![allow(non_snake_case)]
 
-use fuel_core::p2p_test_helpers::{
-    BootstrapSetup,
-    BootstrapType,
-    CustomizeConfig,
-    Nodes,
-    ProducerSetup,
-    ValidatorSetup,
-    make_nodes,
+use fuel_core::{
+    p2p_test_helpers::{
+        BootstrapSetup,
+        BootstrapType,
+        CustomizeConfig,
+        Nodes,
+        ProducerSetup,
+        ValidatorSetup,
+        make_nodes,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
     fuel_crypto::SecretKey,
     fuel_tx::Input,
+    services::p2p::PeerId,
 };
 use rand::{
     SeedableRng,
@@ -112,3 +119,36 @@ async fn max_functional_peers_connected__nodes_will_discover_new_peers_if_first_
         assert_eq!(latest_block_height, new_blocks);
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ban_peer__ban_is_kept_after_restart_until_unbanned() {
+    const ADMIN_TOKEN: &str = "admin-token";
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = Config::local_node();
+    config.graphql_config.admin_token = Some(ADMIN_TOKEN.to_string());
+    config.p2p.as_mut().unwrap().reputation_path =
+        Some(dir.path().join("peer_reputation.json"));
+    let peer_id = PeerId::from(fuel_core_p2p::PeerId::random().to_bytes());
+
+    // given
+    let node = FuelService::new_node(config.clone()).await.unwrap();
+    let mut client = FuelClient::from(node.bound_address);
+    client.with_admin_token(ADMIN_TOKEN);
+    let banned = client.ban_peer(&peer_id, "spam", None).await.unwrap();
+    assert_eq!(banned.len(), 1);
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+
+    // when
+    let node = FuelService::new_node(config).await.unwrap();
+    let mut client = FuelClient::from(node.bound_address);
+    client.with_admin_token(ADMIN_TOKEN);
+    let banned_after_restart = client.banned_peers().await.unwrap();
+    let banned_after_unban = client.unban_peer(&peer_id).await.unwrap();
+
+    // then
+    assert_eq!(banned_after_restart.len(), 1);
+    assert_eq!(banned_after_restart[0].id, peer_id);
+    assert_eq!(banned_after_restart[0].reason, "spam");
+    assert_eq!(banned_after_restart[0].expires_at, None);
+    assert!(banned_after_unban.is_empty());
+}
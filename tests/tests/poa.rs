@@ -307,6 +307,9 @@ mod p2p {
             ConsensusConfig::PoAV2(poa) => {
                 poa.set_genesis_signing_key(key);
             }
+            ConsensusConfig::Bft(_) => {
+                unreachable!("The test uses the PoA consensus")
+            }
         }
         config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
     }
@@ -121,3 +121,69 @@ async fn contract_storage_values_create_tx() {
             .collect_vec()
     );
 }
+
+#[tokio::test]
+async fn contract_slot_proof__verifies_against_the_root_at_height() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Instant;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let initial_storage_slots: Vec<StorageSlot> = (0..10)
+        .map(|i| StorageSlot::new(Bytes32::new([i; 32]), Bytes32::new([i; 32])))
+        .collect();
+    let create: Create = TransactionBuilder::create(
+        vec![].into(),
+        Default::default(),
+        initial_storage_slots.clone(),
+    )
+    .add_contract_created()
+    .add_fee_input()
+    .finalize();
+    let contract_id = *create.outputs()[0].contract_id().unwrap();
+    let slot = &initial_storage_slots[3];
+    let missing_key = Bytes32::new([200; 32]);
+
+    let height_before = client.produce_blocks(1, None).await.unwrap();
+    let TransactionStatus::Success {
+        block_height: height_after,
+        ..
+    } = client
+        .submit_and_await_commit(&create.into())
+        .await
+        .unwrap()
+    else {
+        panic!("Failed to send tx");
+    };
+
+    // When
+    let proof_before = client
+        .contract_slot_proof(&contract_id, slot.key(), Some(height_before))
+        .await
+        .unwrap();
+    let proof_after = client
+        .contract_slot_proof(&contract_id, slot.key(), Some(height_after))
+        .await
+        .unwrap();
+    let missing_proof = client
+        .contract_slot_proof(&contract_id, &missing_key, Some(height_after))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(proof_before.value, None);
+    assert!(proof_before.verify_slot(&contract_id, slot.key()));
+
+    assert_eq!(proof_after.value, Some(slot.value().as_ref().to_vec()));
+    assert!(proof_after.verify_slot(&contract_id, slot.key()));
+    assert!(!proof_after.verify_slot(&contract_id, &missing_key));
+    let mut tampered = proof_after.clone();
+    tampered.value = Some(vec![1; 32]);
+    assert!(!tampered.verify_slot(&contract_id, slot.key()));
+
+    assert_eq!(missing_proof.root, proof_after.root);
+    assert_eq!(missing_proof.value, None);
+    assert!(missing_proof.verify_slot(&contract_id, &missing_key));
+}
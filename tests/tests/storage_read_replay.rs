@@ -1,11 +1,21 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    service::{
+        Config,
+        FuelService,
+    },
+    upgradable_executor::{
+        executor::Executor,
+        replay::{
+            RelayerEventsReplay,
+            StorageReadReplay,
+        },
+    },
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_storage::{
     column::Column,
     kv_store::StorageColumn,
+    transactional::AtomicView,
 };
 use fuel_core_types::{
     fuel_tx::Bytes32,
@@ -204,3 +214,45 @@ async fn dry_run__storage_read_replay__multiple_dry_runs_keep_original_reads() {
         );
     }
 }
+
+/// The recorded storage reads are enough to re-execute the block without the database.
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_read_replay__block_can_be_validated_from_recorded_reads() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0xBAADF00D);
+
+    // given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let (_, contract_id) = counter_contract::deploy(&client, &mut rng).await;
+    let (block_height, _) =
+        counter_contract::increment(&client, &mut rng, contract_id).await;
+
+    let storage_reads = client
+        .storage_read_replay(&block_height)
+        .await
+        .expect("Failed to replay storage read");
+    let block = srv
+        .shared
+        .database
+        .on_chain()
+        .latest_view()
+        .unwrap()
+        .get_full_block(&block_height)
+        .unwrap()
+        .unwrap();
+
+    // when
+    let executor = Executor::native(
+        StorageReadReplay::new(storage_reads),
+        RelayerEventsReplay::default(),
+        Default::default(),
+    );
+    let result = executor.validate(&block);
+
+    // then
+    let result = result.expect("Replay of the block failed").into_result();
+    assert_eq!(result.tx_status.len(), block.transactions().len());
+}
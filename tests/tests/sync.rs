@@ -1,9 +1,15 @@
 #![allow(unexpected_cfgs)] // for cfg(coverage)
 
-use fuel_core::p2p_test_helpers::*;
+use fuel_core::{
+    p2p_test_helpers::*,
+    sync::state_sync,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_crypto::SecretKey,
     fuel_tx::Input,
+    signer::SignMode,
 };
 use rand::{
     SeedableRng,
@@ -18,6 +24,8 @@ use std::{
         Hash,
         Hasher,
     },
+    num::NonZeroU32,
+    time::Duration,
 };
 use test_case::test_case;
 
@@ -332,3 +340,96 @@ async fn test_multiple_producers_same_key() {
         v.consistency_10s(&expected).await;
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn snap_sync__fresh_node_imports_checkpoint_and_continues_with_blocks() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Given
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let every_block = NonZeroU32::new(1).unwrap();
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new_with_overrides(
+                secret,
+                CustomizeConfig::no_overrides().state_checkpoint_interval(every_block),
+            )
+            .with_txs(5)
+            .with_name("Alice"),
+        )],
+        [Some(
+            ValidatorSetup::new_with_overrides(
+                pub_key,
+                CustomizeConfig::no_overrides().state_checkpoint_interval(every_block),
+            )
+            .with_name("Bob"),
+        )],
+        None,
+    )
+    .await;
+    let mut producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    validator.consistency_20s(&expected).await;
+
+    // The operator gets the root of the checkpoint from the trusted node.
+    let network = validator.node.shared.network.clone().unwrap();
+    let checkpoint = tokio::time::timeout(Duration::from_secs(20), async {
+        loop {
+            if let Ok((_, Some(checkpoint))) = network.get_state_checkpoint().await {
+                break checkpoint
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The checkpoint should be served by the peers");
+    let checkpoint_height = checkpoint.height();
+
+    // When
+    let mut node_config = make_config(
+        "Carol".to_string(),
+        producer.config.clone(),
+        CustomizeConfig::no_overrides(),
+    );
+    node_config.block_production = Trigger::Never;
+    node_config.consensus_signer = SignMode::Unavailable;
+    node_config.p2p.as_mut().unwrap().bootstrap_nodes = bootstrap_nodes
+        .iter()
+        .flat_map(|bootstrap| bootstrap.listeners())
+        .collect();
+    node_config.snap_sync = Some(state_sync::Config {
+        timeout: Duration::from_secs(20),
+        ..state_sync::Config::new(checkpoint.root())
+    });
+    let fresh_node = make_node(node_config, vec![]).await;
+
+    // Then
+    let on_chain = fresh_node.node.shared.database.on_chain();
+    assert_eq!(on_chain.latest_height().unwrap(), checkpoint_height);
+    assert!(
+        on_chain
+            .get_sealed_block_by_height(&1u32.into())
+            .unwrap()
+            .is_none(),
+        "The blocks before the checkpoint should not be synced"
+    );
+
+    // And the node continues with the blocks produced after the checkpoint.
+    let client = FuelClient::from(producer.node.bound_address);
+    let produced_height = client.produce_blocks(3, None).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(20), async {
+        while on_chain.latest_height().unwrap() < produced_height {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The node should import the blocks produced after the checkpoint");
+}
@@ -17,6 +17,7 @@ use fuel_core_client::client::{
         PaginationRequest,
     },
     types::{
+        LogFilter,
         StatusWithTransaction,
         TransactionStatus,
     },
@@ -26,6 +27,7 @@ use fuel_core_poa::{
     service::Mode,
 };
 use fuel_core_types::{
+    entities::coins::coin::Coin,
     fuel_asm::{
         RegId,
         op,
@@ -38,7 +40,11 @@ use fuel_core_types::{
     },
     fuel_types::ChainId,
     fuel_vm::ProgramState,
-    services::executor::TransactionExecutionResult,
+    services::executor::{
+        DryRunOverrides,
+        StateOverrides,
+        TransactionExecutionResult,
+    },
     tai64::Tai64,
 };
 use futures::StreamExt;
@@ -441,6 +447,116 @@ async fn dry_run_transaction_should_use_latest_block_time() {
     assert_eq!(expected_returned_timestamp, returned_timestamp);
 }
 
+#[tokio::test]
+async fn dry_run_with_overrides__uses_overridden_block_height_and_timestamp() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::bhei(0x10),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::time(0x11, 0x10),
+        op::ret(0x11),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+
+    let block_height = 1000u32;
+    let timestamp = Tai64::from_unix(1337);
+    let overrides = DryRunOverrides {
+        block_height: Some(block_height.into()),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+
+    // When
+    let tx_statuses = client
+        .dry_run_with_overrides(&[tx], None, None, None, overrides)
+        .await
+        .unwrap();
+
+    // Then
+    let receipts = tx_statuses[0].result.receipts();
+    assert!(matches!(
+        receipts[0],
+        Receipt::Log { ra, .. } if ra == u64::from(block_height)
+    ));
+    assert!(matches!(
+        receipts[1],
+        Receipt::Return { val, .. } if val == timestamp.0
+    ));
+
+    // the overrides don't affect the chain
+    let chain_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(chain_height, 0);
+}
+
+#[tokio::test]
+async fn dry_run_with_overrides__spends_overridden_coin() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let coin = Coin {
+        utxo_id: rng.r#gen(),
+        owner: Input::owner(&secret.public_key()),
+        amount: 1_000_000,
+        asset_id: AssetId::default(),
+        tx_pointer: Default::default(),
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            coin.utxo_id,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+        )
+        .finalize_as_transaction();
+    let result_without_overrides = client
+        .dry_run_opt(&[tx.clone()], Some(true), None, None)
+        .await;
+    assert!(result_without_overrides.is_err());
+
+    let overrides = DryRunOverrides {
+        state: StateOverrides {
+            coins: vec![coin.clone()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // When
+    let tx_statuses = client
+        .dry_run_with_overrides(&[tx], Some(true), None, None, overrides)
+        .await
+        .unwrap();
+
+    // Then
+    assert!(matches!(
+        tx_statuses[0].result,
+        TransactionExecutionResult::Success { .. }
+    ));
+    let coin_in_chain = client.coin(&coin.utxo_id).await.unwrap();
+    assert!(coin_in_chain.is_none());
+}
+
 #[tokio::test]
 async fn receipts() {
     let transaction = Transaction::default_test_tx();
@@ -1066,6 +1182,116 @@ async fn pending_pool_returns_error_after_timeout_for_transaction_that_spends_al
     client.submit_and_await_commit(&transfer).await.unwrap_err();
 }
 
+#[tokio::test]
+async fn logs_returns_script_logs_filtered_by_log_id() {
+    let srv = FuelService::new_node(config_with_fee()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let mut tx_ids = vec![];
+    for log_id in [0xba, 0xbb, 0xba] {
+        let script = vec![
+            op::addi(0x10, RegId::ZERO, 0xca),
+            op::addi(0x11, RegId::ZERO, log_id),
+            op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ];
+        let tx = client
+            .assemble_script(script, vec![], default_signing_wallet())
+            .await
+            .unwrap();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        tx_ids.push(tx.id(&ChainId::default()));
+    }
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // When
+    let filter = LogFilter {
+        // The logs emitted by the script are attributed to the zero contract id.
+        contracts: vec![ContractId::zeroed()],
+        log_ids: Some(vec![0xba]),
+        ..Default::default()
+    };
+    let logs = client.logs(filter, request.clone()).await.unwrap().results;
+
+    // Then
+    let logged_txs = logs.iter().map(|log| log.transaction_id).collect_vec();
+    assert_eq!(logged_txs, vec![tx_ids[0], tx_ids[2]]);
+    assert!(logs.iter().all(|log| log.log_id == 0xba));
+    assert!(matches!(
+        logs[0].receipt,
+        Receipt::Log {
+            ra: 0xca,
+            rb: 0xba,
+            ..
+        }
+    ));
+
+    // When
+    let filter = LogFilter {
+        contracts: vec![ContractId::zeroed()],
+        ..Default::default()
+    };
+    let logs = client.logs(filter, request).await.unwrap().results;
+
+    // Then
+    let logged_txs = logs.iter().map(|log| log.transaction_id).collect_vec();
+    assert_eq!(logged_txs, tx_ids);
+}
+
+#[tokio::test]
+async fn logs_returns_all_log_ids_of_contract_page_by_page() {
+    let srv = FuelService::new_node(config_with_fee()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    // More log ids than the number of the `(contract, log id)` pairs allowed in the filter.
+    let log_ids = 0..150u16;
+    let mut script = log_ids
+        .clone()
+        .flat_map(|log_id| {
+            [
+                op::addi(0x11, RegId::ZERO, log_id),
+                op::log(RegId::ZERO, 0x11, RegId::ZERO, RegId::ZERO),
+            ]
+        })
+        .collect_vec();
+    script.push(op::ret(RegId::ONE));
+    let tx = client
+        .assemble_script(script, vec![], default_signing_wallet())
+        .await
+        .unwrap();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let filter = LogFilter {
+        contracts: vec![ContractId::zeroed()],
+        ..Default::default()
+    };
+
+    // When
+    let mut logged_ids = vec![];
+    let mut cursor = None;
+    loop {
+        let request = PaginationRequest {
+            cursor,
+            results: 50,
+            direction: PageDirection::Forward,
+        };
+        let page = client.logs(filter.clone(), request).await.unwrap();
+        logged_ids.extend(page.results.iter().map(|log| log.log_id));
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    // Then
+    assert_eq!(logged_ids, log_ids.map(u64::from).collect_vec());
+}
+
 // add random val for unique tx
 fn create_mock_tx(val: u64) -> Transaction {
     let mut rng = StdRng::seed_from_u64(val);
@@ -4,10 +4,16 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
-use fuel_core::service::FuelService;
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
 use fuel_core_client::client::{
     FuelClient,
-    types::TransactionStatus,
+    types::{
+        Blacklist,
+        TransactionStatus,
+    },
 };
 use fuel_core_poa::Trigger;
 use fuel_core_types::{
@@ -185,3 +191,152 @@ async fn informs_immediately_if_the_input_was_spent_during_previous_blocks_witho
     let err = status.expect_err("Should receive error that transaction squeezed out");
     assert!(err.to_string().contains("was already spent"))
 }
+
+const ADMIN_TOKEN: &str = "admin-token";
+
+fn config_with_admin_token() -> Config {
+    let mut config = Config::local_node();
+    config.utxo_validation = false;
+    config.txpool.utxo_validation = false;
+    config.block_production = Trigger::Never;
+    config.graphql_config.admin_token = Some(ADMIN_TOKEN.to_string());
+    config
+}
+
+#[tokio::test]
+async fn blacklist__requires_admin_token() {
+    // Given
+    let srv = FuelService::new_node(config_with_admin_token())
+        .await
+        .unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+
+    // When
+    let without_token = client.blacklist().await;
+    client.with_admin_token("wrong-token");
+    let with_wrong_token = client.blacklist().await;
+    client.with_admin_token(ADMIN_TOKEN);
+    let with_token = client.blacklist().await;
+
+    // Then
+    assert!(without_token.is_err());
+    assert!(with_wrong_token.is_err());
+    assert_eq!(with_token.unwrap(), Blacklist::default());
+}
+
+#[tokio::test]
+async fn blacklist_add__evicts_pooled_transaction_of_blacklisted_owner() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(config_with_admin_token())
+        .await
+        .unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_admin_token(ADMIN_TOKEN);
+
+    // Given
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        secret,
+        rng.r#gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let tx_id = tx.id(&Default::default());
+    client.submit(&tx).await.unwrap();
+
+    // When
+    let blacklist = client
+        .blacklist_add(Blacklist {
+            owners: vec![owner],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(blacklist.owners, vec![owner]);
+    let status = client.transaction_status(&tx_id).await.unwrap();
+    assert!(
+        matches!(&status, TransactionStatus::SqueezedOut { reason } if reason.contains("blacklist")),
+        "{status:?}"
+    );
+    let resubmit = client.submit(&tx).await;
+    assert!(resubmit.is_err());
+}
+
+#[tokio::test]
+async fn blacklist_remove__allows_transaction_of_removed_owner() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(config_with_admin_token())
+        .await
+        .unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_admin_token(ADMIN_TOKEN);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        secret,
+        rng.r#gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    let entries = Blacklist {
+        owners: vec![owner],
+        ..Default::default()
+    };
+    client.blacklist_add(entries.clone()).await.unwrap();
+    assert!(client.submit(&tx).await.is_err());
+
+    // Given
+    let blacklist = client.blacklist_remove(entries).await.unwrap();
+    assert_eq!(blacklist, Blacklist::default());
+
+    // When
+    let result = client.submit(&tx).await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn blacklist_add_and_remove__round_trip_all_kinds_of_entries() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(config_with_admin_token())
+        .await
+        .unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_admin_token(ADMIN_TOKEN);
+
+    // Given
+    let entries = Blacklist {
+        owners: vec![rng.r#gen()],
+        coins: vec![rng.r#gen()],
+        messages: vec![rng.r#gen()],
+        contracts: vec![rng.r#gen()],
+    };
+
+    // When
+    let after_add = client.blacklist_add(entries.clone()).await.unwrap();
+    let after_remove = client.blacklist_remove(entries.clone()).await.unwrap();
+
+    // Then
+    assert_eq!(after_add, entries);
+    assert_eq!(after_remove, Blacklist::default());
+    assert_eq!(client.blacklist().await.unwrap(), Blacklist::default());
+}